@@ -10,8 +10,11 @@ use serde_json;
 use std::error::Error;
 use std::fmt;
 
-use crate::syntax::tokenize;
+use crate::syntax::list_detection::determine_decoration_type;
+use crate::syntax::Lexer;
 use crate::syntax::SemanticAnalyzer;
+use crate::syntax::VerbatimScanner;
+pub use crate::syntax::{ColumnUnit, ParseOptions, VerbatimOptions};
 
 #[derive(Debug)]
 pub enum TransformError {
@@ -20,6 +23,8 @@ pub enum TransformError {
     AssemblyError(String),
     SerializationError(String),
     NotImplemented(String),
+    /// A diagnostic rejected by strict parsing
+    Strict(Diagnostic),
 }
 
 impl fmt::Display for TransformError {
@@ -30,6 +35,7 @@ impl fmt::Display for TransformError {
             TransformError::AssemblyError(msg) => write!(f, "Assembly error: {}", msg),
             TransformError::SerializationError(msg) => write!(f, "Serialization error: {}", msg),
             TransformError::NotImplemented(msg) => write!(f, "Not implemented: {}", msg),
+            TransformError::Strict(diagnostic) => write!(f, "Strict mode: {}", diagnostic),
         }
     }
 }
@@ -56,9 +62,16 @@ fn format_element_node(element: &crate::ast::ElementNode) -> String {
     }
 }
 
-use crate::assembly::{AnnotationAttacher, DocumentAssembler};
-use crate::ast::{Document, ElementNode};
+use crate::assembly::document_assembly::duplicate_parameters;
+use crate::assembly::{
+    apply_typography, mark_abstract, suggest_reserved_label, AnnotationAttacher, DocumentAssembler,
+};
+use crate::ast::elements::references::{ReferenceClassifier, SimpleReferenceType};
+use crate::ast::events::{events, DocEvent};
+use crate::ast::{collect_references, find_session_cycles, Diagnostic, Document, ElementNode};
 use crate::cst::{HighLevelTokenList, ScannerToken};
+use crate::semantic::elements::inlines::pipeline::create_standard_pipeline;
+use crate::semantic::elements::paragraph::split_paragraph_lines;
 use crate::semantic::{AstConstructor, InlineParser};
 
 /// Processing stages in the TXXT pipeline (new unified API).
@@ -169,6 +182,14 @@ impl Format {
     }
 }
 
+/// How processed output is formatted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutputOptions {
+    /// Leave empty annotations, parameters and token sequences out of JSON
+    /// output. They deserialize back to their empty defaults.
+    pub compact: bool,
+}
+
 /// Output from processing at a specific stage (new unified API).
 #[derive(Debug, Clone)]
 pub enum Output {
@@ -199,58 +220,356 @@ pub fn process_unified(
     stage: Stage,
     source_path: Option<String>,
 ) -> Result<Output, TransformError> {
-    // Step 1.b: Tokenization
-    let scanner_tokens = tokenize(source);
+    process_unified_with(source, stage, source_path, ParseOptions::default())
+}
+
+/// Process to a specific stage with non-default parse options.
+pub fn process_unified_with(
+    source: &str,
+    stage: Stage,
+    source_path: Option<String>,
+    options: ParseOptions,
+) -> Result<Output, TransformError> {
+    process_with_diagnostics(source, stage, source_path, options, &mut Vec::new())
+}
+
+/// Reusable parser for bulk work
+///
+/// The one-shot functions build the inline pipeline afresh for every
+/// document. A `Parser` builds it once and keeps its diagnostics buffer
+/// between calls, which pays off when parsing many small files.
+pub struct Parser {
+    options: ParseOptions,
+    inline_parser: InlineParser,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parser {
+    /// Create a parser with default parse options
+    pub fn new() -> Self {
+        Self::with_options(ParseOptions::default())
+    }
+
+    /// Create a parser with non-default parse options
+    pub fn with_options(options: ParseOptions) -> Self {
+        Self {
+            options,
+            inline_parser: InlineParser::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Classify references with `classifier`, custom handlers included
+    pub fn with_reference_classifier(mut self, classifier: ReferenceClassifier) -> Self {
+        self.inline_parser = InlineParser::with_reference_classifier(classifier);
+        self
+    }
+
+    /// Fully process `source`, as `run_all_with_options` does
+    pub fn parse_into(&mut self, source: &str) -> Result<Document, TransformError> {
+        self.diagnostics.clear();
+        match run_stages(
+            source,
+            Stage::AstFull,
+            None,
+            self.options,
+            Some(&self.inline_parser),
+            &mut self.diagnostics,
+        )? {
+            Output::AstFull(doc) => Ok(doc),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Diagnostics from the last `parse_into` call
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+}
+
+/// Process to a stage, collecting indentation issues and inline fallbacks
+/// into `diagnostics`
+fn process_with_diagnostics(
+    source: &str,
+    stage: Stage,
+    source_path: Option<String>,
+    options: ParseOptions,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Output, TransformError> {
+    let inline_parser = InlineParser::new();
+    run_stages(
+        source,
+        stage,
+        source_path,
+        options,
+        Some(&inline_parser),
+        diagnostics,
+    )
+}
 
+/// Run the pipeline up to `stage`
+///
+/// Without an inline parser, block content skips Step 2.b and keeps its
+/// text unparsed.
+fn run_stages(
+    source: &str,
+    stage: Stage,
+    source_path: Option<String>,
+    options: ParseOptions,
+    inline_parser: Option<&InlineParser>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Output, TransformError> {
+    let scanner_tokens = lex_step(source, options, diagnostics);
     if stage == Stage::ScannerTokens {
         return Ok(Output::ScannerTokens(scanner_tokens));
     }
 
-    // Step 1.c: Semantic Analysis
-    let semantic_analyzer = SemanticAnalyzer::new();
-    let high_level_tokens = semantic_analyzer
-        .analyze(scanner_tokens)
-        .map_err(|e| TransformError::TokenizationError(e.to_string()))?;
-
+    let high_level_tokens = semantic_analysis_step(scanner_tokens)?;
     if stage == Stage::HighLevelTokens {
         return Ok(Output::HighLevelTokens(high_level_tokens));
     }
 
-    // Step 2.a: AST Construction (blocks only)
-    let ast_blocks = AstConstructor::parse_to_element_nodes(&high_level_tokens)
-        .map_err(|e| TransformError::ParseError(e.to_string()))?;
-
+    let ast_blocks = block_step(&high_level_tokens, options, diagnostics)?;
     if stage == Stage::AstBlock {
         return Ok(Output::AstBlock(ast_blocks));
     }
 
-    // Step 2.b: Inline Parsing
-    let inline_parser = InlineParser::new();
-    let ast_with_inlines = inline_parser
-        .parse_inlines(ast_blocks)
-        .map_err(|e| TransformError::ParseError(e.to_string()))?;
-
+    let ast_with_inlines = inline_step(ast_blocks, inline_parser, diagnostics);
     if stage == Stage::AstInlines {
         return Ok(Output::AstInlines(ast_with_inlines));
     }
 
-    // Step 3.a: Document Assembly
+    let document = assembly_step(ast_with_inlines, source_path, options)?;
+    if stage == Stage::AstDocument {
+        return Ok(Output::AstDocument(document));
+    }
+
+    Ok(Output::AstFull(attachment_step(document)?))
+}
+
+/// Step 1.b: Tokenization
+fn lex_step(
+    source: &str,
+    options: ParseOptions,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Vec<ScannerToken> {
+    let mut lexer = Lexer::with_options(source, options);
+    let scanner_tokens = lexer.tokenize();
+    diagnostics.extend(
+        lexer
+            .indentation_issues()
+            .iter()
+            .cloned()
+            .map(Diagnostic::Indentation),
+    );
+    scanner_tokens
+}
+
+/// Step 1.c: Semantic Analysis
+fn semantic_analysis_step(
+    scanner_tokens: Vec<ScannerToken>,
+) -> Result<HighLevelTokenList, TransformError> {
+    SemanticAnalyzer::new()
+        .analyze(scanner_tokens)
+        .map_err(|e| TransformError::TokenizationError(e.to_string()))
+}
+
+/// Step 2.a: AST Construction (blocks only)
+fn block_step(
+    high_level_tokens: &HighLevelTokenList,
+    options: ParseOptions,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Vec<ElementNode>, TransformError> {
+    let (ast_blocks, block_diagnostics) =
+        AstConstructor::parse_to_element_nodes_with_diagnostics(high_level_tokens, options)
+            .map_err(|e| TransformError::ParseError(e.to_string()))?;
+    diagnostics.extend(block_diagnostics);
+    Ok(ast_blocks)
+}
+
+/// Step 2.b: Inline Parsing
+fn inline_step(
+    ast_blocks: Vec<ElementNode>,
+    inline_parser: Option<&InlineParser>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Vec<ElementNode> {
+    match inline_parser {
+        Some(inline_parser) => {
+            let (ast_with_inlines, inline_diagnostics) =
+                inline_parser.parse_inlines_with_diagnostics(ast_blocks);
+            diagnostics.extend(inline_diagnostics);
+            ast_with_inlines
+        }
+        None => ast_blocks,
+    }
+}
+
+/// Step 3.a: Document Assembly, with the opt-in passes `options` asks for
+fn assembly_step(
+    ast_with_inlines: Vec<ElementNode>,
+    source_path: Option<String>,
+    options: ParseOptions,
+) -> Result<Document, TransformError> {
     let document_assembler = DocumentAssembler::new();
-    let document = document_assembler
+    let mut document = document_assembler
         .assemble_document(ast_with_inlines, source_path)
         .map_err(|e| TransformError::AssemblyError(e.to_string()))?;
-
-    if stage == Stage::AstDocument {
-        return Ok(Output::AstDocument(document));
+    if options.mark_abstract {
+        mark_abstract(&mut document);
+    }
+    if options.typography {
+        apply_typography(&mut document);
     }
+    Ok(document)
+}
 
-    // Step 3.b: Annotation Attachment
-    let annotation_attacher = AnnotationAttacher::new();
-    let full_document = annotation_attacher
+/// Step 3.b: Annotation Attachment
+fn attachment_step(document: Document) -> Result<Document, TransformError> {
+    AnnotationAttacher::new()
         .attach_annotations(document)
-        .map_err(|e| TransformError::AssemblyError(e.to_string()))?;
+        .map_err(|e| TransformError::AssemblyError(e.to_string()))
+}
+
+/// Pipeline that keeps the output of every stage it has run
+///
+/// Tooling that looks at several intermediate representations of one
+/// source (scanner tokens, high-level tokens, the block AST, the document)
+/// asks for each in turn; every stage runs once, from the cached output of
+/// the one before it. The stages are the [`Stage`]s that `process_unified`
+/// stops at.
+pub struct Pipeline {
+    source: String,
+    source_path: Option<String>,
+    options: ParseOptions,
+    inline_parser: InlineParser,
+    diagnostics: Vec<Diagnostic>,
+    scanner_tokens: Option<Vec<ScannerToken>>,
+    high_level_tokens: Option<HighLevelTokenList>,
+    block_ast: Option<Vec<ElementNode>>,
+    inline_ast: Option<Vec<ElementNode>>,
+    assembled: Option<Document>,
+    document: Option<Document>,
+}
+
+// The `to_*` methods fill the caches as they go, hence `&mut self`
+#[allow(clippy::wrong_self_convention)]
+impl Pipeline {
+    /// Create a pipeline over `source` with default parse options
+    pub fn new(source: impl Into<String>) -> Self {
+        Self::with_options(source, ParseOptions::default())
+    }
+
+    /// Create a pipeline over `source` with non-default parse options
+    pub fn with_options(source: impl Into<String>, options: ParseOptions) -> Self {
+        Self {
+            source: source.into(),
+            source_path: None,
+            options,
+            inline_parser: InlineParser::new(),
+            diagnostics: Vec::new(),
+            scanner_tokens: None,
+            high_level_tokens: None,
+            block_ast: None,
+            inline_ast: None,
+            assembled: None,
+            document: None,
+        }
+    }
+
+    /// Record the path the source was read from, for document metadata
+    pub fn with_source_path(mut self, source_path: impl Into<String>) -> Self {
+        self.source_path = Some(source_path.into());
+        self
+    }
+
+    /// Whether the output of `stage` is cached
+    pub fn is_cached(&self, stage: Stage) -> bool {
+        match stage {
+            Stage::ScannerTokens => self.scanner_tokens.is_some(),
+            Stage::HighLevelTokens => self.high_level_tokens.is_some(),
+            Stage::AstBlock => self.block_ast.is_some(),
+            Stage::AstInlines => self.inline_ast.is_some(),
+            Stage::AstDocument => self.assembled.is_some(),
+            Stage::AstFull => self.document.is_some(),
+        }
+    }
+
+    /// Diagnostics from the stages run so far
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Step 1.b output: scanner tokens
+    pub fn to_scanner_tokens(&mut self) -> Result<&[ScannerToken], TransformError> {
+        if self.scanner_tokens.is_none() {
+            self.scanner_tokens = Some(lex_step(&self.source, self.options, &mut self.diagnostics));
+        }
+        Ok(self.scanner_tokens.as_deref().unwrap())
+    }
+
+    /// Step 1.c output: high-level tokens
+    pub fn to_high_level_tokens(&mut self) -> Result<&HighLevelTokenList, TransformError> {
+        if self.high_level_tokens.is_none() {
+            let scanner_tokens = self.to_scanner_tokens()?.to_vec();
+            self.high_level_tokens = Some(semantic_analysis_step(scanner_tokens)?);
+        }
+        Ok(self.high_level_tokens.as_ref().unwrap())
+    }
+
+    /// Step 2.a output: block AST, before inline parsing
+    pub fn to_block_ast(&mut self) -> Result<&[ElementNode], TransformError> {
+        if self.block_ast.is_none() {
+            self.to_high_level_tokens()?;
+            let high_level_tokens = self.high_level_tokens.as_ref().unwrap();
+            self.block_ast = Some(block_step(
+                high_level_tokens,
+                self.options,
+                &mut self.diagnostics,
+            )?);
+        }
+        Ok(self.block_ast.as_deref().unwrap())
+    }
+
+    /// Step 2.b output: AST with inlines parsed
+    pub fn to_inline_ast(&mut self) -> Result<&[ElementNode], TransformError> {
+        if self.inline_ast.is_none() {
+            let ast_blocks = self.to_block_ast()?.to_vec();
+            self.inline_ast = Some(inline_step(
+                ast_blocks,
+                Some(&self.inline_parser),
+                &mut self.diagnostics,
+            ));
+        }
+        Ok(self.inline_ast.as_deref().unwrap())
+    }
+
+    /// Step 3.a output: assembled document, annotations still in content
+    pub fn to_assembled_document(&mut self) -> Result<&Document, TransformError> {
+        if self.assembled.is_none() {
+            let ast_with_inlines = self.to_inline_ast()?.to_vec();
+            self.assembled = Some(assembly_step(
+                ast_with_inlines,
+                self.source_path.clone(),
+                self.options,
+            )?);
+        }
+        Ok(self.assembled.as_ref().unwrap())
+    }
 
-    Ok(Output::AstFull(full_document))
+    /// Step 3.b output: the full document, as `run_all_with_options` returns
+    pub fn to_document(&mut self) -> Result<&Document, TransformError> {
+        if self.document.is_none() {
+            let document = self.to_assembled_document()?.clone();
+            self.document = Some(attachment_step(document)?);
+        }
+        Ok(self.document.as_ref().unwrap())
+    }
 }
 
 /// Convenience function for full processing.
@@ -258,17 +577,373 @@ pub fn run_all_unified(
     source: &str,
     source_path: Option<String>,
 ) -> Result<Document, TransformError> {
-    match process_unified(source, Stage::AstFull, source_path)? {
+    run_all_with_options(source, source_path, ParseOptions::default())
+}
+
+/// Parse block structure only, for outlines and other structural views.
+///
+/// The document is assembled from the block phase (the `ast-block`
+/// stage) without inline parsing: sessions, lists, definitions and the
+/// rest are as a full parse builds them, but their text stays one
+/// `Identity` span per line, with formatting and references unexpanded.
+pub fn parse_blocks_only(source: &str) -> Result<Document, TransformError> {
+    match run_stages(
+        source,
+        Stage::AstFull,
+        None,
+        ParseOptions::default(),
+        None,
+        &mut Vec::new(),
+    )? {
         Output::AstFull(doc) => Ok(doc),
         _ => unreachable!(),
     }
 }
 
+/// Full processing with non-default parse options (e.g. 2-space indentation).
+pub fn run_all_with_options(
+    source: &str,
+    source_path: Option<String>,
+    options: ParseOptions,
+) -> Result<Document, TransformError> {
+    match process_unified_with(source, Stage::AstFull, source_path, options)? {
+        Output::AstFull(doc) => Ok(doc),
+        _ => unreachable!(),
+    }
+}
+
+/// Full processing that reports what the parser glossed over.
+///
+/// Lenient mode (`strict == false`) returns the document with every
+/// diagnostic: indentation mixing tabs and spaces, inline markup kept as
+/// plain text, references of unknown type, session references that form
+/// a cycle, lists mixing marker styles, `Term:` lines with no body,
+/// annotation labels that look like a misspelled reserved label, and
+/// truncated annotations or verbatim blocks. Strict mode, meant for CI
+/// validation, fails with the first diagnostic in source order.
+pub fn run_all_checked(
+    source: &str,
+    source_path: Option<String>,
+    strict: bool,
+) -> Result<(Document, Vec<Diagnostic>), TransformError> {
+    let mut diagnostics = Vec::new();
+    let document = match process_with_diagnostics(
+        source,
+        Stage::AstFull,
+        source_path,
+        ParseOptions::default(),
+        &mut diagnostics,
+    )? {
+        Output::AstFull(doc) => doc,
+        _ => unreachable!(),
+    };
+
+    diagnostics.extend(unresolved_references(&document));
+    diagnostics.extend(find_session_cycles(&document).into_iter().map(|cycle| {
+        Diagnostic::ReferenceCycle {
+            sessions: cycle.sessions,
+            line: cycle.span.start.row + 1,
+        }
+    }));
+    diagnostics.extend(mixed_list_styles(&document));
+    diagnostics.extend(unknown_annotation_labels(&document));
+    diagnostics.extend(
+        document
+            .assembly_info
+            .warnings
+            .iter()
+            .cloned()
+            .map(Diagnostic::Recovery),
+    );
+    diagnostics.sort_by_key(Diagnostic::line);
+
+    if strict && !diagnostics.is_empty() {
+        return Err(TransformError::Strict(diagnostics.remove(0)));
+    }
+
+    Ok((document, diagnostics))
+}
+
+/// A parsed document with every diagnostic found on the way
+#[derive(Debug, Clone)]
+pub struct ParseResult {
+    pub document: Document,
+
+    /// Diagnostics from every phase, in source order
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Parse leniently, collecting diagnostics from all phases in one place.
+///
+/// Lexing, block construction, inline parsing, assembly and reference
+/// checks each contribute what `run_all_checked` reports; each diagnostic
+/// carries its `severity()`, `code()`, line and, where known, `span()`,
+/// and displays as its message. Only input the pipeline cannot process at
+/// all is an error.
+pub fn parse(source: &str) -> Result<ParseResult, TransformError> {
+    let (document, diagnostics) = run_all_checked(source, None, false)?;
+    Ok(ParseResult {
+        document,
+        diagnostics,
+    })
+}
+
+/// Which checks [`validate_with`] runs; every check is on by default
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationConfig {
+    /// Parameter keys given more than once on the same element
+    pub duplicate_parameters: bool,
+
+    /// `Term:` lines left without an indented body
+    pub empty_definitions: bool,
+
+    /// Formatting delimiters with no partner (see [`lint_unmatched_delimiters`])
+    pub unmatched_delimiters: bool,
+
+    /// References whose target type could not be determined
+    pub unresolved_references: bool,
+
+    /// Lists whose items switch marker style partway through
+    pub conflicting_list_styles: bool,
+}
+
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        Self {
+            duplicate_parameters: true,
+            empty_definitions: true,
+            unmatched_delimiters: true,
+            unresolved_references: true,
+            conflicting_list_styles: true,
+        }
+    }
+}
+
+/// Check an already parsed document for structural problems.
+///
+/// Runs every check in [`ValidationConfig`]; see [`validate_with`].
+pub fn validate(document: &Document) -> Vec<Diagnostic> {
+    validate_with(document, &ValidationConfig::default())
+}
+
+/// Check an already parsed document, running only the enabled checks.
+///
+/// Unlike `run_all_checked`, this works from the document alone, so it
+/// also covers documents that were built, transformed or deserialized
+/// rather than parsed: repeated parameter keys are found again from each
+/// parameter list's tokens rather than taken from the assembly warnings.
+/// Diagnostics come back in source order.
+pub fn validate_with(document: &Document, config: &ValidationConfig) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    if config.duplicate_parameters {
+        diagnostics.extend(
+            duplicate_parameters(document)
+                .into_iter()
+                .map(Diagnostic::Recovery),
+        );
+    }
+    if config.empty_definitions {
+        diagnostics.extend(empty_definition_bodies(document));
+    }
+    if config.unmatched_delimiters {
+        diagnostics.extend(lint_unmatched_delimiters(document));
+    }
+    if config.unresolved_references {
+        diagnostics.extend(unresolved_references(document));
+    }
+    if config.conflicting_list_styles {
+        diagnostics.extend(mixed_list_styles(document));
+    }
+    diagnostics.sort_by_key(Diagnostic::line);
+    diagnostics
+}
+
+/// Report references whose target type could not be determined
+fn unresolved_references(document: &Document) -> Vec<Diagnostic> {
+    collect_references(document)
+        .into_iter()
+        .filter(|occurrence| occurrence.kind == SimpleReferenceType::NotSure)
+        .map(|occurrence| Diagnostic::UnresolvedReference {
+            target: occurrence.target,
+            line: occurrence.span.start.row + 1,
+            span: occurrence.span,
+        })
+        .collect()
+}
+
+/// Report one-line `Term:` paragraphs, as AST construction does
+///
+/// A term followed by indented content would have become a definition or
+/// session, so a lone colon line left as a paragraph lost its body; one
+/// directly followed by a list introduces the list and is left alone.
+fn empty_definition_bodies(document: &Document) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut pending: Option<Diagnostic> = None;
+    for event in events(document) {
+        match event {
+            DocEvent::EnterList(_) => {
+                pending = None;
+            }
+            DocEvent::EnterParagraph(paragraph) => {
+                diagnostics.extend(pending.take());
+                let text = paragraph.text_content();
+                let is_term = text.trim_end().ends_with(':')
+                    && split_paragraph_lines(&paragraph.tokens.tokens).len() == 1;
+                if let (true, Some(token)) = (is_term, paragraph.tokens.tokens.first()) {
+                    pending = Some(Diagnostic::EmptyDefinitionBody {
+                        term: text.trim_end().trim_end_matches(':').trim().to_string(),
+                        line: token.span().start.row + 1,
+                    });
+                }
+            }
+            DocEvent::ExitParagraph | DocEvent::Text(_) => {}
+            _ => diagnostics.extend(pending.take()),
+        }
+    }
+    diagnostics.extend(pending);
+    diagnostics
+}
+
+/// Report list items whose marker style differs from their list's first item
+///
+/// The list keeps the first item's decoration either way; a switch from
+/// `1.` to `b)` halfway through is more likely a typo than a new list.
+fn mixed_list_styles(document: &Document) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for event in events(document) {
+        let DocEvent::EnterList(list) = event else {
+            continue;
+        };
+        let Some((first, rest)) = list.items.split_first() else {
+            continue;
+        };
+        let style = determine_decoration_type(&first.marker).style;
+        for item in rest {
+            if determine_decoration_type(&item.marker).style == style {
+                continue;
+            }
+            let Some(token) = item.tokens.tokens.first() else {
+                continue;
+            };
+            diagnostics.push(Diagnostic::MixedListStyle {
+                line: token.span().start.row + 1,
+                expected: first.marker.trim().to_string(),
+                found: item.marker.trim().to_string(),
+            });
+        }
+    }
+    diagnostics
+}
+
+/// Report annotation labels that look like a misspelled reserved label
+///
+/// `:: titel ::` is a valid custom annotation, so it is kept as one; the
+/// diagnostic only points out the reserved label it was probably meant to
+/// be.
+fn unknown_annotation_labels(document: &Document) -> Vec<Diagnostic> {
+    events(document)
+        .filter_map(|event| {
+            let DocEvent::EnterAnnotation(annotation) = event else {
+                return None;
+            };
+            if annotation.namespace.is_some() {
+                return None;
+            }
+            let suggestion = suggest_reserved_label(&annotation.name)?;
+            let token = annotation.tokens.tokens.first()?;
+            Some(Diagnostic::UnknownAnnotationLabel {
+                got: annotation.name.clone(),
+                suggestion: suggestion.to_string(),
+                line: token.span().start.row + 1,
+            })
+        })
+        .collect()
+}
+
+/// Report every line longer than `max` columns.
+///
+/// Columns are counted in characters. Verbatim content is exempt, since
+/// code and other preformatted lines are often long on purpose; verbatim
+/// titles and terminators are checked like any other line.
+pub fn lint_line_length(source: &str, max: usize) -> Vec<Diagnostic> {
+    let verbatim_lines: Vec<(usize, usize)> = VerbatimScanner::new()
+        .scan_boundaries(source)
+        .iter()
+        .filter_map(|boundary| Some((boundary.content_start?, boundary.content_end?)))
+        .collect();
+
+    source
+        .lines()
+        .enumerate()
+        .map(|(index, text)| (index + 1, text.chars().count()))
+        .filter(|&(line, length)| {
+            length > max
+                && !verbatim_lines
+                    .iter()
+                    .any(|&(start, end)| (start..=end).contains(&line))
+        })
+        .map(|(line, length)| Diagnostic::LineTooLong { line, length, max })
+        .collect()
+}
+
+/// Report formatting delimiters that open or close nothing.
+///
+/// `a* b` parses leniently as plain text; this points at the stray `*`.
+/// Paragraph lines and table cells are checked one at a time, as the
+/// inline parser sees them. Only formatting delimiters count: the
+/// brackets and parens of references and links are left alone.
+pub fn lint_unmatched_delimiters(document: &Document) -> Vec<Diagnostic> {
+    let pipeline = create_standard_pipeline();
+    let mut diagnostics = Vec::new();
+    let mut check = |tokens: &[ScannerToken]| {
+        diagnostics.extend(
+            pipeline
+                .unmatched_delimiters(tokens)
+                .into_iter()
+                .filter(|&(matcher, _)| matches!(matcher, "bold" | "italic" | "code" | "math"))
+                .map(|(_, token)| Diagnostic::UnmatchedDelimiter {
+                    delimiter: token.content().to_string(),
+                    line: token.span().start.row + 1,
+                    span: token.span().clone(),
+                }),
+        )
+    };
+
+    for event in events(document) {
+        match event {
+            DocEvent::EnterParagraph(paragraph) => {
+                for line in split_paragraph_lines(&paragraph.tokens.tokens) {
+                    check(&line.content);
+                }
+            }
+            DocEvent::Table(table) => {
+                for row in table.header.iter().chain(&table.rows) {
+                    for cell in &row.cells {
+                        check(&cell.tokens.tokens);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    diagnostics
+}
+
 /// Format processed output for display (new unified API).
 pub fn format_output_unified(
     output: &Output,
     format: Format,
     source_path: Option<&str>,
+) -> Result<String, TransformError> {
+    format_output_unified_with(output, format, source_path, OutputOptions::default())
+}
+
+/// Format processed output for display with non-default output options.
+pub fn format_output_unified_with(
+    output: &Output,
+    format: Format,
+    source_path: Option<&str>,
+    options: OutputOptions,
 ) -> Result<String, TransformError> {
     if !output.stage().supports_format(format) {
         return Err(TransformError::NotImplemented(format!(
@@ -279,7 +954,7 @@ pub fn format_output_unified(
     }
 
     match format {
-        Format::Json => format_as_json_unified(output, source_path),
+        Format::Json => format_as_json_unified(output, source_path, options),
         Format::TreeViz => format_as_treeviz_unified(output, source_path),
     }
 }
@@ -287,10 +962,11 @@ pub fn format_output_unified(
 fn format_as_json_unified(
     output: &Output,
     source_path: Option<&str>,
+    options: OutputOptions,
 ) -> Result<String, TransformError> {
     let source = source_path.unwrap_or("(no source)");
 
-    let json_value = match output {
+    let mut json_value = match output {
         Output::ScannerTokens(tokens) => {
             serde_json::json!({
                 "source": source,
@@ -341,10 +1017,41 @@ fn format_as_json_unified(
         }
     };
 
+    if options.compact {
+        omit_empty_defaults(&mut json_value);
+    }
+
     serde_json::to_string_pretty(&json_value)
         .map_err(|e| TransformError::SerializationError(e.to_string()))
 }
 
+/// Fields compact output drops when empty; the AST defaults them when read
+const DEFAULTED_FIELDS: &[&str] = &["annotations", "parameters", "tokens"];
+
+/// Remove every defaulted field whose value is empty, innermost first
+fn omit_empty_defaults(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Array(items) => items.iter_mut().for_each(omit_empty_defaults),
+        serde_json::Value::Object(fields) => {
+            fields.values_mut().for_each(omit_empty_defaults);
+            fields.retain(|key, value| {
+                !(DEFAULTED_FIELDS.contains(&key.as_str()) && is_empty_value(value))
+            });
+        }
+        _ => {}
+    }
+}
+
+/// An empty array or map, or a map of only empty values, such as empty
+/// parameters once their token sequence has been dropped
+fn is_empty_value(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Array(items) => items.is_empty(),
+        serde_json::Value::Object(fields) => fields.values().all(is_empty_value),
+        _ => false,
+    }
+}
+
 fn format_as_treeviz_unified(
     output: &Output,
     source_path: Option<&str>,
@@ -0,0 +1,48 @@
+//! Opt-in abstract marking
+//!
+//! Academic tooling treats a document's lead paragraph as its abstract.
+//! [`mark_abstract`] tags that paragraph with a synthetic `abstract`
+//! annotation, so consumers find it the same way they find an authored
+//! `:: abstract ::`. Only a paragraph that opens the document counts: blank
+//! lines and document-level annotations before it are skipped, but a
+//! document that opens with a session, list or any other block has no
+//! abstract.
+
+use crate::ast::elements::annotation::annotation_content::{Annotation, AnnotationContent};
+use crate::ast::elements::components::parameters::Parameters;
+use crate::ast::elements::session::session_container::SessionContainerElement;
+use crate::ast::Document;
+use crate::cst::ScannerTokenSequence;
+
+/// Label of the synthetic annotation on the lead paragraph
+pub const ABSTRACT_LABEL: &str = "abstract";
+
+/// Tag the document's lead paragraph with an `abstract` annotation
+///
+/// Returns whether a paragraph was marked.
+pub fn mark_abstract(document: &mut Document) -> bool {
+    let lead = document.content.content.iter_mut().find(|element| {
+        !matches!(
+            element,
+            SessionContainerElement::BlankLine(_) | SessionContainerElement::Annotation(_)
+        )
+    });
+    let Some(SessionContainerElement::Paragraph(paragraph)) = lead else {
+        return false;
+    };
+
+    if !paragraph
+        .annotations
+        .iter()
+        .any(|annotation| annotation.name == ABSTRACT_LABEL)
+    {
+        paragraph.annotations.push(Annotation {
+            name: ABSTRACT_LABEL.to_string(),
+            parameters: Parameters::new(),
+            content: AnnotationContent::Inline(Vec::new()),
+            tokens: ScannerTokenSequence::new(),
+            namespace: None,
+        });
+    }
+    true
+}
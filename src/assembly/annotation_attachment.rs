@@ -4,26 +4,94 @@
 //! This is the second step of Phase 3 assembly, where we take the document
 //! structure and attach annotations according to the TXXT specification.
 //!
+//! Attachment is opt-in: [`AnnotationAttacher::new`] leaves annotation
+//! blocks in the content, where renderers, plain text extraction and
+//! reference collection read them. [`AnnotationAttacher::with_policy`] (or
+//! [`attach_annotations`] on an assembled document) moves each block into
+//! the `annotations` field of its target, following an
+//! [`AttachmentPolicy`]:
+//!
+//! - Document start: annotations before any other content, followed by a
+//!   blank line, attach to the document.
+//! - Next element: an annotation attaches to the element after it in the
+//!   same container, blank lines in between ignored.
+//! - Trailing: an annotation with no element after it attaches to the
+//!   element before it, or to the container's owner (session, quote, list
+//!   item, document), as the policy says.
+//!
+//! An annotation no rule applies to stays in the content.
+//!
+//! After an edit, [`reattach_annotations`] redoes this for the annotations
+//! written in the changed rows only: their old attachments are dropped, the
+//! annotation blocks now in those rows are attached, and every other
+//! attachment is left as it is. Nothing is reparsed or reassembled, so the
+//! caller parses the edited annotations and splices their blocks back into
+//! the content where they were written before calling it.
+//!
 //! src/parser/mod.rs has the full architecture overview.
 
-use crate::ast::Document;
+use std::ops::Range;
+
+use crate::ast::elements::annotation::{Annotation, AnnotationBlock};
+use crate::ast::elements::containers::content::{ContentContainer, ContentContainerElement};
+use crate::ast::elements::core::{ContainerElement, HeaderedBlock, TxxtElement};
+use crate::ast::elements::session::session_container::SessionContainerElement;
+use crate::ast::elements::session::SessionContainer;
+use crate::ast::{Document, ListBlock};
+
+/// Where an annotation with no element after it attaches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrailingAttachment {
+    /// The element before it in the same container
+    #[default]
+    PreviousElement,
+    /// The owner of its container (the spec's "last element rule")
+    Parent,
+    /// Nothing; it stays in the content
+    Keep,
+}
+
+/// Which proximity rules [`attach_annotations`] applies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttachmentPolicy {
+    /// Annotations at the start of the document attach to the document
+    pub document_start: bool,
+    /// Annotations attach to the element that follows them
+    pub next_element: bool,
+    /// Where annotations with no following element attach
+    pub trailing: TrailingAttachment,
+}
+
+impl Default for AttachmentPolicy {
+    fn default() -> Self {
+        Self {
+            document_start: true,
+            next_element: true,
+            trailing: TrailingAttachment::default(),
+        }
+    }
+}
 
 /// Annotation attacher for applying proximity rules
 ///
 /// This attacher takes a document with unprocessed annotations and
 /// applies the TXXT proximity rules to attach them to their targets.
-pub struct AnnotationAttacher;
-
-impl Default for AnnotationAttacher {
-    fn default() -> Self {
-        Self::new()
-    }
+#[derive(Default)]
+pub struct AnnotationAttacher {
+    policy: Option<AttachmentPolicy>,
 }
 
 impl AnnotationAttacher {
-    /// Create a new annotation attacher instance
+    /// Create an attacher that leaves annotations in the content
     pub fn new() -> Self {
-        Self
+        Self { policy: None }
+    }
+
+    /// Create an attacher that applies `policy`
+    pub fn with_policy(policy: AttachmentPolicy) -> Self {
+        Self {
+            policy: Some(policy),
+        }
     }
 
     /// Attach annotations to their target elements
@@ -32,14 +100,514 @@ impl AnnotationAttacher {
     /// TXXT proximity rules to attach them to their appropriate targets.
     pub fn attach_annotations(
         &self,
-        document: Document,
+        mut document: Document,
     ) -> Result<Document, AnnotationAttachmentError> {
-        // TODO: Implement annotation attachment logic
-        // For now, return the document unchanged as Phase 3b is not yet implemented
+        if let Some(policy) = &self.policy {
+            attach_annotations(&mut document, policy);
+        }
         Ok(document)
     }
 }
 
+/// Move annotation blocks onto their targets, following `policy`
+pub fn attach_annotations(document: &mut Document, policy: &AttachmentPolicy) {
+    let root = &mut document.content;
+    if policy.document_start {
+        let leading = leading_document_annotations(root);
+        let annotations: Vec<Annotation> = root
+            .content
+            .drain(..leading)
+            .filter_map(|element| match element {
+                SessionContainerElement::Annotation(block) => Some(block.into()),
+                _ => None,
+            })
+            .collect();
+        root.annotations.extend(annotations);
+    }
+    attach_in_session_container(root, policy);
+}
+
+/// Re-attach the annotations written in `changed_rows` with the default policy
+///
+/// See [`reattach_annotations_with`].
+pub fn reattach_annotations(document: &mut Document, changed_rows: Range<usize>) {
+    reattach_annotations_with(document, changed_rows, &AttachmentPolicy::default());
+}
+
+/// Re-attach the annotations written in `changed_rows`, following `policy`
+///
+/// `changed_rows` are 0-based source rows, as in token spans; an annotation
+/// is in them when its label line is. Rows rather than byte offsets, since a
+/// document keeps (row, column) positions and not its source; an editor
+/// holding the source gets rows for a byte range from
+/// [`SourceMap::offset_to_line_col`](crate::ast::source_map::SourceMap::offset_to_line_col).
+///
+/// For a document already attached with `policy` and then edited, attached
+/// annotations from those rows are dropped as stale and the annotation
+/// blocks left in the content there are attached by the usual rules.
+/// Annotations outside the rows keep their targets, and attachments keep
+/// their source order.
+///
+/// Nothing is parsed here: the caller must re-parse the edited annotations
+/// and splice their blocks back into the content, in the container and
+/// position they were written at, before calling this. An edited
+/// annotation that is not back in the content is dropped.
+pub fn reattach_annotations_with(
+    document: &mut Document,
+    changed_rows: Range<usize>,
+    policy: &AttachmentPolicy,
+) {
+    let root = &mut document.content;
+    root.annotations
+        .retain(|annotation| !written_in(annotation, &changed_rows));
+    if policy.document_start {
+        let leading = leading_document_annotations(root);
+        let (annotations, rest): (Vec<_>, Vec<_>) =
+            root.content.drain(..leading).partition(|element| {
+                element
+                    .annotation_row()
+                    .is_some_and(|row| changed_rows.contains(&row))
+            });
+        root.content.splice(0..0, rest);
+        root.annotations.extend(
+            annotations
+                .into_iter()
+                .filter_map(|element| element.into_annotation().ok().map(Annotation::from)),
+        );
+    }
+    let parent = reattach_in(&mut root.content, policy, &changed_rows);
+    root.annotations.extend(parent);
+    sort_by_row(&mut root.annotations);
+}
+
+/// Whether an attached annotation's label line is in `rows`
+fn written_in(annotation: &Annotation, rows: &Range<usize>) -> bool {
+    annotation
+        .tokens
+        .tokens
+        .first()
+        .is_some_and(|token| rows.contains(&token.span().start.row))
+}
+
+/// Put annotations back in source order after some were added
+fn sort_by_row(annotations: &mut [Annotation]) {
+    annotations.sort_by_key(|annotation| {
+        annotation
+            .tokens
+            .tokens
+            .first()
+            .map(|token| token.span().start.row)
+    });
+}
+
+/// Number of root elements, blank lines included, that make up the
+/// document-level annotation group
+///
+/// The group is the annotations before any other content; it only counts
+/// if a blank line or the end of the document follows it.
+fn leading_document_annotations(root: &SessionContainer) -> usize {
+    let end = root
+        .content
+        .iter()
+        .position(|element| !is_annotation_or_blank(element))
+        .unwrap_or(root.content.len());
+    let Some(last) = root.content[..end]
+        .iter()
+        .rposition(|element| matches!(element, SessionContainerElement::Annotation(_)))
+    else {
+        return 0;
+    };
+
+    let SessionContainerElement::Annotation(block) = &root.content[last] else {
+        return 0;
+    };
+    let separated = match root.child_elements().get(end) {
+        None => true,
+        Some(next) => first_row(*next).is_some_and(|row| row > last_row(block) + 1),
+    };
+    if separated {
+        last + 1
+    } else {
+        0
+    }
+}
+
+fn is_annotation_or_blank(element: &SessionContainerElement) -> bool {
+    matches!(
+        element,
+        SessionContainerElement::Annotation(_) | SessionContainerElement::BlankLine(_)
+    )
+}
+
+fn first_row(element: &dyn TxxtElement) -> Option<usize> {
+    element
+        .tokens()
+        .tokens
+        .first()
+        .map(|token| token.span().start.row)
+}
+
+/// Last source row of an annotation, block content included
+fn last_row(block: &AnnotationBlock) -> usize {
+    // A line's newline token ends on the next row, so rows come from starts
+    let header = block
+        .tokens
+        .tokens
+        .iter()
+        .map(|token| token.span().start.row);
+    let body = block
+        .tail_container()
+        .into_iter()
+        .flat_map(|container| container.child_elements())
+        .flat_map(|element| element.tokens().tokens.iter())
+        .map(|token| token.span().start.row);
+    header.chain(body).max().unwrap_or(0)
+}
+
+/// Where one annotation of a container goes
+enum Target {
+    Element(usize),
+    Parent,
+}
+
+/// Decide the target of every annotation among `elements`
+///
+/// `kinds` tells, per element, whether it is an annotation, a blank line
+/// or anything else.
+fn targets(kinds: &[Kind], policy: &AttachmentPolicy) -> Vec<Option<Target>> {
+    let is_element = |index: &usize| kinds[*index] == Kind::Element;
+    (0..kinds.len())
+        .map(|index| {
+            if kinds[index] != Kind::Annotation {
+                return None;
+            }
+            match (index + 1..kinds.len()).find(is_element) {
+                Some(next) => policy.next_element.then_some(Target::Element(next)),
+                None => match policy.trailing {
+                    TrailingAttachment::PreviousElement => Some(
+                        (0..index)
+                            .rev()
+                            .find(is_element)
+                            .map_or(Target::Parent, Target::Element),
+                    ),
+                    TrailingAttachment::Parent => Some(Target::Parent),
+                    TrailingAttachment::Keep => None,
+                },
+            }
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Annotation,
+    Blank,
+    Element,
+}
+
+/// Container elements the attacher can move annotations between
+trait Slot: Sized {
+    fn kind(&self) -> Kind;
+    fn into_annotation(self) -> Result<AnnotationBlock, Self>;
+    fn annotations_mut(&mut self) -> Option<&mut Vec<Annotation>>;
+    /// Label line of an annotation block, `None` for other elements
+    fn annotation_row(&self) -> Option<usize>;
+    /// Apply the policy to the containers inside this element
+    fn attach_within(&mut self, policy: &AttachmentPolicy);
+    /// Re-attach annotations from `rows` in the containers inside this element
+    fn reattach_within(&mut self, policy: &AttachmentPolicy, rows: &Range<usize>);
+}
+
+/// Apply the policy to `elements`, returning the annotations for their owner
+fn attach_in<E: Slot>(elements: &mut Vec<E>, policy: &AttachmentPolicy) -> Vec<Annotation> {
+    for element in elements.iter_mut() {
+        element.attach_within(policy);
+    }
+
+    let kinds: Vec<Kind> = elements.iter().map(Slot::kind).collect();
+    let targets = targets(&kinds, policy);
+    move_to_targets(elements, targets)
+}
+
+/// Re-attach the annotations of `elements` written in `rows`, returning
+/// those for their owner
+///
+/// Stale attachments from `rows` are dropped first; targets are decided
+/// over all of `elements`, but only blocks in `rows` move.
+fn reattach_in<E: Slot>(
+    elements: &mut Vec<E>,
+    policy: &AttachmentPolicy,
+    rows: &Range<usize>,
+) -> Vec<Annotation> {
+    for element in elements.iter_mut() {
+        if let Some(annotations) = element.annotations_mut() {
+            annotations.retain(|annotation| !written_in(annotation, rows));
+        }
+        element.reattach_within(policy, rows);
+    }
+
+    let kinds: Vec<Kind> = elements.iter().map(Slot::kind).collect();
+    let targets = targets(&kinds, policy)
+        .into_iter()
+        .zip(elements.iter())
+        .map(|(target, element)| {
+            target.filter(|_| {
+                element
+                    .annotation_row()
+                    .is_some_and(|row| rows.contains(&row))
+            })
+        })
+        .collect();
+    let parent = move_to_targets(elements, targets);
+    for element in elements.iter_mut() {
+        if let Some(annotations) = element.annotations_mut() {
+            sort_by_row(annotations);
+        }
+    }
+    parent
+}
+
+/// Move each annotation block with a target onto it, returning those for
+/// the owner of `elements`
+fn move_to_targets<E: Slot>(
+    elements: &mut Vec<E>,
+    targets: Vec<Option<Target>>,
+) -> Vec<Annotation> {
+    let mut incoming: Vec<Vec<Annotation>> = elements.iter().map(|_| Vec::new()).collect();
+    let mut parent = Vec::new();
+    let mut kept = Vec::with_capacity(elements.len());
+
+    for ((index, element), target) in elements.drain(..).enumerate().zip(targets) {
+        let Some(target) = target else {
+            kept.push((index, element));
+            continue;
+        };
+        let annotation: Annotation = match element.into_annotation() {
+            Ok(block) => block.into(),
+            Err(element) => {
+                kept.push((index, element));
+                continue;
+            }
+        };
+        match target {
+            Target::Element(target) => incoming[target].push(annotation),
+            Target::Parent => parent.push(annotation),
+        }
+    }
+
+    for (index, mut element) in kept {
+        if let Some(annotations) = element.annotations_mut() {
+            annotations.append(&mut incoming[index]);
+        }
+        elements.push(element);
+    }
+    parent
+}
+
+fn attach_in_session_container(container: &mut SessionContainer, policy: &AttachmentPolicy) {
+    let parent = attach_in(&mut container.content, policy);
+    container.annotations.extend(parent);
+}
+
+fn attach_in_content_container(container: &mut ContentContainer, policy: &AttachmentPolicy) {
+    let parent = attach_in(&mut container.content, policy);
+    container.annotations.extend(parent);
+}
+
+impl Slot for SessionContainerElement {
+    fn kind(&self) -> Kind {
+        match self {
+            SessionContainerElement::Annotation(_) => Kind::Annotation,
+            SessionContainerElement::BlankLine(_) => Kind::Blank,
+            _ => Kind::Element,
+        }
+    }
+
+    fn into_annotation(self) -> Result<AnnotationBlock, Self> {
+        match self {
+            SessionContainerElement::Annotation(block) => Ok(block),
+            other => Err(other),
+        }
+    }
+
+    fn annotations_mut(&mut self) -> Option<&mut Vec<Annotation>> {
+        match self {
+            SessionContainerElement::Paragraph(paragraph) => Some(&mut paragraph.annotations),
+            SessionContainerElement::List(list) => Some(&mut list.annotations),
+            SessionContainerElement::Definition(definition) => Some(&mut definition.annotations),
+            SessionContainerElement::Verbatim(verbatim) => Some(&mut verbatim.annotations),
+            SessionContainerElement::Quote(quote) => Some(&mut quote.annotations),
+            SessionContainerElement::Table(table) => Some(&mut table.annotations),
+            SessionContainerElement::FootnoteDefinition(footnote) => {
+                Some(&mut footnote.annotations)
+            }
+            SessionContainerElement::Session(session) => Some(&mut session.annotations),
+            SessionContainerElement::ContentContainer(container) => {
+                Some(&mut container.annotations)
+            }
+            SessionContainerElement::SessionContainer(container) => {
+                Some(&mut container.annotations)
+            }
+            SessionContainerElement::Annotation(_) | SessionContainerElement::BlankLine(_) => None,
+        }
+    }
+
+    fn annotation_row(&self) -> Option<usize> {
+        match self {
+            SessionContainerElement::Annotation(block) => first_row(block),
+            _ => None,
+        }
+    }
+
+    fn reattach_within(&mut self, policy: &AttachmentPolicy, rows: &Range<usize>) {
+        match self {
+            SessionContainerElement::Session(session) => {
+                let parent = reattach_in(&mut session.content.content, policy, rows);
+                session.annotations.extend(parent);
+                sort_by_row(&mut session.annotations);
+            }
+            SessionContainerElement::SessionContainer(container) => {
+                let parent = reattach_in(&mut container.content, policy, rows);
+                container.annotations.extend(parent);
+                sort_by_row(&mut container.annotations);
+            }
+            SessionContainerElement::ContentContainer(container) => {
+                reattach_in_content_container(container, policy, rows)
+            }
+            SessionContainerElement::Quote(quote) => {
+                let parent = reattach_in(&mut quote.content.content, policy, rows);
+                quote.annotations.extend(parent);
+                sort_by_row(&mut quote.annotations);
+            }
+            SessionContainerElement::List(list) => reattach_in_list_items(list, policy, rows),
+            _ => {}
+        }
+    }
+
+    fn attach_within(&mut self, policy: &AttachmentPolicy) {
+        match self {
+            SessionContainerElement::Session(session) => {
+                let parent = attach_in(&mut session.content.content, policy);
+                session.annotations.extend(parent);
+            }
+            SessionContainerElement::SessionContainer(container) => {
+                attach_in_session_container(container, policy)
+            }
+            SessionContainerElement::ContentContainer(container) => {
+                attach_in_content_container(container, policy)
+            }
+            SessionContainerElement::Quote(quote) => {
+                let parent = attach_in(&mut quote.content.content, policy);
+                quote.annotations.extend(parent);
+            }
+            SessionContainerElement::List(list) => attach_in_list_items(list, policy),
+            _ => {}
+        }
+    }
+}
+
+impl Slot for ContentContainerElement {
+    fn kind(&self) -> Kind {
+        match self {
+            ContentContainerElement::Annotation(_) => Kind::Annotation,
+            ContentContainerElement::BlankLine(_) => Kind::Blank,
+            _ => Kind::Element,
+        }
+    }
+
+    fn into_annotation(self) -> Result<AnnotationBlock, Self> {
+        match self {
+            ContentContainerElement::Annotation(block) => Ok(block),
+            other => Err(other),
+        }
+    }
+
+    fn annotations_mut(&mut self) -> Option<&mut Vec<Annotation>> {
+        match self {
+            ContentContainerElement::Paragraph(paragraph) => Some(&mut paragraph.annotations),
+            ContentContainerElement::List(list) => Some(&mut list.annotations),
+            ContentContainerElement::Definition(definition) => Some(&mut definition.annotations),
+            ContentContainerElement::Verbatim(verbatim) => Some(&mut verbatim.annotations),
+            ContentContainerElement::Quote(quote) => Some(&mut quote.annotations),
+            ContentContainerElement::Table(table) => Some(&mut table.annotations),
+            ContentContainerElement::FootnoteDefinition(footnote) => {
+                Some(&mut footnote.annotations)
+            }
+            ContentContainerElement::Container(container) => Some(&mut container.annotations),
+            ContentContainerElement::Annotation(_) | ContentContainerElement::BlankLine(_) => None,
+        }
+    }
+
+    fn annotation_row(&self) -> Option<usize> {
+        match self {
+            ContentContainerElement::Annotation(block) => first_row(block),
+            _ => None,
+        }
+    }
+
+    fn reattach_within(&mut self, policy: &AttachmentPolicy, rows: &Range<usize>) {
+        match self {
+            ContentContainerElement::Container(container) => {
+                reattach_in_content_container(container, policy, rows)
+            }
+            ContentContainerElement::Quote(quote) => {
+                let parent = reattach_in(&mut quote.content.content, policy, rows);
+                quote.annotations.extend(parent);
+                sort_by_row(&mut quote.annotations);
+            }
+            ContentContainerElement::List(list) => reattach_in_list_items(list, policy, rows),
+            _ => {}
+        }
+    }
+
+    fn attach_within(&mut self, policy: &AttachmentPolicy) {
+        match self {
+            ContentContainerElement::Container(container) => {
+                attach_in_content_container(container, policy)
+            }
+            ContentContainerElement::Quote(quote) => {
+                let parent = attach_in(&mut quote.content.content, policy);
+                quote.annotations.extend(parent);
+            }
+            ContentContainerElement::List(list) => attach_in_list_items(list, policy),
+            _ => {}
+        }
+    }
+}
+
+/// Nested content of a list item attaches its trailing annotations to the item
+fn attach_in_list_items(list: &mut ListBlock, policy: &AttachmentPolicy) {
+    for item in &mut list.items {
+        if let Some(nested) = &mut item.nested {
+            let parent = attach_in(&mut nested.content, policy);
+            item.annotations.extend(parent);
+        }
+    }
+}
+
+fn reattach_in_content_container(
+    container: &mut ContentContainer,
+    policy: &AttachmentPolicy,
+    rows: &Range<usize>,
+) {
+    let parent = reattach_in(&mut container.content, policy, rows);
+    container.annotations.extend(parent);
+    sort_by_row(&mut container.annotations);
+}
+
+/// Re-attach within list item content, like [`attach_in_list_items`]
+fn reattach_in_list_items(list: &mut ListBlock, policy: &AttachmentPolicy, rows: &Range<usize>) {
+    for item in &mut list.items {
+        item.annotations
+            .retain(|annotation| !written_in(annotation, rows));
+        if let Some(nested) = &mut item.nested {
+            let parent = reattach_in(&mut nested.content, policy, rows);
+            item.annotations.extend(parent);
+            sort_by_row(&mut item.annotations);
+        }
+    }
+}
+
 /// Errors that can occur during annotation attachment
 #[derive(Debug)]
 pub enum AnnotationAttachmentError {
@@ -115,6 +683,7 @@ mod tests {
                     block_count: 0,
                     max_depth: 0,
                 },
+                warnings: Vec::new(),
             },
         };
 
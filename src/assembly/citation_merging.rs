@@ -0,0 +1,223 @@
+//! Opt-in merging of split citation groups
+//!
+//! A citation group is written `[@a; @b]`. Authors also write it as two
+//! adjacent spans, `[@a][@b]` or `[@a] [@b]`, which parses as two separate
+//! citations. [`merge_adjacent_citations`] joins such runs into one group,
+//! as if the author had written `[@a; @b]`: the merged reference is a
+//! `RefMarker` holding `@a; @b`, the token the lexer produces for a written
+//! group, and its span runs from the first opening bracket to the last
+//! closing one.
+//!
+//! Citations merge when nothing but spaces separates them on the same line.
+//! Text, formatting or a line break between two citations keeps them apart.
+
+use crate::ast::elements::containers::content::ContentContainerElement;
+use crate::ast::elements::containers::simple::{SimpleBlockElement, SimpleContainer};
+use crate::ast::elements::session::session_container::SessionContainerElement;
+use crate::ast::{
+    AnnotationBlock, AnnotationContent, ContentContainer, DefinitionBlock, Document,
+    FootnoteDefinitionBlock, ListBlock, TableBlock, TextTransform,
+};
+use crate::cst::{ScannerToken, SourceSpan};
+
+/// Merge citations separated only by spaces throughout the document
+pub fn merge_adjacent_citations(document: &mut Document) {
+    merge_session_elements(&mut document.content.content);
+}
+
+fn merge_session_elements(elements: &mut [SessionContainerElement]) {
+    for element in elements {
+        match element {
+            SessionContainerElement::Paragraph(paragraph) => {
+                merge_citations(&mut paragraph.content)
+            }
+            SessionContainerElement::List(list) => merge_list(list),
+            SessionContainerElement::Definition(definition) => merge_definition(definition),
+            SessionContainerElement::Verbatim(verbatim) => merge_citations(&mut verbatim.title),
+            SessionContainerElement::Annotation(annotation) => merge_annotation(annotation),
+            SessionContainerElement::Quote(quote) => merge_content_container(&mut quote.content),
+            SessionContainerElement::Table(table) => merge_table(table),
+            SessionContainerElement::FootnoteDefinition(footnote) => merge_footnote(footnote),
+            SessionContainerElement::Session(session) => {
+                merge_citations(&mut session.title.content);
+                merge_session_elements(&mut session.content.content);
+            }
+            SessionContainerElement::ContentContainer(container) => {
+                merge_content_container(container)
+            }
+            SessionContainerElement::SessionContainer(container) => {
+                merge_session_elements(&mut container.content)
+            }
+            SessionContainerElement::BlankLine(_) => {}
+        }
+    }
+}
+
+fn merge_content_container(container: &mut ContentContainer) {
+    for element in &mut container.content {
+        match element {
+            ContentContainerElement::Paragraph(paragraph) => {
+                merge_citations(&mut paragraph.content)
+            }
+            ContentContainerElement::List(list) => merge_list(list),
+            ContentContainerElement::Definition(definition) => merge_definition(definition),
+            ContentContainerElement::Verbatim(verbatim) => merge_citations(&mut verbatim.title),
+            ContentContainerElement::Annotation(annotation) => merge_annotation(annotation),
+            ContentContainerElement::Quote(quote) => merge_content_container(&mut quote.content),
+            ContentContainerElement::Table(table) => merge_table(table),
+            ContentContainerElement::FootnoteDefinition(footnote) => merge_footnote(footnote),
+            ContentContainerElement::Container(container) => merge_content_container(container),
+            ContentContainerElement::BlankLine(_) => {}
+        }
+    }
+}
+
+fn merge_simple_container(container: &mut SimpleContainer) {
+    for element in &mut container.content {
+        match element {
+            SimpleBlockElement::Paragraph(paragraph) => merge_citations(&mut paragraph.content),
+            SimpleBlockElement::List(list) => merge_list(list),
+            SimpleBlockElement::Verbatim(verbatim) => merge_citations(&mut verbatim.title),
+            SimpleBlockElement::BlankLine(_) => {}
+        }
+    }
+}
+
+fn merge_list(list: &mut ListBlock) {
+    for item in &mut list.items {
+        merge_citations(&mut item.content);
+        if let Some(nested) = &mut item.nested {
+            merge_content_container(nested);
+        }
+    }
+}
+
+fn merge_definition(definition: &mut DefinitionBlock) {
+    merge_citations(&mut definition.term.content);
+    merge_simple_container(&mut definition.content);
+}
+
+fn merge_footnote(footnote: &mut FootnoteDefinitionBlock) {
+    merge_citations(&mut footnote.content);
+    merge_simple_container(&mut footnote.body);
+}
+
+fn merge_annotation(annotation: &mut AnnotationBlock) {
+    match &mut annotation.content {
+        AnnotationContent::Inline(body) => merge_citations(body),
+        AnnotationContent::Block(container) => merge_simple_container(container),
+    }
+}
+
+fn merge_table(table: &mut TableBlock) {
+    for row in table.header.iter_mut().chain(&mut table.rows) {
+        for cell in &mut row.cells {
+            merge_citations(&mut cell.content);
+        }
+    }
+}
+
+/// A token in a run of plain text spans: (transform index, token index)
+type Slot = (usize, usize);
+
+/// Tokens of a run, each marked with whether it is a citation
+type Run = Vec<(Slot, bool)>;
+
+/// Merge the citation runs of one inline sequence
+///
+/// Runs may cross `Identity` boundaries, since inline parsing gives every
+/// token its own span. Spans left empty by the merge are dropped.
+fn merge_citations(transforms: &mut Vec<TextTransform>) {
+    // Each run starts at a citation; spaces are kept until the next citation
+    let mut runs: Vec<Vec<Slot>> = Vec::new();
+    let mut run: Run = Vec::new();
+
+    for (index, transform) in transforms.iter_mut().enumerate() {
+        let text = match transform {
+            TextTransform::Identity(text) => text,
+            TextTransform::Emphasis(inner)
+            | TextTransform::Strong(inner)
+            | TextTransform::Composed(inner)
+            | TextTransform::Custom { content: inner, .. } => {
+                merge_citations(inner);
+                close_run(&mut run, &mut runs);
+                continue;
+            }
+            TextTransform::Code(_) | TextTransform::Math(_) | TextTransform::LineBreak(_) => {
+                close_run(&mut run, &mut runs);
+                continue;
+            }
+        };
+
+        for (position, token) in text.tokens.tokens.iter().enumerate() {
+            if citation_keys(token).is_some() {
+                run.push(((index, position), true));
+            } else if matches!(token, ScannerToken::Whitespace { .. }) && !run.is_empty() {
+                run.push(((index, position), false));
+            } else {
+                close_run(&mut run, &mut runs);
+            }
+        }
+    }
+    close_run(&mut run, &mut runs);
+
+    if runs.is_empty() {
+        return;
+    }
+
+    let mut removed: Vec<Slot> = Vec::new();
+    for run in runs {
+        let token_at = |(index, position): Slot| match &transforms[index] {
+            TextTransform::Identity(text) => &text.tokens.tokens[position],
+            _ => unreachable!("runs only hold plain text slots"),
+        };
+
+        let content = run
+            .iter()
+            .filter_map(|&slot| citation_keys(token_at(slot)))
+            .collect::<Vec<_>>()
+            .join("; ");
+        let span = SourceSpan {
+            start: token_at(run[0]).span().start,
+            end: token_at(run[run.len() - 1]).span().end,
+        };
+
+        let (index, position) = run[0];
+        if let TextTransform::Identity(text) = &mut transforms[index] {
+            text.tokens.tokens[position] = ScannerToken::RefMarker { content, span };
+        }
+        removed.extend_from_slice(&run[1..]);
+    }
+
+    // Remove from the back so earlier slots keep their indexes
+    for &(index, position) in removed.iter().rev() {
+        if let TextTransform::Identity(text) = &mut transforms[index] {
+            text.tokens.tokens.remove(position);
+            if text.tokens.tokens.is_empty() {
+                transforms.remove(index);
+            }
+        }
+    }
+}
+
+/// Keep a run that joins at least two citations, minus its trailing spaces
+fn close_run(run: &mut Run, runs: &mut Vec<Vec<Slot>>) {
+    let mut finished = std::mem::take(run);
+    while finished.last().is_some_and(|&(_, citation)| !citation) {
+        finished.pop();
+    }
+    if finished.iter().filter(|&&(_, citation)| citation).count() >= 2 {
+        runs.push(finished.into_iter().map(|(slot, _)| slot).collect());
+    }
+}
+
+/// The `@key` list a citation token contributes to a merged group
+fn citation_keys(token: &ScannerToken) -> Option<String> {
+    match token {
+        ScannerToken::CitationRef { content, .. } => Some(format!("@{}", content)),
+        ScannerToken::RefMarker { content, .. } if content.starts_with('@') => {
+            Some(content.clone())
+        }
+        _ => None,
+    }
+}
@@ -7,11 +7,15 @@
 //! src/parser/mod.rs has the full architecture overview.
 
 use crate::ast::elements::{
-    document::document_structure::{AssemblyInfo, Meta, ProcessingStats},
-    session::SessionContainer,
+    components::parameters::Parameters,
+    document::document_structure::{AssemblyInfo, AssemblyWarning, Meta, ProcessingStats},
+    session::{session_container::SessionContainerElement, SessionContainer},
 };
+use crate::ast::events::{events, DocEvent};
 use crate::ast::Document;
 use crate::ast::ElementNode;
+use crate::cst::ScannerTokenSequence;
+use crate::syntax::parameter_parsing::find_duplicate_parameters;
 
 /// Document assembler for creating final document structure
 ///
@@ -63,6 +67,18 @@ impl DocumentAssembler {
                 ElementNode::AnnotationBlock(annotation) => {
                     all_elements.push(crate::ast::elements::session::session_container::SessionContainerElement::Annotation(annotation));
                 }
+                ElementNode::QuoteBlock(quote) => {
+                    all_elements.push(crate::ast::elements::session::session_container::SessionContainerElement::Quote(quote));
+                }
+                ElementNode::TableBlock(table) => {
+                    all_elements.push(crate::ast::elements::session::session_container::SessionContainerElement::Table(table));
+                }
+                ElementNode::FootnoteDefinitionBlock(footnote) => {
+                    all_elements.push(crate::ast::elements::session::session_container::SessionContainerElement::FootnoteDefinition(footnote));
+                }
+                ElementNode::BlankLine(blank_line) => {
+                    all_elements.push(crate::ast::elements::session::session_container::SessionContainerElement::BlankLine(blank_line));
+                }
                 // Handle other element types as needed
                 _ => {
                     // For now, skip unsupported element types
@@ -74,7 +90,10 @@ impl DocumentAssembler {
         let stats = ProcessingStats {
             token_count: 0,      // TODO: Calculate from elements
             annotation_count: 0, // TODO: Calculate from elements
-            block_count: all_elements.len(),
+            block_count: all_elements
+                .iter()
+                .filter(|element| !matches!(element, SessionContainerElement::BlankLine(_)))
+                .count(),
             max_depth: 0, // TODO: Calculate from elements
         };
 
@@ -83,9 +102,10 @@ impl DocumentAssembler {
             source_path,
             processed_at: Some(chrono::Utc::now().to_rfc3339()),
             stats,
+            warnings: Vec::new(),
         };
 
-        let document = Document {
+        let mut document = Document {
             meta: Meta::default(),
             content: SessionContainer::new(
                 all_elements,
@@ -95,11 +115,91 @@ impl DocumentAssembler {
             ),
             assembly_info,
         };
+        document.assembly_info.warnings = recovery_warnings(&document);
 
         Ok(document)
     }
 }
 
+/// Report elements the parser had to recover from
+///
+/// Truncation only happens at the end of input, but the partial element can
+/// sit at any depth: in a session, a list item, a quote or a definition
+/// body. Walking the document's events visits every annotation, definition
+/// and verbatim block wherever it is, which also covers repeated parameter
+/// keys.
+fn recovery_warnings(document: &Document) -> Vec<AssemblyWarning> {
+    let mut warnings = Vec::new();
+    for event in events(document) {
+        match event {
+            DocEvent::Verbatim(verbatim) => {
+                check_parameters(&verbatim.parameters, &mut warnings);
+                if verbatim.is_truncated() {
+                    warnings.push(AssemblyWarning::UnterminatedVerbatim {
+                        title: verbatim.title_text(),
+                        line: first_line(&verbatim.tokens),
+                    });
+                }
+            }
+            DocEvent::EnterAnnotation(annotation) => {
+                check_parameters(&annotation.parameters, &mut warnings);
+                if annotation.is_truncated() {
+                    warnings.push(AssemblyWarning::UnterminatedAnnotation {
+                        label: annotation.name.clone(),
+                        line: first_line(&annotation.tokens),
+                    });
+                }
+            }
+            DocEvent::EnterDefinition(definition) => {
+                check_parameters(&definition.parameters, &mut warnings);
+            }
+            _ => {}
+        }
+    }
+    warnings
+}
+
+/// Report each repeated parameter key, wherever it sits in the document
+///
+/// Keys are read from each parameter list's tokens, so this finds the same
+/// repeats as assembly whether the document was parsed, transformed or
+/// deserialized.
+pub(crate) fn duplicate_parameters(document: &Document) -> Vec<AssemblyWarning> {
+    let mut warnings = Vec::new();
+    for event in events(document) {
+        match event {
+            DocEvent::Verbatim(verbatim) => check_parameters(&verbatim.parameters, &mut warnings),
+            DocEvent::EnterAnnotation(annotation) => {
+                check_parameters(&annotation.parameters, &mut warnings)
+            }
+            DocEvent::EnterDefinition(definition) => {
+                check_parameters(&definition.parameters, &mut warnings)
+            }
+            _ => {}
+        }
+    }
+    warnings
+}
+
+/// Report each repeated key of a parameter list
+fn check_parameters(parameters: &Parameters, warnings: &mut Vec<AssemblyWarning>) {
+    for (key, span) in find_duplicate_parameters(&parameters.tokens.tokens) {
+        warnings.push(AssemblyWarning::DuplicateParameter {
+            key,
+            line: span.start.row + 1,
+        });
+    }
+}
+
+/// 1-based source line of the first token in a sequence
+fn first_line(tokens: &ScannerTokenSequence) -> usize {
+    tokens
+        .tokens
+        .first()
+        .map_or(0, |token| token.span().start.row)
+        + 1
+}
+
 /// Errors that can occur during document assembly
 #[derive(Debug)]
 pub enum DocumentAssemblyError {
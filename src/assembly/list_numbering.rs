@@ -0,0 +1,90 @@
+//! Opt-in list renumbering
+//!
+//! Assembly keeps list markers exactly as written, so `1. 3. 2.` survives
+//! into the Document. Callers that want normalized output (formatters,
+//! exporters) run [`renumber_lists`] over the assembled document, which
+//! applies [`ListBlock::renumber`] to every list, including lists nested in
+//! sessions, definitions, annotations, quotes and other list items.
+
+use crate::ast::elements::containers::content::ContentContainerElement;
+use crate::ast::elements::containers::simple::{SimpleBlockElement, SimpleContainer};
+use crate::ast::elements::session::session_container::SessionContainerElement;
+use crate::ast::{AnnotationBlock, AnnotationContent, ContentContainer, Document, ListBlock};
+
+/// Renumber every list in the document into an ascending sequence
+pub fn renumber_lists(document: &mut Document) {
+    renumber_session_elements(&mut document.content.content);
+}
+
+fn renumber_session_elements(elements: &mut [SessionContainerElement]) {
+    for element in elements {
+        match element {
+            SessionContainerElement::List(list) => renumber_list(list),
+            SessionContainerElement::Definition(definition) => {
+                renumber_simple_container(&mut definition.content)
+            }
+            SessionContainerElement::Annotation(annotation) => renumber_annotation(annotation),
+            SessionContainerElement::Quote(quote) => renumber_content_container(&mut quote.content),
+            SessionContainerElement::FootnoteDefinition(footnote) => {
+                renumber_simple_container(&mut footnote.body)
+            }
+            SessionContainerElement::Session(session) => {
+                renumber_session_elements(&mut session.content.content)
+            }
+            SessionContainerElement::ContentContainer(container) => {
+                renumber_content_container(container)
+            }
+            SessionContainerElement::SessionContainer(container) => {
+                renumber_session_elements(&mut container.content)
+            }
+            SessionContainerElement::Paragraph(_)
+            | SessionContainerElement::Verbatim(_)
+            | SessionContainerElement::Table(_)
+            | SessionContainerElement::BlankLine(_) => {}
+        }
+    }
+}
+
+fn renumber_content_container(container: &mut ContentContainer) {
+    for element in &mut container.content {
+        match element {
+            ContentContainerElement::List(list) => renumber_list(list),
+            ContentContainerElement::Definition(definition) => {
+                renumber_simple_container(&mut definition.content)
+            }
+            ContentContainerElement::Annotation(annotation) => renumber_annotation(annotation),
+            ContentContainerElement::Quote(quote) => renumber_content_container(&mut quote.content),
+            ContentContainerElement::FootnoteDefinition(footnote) => {
+                renumber_simple_container(&mut footnote.body)
+            }
+            ContentContainerElement::Container(container) => renumber_content_container(container),
+            ContentContainerElement::Paragraph(_)
+            | ContentContainerElement::Verbatim(_)
+            | ContentContainerElement::Table(_)
+            | ContentContainerElement::BlankLine(_) => {}
+        }
+    }
+}
+
+fn renumber_simple_container(container: &mut SimpleContainer) {
+    for element in &mut container.content {
+        if let SimpleBlockElement::List(list) = element {
+            renumber_list(list);
+        }
+    }
+}
+
+fn renumber_annotation(annotation: &mut AnnotationBlock) {
+    if let AnnotationContent::Block(container) = &mut annotation.content {
+        renumber_simple_container(container);
+    }
+}
+
+fn renumber_list(list: &mut ListBlock) {
+    list.renumber();
+    for item in &mut list.items {
+        if let Some(nested) = &mut item.nested {
+            renumber_content_container(nested);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Document metadata validation
+//!
+//! Metadata is lifted out of annotations without judging it: a `:: date ::`
+//! annotation holding "next tuesday" still lands in `Meta::date`. That keeps
+//! assembly lossless, but authoring tools want a lint step that flags values
+//! they can't use. This module is that step; it never modifies the Meta.
+//!
+//! Checks performed:
+//! - Dates must parse as RFC 3339 or as a plain ISO 8601 calendar date
+//! - A document has one title (a list value, or a custom key that is
+//!   really a title, means the author wrote it more than once)
+//! - Custom keys that look like a misspelled or miscased standard key
+//!
+//! [`suggest_reserved_label`] applies the same near-miss test to annotation
+//! labels, for `api::run_all_checked`.
+
+use chrono::{DateTime, NaiveDate};
+
+use crate::ast::{Meta, MetaValue};
+use crate::syntax::annotation_processing::RESERVED_LABELS;
+
+/// Standard metadata keys with dedicated `Meta` fields
+const KNOWN_KEYS: &[&str] = &["title", "author", "authors", "date", "pub-date"];
+
+/// Problems found in document metadata
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetaWarning {
+    /// The date value is neither RFC 3339 nor an ISO calendar date
+    UnparseableDate { value: String },
+
+    /// More than one title was provided
+    MultipleTitles { count: usize },
+
+    /// A custom key that probably meant to be a standard one
+    SuspiciousKey { key: String, suggestion: String },
+}
+
+impl std::fmt::Display for MetaWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MetaWarning::UnparseableDate { value } => {
+                write!(f, "Unparseable date: {}", value)
+            }
+            MetaWarning::MultipleTitles { count } => {
+                write!(f, "Document has {} titles, expected one", count)
+            }
+            MetaWarning::SuspiciousKey { key, suggestion } => {
+                write!(f, "Custom key '{}' looks like '{}'", key, suggestion)
+            }
+        }
+    }
+}
+
+/// Validate document metadata, returning every problem found
+///
+/// An empty result means the metadata is clean.
+pub fn validate_meta(meta: &Meta) -> Vec<MetaWarning> {
+    let mut warnings = Vec::new();
+
+    if let Some(MetaValue::String(date)) = &meta.date {
+        if !is_valid_date(date) {
+            warnings.push(MetaWarning::UnparseableDate {
+                value: date.clone(),
+            });
+        }
+    }
+
+    let mut title_count = match &meta.title {
+        Some(MetaValue::List(values)) => values.len(),
+        Some(_) => 1,
+        None => 0,
+    };
+
+    let mut keys: Vec<&String> = meta.custom.keys().collect();
+    keys.sort();
+    for key in keys {
+        match suggest_known_key(key) {
+            // A differently-cased "Title" is a second title, not a custom field
+            Some("title") if key.eq_ignore_ascii_case("title") => title_count += 1,
+            Some(suggestion) => warnings.push(MetaWarning::SuspiciousKey {
+                key: key.clone(),
+                suggestion: suggestion.to_string(),
+            }),
+            None => {}
+        }
+    }
+
+    if title_count > 1 {
+        warnings.push(MetaWarning::MultipleTitles { count: title_count });
+    }
+
+    warnings
+}
+
+fn is_valid_date(value: &str) -> bool {
+    let value = value.trim();
+    DateTime::parse_from_rfc3339(value).is_ok()
+        || NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok()
+}
+
+/// Known key a custom key was probably meant to be, if any
+///
+/// Matches case-insensitively, and tolerates typos as [`typo_tolerance`]
+/// allows.
+fn suggest_known_key(key: &str) -> Option<&'static str> {
+    let normalized = key.trim().to_lowercase();
+    KNOWN_KEYS
+        .iter()
+        .copied()
+        .find(|known| edit_distance(&normalized, known) <= typo_tolerance(known))
+}
+
+/// Edits a near-miss of `known` may be away from it
+///
+/// Two for words of five or more characters. Shorter words have ordinary
+/// neighbours one edit away (`data` for `date`), so they only match
+/// exactly.
+fn typo_tolerance(known: &str) -> usize {
+    if known.chars().count() >= 5 {
+        2
+    } else {
+        0
+    }
+}
+
+/// Reserved annotation label a label was probably meant to be, if any
+///
+/// Exact matches and namespaced labels are not near-misses. Otherwise the
+/// same tolerance as for metadata keys applies: two edits for labels of
+/// five or more characters, none for shorter ones, so a short label such
+/// as `data` is taken as the custom label it is.
+pub fn suggest_reserved_label(label: &str) -> Option<&'static str> {
+    if RESERVED_LABELS.contains(&label) || label.contains('.') {
+        return None;
+    }
+    let normalized = label.trim().to_lowercase();
+    RESERVED_LABELS
+        .iter()
+        .copied()
+        .find(|known| edit_distance(&normalized, known) <= typo_tolerance(known))
+}
+
+/// Levenshtein distance counting an adjacent transposition as one edit
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+
+    rows[a.len()][b.len()]
+}
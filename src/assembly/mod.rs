@@ -12,15 +12,59 @@
 //!   - Creates proper document hierarchy with Session containers
 //!
 //! - [`annotation_attachment`] - Step 3.b: Annotation proximity-based attachment
-//!   - Attaches annotations to their target elements using proximity rules
+//!   - Opt-in: attaches annotations to their targets using a proximity policy
 //!   - Input: Document with unattached annotations
 //!   - Output: Document with annotations properly attached
+//!   - `reattach_annotations` redoes it for the rows an edit changed
 //!   - Handles document-level and content-level annotation processing
+//!
+//! - [`abstract_marking`] - Opt-in tagging of the lead paragraph as the abstract
+//!
+//! - [`citation_merging`] - Opt-in joining of adjacent citations into one group
+//!
+//! - [`metadata`] - Metadata lint over the assembled document's Meta
+//!
+//! - [`list_numbering`] - Opt-in marker renumbering for assembled lists
+//!
+//! - [`session_levels`] - Opt-in shifting of sessions down or up a level
+//!
+//! - [`session_split`] - Splitting a document into its top-level sessions
+//!
+//! - [`session_numbering`] - Opt-in numbering of sessions from their nesting
+//!
+//! - [`session_slugs`] - Opt-in document-unique anchors for sessions
+//!
+//! - [`text_coalescing`] - Opt-in merging of adjacent plain text spans
+//!
+//! - [`typography`] - Opt-in curly quotes and en/em dashes in prose text
 
 // Processing steps
+pub mod abstract_marking;
 pub mod annotation_attachment;
+pub mod citation_merging;
 pub mod document_assembly;
+pub mod list_numbering;
+pub mod metadata;
+pub mod session_levels;
+pub mod session_numbering;
+pub mod session_slugs;
+pub mod session_split;
+pub mod text_coalescing;
+pub mod typography;
 
 // Re-export main interfaces
-pub use annotation_attachment::{AnnotationAttacher, AnnotationAttachmentError};
+pub use abstract_marking::mark_abstract;
+pub use annotation_attachment::{
+    attach_annotations, reattach_annotations, reattach_annotations_with, AnnotationAttacher,
+    AnnotationAttachmentError, AttachmentPolicy, TrailingAttachment,
+};
+pub use citation_merging::merge_adjacent_citations;
 pub use document_assembly::{DocumentAssembler, DocumentAssemblyError};
+pub use list_numbering::renumber_lists;
+pub use metadata::{suggest_reserved_label, validate_meta, MetaWarning};
+pub use session_levels::shift_session_levels;
+pub use session_numbering::{auto_number_sessions, auto_number_sessions_with};
+pub use session_slugs::assign_slugs;
+pub use session_split::{split_by_session, PREAMBLE_KEY};
+pub use text_coalescing::coalesce_document_text;
+pub use typography::{apply_typography, typeset_text};
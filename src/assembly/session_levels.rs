@@ -0,0 +1,105 @@
+//! Opt-in session level shifting
+//!
+//! Merging one document into another usually means moving its headings
+//! down a level, or back up. [`shift_session_levels`] does that on an
+//! assembled document, one level per step of `delta`:
+//!
+//! - Down (positive): the top-level sessions, from the first one to the end
+//!   of the document, are nested under a new untitled session. Content
+//!   before the first session stays at the root.
+//! - Up (negative): the child sessions of every top-level session move up
+//!   beside it. Top-level sessions are already at the root and stay there;
+//!   an untitled session left empty by the move is removed, so shifting up
+//!   undoes a shift down.
+//!
+//! Content a parent session has after one of its child sessions ends up
+//! after that child once it moves up, so it follows the same heading in
+//! reading order.
+//!
+//! Explicit numbering is kept as written; run `auto_number_sessions_with`
+//! afterwards to number sessions from their new positions.
+
+use crate::ast::elements::components::parameters::Parameters;
+use crate::ast::elements::session::session_container::SessionContainerElement;
+use crate::ast::{Document, SessionBlock, SessionContainer, SessionTitle};
+use crate::cst::ScannerTokenSequence;
+
+/// Move every session `delta` levels down (positive) or up (negative)
+pub fn shift_session_levels(document: &mut Document, delta: i32) {
+    for _ in 0..delta.unsigned_abs() {
+        if delta > 0 {
+            nest_sessions(&mut document.content);
+        } else {
+            lift_sessions(&mut document.content);
+        }
+    }
+}
+
+/// Nest the root's sessions, and whatever follows them, under a new session
+fn nest_sessions(root: &mut SessionContainer) {
+    let Some(first) = root
+        .content
+        .iter()
+        .position(|element| matches!(element, SessionContainerElement::Session(_)))
+    else {
+        return;
+    };
+    let nested = root.content.split_off(first);
+    root.content
+        .push(SessionContainerElement::Session(SessionBlock::new(
+            SessionTitle::new(Vec::new(), None, ScannerTokenSequence::new()),
+            SessionContainer::new(
+                nested,
+                Vec::new(),
+                Parameters::default(),
+                ScannerTokenSequence::new(),
+            ),
+            Vec::new(),
+            Parameters::default(),
+            ScannerTokenSequence::new(),
+        )));
+}
+
+/// Move the child sessions of the root's sessions up beside their parents
+fn lift_sessions(root: &mut SessionContainer) {
+    let mut lifted = Vec::with_capacity(root.content.len());
+    for element in root.content.drain(..) {
+        let SessionContainerElement::Session(mut session) = element else {
+            lifted.push(element);
+            continue;
+        };
+
+        let mut children = std::mem::take(&mut session.content.content)
+            .into_iter()
+            .peekable();
+        while let Some(child) =
+            children.next_if(|child| !matches!(child, SessionContainerElement::Session(_)))
+        {
+            session.content.content.push(child);
+        }
+        if !is_empty_wrapper(&session) {
+            lifted.push(SessionContainerElement::Session(session));
+        }
+
+        for child in children {
+            match (child, lifted.last_mut()) {
+                (SessionContainerElement::Session(child), _) => {
+                    lifted.push(SessionContainerElement::Session(child))
+                }
+                // The last lifted element is the child session just pushed
+                (other, Some(SessionContainerElement::Session(previous))) => {
+                    previous.content.content.push(other)
+                }
+                (other, _) => lifted.push(other),
+            }
+        }
+    }
+    root.content = lifted;
+}
+
+/// An untitled session with nothing left in it
+fn is_empty_wrapper(session: &SessionBlock) -> bool {
+    session.title.content.is_empty()
+        && session.content.content.is_empty()
+        && session.annotations.is_empty()
+}
@@ -0,0 +1,68 @@
+//! Opt-in session numbering
+//!
+//! Sessions keep their numbering exactly as written, and unnumbered titles
+//! get `numbering: None`. Callers that want every session numbered run
+//! [`auto_number_sessions`] over the assembled document, which assigns
+//! hierarchical numerical markers from each session's position among its
+//! siblings: `1.`, `1.1.`, `1.2.`, `2.`.
+//!
+//! Numbers follow position, not explicit markers: the second top-level
+//! session is `2.` whatever the first one was numbered, and its children
+//! are `2.1.`, `2.2.` and so on.
+
+use crate::ast::elements::session::session_container::SessionContainerElement;
+use crate::ast::{Document, NumberingForm, NumberingStyle, SessionNumbering};
+
+/// Number every session that has no explicit numbering
+pub fn auto_number_sessions(document: &mut Document) {
+    auto_number_sessions_with(document, false);
+}
+
+/// Number sessions from the hierarchy, replacing explicit numbering if `force`
+pub fn auto_number_sessions_with(document: &mut Document, force: bool) {
+    number_session_elements(&mut document.content.content, &[], &mut 0, force);
+}
+
+/// Number the sessions among `elements`, continuing the sibling `counter`
+///
+/// Nested session containers hold more siblings at the same level, so they
+/// share the counter.
+fn number_session_elements(
+    elements: &mut [SessionContainerElement],
+    parent: &[usize],
+    counter: &mut usize,
+    force: bool,
+) {
+    for element in elements {
+        match element {
+            SessionContainerElement::Session(session) => {
+                *counter += 1;
+                let mut path = parent.to_vec();
+                path.push(*counter);
+
+                if force || session.title.numbering.is_none() {
+                    session.title.numbering = Some(numbering_for(&path));
+                }
+                number_session_elements(&mut session.content.content, &path, &mut 0, force);
+            }
+            SessionContainerElement::SessionContainer(container) => {
+                number_session_elements(&mut container.content, parent, counter, force)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Numerical marker for a position path: `[1]` is `1.`, `[1, 2]` is `1.2.`
+fn numbering_for(path: &[usize]) -> SessionNumbering {
+    let marker = path
+        .iter()
+        .map(|number| format!("{}.", number))
+        .collect::<String>();
+    let form = if path.len() > 1 {
+        NumberingForm::Full
+    } else {
+        NumberingForm::Short
+    };
+    SessionNumbering::new(marker, NumberingStyle::Numerical, form)
+}
@@ -0,0 +1,87 @@
+//! Opt-in session slugs
+//!
+//! Named session references (`[introduction]`) need an anchor per session.
+//! [`assign_slugs`] stores one on every session of an assembled document,
+//! derived from the title by `slugify`. Titles that share a slug are told
+//! apart by position: two `Notes` sessions become `notes-1` and `notes-2`,
+//! in document order. A suffixed slug never takes one already in use, so a
+//! third session titled `Notes 1` keeps `notes-1` and the two `Notes`
+//! become `notes-2` and `notes-3`.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::ast::elements::session::session_container::SessionContainerElement;
+use crate::ast::elements::session::slugify;
+use crate::ast::Document;
+
+/// Give every session a slug that is unique within the document
+pub fn assign_slugs(document: &mut Document) {
+    let mut slugs = Vec::new();
+    collect_title_slugs(&document.content.content, &mut slugs);
+
+    let mut unique = dedupe_slugs(slugs, &[]).into_iter();
+    store_slugs(&mut document.content.content, &mut unique);
+}
+
+/// Make `slugs` unique, keeping their order
+///
+/// A slug that appears once and isn't `reserved` is kept as it is. The
+/// others get the lowest `-N` suffix, counting from 1, that no other slug
+/// uses yet.
+pub(crate) fn dedupe_slugs(slugs: Vec<String>, reserved: &[&str]) -> Vec<String> {
+    let mut totals: HashMap<&str, usize> = HashMap::new();
+    for slug in &slugs {
+        *totals.entry(slug).or_default() += 1;
+    }
+    let keeps = |slug: &str| totals[slug] == 1 && !reserved.contains(&slug);
+
+    let mut in_use: HashSet<String> = reserved.iter().map(|slug| slug.to_string()).collect();
+    in_use.extend(slugs.iter().filter(|slug| keeps(slug)).cloned());
+
+    slugs
+        .iter()
+        .map(|slug| {
+            if keeps(slug) {
+                return slug.clone();
+            }
+            let candidate = (1..)
+                .map(|index| format!("{}-{}", slug, index))
+                .find(|candidate| !in_use.contains(candidate))
+                .expect("an unused suffix exists");
+            in_use.insert(candidate.clone());
+            candidate
+        })
+        .collect()
+}
+
+/// Title slug of every session, depth first in document order
+fn collect_title_slugs(elements: &[SessionContainerElement], out: &mut Vec<String>) {
+    for element in elements {
+        match element {
+            SessionContainerElement::Session(session) => {
+                out.push(slugify(&session.title.text_content()));
+                collect_title_slugs(&session.content.content, out);
+            }
+            SessionContainerElement::SessionContainer(container) => {
+                collect_title_slugs(&container.content, out)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Store `slugs` on the sessions, in the order `collect_title_slugs` read them
+fn store_slugs(elements: &mut [SessionContainerElement], slugs: &mut impl Iterator<Item = String>) {
+    for element in elements {
+        match element {
+            SessionContainerElement::Session(session) => {
+                session.slug = slugs.next();
+                store_slugs(&mut session.content.content, slugs);
+            }
+            SessionContainerElement::SessionContainer(container) => {
+                store_slugs(&mut container.content, slugs)
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Splitting a document into its top-level sessions
+//!
+//! Exporting one file per chapter needs the document cut at its top-level
+//! sessions. [`split_by_session`] does that on an assembled document:
+//!
+//! - Each top-level session becomes a document of its own, holding the
+//!   session itself, and is keyed by the session's slug.
+//! - Content before the first session goes into a document keyed
+//!   [`PREAMBLE_KEY`], left out when there is none.
+//! - Content after a session that isn't itself a session (it can only
+//!   follow a session at the root when indentation ends it) stays with the
+//!   session before it, which it follows in reading order.
+//!
+//! Every part keeps the document's metadata and root annotations; the
+//! root's source tokens, which span the whole source, are dropped.
+//!
+//! Keys are always unique. [`PREAMBLE_KEY`] is reserved, so a session
+//! titled "Preamble" is keyed `preamble-1`, and sessions sharing a slug
+//! (possible before `assign_slugs` has run) are told apart the same way
+//! `assign_slugs` does it, with `-N` suffixes in document order.
+
+use super::session_slugs::dedupe_slugs;
+use crate::ast::elements::session::session_container::SessionContainerElement;
+use crate::ast::{Document, SessionContainer};
+use crate::cst::ScannerTokenSequence;
+
+/// Key of the document holding the content before the first session
+pub const PREAMBLE_KEY: &str = "preamble";
+
+/// Split the document into one document per top-level session, in order
+pub fn split_by_session(doc: Document) -> Vec<(String, Document)> {
+    let Document {
+        meta,
+        content,
+        assembly_info,
+    } = doc;
+    let SessionContainer {
+        content: elements,
+        annotations,
+        parameters,
+        ..
+    } = content;
+
+    let mut parts: Vec<(String, Vec<SessionContainerElement>)> = Vec::new();
+    let mut preamble = Vec::new();
+    for element in elements {
+        if let SessionContainerElement::Session(session) = &element {
+            parts.push((session.slug(), vec![element]));
+        } else if let Some((_, part)) = parts.last_mut() {
+            part.push(element);
+        } else {
+            preamble.push(element);
+        }
+    }
+
+    let keys = parts.iter().map(|(key, _)| key.clone()).collect();
+    for ((key, _), unique) in parts.iter_mut().zip(dedupe_slugs(keys, &[PREAMBLE_KEY])) {
+        *key = unique;
+    }
+    if preamble
+        .iter()
+        .any(|element| !matches!(element, SessionContainerElement::BlankLine(_)))
+    {
+        parts.insert(0, (PREAMBLE_KEY.to_string(), preamble));
+    }
+
+    parts
+        .into_iter()
+        .map(|(key, elements)| {
+            let document = Document {
+                meta: meta.clone(),
+                content: SessionContainer::new(
+                    elements,
+                    annotations.clone(),
+                    parameters.clone(),
+                    ScannerTokenSequence::new(),
+                ),
+                assembly_info: assembly_info.clone(),
+            };
+            (key, document)
+        })
+        .collect()
+}
@@ -0,0 +1,109 @@
+//! Opt-in text span coalescing
+//!
+//! Inline parsing leaves one `Identity` transform per text token, so a
+//! plain paragraph holds dozens of spans. Consumers that walk inline content
+//! (renderers, exporters) run [`coalesce_document_text`] over the assembled
+//! document, which applies [`coalesce_text`] to every inline run: paragraphs,
+//! session titles, list items, definition terms, footnote definitions,
+//! verbatim titles, table cells and inline annotation bodies.
+
+use crate::ast::elements::containers::content::ContentContainerElement;
+use crate::ast::elements::containers::simple::{SimpleBlockElement, SimpleContainer};
+use crate::ast::elements::session::session_container::SessionContainerElement;
+use crate::ast::{
+    coalesce_text, AnnotationBlock, AnnotationContent, ContentContainer, DefinitionBlock, Document,
+    FootnoteDefinitionBlock, ListBlock, TableBlock,
+};
+
+/// Merge adjacent plain text spans throughout the document
+pub fn coalesce_document_text(document: &mut Document) {
+    coalesce_session_elements(&mut document.content.content);
+}
+
+fn coalesce_session_elements(elements: &mut [SessionContainerElement]) {
+    for element in elements {
+        match element {
+            SessionContainerElement::Paragraph(paragraph) => coalesce_text(&mut paragraph.content),
+            SessionContainerElement::List(list) => coalesce_list(list),
+            SessionContainerElement::Definition(definition) => coalesce_definition(definition),
+            SessionContainerElement::Verbatim(verbatim) => coalesce_text(&mut verbatim.title),
+            SessionContainerElement::Annotation(annotation) => coalesce_annotation(annotation),
+            SessionContainerElement::Quote(quote) => coalesce_content_container(&mut quote.content),
+            SessionContainerElement::Table(table) => coalesce_table(table),
+            SessionContainerElement::FootnoteDefinition(footnote) => coalesce_footnote(footnote),
+            SessionContainerElement::Session(session) => {
+                coalesce_text(&mut session.title.content);
+                coalesce_session_elements(&mut session.content.content);
+            }
+            SessionContainerElement::ContentContainer(container) => {
+                coalesce_content_container(container)
+            }
+            SessionContainerElement::SessionContainer(container) => {
+                coalesce_session_elements(&mut container.content)
+            }
+            SessionContainerElement::BlankLine(_) => {}
+        }
+    }
+}
+
+fn coalesce_content_container(container: &mut ContentContainer) {
+    for element in &mut container.content {
+        match element {
+            ContentContainerElement::Paragraph(paragraph) => coalesce_text(&mut paragraph.content),
+            ContentContainerElement::List(list) => coalesce_list(list),
+            ContentContainerElement::Definition(definition) => coalesce_definition(definition),
+            ContentContainerElement::Verbatim(verbatim) => coalesce_text(&mut verbatim.title),
+            ContentContainerElement::Annotation(annotation) => coalesce_annotation(annotation),
+            ContentContainerElement::Quote(quote) => coalesce_content_container(&mut quote.content),
+            ContentContainerElement::Table(table) => coalesce_table(table),
+            ContentContainerElement::FootnoteDefinition(footnote) => coalesce_footnote(footnote),
+            ContentContainerElement::Container(container) => coalesce_content_container(container),
+            ContentContainerElement::BlankLine(_) => {}
+        }
+    }
+}
+
+fn coalesce_simple_container(container: &mut SimpleContainer) {
+    for element in &mut container.content {
+        match element {
+            SimpleBlockElement::Paragraph(paragraph) => coalesce_text(&mut paragraph.content),
+            SimpleBlockElement::List(list) => coalesce_list(list),
+            SimpleBlockElement::Verbatim(verbatim) => coalesce_text(&mut verbatim.title),
+            SimpleBlockElement::BlankLine(_) => {}
+        }
+    }
+}
+
+fn coalesce_list(list: &mut ListBlock) {
+    for item in &mut list.items {
+        coalesce_text(&mut item.content);
+        if let Some(nested) = &mut item.nested {
+            coalesce_content_container(nested);
+        }
+    }
+}
+
+fn coalesce_definition(definition: &mut DefinitionBlock) {
+    coalesce_text(&mut definition.term.content);
+    coalesce_simple_container(&mut definition.content);
+}
+
+fn coalesce_footnote(footnote: &mut FootnoteDefinitionBlock) {
+    coalesce_text(&mut footnote.content);
+    coalesce_simple_container(&mut footnote.body);
+}
+
+fn coalesce_annotation(annotation: &mut AnnotationBlock) {
+    match &mut annotation.content {
+        AnnotationContent::Inline(body) => coalesce_text(body),
+        AnnotationContent::Block(container) => coalesce_simple_container(container),
+    }
+}
+
+fn coalesce_table(table: &mut TableBlock) {
+    for row in table.header.iter_mut().chain(&mut table.rows) {
+        for cell in &mut row.cells {
+            coalesce_text(&mut cell.content);
+        }
+    }
+}
@@ -0,0 +1,236 @@
+//! Opt-in typographic punctuation
+//!
+//! Authors type straight quotes and doubled hyphens; typeset output wants
+//! curly quotes and real dashes. [`apply_typography`] rewrites prose text
+//! throughout the assembled document:
+//!
+//! - `"` becomes `“` or `”`, and `'` becomes `‘` or `’`
+//! - `--` becomes an en dash `–`, and `---` an em dash `—`
+//!
+//! A quote opens when it starts the run or follows whitespace or opening
+//! punctuation, and closes otherwise, so apostrophes come out as `’`.
+//! Code and math spans, verbatim content and escaped characters are left as
+//! written. Each inline run is coalesced first (see [`coalesce_text`]), since
+//! the lexer reads every hyphen as its own token and inline parsing leaves
+//! them in separate spans.
+
+use crate::ast::elements::containers::content::ContentContainerElement;
+use crate::ast::elements::containers::simple::{SimpleBlockElement, SimpleContainer};
+use crate::ast::elements::formatting::inlines::Text;
+use crate::ast::elements::session::session_container::SessionContainerElement;
+use crate::ast::{
+    coalesce_text, AnnotationBlock, AnnotationContent, ContentContainer, DefinitionBlock, Document,
+    FootnoteDefinitionBlock, ListBlock, TableBlock, TextTransform,
+};
+use crate::cst::{ScannerToken, SourceSpan};
+
+/// Rewrite quotes and dashes in prose text throughout the document
+pub fn apply_typography(document: &mut Document) {
+    typeset_session_elements(&mut document.content.content);
+}
+
+/// Rewrite quotes and dashes in one inline run
+pub fn typeset_text(transforms: &mut Vec<TextTransform>) {
+    coalesce_text(transforms);
+    let mut previous = None;
+    typeset_transforms(transforms, &mut previous);
+}
+
+/// Walk a run in reading order, carrying the last character seen so quotes
+/// pair across formatting boundaries
+fn typeset_transforms(transforms: &mut [TextTransform], previous: &mut Option<char>) {
+    for transform in transforms {
+        match transform {
+            TextTransform::Identity(text) => typeset_tokens(text, previous),
+            TextTransform::Emphasis(inner)
+            | TextTransform::Strong(inner)
+            | TextTransform::Composed(inner)
+            | TextTransform::Custom { content: inner, .. } => typeset_transforms(inner, previous),
+            TextTransform::Code(text)
+            | TextTransform::Math(text)
+            | TextTransform::LineBreak(text) => {
+                if let Some(last) = text.content().chars().last() {
+                    *previous = Some(last);
+                }
+            }
+        }
+    }
+}
+
+fn typeset_tokens(text: &mut Text, previous: &mut Option<char>) {
+    let tokens = std::mem::take(&mut text.tokens.tokens);
+    let mut typeset = Vec::with_capacity(tokens.len());
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            ScannerToken::Dash { span } => {
+                let mut dashes = vec![span];
+                while let Some(ScannerToken::Dash { span }) = tokens.peek() {
+                    dashes.push(span.clone());
+                    tokens.next();
+                }
+                let dash = match dashes.len() {
+                    2 => "\u{2013}",
+                    3 => "\u{2014}",
+                    _ => {
+                        typeset.extend(dashes.into_iter().map(|span| ScannerToken::Dash { span }));
+                        *previous = Some('-');
+                        continue;
+                    }
+                };
+                let span = SourceSpan {
+                    start: dashes[0].start,
+                    end: dashes[dashes.len() - 1].end,
+                };
+                typeset.push(ScannerToken::Text {
+                    content: dash.to_string(),
+                    span,
+                });
+                *previous = dash.chars().last();
+            }
+            ScannerToken::Text { content, span } => {
+                let content = typeset_quotes(&content, previous);
+                typeset.push(ScannerToken::Text { content, span });
+            }
+            token => {
+                if let Some(last) = token.content().chars().last() {
+                    *previous = Some(last);
+                }
+                typeset.push(token);
+            }
+        }
+    }
+
+    text.tokens.tokens = typeset;
+}
+
+/// Curl the straight quotes in `content`, leaving escaped characters alone
+fn typeset_quotes(content: &str, previous: &mut Option<char>) -> String {
+    let mut typeset = String::with_capacity(content.len());
+    let mut chars = content.chars();
+
+    while let Some(ch) = chars.next() {
+        let out = match ch {
+            '\\' => {
+                typeset.push(ch);
+                match chars.next() {
+                    Some(escaped) => escaped,
+                    None => continue,
+                }
+            }
+            '"' if opens_quote(*previous) => '\u{201C}',
+            '"' => '\u{201D}',
+            '\'' if opens_quote(*previous) => '\u{2018}',
+            '\'' => '\u{2019}',
+            _ => ch,
+        };
+        typeset.push(out);
+        *previous = Some(out);
+    }
+
+    typeset
+}
+
+/// A quote opens at the start of a run and after whitespace, dashes and
+/// opening brackets or quotes
+fn opens_quote(previous: Option<char>) -> bool {
+    match previous {
+        None => true,
+        Some(ch) => {
+            ch.is_whitespace()
+                || matches!(
+                    ch,
+                    '(' | '[' | '{' | '-' | '\u{2013}' | '\u{2014}' | '\u{201C}' | '\u{2018}'
+                )
+        }
+    }
+}
+
+fn typeset_session_elements(elements: &mut [SessionContainerElement]) {
+    for element in elements {
+        match element {
+            SessionContainerElement::Paragraph(paragraph) => typeset_text(&mut paragraph.content),
+            SessionContainerElement::List(list) => typeset_list(list),
+            SessionContainerElement::Definition(definition) => typeset_definition(definition),
+            SessionContainerElement::Verbatim(verbatim) => typeset_text(&mut verbatim.title),
+            SessionContainerElement::Annotation(annotation) => typeset_annotation(annotation),
+            SessionContainerElement::Quote(quote) => typeset_content_container(&mut quote.content),
+            SessionContainerElement::Table(table) => typeset_table(table),
+            SessionContainerElement::FootnoteDefinition(footnote) => typeset_footnote(footnote),
+            SessionContainerElement::Session(session) => {
+                typeset_text(&mut session.title.content);
+                typeset_session_elements(&mut session.content.content);
+            }
+            SessionContainerElement::ContentContainer(container) => {
+                typeset_content_container(container)
+            }
+            SessionContainerElement::SessionContainer(container) => {
+                typeset_session_elements(&mut container.content)
+            }
+            SessionContainerElement::BlankLine(_) => {}
+        }
+    }
+}
+
+fn typeset_content_container(container: &mut ContentContainer) {
+    for element in &mut container.content {
+        match element {
+            ContentContainerElement::Paragraph(paragraph) => typeset_text(&mut paragraph.content),
+            ContentContainerElement::List(list) => typeset_list(list),
+            ContentContainerElement::Definition(definition) => typeset_definition(definition),
+            ContentContainerElement::Verbatim(verbatim) => typeset_text(&mut verbatim.title),
+            ContentContainerElement::Annotation(annotation) => typeset_annotation(annotation),
+            ContentContainerElement::Quote(quote) => typeset_content_container(&mut quote.content),
+            ContentContainerElement::Table(table) => typeset_table(table),
+            ContentContainerElement::FootnoteDefinition(footnote) => typeset_footnote(footnote),
+            ContentContainerElement::Container(container) => typeset_content_container(container),
+            ContentContainerElement::BlankLine(_) => {}
+        }
+    }
+}
+
+fn typeset_simple_container(container: &mut SimpleContainer) {
+    for element in &mut container.content {
+        match element {
+            SimpleBlockElement::Paragraph(paragraph) => typeset_text(&mut paragraph.content),
+            SimpleBlockElement::List(list) => typeset_list(list),
+            SimpleBlockElement::Verbatim(verbatim) => typeset_text(&mut verbatim.title),
+            SimpleBlockElement::BlankLine(_) => {}
+        }
+    }
+}
+
+fn typeset_list(list: &mut ListBlock) {
+    for item in &mut list.items {
+        typeset_text(&mut item.content);
+        if let Some(nested) = &mut item.nested {
+            typeset_content_container(nested);
+        }
+    }
+}
+
+fn typeset_definition(definition: &mut DefinitionBlock) {
+    typeset_text(&mut definition.term.content);
+    typeset_simple_container(&mut definition.content);
+}
+
+fn typeset_footnote(footnote: &mut FootnoteDefinitionBlock) {
+    typeset_text(&mut footnote.content);
+    typeset_simple_container(&mut footnote.body);
+}
+
+fn typeset_annotation(annotation: &mut AnnotationBlock) {
+    match &mut annotation.content {
+        AnnotationContent::Inline(body) => typeset_text(body),
+        AnnotationContent::Block(container) => typeset_simple_container(container),
+    }
+}
+
+fn typeset_table(table: &mut TableBlock) {
+    for row in table.header.iter_mut().chain(&mut table.rows) {
+        for cell in &mut row.cells {
+            typeset_text(&mut cell.content);
+        }
+    }
+}
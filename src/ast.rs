@@ -128,6 +128,7 @@
 // ============================================================================
 
 // NEW: Spec-aligned element structure (replaces nodes/)
+pub mod build;
 pub mod debug;
 pub mod elements;
 
@@ -135,23 +136,45 @@ pub mod elements;
 
 // Re-export spec-aligned element types as the canonical AST
 pub use elements::{
-    annotation::{AnnotationBlock, AnnotationContent},
+    annotation::{
+        collect_annotations_in_ast, AnnotationBlock, AnnotationContent, AnnotationOccurrence,
+        AnnotationTarget,
+    },
     containers::ContentContainer,
-    core::{BlankLine, ContainerType, ElementNode, ElementType, TxxtElement},
+    core::{
+        content_hash, content_hash_all, subtree_token_count, BlankLine, ContainerType, ElementNode,
+        ElementType, TxxtElement,
+    },
     definition::{DefinitionBlock, DefinitionTerm},
-    document::{AssemblyInfo, Document, Meta, MetaValue, ProcessingStats},
+    document::{
+        AssemblyInfo, AssemblyWarning, Diagnostic, Document, Meta, MetaValue, ProcessingStats,
+        Severity,
+    },
+    footnote::FootnoteDefinitionBlock,
     formatting::{BoldSpan, CodeSpan, ItalicSpan, MathSpan},
-    inlines::{Link, Reference, ReferenceSpan, TextLine, TextSpan, TextTransform},
+    inlines::{coalesce_text, Link, Reference, ReferenceSpan, TextLine, TextSpan, TextTransform},
     list::{ListBlock, ListDecorationType, ListItem, NumberingForm, NumberingStyle},
     paragraph::ParagraphBlock,
-    references::{CitationSpan, FootnoteReferenceSpan, PageReferenceSpan, SessionReferenceSpan},
+    quote::QuoteBlock,
+    references::{
+        collect_references, find_session_cycles, CitationSpan, FootnoteReferenceSpan,
+        FootnoteRegistry, PageReferenceSpan, ReferenceOccurrence, SessionCycle,
+        SessionReferenceSpan,
+    },
     session::SessionContainer,
-    session::{SessionBlock, SessionNumbering, SessionTitle},
+    session::{generate_toc, SessionBlock, SessionNumbering, SessionTitle, Toc, TocEntry},
+    table::{ColumnAlignment, TableBlock, TableCell, TableRow},
     verbatim::IgnoreContainer,
     verbatim::{VerbatimBlock, VerbatimType},
 };
 
 // Core AST infrastructure
+pub use elements::events;
+pub use elements::flat_json;
+pub use elements::folding;
+pub use elements::plaintext;
+pub use elements::semantic_tokens;
+pub use elements::source_map;
 pub use elements::traversal;
 
 // Note: Tree traversal and querying is provided by elements::traversal
@@ -0,0 +1,217 @@
+//! Programmatic Document construction
+//!
+//! The AST is shaped for parsing: every element carries its scanner tokens,
+//! annotations and parameters, which makes building one by hand verbose.
+//! [`DocBuilder`] fills in those fields so documents can be generated from
+//! data:
+//!
+//! ```
+//! use txxt::ast::build::DocBuilder;
+//!
+//! let document = DocBuilder::new()
+//!     .session("Intro", |s| s.paragraph("hi").list(&["a", "b"]))
+//!     .build();
+//!
+//! assert_eq!(document.content.content.len(), 1);
+//! ```
+//!
+//! Built elements have the same structure the parser produces for the
+//! equivalent source. There is no source text behind them, so their tokens
+//! are synthetic: one text token per line of content, positioned at the
+//! start of row 0. Position-based tooling (SourceMap, hover) is meaningless
+//! on a built document.
+
+use crate::ast::elements::components::parameters::Parameters;
+use crate::ast::elements::inlines::Text;
+use crate::ast::elements::session::session_container::SessionContainerElement;
+use crate::ast::{
+    AssemblyInfo, Document, ListBlock, ListDecorationType, ListItem, Meta, MetaValue,
+    NumberingStyle, ParagraphBlock, SessionBlock, SessionContainer, SessionTitle, TextTransform,
+};
+use crate::cst::{Position, ScannerToken, ScannerTokenSequence, SourceSpan};
+
+/// Fluent builder for a complete [`Document`]
+#[derive(Debug, Clone, Default)]
+pub struct DocBuilder {
+    meta: Meta,
+    content: SessionBuilder,
+}
+
+impl DocBuilder {
+    /// Start an empty document
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the document title metadata
+    pub fn title(mut self, title: &str) -> Self {
+        self.meta.title = Some(MetaValue::String(title.to_string()));
+        self
+    }
+
+    /// Add an author to the document metadata
+    pub fn author(mut self, author: &str) -> Self {
+        self.meta
+            .authors
+            .push(MetaValue::String(author.to_string()));
+        self
+    }
+
+    /// Append a paragraph; each line of `text` becomes one paragraph line
+    pub fn paragraph(mut self, text: &str) -> Self {
+        self.content = self.content.paragraph(text);
+        self
+    }
+
+    /// Append a plain (`-`) list
+    pub fn list(mut self, items: &[&str]) -> Self {
+        self.content = self.content.list(items);
+        self
+    }
+
+    /// Append a numbered (`1.`, `2.`, ...) list
+    pub fn numbered_list(mut self, items: &[&str]) -> Self {
+        self.content = self.content.numbered_list(items);
+        self
+    }
+
+    /// Append a session whose content is filled in by `build`
+    pub fn session(
+        mut self,
+        title: &str,
+        build: impl FnOnce(SessionBuilder) -> SessionBuilder,
+    ) -> Self {
+        self.content = self.content.session(title, build);
+        self
+    }
+
+    /// Finish the document
+    pub fn build(self) -> Document {
+        Document {
+            meta: self.meta,
+            content: self.content.into_container(),
+            assembly_info: AssemblyInfo::default(),
+        }
+    }
+}
+
+/// Fluent builder for the content of a session (or the document root)
+#[derive(Debug, Clone, Default)]
+pub struct SessionBuilder {
+    elements: Vec<SessionContainerElement>,
+}
+
+impl SessionBuilder {
+    /// Append a paragraph; each line of `text` becomes one paragraph line
+    pub fn paragraph(mut self, text: &str) -> Self {
+        // Lines are joined by a soft wrap (a single space), as when parsed
+        let mut content = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            if index > 0 {
+                content.push(synthetic_text(" "));
+            }
+            content.push(synthetic_text(line));
+        }
+        self.elements
+            .push(SessionContainerElement::Paragraph(ParagraphBlock::new(
+                content,
+                Vec::new(),
+                Parameters::default(),
+                ScannerTokenSequence::new(),
+            )));
+        self
+    }
+
+    /// Append a plain (`-`) list
+    pub fn list(self, items: &[&str]) -> Self {
+        self.push_list(ListDecorationType::default(), items, |_| "-".to_string())
+    }
+
+    /// Append a numbered (`1.`, `2.`, ...) list
+    pub fn numbered_list(self, items: &[&str]) -> Self {
+        let decoration = ListDecorationType {
+            style: NumberingStyle::Numerical,
+            ..ListDecorationType::default()
+        };
+        self.push_list(decoration, items, |index| format!("{}.", index + 1))
+    }
+
+    /// Append a nested session whose content is filled in by `build`
+    pub fn session(
+        mut self,
+        title: &str,
+        build: impl FnOnce(SessionBuilder) -> SessionBuilder,
+    ) -> Self {
+        let content = build(SessionBuilder::default()).into_container();
+        let title = SessionTitle::new(
+            vec![synthetic_text(title)],
+            None,
+            ScannerTokenSequence::new(),
+        );
+        self.elements
+            .push(SessionContainerElement::Session(SessionBlock::new(
+                title,
+                content,
+                Vec::new(),
+                Parameters::default(),
+                ScannerTokenSequence::new(),
+            )));
+        self
+    }
+
+    fn push_list(
+        mut self,
+        decoration: ListDecorationType,
+        items: &[&str],
+        marker: impl Fn(usize) -> String,
+    ) -> Self {
+        let items = items
+            .iter()
+            .enumerate()
+            .map(|(index, text)| {
+                ListItem::new(
+                    marker(index),
+                    vec![synthetic_text(text)],
+                    None,
+                    Vec::new(),
+                    Parameters::default(),
+                    ScannerTokenSequence::new(),
+                )
+            })
+            .collect();
+        self.elements
+            .push(SessionContainerElement::List(ListBlock::new(
+                decoration,
+                items,
+                Vec::new(),
+                Parameters::default(),
+                ScannerTokenSequence::new(),
+            )));
+        self
+    }
+
+    fn into_container(self) -> SessionContainer {
+        SessionContainer::new(
+            self.elements,
+            Vec::new(),
+            Parameters::default(),
+            ScannerTokenSequence::new(),
+        )
+    }
+}
+
+/// Wrap a line of text in a single synthetic text token
+fn synthetic_text(content: &str) -> TextTransform {
+    let span = SourceSpan {
+        start: Position { row: 0, column: 0 },
+        end: Position {
+            row: 0,
+            column: content.len(),
+        },
+    };
+    let tokens = ScannerTokenSequence::from_tokens(vec![ScannerToken::Text {
+        content: content.to_string(),
+        span,
+    }]);
+    TextTransform::Identity(Text::simple_with_tokens(content, tokens))
+}
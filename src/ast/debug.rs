@@ -285,6 +285,34 @@ impl AstTreeVisualizer {
                 }
             }
 
+            SessionContainerElement::Quote(quote) => {
+                writeln!(
+                    output,
+                    "{} Quote ({} elements)",
+                    prefix,
+                    quote.content.content.len()
+                )
+                .unwrap();
+                self.visualize_content_container(&quote.content, output, indent, depth);
+            }
+            SessionContainerElement::Table(table) => {
+                writeln!(
+                    output,
+                    "{} Table ({} rows x {} columns)",
+                    prefix,
+                    table.row_count(),
+                    table.column_count()
+                )
+                .unwrap();
+            }
+            SessionContainerElement::FootnoteDefinition(footnote) => {
+                writeln!(output, "{} Footnote: [^{}]", prefix, footnote.label).unwrap();
+
+                if !footnote.body.content.is_empty() {
+                    self.visualize_simple_container(&footnote.body, output, indent, depth);
+                }
+            }
+
             SessionContainerElement::BlankLine(_) => {
                 if !self.config.compact {
                     writeln!(output, "{} Blank Line", prefix).unwrap();
@@ -433,6 +461,29 @@ impl AstTreeVisualizer {
             ContentContainerElement::Annotation(_annotation) => {
                 writeln!(output, "{} Annotation", prefix).unwrap();
             }
+            ContentContainerElement::Quote(quote) => {
+                writeln!(
+                    output,
+                    "{} Quote ({} elements)",
+                    prefix,
+                    quote.content.content.len()
+                )
+                .unwrap();
+                self.visualize_content_container(&quote.content, output, indent, depth);
+            }
+            ContentContainerElement::Table(table) => {
+                writeln!(
+                    output,
+                    "{} Table ({} rows x {} columns)",
+                    prefix,
+                    table.row_count(),
+                    table.column_count()
+                )
+                .unwrap();
+            }
+            ContentContainerElement::FootnoteDefinition(footnote) => {
+                writeln!(output, "{} Footnote: [^{}]", prefix, footnote.label).unwrap();
+            }
             ContentContainerElement::BlankLine(_) => {
                 if !self.config.compact {
                     writeln!(output, "{} Blank Line", prefix).unwrap();
@@ -637,6 +688,9 @@ impl AstComparator {
             SessionContainerElement::Verbatim(_) => "Verbatim",
             SessionContainerElement::Definition(_) => "Definition",
             SessionContainerElement::Annotation(_) => "Annotation",
+            SessionContainerElement::Quote(_) => "Quote",
+            SessionContainerElement::Table(_) => "Table",
+            SessionContainerElement::FootnoteDefinition(_) => "FootnoteDefinition",
             SessionContainerElement::BlankLine(_) => "BlankLine",
             SessionContainerElement::ContentContainer(_) => "ContentContainer",
             SessionContainerElement::SessionContainer(_) => "SessionContainer",
@@ -723,6 +777,22 @@ impl AstStatistics {
             SessionContainerElement::Annotation(_) => {
                 // Annotations don't count as primary content
             }
+            SessionContainerElement::Quote(quote) => {
+                self.collect_from_content_container(&quote.content, depth + 1);
+            }
+            SessionContainerElement::Table(table) => {
+                self.total_characters += table
+                    .header
+                    .iter()
+                    .chain(&table.rows)
+                    .flat_map(|row| &row.cells)
+                    .map(|cell| cell.text_content().len())
+                    .sum::<usize>();
+            }
+            SessionContainerElement::FootnoteDefinition(footnote) => {
+                self.total_characters += footnote.text().len();
+                self.collect_from_simple_container(&footnote.body, depth + 1);
+            }
             SessionContainerElement::BlankLine(_) => {
                 // Blank lines don't add to character count
             }
@@ -797,6 +867,22 @@ impl AstStatistics {
             ContentContainerElement::Annotation(_) => {
                 // Annotations don't count as primary content
             }
+            ContentContainerElement::Quote(quote) => {
+                self.collect_from_content_container(&quote.content, depth + 1);
+            }
+            ContentContainerElement::Table(table) => {
+                self.total_characters += table
+                    .header
+                    .iter()
+                    .chain(&table.rows)
+                    .flat_map(|row| &row.cells)
+                    .map(|cell| cell.text_content().len())
+                    .sum::<usize>();
+            }
+            ContentContainerElement::FootnoteDefinition(footnote) => {
+                self.total_characters += footnote.text().len();
+                self.collect_from_simple_container(&footnote.body, depth + 1);
+            }
             ContentContainerElement::BlankLine(_) => {
                 // Blank lines don't add to character count
             }
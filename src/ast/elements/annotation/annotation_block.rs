@@ -9,7 +9,7 @@ use crate::ast::elements::{
     annotation::annotation_content::Annotation,
     components::{parameters::Parameters, ParsedLabel},
 };
-use crate::cst::ScannerTokenSequence;
+use crate::cst::{ScannerToken, ScannerTokenSequence};
 
 use super::super::{
     containers::SimpleContainer,
@@ -35,12 +35,15 @@ pub struct AnnotationBlock {
     pub content: AnnotationContent,
 
     /// Parameters in key=value format (severity=high, ref=, id=, etc.)
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Annotations attached to this annotation block (meta-annotations)
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Raw tokens for precise source reconstruction
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 
     /// Namespace information (if label contains dots)
@@ -91,6 +94,13 @@ impl TxxtElement for AnnotationBlock {
     fn parameters(&self) -> &Parameters {
         &self.parameters
     }
+
+    fn child_nodes(&self) -> Vec<&dyn TxxtElement> {
+        match &self.content {
+            AnnotationContent::Block(container) => vec![container],
+            AnnotationContent::Inline(_) => Vec::new(),
+        }
+    }
 }
 
 impl BlockElement for AnnotationBlock {
@@ -167,4 +177,17 @@ impl AnnotationBlock {
     pub fn is_block(&self) -> bool {
         matches!(self.content, AnnotationContent::Block(_))
     }
+
+    /// Check if the closing `::` was cut off by the end of input
+    ///
+    /// The parser keeps such an annotation with its label and no content.
+    pub fn is_truncated(&self) -> bool {
+        let markers = self
+            .tokens
+            .tokens
+            .iter()
+            .filter(|token| matches!(token, ScannerToken::TxxtMarker { .. }))
+            .count();
+        markers == 1
+    }
 }
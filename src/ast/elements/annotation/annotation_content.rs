@@ -46,12 +46,14 @@ pub struct Annotation {
     /// Optional parameters in key=value format
     /// Example: :: warning severity=high :: Content
     /// Supports ref=, id=, severity=, category= and other metadata
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Annotation content (can be rich text with formatting)
     pub content: AnnotationContent,
 
     /// Raw tokens for source reconstruction and positioning
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 
     /// Namespace information (if label contains dots)
@@ -5,7 +5,9 @@
 
 pub mod annotation_block;
 pub mod annotation_content;
+pub mod occurrences;
 
 // Re-export annotation types
 pub use annotation_block::{AnnotationBlock, AnnotationContent};
 pub use annotation_content::*;
+pub use occurrences::{collect_annotations_in_ast, AnnotationOccurrence, AnnotationTarget};
@@ -0,0 +1,125 @@
+//! Annotation enumeration
+//!
+//! [`collect_annotations_in_ast`] lists every annotation in a parsed
+//! document, in document order, with its label, namespace, parameters and
+//! the node it belongs to. It works on the AST; the crate-level
+//! `collect_annotations` reads `.info` files instead.
+//!
+//! Annotations show up in two places. Attached annotations sit in the
+//! `annotations` field of the document's root container or of an element.
+//! Annotation blocks the attacher has not moved onto an element stay in the
+//! content; they are reported against the element whose content holds
+//! them, or the document when they stand at the top level.
+//!
+//! The walk follows [`events`], so the untyped wrapper containers it walks
+//! through are skipped here too.
+
+use crate::ast::elements::components::parameters::Parameters;
+use crate::ast::elements::events::{events, DocEvent};
+use crate::ast::Document;
+
+use super::{Annotation, AnnotationBlock};
+
+/// One annotation and the node it belongs to
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnnotationOccurrence<'a> {
+    /// Label without its namespace (`review` for `org.example.review`)
+    pub label: &'a str,
+
+    /// Namespace of the label, if it has one (`org.example`)
+    pub namespace: Option<&'a str>,
+
+    /// Parameters given after the label
+    pub parameters: &'a Parameters,
+
+    /// Node the annotation belongs to
+    pub target: AnnotationTarget<'a>,
+}
+
+/// Node an annotation belongs to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnnotationTarget<'a> {
+    /// The document as a whole
+    Document,
+
+    /// An element, given as the event that opens it
+    ///
+    /// Verbatim blocks and tables come as their single event.
+    Element(DocEvent<'a>),
+}
+
+/// List every annotation in a document, in document order
+pub fn collect_annotations_in_ast(doc: &Document) -> Vec<AnnotationOccurrence<'_>> {
+    let mut occurrences: Vec<AnnotationOccurrence<'_>> = doc
+        .content
+        .annotations
+        .iter()
+        .map(|annotation| attached(annotation, AnnotationTarget::Document))
+        .collect();
+    let mut open: Vec<DocEvent<'_>> = Vec::new();
+
+    for event in events(doc) {
+        if event.is_exit() {
+            open.pop();
+            continue;
+        }
+        if let DocEvent::EnterAnnotation(block) = event {
+            let holder = open.last().map_or(AnnotationTarget::Document, |&event| {
+                AnnotationTarget::Element(event)
+            });
+            occurrences.push(standing(block, holder));
+        }
+
+        let target = AnnotationTarget::Element(event);
+        occurrences.extend(
+            attached_annotations(&event)
+                .iter()
+                .map(|annotation| attached(annotation, target)),
+        );
+        if event.is_enter() {
+            open.push(event);
+        }
+    }
+    occurrences
+}
+
+/// The `annotations` field of the element an event reports
+fn attached_annotations<'a>(event: &DocEvent<'a>) -> &'a [Annotation] {
+    match *event {
+        DocEvent::EnterSession(session) => &session.annotations,
+        DocEvent::EnterParagraph(paragraph) => &paragraph.annotations,
+        DocEvent::EnterList(list) => &list.annotations,
+        DocEvent::EnterListItem(item) => &item.annotations,
+        DocEvent::EnterDefinition(definition) => &definition.annotations,
+        DocEvent::EnterQuote(quote) => &quote.annotations,
+        DocEvent::EnterAnnotation(annotation) => &annotation.annotations,
+        DocEvent::EnterFootnote(footnote) => &footnote.annotations,
+        DocEvent::Verbatim(verbatim) => &verbatim.annotations,
+        DocEvent::Table(table) => &table.annotations,
+        _ => &[],
+    }
+}
+
+fn attached<'a>(
+    annotation: &'a Annotation,
+    target: AnnotationTarget<'a>,
+) -> AnnotationOccurrence<'a> {
+    AnnotationOccurrence {
+        label: annotation.local_label(),
+        namespace: annotation.namespace.as_deref(),
+        parameters: &annotation.parameters,
+        target,
+    }
+}
+
+fn standing<'a>(
+    block: &'a AnnotationBlock,
+    target: AnnotationTarget<'a>,
+) -> AnnotationOccurrence<'a> {
+    AnnotationOccurrence {
+        label: block.local_label(),
+        namespace: block.namespace.as_deref(),
+        parameters: &block.parameters,
+        target,
+    }
+}
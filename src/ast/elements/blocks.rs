@@ -31,11 +31,14 @@ pub enum Block {
     Paragraph(super::paragraph::ParagraphBlock),
     VerbatimBlock(VerbatimBlock),
     BlankLine(super::core::BlankLine),
+    Table(super::table::TableBlock),
 
     // Content container blocks (cannot host sessions)
     List(List),
     Definition(Definition),
     Annotation(super::annotation::AnnotationBlock),
+    Quote(super::quote::QuoteBlock),
+    FootnoteDefinition(super::footnote::FootnoteDefinitionBlock),
 
     // Session container blocks (can host new document sessions)
     Session(super::session::SessionBlock),
@@ -55,6 +58,9 @@ impl From<super::containers::content::ContentContainerElement> for Block {
             ContentContainerElement::Definition(d) => Block::Definition(d),
             ContentContainerElement::Verbatim(v) => Block::VerbatimBlock(v),
             ContentContainerElement::Annotation(a) => Block::Annotation(a),
+            ContentContainerElement::Quote(q) => Block::Quote(q),
+            ContentContainerElement::FootnoteDefinition(f) => Block::FootnoteDefinition(f),
+            ContentContainerElement::Table(t) => Block::Table(t),
             ContentContainerElement::Container(c) => Block::Container(c),
             ContentContainerElement::BlankLine(b) => Block::BlankLine(b),
         }
@@ -67,7 +73,7 @@ impl From<super::containers::simple::SimpleBlockElement> for Block {
         match element {
             SimpleBlockElement::Paragraph(p) => Block::Paragraph(p),
             SimpleBlockElement::List(l) => Block::List(l),
-            SimpleBlockElement::Verbatim(v) => Block::VerbatimBlock(v),
+            SimpleBlockElement::Verbatim(v) => Block::VerbatimBlock(*v),
             SimpleBlockElement::BlankLine(b) => Block::BlankLine(b),
         }
     }
@@ -65,9 +65,11 @@ use crate::cst::ScannerTokenSequence;
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Parameters {
     /// Key-value parameter map
+    #[serde(default)]
     pub map: HashMap<String, String>,
 
     /// Raw tokens for source reconstruction
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
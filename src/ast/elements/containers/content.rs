@@ -46,12 +46,15 @@ pub struct ContentContainer {
     pub content: Vec<ContentContainerElement>,
 
     /// Annotations attached to this container
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for metadata (rare for containers)
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Source position information
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -76,6 +79,15 @@ pub enum ContentContainerElement {
     /// Annotation blocks
     Annotation(super::super::annotation::AnnotationBlock),
 
+    /// Quote blocks
+    Quote(super::super::quote::QuoteBlock),
+
+    /// Table blocks
+    Table(super::super::table::TableBlock),
+
+    /// Footnote definition blocks
+    FootnoteDefinition(super::super::footnote::FootnoteDefinitionBlock),
+
     /// Nested content containers
     Container(ContentContainer),
 
@@ -100,6 +112,10 @@ impl TxxtElement for ContentContainer {
     fn parameters(&self) -> &Parameters {
         &self.parameters
     }
+
+    fn child_nodes(&self) -> Vec<&dyn TxxtElement> {
+        self.child_elements()
+    }
 }
 
 impl ContainerElement for ContentContainer {
@@ -119,6 +135,9 @@ impl ContainerElement for ContentContainer {
                 ContentContainerElement::List(l) => l as &dyn TxxtElement,
                 ContentContainerElement::Definition(d) => d as &dyn TxxtElement,
                 ContentContainerElement::Verbatim(v) => v as &dyn TxxtElement,
+                ContentContainerElement::Quote(q) => q as &dyn TxxtElement,
+                ContentContainerElement::Table(t) => t as &dyn TxxtElement,
+                ContentContainerElement::FootnoteDefinition(f) => f as &dyn TxxtElement,
                 ContentContainerElement::Annotation(a) => a as &dyn TxxtElement,
                 ContentContainerElement::Container(c) => c as &dyn TxxtElement,
                 ContentContainerElement::BlankLine(b) => b as &dyn TxxtElement,
@@ -97,12 +97,15 @@ pub struct SimpleContainer {
     pub content: Vec<SimpleBlockElement>,
 
     /// Annotations attached to this container
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for metadata (rare for containers)
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Source position information
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -122,7 +125,7 @@ pub enum SimpleBlockElement {
     List(super::super::list::ListBlock),
 
     /// Verbatim blocks - code examples, literal content
-    Verbatim(super::super::verbatim::VerbatimBlock),
+    Verbatim(Box<super::super::verbatim::VerbatimBlock>),
 
     /// Blank lines (structural separators)
     BlankLine(super::super::core::BlankLine),
@@ -149,6 +152,10 @@ impl TxxtElement for SimpleContainer {
     fn parameters(&self) -> &Parameters {
         &self.parameters
     }
+
+    fn child_nodes(&self) -> Vec<&dyn TxxtElement> {
+        self.child_elements()
+    }
 }
 
 impl ContainerElement for SimpleContainer {
@@ -166,7 +173,7 @@ impl ContainerElement for SimpleContainer {
             .map(|element| match element {
                 SimpleBlockElement::Paragraph(p) => p as &dyn TxxtElement,
                 SimpleBlockElement::List(l) => l as &dyn TxxtElement,
-                SimpleBlockElement::Verbatim(v) => v as &dyn TxxtElement,
+                SimpleBlockElement::Verbatim(v) => v.as_ref() as &dyn TxxtElement,
                 SimpleBlockElement::BlankLine(b) => b as &dyn TxxtElement,
             })
             .collect()
@@ -3,12 +3,14 @@
 //! This module defines the foundational type system for TXXT AST elements,
 //! implementing the element hierarchy from `docs/specs/core/terminology.txxt`.
 
+use std::collections::HashSet;
+
 use serde::{Deserialize, Serialize};
 
 use crate::ast::elements::{
     annotation::annotation_content::Annotation, components::parameters::Parameters,
 };
-use crate::cst::ScannerTokenSequence;
+use crate::cst::{ScannerToken, ScannerTokenSequence};
 
 /// Element type classification according to specification terminology
 ///
@@ -51,6 +53,166 @@ pub trait TxxtElement {
 
     /// Access parameters attached to this element
     fn parameters(&self) -> &Parameters;
+
+    /// Number of tokens this element holds itself
+    ///
+    /// Children keep their own tokens; `subtree_token_count` counts those
+    /// too.
+    fn token_count(&self) -> usize {
+        self.tokens().tokens.len()
+    }
+
+    /// Elements nested directly inside this one (none for leaves)
+    fn child_nodes(&self) -> Vec<&dyn TxxtElement> {
+        Vec::new()
+    }
+
+    /// Content this element holds outside its token sequence
+    ///
+    /// Elements built without tokens (session titles) report their text
+    /// here, so [`content_hash`] still sees it.
+    fn untokenized_content(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Number of tokens in an element and everything nested inside it
+///
+/// Meant for profiling which elements are token-heavy. Blocks often hold
+/// their parts' tokens as well (a list holds its items'), so each source
+/// span is counted once.
+pub fn subtree_token_count(element: &dyn TxxtElement) -> usize {
+    fn collect(element: &dyn TxxtElement, spans: &mut HashSet<(usize, usize, usize, usize)>) {
+        spans.extend(element.tokens().tokens.iter().map(|token| {
+            let span = token.span();
+            (
+                span.start.row,
+                span.start.column,
+                span.end.row,
+                span.end.column,
+            )
+        }));
+        for child in element.child_nodes() {
+            collect(child, spans);
+        }
+    }
+
+    let mut spans = HashSet::new();
+    collect(element, &mut spans);
+    spans.len()
+}
+
+/// Stable hash of an element's content and everything nested inside it
+///
+/// Covers element types, token text, text held outside tokens (see
+/// [`TxxtElement::untokenized_content`]), parameters and attached
+/// annotation labels, but not where tokens sit: spans, whitespace and line
+/// breaks are skipped, so reflowing or re-indenting text that keeps its
+/// structure leaves the hash unchanged. Uses FNV-1a rather than
+/// `DefaultHasher`, so the value is the same across runs and builds and
+/// can be stored.
+pub fn content_hash(element: &dyn TxxtElement) -> u64 {
+    content_hash_all(std::iter::once(element))
+}
+
+/// [`content_hash`] of a sequence of sibling elements, in order
+pub fn content_hash_all<'a>(elements: impl IntoIterator<Item = &'a dyn TxxtElement>) -> u64 {
+    let mut hasher = ContentHasher::new();
+    for element in elements {
+        hasher.element(element);
+    }
+    hasher.finish()
+}
+
+/// 64-bit FNV-1a over length-delimited fields
+struct ContentHasher(u64);
+
+impl ContentHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    /// Hash a field with its length up front, so `ab`,`c` and `a`,`bc` differ
+    fn field(&mut self, text: &str) {
+        self.bytes(&(text.len() as u64).to_le_bytes());
+        self.bytes(text.as_bytes());
+    }
+
+    fn element(&mut self, element: &dyn TxxtElement) {
+        let children = element.child_nodes();
+        let tokens: Vec<_> = element
+            .tokens()
+            .tokens
+            .iter()
+            .filter(|token| !token_is_layout(token))
+            .collect();
+        let untokenized = element.untokenized_content();
+        // Blank lines and other whitespace-only elements carry no content
+        if tokens.is_empty() && untokenized.is_empty() && children.is_empty() {
+            return;
+        }
+
+        self.field(&format!("{:?}", element.element_type()));
+        self.bytes(&(tokens.len() as u64).to_le_bytes());
+        for token in tokens {
+            match token {
+                ScannerToken::FootnoteRef { footnote_type, .. } => {
+                    self.field(&format!("{:?}", footnote_type))
+                }
+                token => self.field(token.content()),
+            }
+        }
+
+        self.bytes(&(untokenized.len() as u64).to_le_bytes());
+        for text in &untokenized {
+            self.field(text);
+        }
+
+        let mut parameters: Vec<_> = element.parameters().map.iter().collect();
+        parameters.sort();
+        self.bytes(&(parameters.len() as u64).to_le_bytes());
+        for (key, value) in parameters {
+            self.field(key);
+            self.field(value);
+        }
+
+        self.bytes(&(element.annotations().len() as u64).to_le_bytes());
+        for annotation in element.annotations() {
+            self.field(&annotation.name);
+        }
+
+        self.bytes(&(children.len() as u64).to_le_bytes());
+        for child in children {
+            self.element(child);
+        }
+    }
+}
+
+/// Tokens that only place content on the page
+fn token_is_layout(token: &ScannerToken) -> bool {
+    matches!(
+        token,
+        ScannerToken::Whitespace { .. }
+            | ScannerToken::Newline { .. }
+            | ScannerToken::BlankLine { .. }
+            | ScannerToken::Indent { .. }
+            | ScannerToken::Dedent { .. }
+            | ScannerToken::Eof { .. }
+    )
 }
 
 /// Trait for span elements (inline content)
@@ -58,9 +220,14 @@ pub trait TxxtElement {
 /// Span elements cannot contain line breaks and represent the smallest
 /// semantic units within lines.
 pub trait SpanElement: TxxtElement {
-    /// Get the text content of this span
+    /// Get the text content of this span, escape sequences resolved
     fn text_content(&self) -> String;
 
+    /// Get the source form of this span, escape backslashes included
+    fn raw_text(&self) -> String {
+        self.tokens().raw_text()
+    }
+
     /// Check if this span contains formatting
     fn is_formatted(&self) -> bool {
         false // Default: plain text
@@ -208,6 +375,9 @@ pub enum ElementNode {
     VerbatimBlock(super::verbatim::VerbatimBlock),
     SessionBlock(super::session::SessionBlock),
     AnnotationBlock(super::annotation::AnnotationBlock),
+    QuoteBlock(super::quote::QuoteBlock),
+    TableBlock(super::table::TableBlock),
+    FootnoteDefinitionBlock(super::footnote::FootnoteDefinitionBlock),
 
     // Container elements
     ContentContainer(super::containers::ContentContainer),
@@ -240,7 +410,10 @@ impl ElementNode {
             | ElementNode::DefinitionBlock(_)
             | ElementNode::VerbatimBlock(_)
             | ElementNode::SessionBlock(_)
-            | ElementNode::AnnotationBlock(_) => ElementType::Block,
+            | ElementNode::AnnotationBlock(_)
+            | ElementNode::QuoteBlock(_)
+            | ElementNode::TableBlock(_)
+            | ElementNode::FootnoteDefinitionBlock(_) => ElementType::Block,
 
             // Container elements
             ElementNode::ContentContainer(_)
@@ -257,6 +430,7 @@ impl ElementNode {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BlankLine {
     /// Source position information
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -32,12 +32,15 @@ pub struct DefinitionBlock {
     pub content: SimpleContainer,
 
     /// Parameters for metadata including ref= for named anchors
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Annotations attached to this definition
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Raw tokens for source reconstruction
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -48,6 +51,7 @@ pub struct DefinitionTerm {
     pub content: Vec<TextTransform>,
 
     /// Raw tokens for exact positioning
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -67,6 +71,10 @@ impl TxxtElement for DefinitionBlock {
     fn parameters(&self) -> &Parameters {
         &self.parameters
     }
+
+    fn child_nodes(&self) -> Vec<&dyn TxxtElement> {
+        vec![&self.term, &self.content]
+    }
 }
 
 impl BlockElement for DefinitionBlock {
@@ -24,12 +24,15 @@ pub struct DefinitionBlock {
     pub content: ContentContainer,
 
     /// Parameters for metadata including ref= for named anchors
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Annotations attached to this definition
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Raw tokens for source reconstruction
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -40,6 +43,7 @@ pub struct DefinitionTerm {
     pub content: Vec<TextTransform>,
 
     /// Raw tokens for exact positioning
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -16,9 +16,13 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::ast::elements::{
-    blocks::Block, components::parameters::Parameters, session::SessionContainer,
+    blocks::Block,
+    components::parameters::Parameters,
+    core::{content_hash_all, TxxtElement},
+    session::SessionContainer,
 };
-use crate::cst::ScannerTokenSequence;
+use crate::cst::{ScannerTokenSequence, SourceSpan};
+use crate::syntax::IndentationIssue;
 
 /// Top-level document structure
 ///
@@ -94,6 +98,259 @@ pub struct AssemblyInfo {
 
     /// Parsing/assembly statistics
     pub stats: ProcessingStats,
+
+    /// Problems the parser recovered from; the affected content is still in the tree
+    #[serde(default)]
+    pub warnings: Vec<AssemblyWarning>,
+}
+
+/// A malformed construct the parser recovered from instead of failing
+///
+/// Recovery keeps the content as a partial element, so a truncated document
+/// still assembles; the warning tells tooling the source needs fixing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AssemblyWarning {
+    /// A verbatim block cut off by the end of input, before or inside its terminator
+    UnterminatedVerbatim { title: String, line: usize },
+
+    /// An annotation missing its closing `::` at end of input
+    UnterminatedAnnotation { label: String, line: usize },
+
+    /// A parameter key given more than once; the last value is kept
+    DuplicateParameter { key: String, line: usize },
+}
+
+impl std::fmt::Display for AssemblyWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssemblyWarning::UnterminatedVerbatim { title, line } => write!(
+                f,
+                "Unterminated verbatim block '{}' at line {}: input ends before its terminator is complete",
+                title, line
+            ),
+            AssemblyWarning::UnterminatedAnnotation { label, line } => write!(
+                f,
+                "Unterminated annotation '{}' at line {}: missing closing ::",
+                label, line
+            ),
+            AssemblyWarning::DuplicateParameter { key, line } => write!(
+                f,
+                "Duplicate parameter '{}' at line {}: the last value is kept",
+                key, line
+            ),
+        }
+    }
+}
+
+/// A problem found while parsing a document
+///
+/// Lenient parsing reports these alongside the document; strict parsing
+/// turns the first one into an error (see `api::run_all_checked`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Diagnostic {
+    /// Inline markup that failed to parse and was kept as plain text
+    InlineFallback { line: usize, message: String },
+
+    /// A reference whose target type could not be determined
+    ///
+    /// `span` covers the reference in the source, brackets included.
+    UnresolvedReference {
+        target: String,
+        line: usize,
+        span: SourceSpan,
+    },
+
+    /// Session references that lead back to where they started
+    ///
+    /// `sessions` holds the titles of the sessions in the cycle; `line` is
+    /// that of the reference closing it.
+    ReferenceCycle { sessions: Vec<String>, line: usize },
+
+    /// A malformed construct the assembler recovered from
+    Recovery(AssemblyWarning),
+
+    /// Leading whitespace that mixes tabs and spaces
+    Indentation(IndentationIssue),
+
+    /// A line longer than the configured maximum (see `api::lint_line_length`)
+    LineTooLong {
+        line: usize,
+        length: usize,
+        max: usize,
+    },
+
+    /// A list item whose marker style (plain, numerical, alphabetical)
+    /// differs from the list's first item
+    MixedListStyle {
+        line: usize,
+        expected: String,
+        found: String,
+    },
+
+    /// A `Term:` line with no indented body, read as a paragraph
+    EmptyDefinitionBody { term: String, line: usize },
+
+    /// A formatting delimiter (`*`, `_`, `` ` ``, `#`) with no partner,
+    /// kept as plain text (see `api::lint_unmatched_delimiters`)
+    UnmatchedDelimiter {
+        delimiter: String,
+        line: usize,
+        span: SourceSpan,
+    },
+
+    /// An annotation label one or two typos away from a reserved label,
+    /// read as a custom label
+    UnknownAnnotationLabel {
+        got: String,
+        suggestion: String,
+        line: usize,
+    },
+}
+
+impl Diagnostic {
+    /// The 1-based source line the diagnostic points at
+    pub fn line(&self) -> usize {
+        match self {
+            Diagnostic::InlineFallback { line, .. }
+            | Diagnostic::UnresolvedReference { line, .. }
+            | Diagnostic::ReferenceCycle { line, .. }
+            | Diagnostic::LineTooLong { line, .. }
+            | Diagnostic::MixedListStyle { line, .. }
+            | Diagnostic::UnmatchedDelimiter { line, .. }
+            | Diagnostic::UnknownAnnotationLabel { line, .. }
+            | Diagnostic::EmptyDefinitionBody { line, .. } => *line,
+            Diagnostic::Recovery(AssemblyWarning::UnterminatedVerbatim { line, .. })
+            | Diagnostic::Recovery(AssemblyWarning::UnterminatedAnnotation { line, .. })
+            | Diagnostic::Recovery(AssemblyWarning::DuplicateParameter { line, .. }) => *line,
+            Diagnostic::Indentation(issue) => issue.line(),
+        }
+    }
+
+    /// How much the diagnostic matters
+    ///
+    /// Input cut off mid-construct is an error, constructs read other than
+    /// the author likely meant are warnings, and style lints are hints.
+    pub fn severity(&self) -> Severity {
+        match self {
+            Diagnostic::Recovery(
+                AssemblyWarning::UnterminatedVerbatim { .. }
+                | AssemblyWarning::UnterminatedAnnotation { .. },
+            ) => Severity::Error,
+            Diagnostic::InlineFallback { .. }
+            | Diagnostic::UnresolvedReference { .. }
+            | Diagnostic::ReferenceCycle { .. }
+            | Diagnostic::Recovery(AssemblyWarning::DuplicateParameter { .. })
+            | Diagnostic::Indentation(_)
+            | Diagnostic::MixedListStyle { .. }
+            | Diagnostic::EmptyDefinitionBody { .. }
+            | Diagnostic::UnmatchedDelimiter { .. } => Severity::Warning,
+            Diagnostic::LineTooLong { .. } | Diagnostic::UnknownAnnotationLabel { .. } => {
+                Severity::Hint
+            }
+        }
+    }
+
+    /// Stable identifier of the kind of diagnostic, for filtering and
+    /// suppressing in tools
+    pub fn code(&self) -> &'static str {
+        match self {
+            Diagnostic::InlineFallback { .. } => "inline-fallback",
+            Diagnostic::UnresolvedReference { .. } => "unresolved-reference",
+            Diagnostic::ReferenceCycle { .. } => "reference-cycle",
+            Diagnostic::Recovery(AssemblyWarning::UnterminatedVerbatim { .. }) => {
+                "unterminated-verbatim"
+            }
+            Diagnostic::Recovery(AssemblyWarning::UnterminatedAnnotation { .. }) => {
+                "unterminated-annotation"
+            }
+            Diagnostic::Recovery(AssemblyWarning::DuplicateParameter { .. }) => {
+                "duplicate-parameter"
+            }
+            Diagnostic::Indentation(_) => "mixed-indentation",
+            Diagnostic::LineTooLong { .. } => "line-too-long",
+            Diagnostic::MixedListStyle { .. } => "mixed-list-style",
+            Diagnostic::EmptyDefinitionBody { .. } => "empty-definition-body",
+            Diagnostic::UnmatchedDelimiter { .. } => "unmatched-delimiter",
+            Diagnostic::UnknownAnnotationLabel { .. } => "unknown-annotation-label",
+        }
+    }
+
+    /// Source range the diagnostic covers, for diagnostics that know more
+    /// than their line
+    pub fn span(&self) -> Option<&SourceSpan> {
+        match self {
+            Diagnostic::UnresolvedReference { span, .. }
+            | Diagnostic::UnmatchedDelimiter { span, .. } => Some(span),
+            _ => None,
+        }
+    }
+}
+
+/// How much a [`Diagnostic`] matters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Severity {
+    Error,
+    Warning,
+    Hint,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Diagnostic::InlineFallback { line, message } => write!(
+                f,
+                "Inline markup at line {} kept as plain text: {}",
+                line, message
+            ),
+            Diagnostic::UnresolvedReference { target, line, .. } => {
+                write!(f, "Unresolved reference '[{}]' at line {}", target, line)
+            }
+            Diagnostic::ReferenceCycle { sessions, line } => write!(
+                f,
+                "Session references at line {} form a cycle: {} -> {}",
+                line,
+                sessions.join(" -> "),
+                sessions.first().map(String::as_str).unwrap_or_default()
+            ),
+            Diagnostic::Recovery(warning) => warning.fmt(f),
+            Diagnostic::Indentation(issue) => issue.fmt(f),
+            Diagnostic::LineTooLong { line, length, max } => write!(
+                f,
+                "Line {} is {} columns long, over the maximum of {}",
+                line, length, max
+            ),
+            Diagnostic::MixedListStyle {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "List item marker '{}' at line {} does not match the list's first marker '{}'",
+                found, line, expected
+            ),
+            Diagnostic::EmptyDefinitionBody { term, line } => write!(
+                f,
+                "Definition '{}' at line {} has no indented body; read as a paragraph",
+                term, line
+            ),
+            Diagnostic::UnmatchedDelimiter {
+                delimiter, line, ..
+            } => write!(
+                f,
+                "Unmatched '{}' at line {} kept as plain text",
+                delimiter, line
+            ),
+            Diagnostic::UnknownAnnotationLabel {
+                got,
+                suggestion,
+                line,
+            } => write!(
+                f,
+                "Unknown annotation label '{}' at line {}: did you mean '{}'?",
+                got, line, suggestion
+            ),
+        }
+    }
 }
 
 /// Statistics about the parsing and assembly process
@@ -129,6 +386,15 @@ impl Document {
             },
         }
     }
+
+    /// Stable hash of the document's content
+    ///
+    /// Two parses of the same source hash equal, and so do sources that
+    /// differ only in whitespace that doesn't change structure. Metadata and
+    /// assembly info are left out. See [`content_hash`](crate::ast::content_hash).
+    pub fn content_hash(&self) -> u64 {
+        content_hash_all(self.content.child_nodes())
+    }
 }
 
 impl Default for AssemblyInfo {
@@ -138,6 +404,7 @@ impl Default for AssemblyInfo {
             source_path: None,
             processed_at: None,
             stats: ProcessingStats::default(),
+            warnings: Vec::new(),
         }
     }
 }
@@ -5,4 +5,6 @@
 pub mod document_structure;
 
 // Re-export document types
-pub use document_structure::{AssemblyInfo, Document, Meta, MetaValue, ProcessingStats};
+pub use document_structure::{
+    AssemblyInfo, AssemblyWarning, Diagnostic, Document, Meta, MetaValue, ProcessingStats, Severity,
+};
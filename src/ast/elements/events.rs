@@ -0,0 +1,214 @@
+//! Event stream over a document
+//!
+//! [`events`] flattens a document into a linear sequence of [`DocEvent`]s,
+//! for serializers and renderers that would rather pull events than write a
+//! recursive walk. Elements that contain other content come as an `Enter`
+//! and `Exit` pair around their children; verbatim blocks and tables come as
+//! a single event. Inline content is reported as [`DocEvent::Text`], one per
+//! top-level transform, right after the `Enter` of the element it belongs
+//! to: a session's title, a list item's line, a definition's term, a
+//! footnote's marker line, a paragraph's lines or an inline annotation's
+//! body.
+//!
+//! Blank lines are dropped, and the untyped wrapper containers the assembler
+//! leaves in the tree are walked through without events of their own.
+//!
+//! This is the one walk over a document's elements: semantic tokens,
+//! folding ranges, plain text and reference enumeration all read these
+//! events rather than matching on every container type themselves.
+
+use crate::ast::elements::annotation::{AnnotationBlock, AnnotationContent};
+use crate::ast::elements::containers::content::{ContentContainer, ContentContainerElement};
+use crate::ast::elements::containers::simple::{SimpleBlockElement, SimpleContainer};
+use crate::ast::elements::definition::DefinitionBlock;
+use crate::ast::elements::footnote::FootnoteDefinitionBlock;
+use crate::ast::elements::formatting::inlines::TextTransform;
+use crate::ast::elements::list::{ListBlock, ListItem};
+use crate::ast::elements::paragraph::ParagraphBlock;
+use crate::ast::elements::quote::QuoteBlock;
+use crate::ast::elements::session::session_container::SessionContainerElement;
+use crate::ast::elements::session::{SessionBlock, SessionContainer};
+use crate::ast::elements::table::TableBlock;
+use crate::ast::elements::verbatim::VerbatimBlock;
+use crate::ast::Document;
+
+/// One step of a document walk
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DocEvent<'a> {
+    EnterSession(&'a SessionBlock),
+    ExitSession,
+    EnterParagraph(&'a ParagraphBlock),
+    ExitParagraph,
+    EnterList(&'a ListBlock),
+    ExitList,
+    EnterListItem(&'a ListItem),
+    ExitListItem,
+    EnterDefinition(&'a DefinitionBlock),
+    ExitDefinition,
+    EnterQuote(&'a QuoteBlock),
+    ExitQuote,
+    EnterAnnotation(&'a AnnotationBlock),
+    ExitAnnotation,
+    EnterFootnote(&'a FootnoteDefinitionBlock),
+    ExitFootnote,
+    Verbatim(&'a VerbatimBlock),
+    Table(&'a TableBlock),
+    /// Inline content of the innermost entered element
+    Text(&'a TextTransform),
+}
+
+impl DocEvent<'_> {
+    /// Whether this event opens an element that a later event closes
+    pub fn is_enter(&self) -> bool {
+        matches!(
+            self,
+            DocEvent::EnterSession(_)
+                | DocEvent::EnterParagraph(_)
+                | DocEvent::EnterList(_)
+                | DocEvent::EnterListItem(_)
+                | DocEvent::EnterDefinition(_)
+                | DocEvent::EnterQuote(_)
+                | DocEvent::EnterAnnotation(_)
+                | DocEvent::EnterFootnote(_)
+        )
+    }
+
+    /// Whether this event closes the most recently entered element
+    pub fn is_exit(&self) -> bool {
+        matches!(
+            self,
+            DocEvent::ExitSession
+                | DocEvent::ExitParagraph
+                | DocEvent::ExitList
+                | DocEvent::ExitListItem
+                | DocEvent::ExitDefinition
+                | DocEvent::ExitQuote
+                | DocEvent::ExitAnnotation
+                | DocEvent::ExitFootnote
+        )
+    }
+}
+
+/// Flatten a document into its events, in document order
+///
+/// The events are gathered in one walk before the iterator is returned;
+/// they only borrow from `doc`, so the walk itself is cheap.
+pub fn events(doc: &Document) -> impl Iterator<Item = DocEvent<'_>> {
+    let mut events = Vec::new();
+    walk_session_container(&doc.content, &mut events);
+    events.into_iter()
+}
+
+/// The events of one session, from its `EnterSession` to its `ExitSession`
+pub(crate) fn session_events(session: &SessionBlock) -> Vec<DocEvent<'_>> {
+    let mut events = Vec::new();
+    walk_session(session, &mut events);
+    events
+}
+
+fn walk_session_container<'a>(container: &'a SessionContainer, out: &mut Vec<DocEvent<'a>>) {
+    for element in &container.content {
+        match element {
+            SessionContainerElement::Paragraph(paragraph) => walk_paragraph(paragraph, out),
+            SessionContainerElement::List(list) => walk_list(list, out),
+            SessionContainerElement::Definition(definition) => walk_definition(definition, out),
+            SessionContainerElement::Verbatim(verbatim) => out.push(DocEvent::Verbatim(verbatim)),
+            SessionContainerElement::Annotation(annotation) => walk_annotation(annotation, out),
+            SessionContainerElement::Quote(quote) => walk_quote(quote, out),
+            SessionContainerElement::Table(table) => out.push(DocEvent::Table(table)),
+            SessionContainerElement::FootnoteDefinition(footnote) => walk_footnote(footnote, out),
+            SessionContainerElement::Session(session) => walk_session(session, out),
+            SessionContainerElement::ContentContainer(container) => {
+                walk_content_container(container, out)
+            }
+            SessionContainerElement::SessionContainer(container) => {
+                walk_session_container(container, out)
+            }
+            SessionContainerElement::BlankLine(_) => {}
+        }
+    }
+}
+
+fn walk_session<'a>(session: &'a SessionBlock, out: &mut Vec<DocEvent<'a>>) {
+    out.push(DocEvent::EnterSession(session));
+    out.extend(session.title.content.iter().map(DocEvent::Text));
+    walk_session_container(&session.content, out);
+    out.push(DocEvent::ExitSession);
+}
+
+fn walk_content_container<'a>(container: &'a ContentContainer, out: &mut Vec<DocEvent<'a>>) {
+    for element in &container.content {
+        match element {
+            ContentContainerElement::Paragraph(paragraph) => walk_paragraph(paragraph, out),
+            ContentContainerElement::List(list) => walk_list(list, out),
+            ContentContainerElement::Definition(definition) => walk_definition(definition, out),
+            ContentContainerElement::Verbatim(verbatim) => out.push(DocEvent::Verbatim(verbatim)),
+            ContentContainerElement::Annotation(annotation) => walk_annotation(annotation, out),
+            ContentContainerElement::Quote(quote) => walk_quote(quote, out),
+            ContentContainerElement::Table(table) => out.push(DocEvent::Table(table)),
+            ContentContainerElement::FootnoteDefinition(footnote) => walk_footnote(footnote, out),
+            ContentContainerElement::Container(container) => walk_content_container(container, out),
+            ContentContainerElement::BlankLine(_) => {}
+        }
+    }
+}
+
+fn walk_simple_container<'a>(container: &'a SimpleContainer, out: &mut Vec<DocEvent<'a>>) {
+    for element in &container.content {
+        match element {
+            SimpleBlockElement::Paragraph(paragraph) => walk_paragraph(paragraph, out),
+            SimpleBlockElement::List(list) => walk_list(list, out),
+            SimpleBlockElement::Verbatim(verbatim) => out.push(DocEvent::Verbatim(verbatim)),
+            SimpleBlockElement::BlankLine(_) => {}
+        }
+    }
+}
+
+fn walk_paragraph<'a>(paragraph: &'a ParagraphBlock, out: &mut Vec<DocEvent<'a>>) {
+    out.push(DocEvent::EnterParagraph(paragraph));
+    out.extend(paragraph.content.iter().map(DocEvent::Text));
+    out.push(DocEvent::ExitParagraph);
+}
+
+/// Each item's line comes before its nested content
+fn walk_list<'a>(list: &'a ListBlock, out: &mut Vec<DocEvent<'a>>) {
+    out.push(DocEvent::EnterList(list));
+    for item in &list.items {
+        out.push(DocEvent::EnterListItem(item));
+        out.extend(item.content.iter().map(DocEvent::Text));
+        if let Some(nested) = &item.nested {
+            walk_content_container(nested, out);
+        }
+        out.push(DocEvent::ExitListItem);
+    }
+    out.push(DocEvent::ExitList);
+}
+
+fn walk_definition<'a>(definition: &'a DefinitionBlock, out: &mut Vec<DocEvent<'a>>) {
+    out.push(DocEvent::EnterDefinition(definition));
+    out.extend(definition.term.content.iter().map(DocEvent::Text));
+    walk_simple_container(&definition.content, out);
+    out.push(DocEvent::ExitDefinition);
+}
+
+fn walk_quote<'a>(quote: &'a QuoteBlock, out: &mut Vec<DocEvent<'a>>) {
+    out.push(DocEvent::EnterQuote(quote));
+    walk_content_container(&quote.content, out);
+    out.push(DocEvent::ExitQuote);
+}
+
+fn walk_footnote<'a>(footnote: &'a FootnoteDefinitionBlock, out: &mut Vec<DocEvent<'a>>) {
+    out.push(DocEvent::EnterFootnote(footnote));
+    out.extend(footnote.content.iter().map(DocEvent::Text));
+    walk_simple_container(&footnote.body, out);
+    out.push(DocEvent::ExitFootnote);
+}
+
+fn walk_annotation<'a>(annotation: &'a AnnotationBlock, out: &mut Vec<DocEvent<'a>>) {
+    out.push(DocEvent::EnterAnnotation(annotation));
+    match &annotation.content {
+        AnnotationContent::Inline(body) => out.extend(body.iter().map(DocEvent::Text)),
+        AnnotationContent::Block(container) => walk_simple_container(container, out),
+    }
+    out.push(DocEvent::ExitAnnotation);
+}
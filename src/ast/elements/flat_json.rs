@@ -0,0 +1,189 @@
+//! Flat JSON export
+//!
+//! [`to_flat_json`] renders a document as a JSON array with one record per
+//! element, in document order, for exploring from the command line with
+//! `jq` or `grep`. Each record has:
+//!
+//! - `type`: `session`, `paragraph`, `list`, `list_item`, `definition`,
+//!   `quote`, `annotation`, `footnote`, `verbatim` or `table`
+//! - `depth`: how many elements enclose it, 0 at the top level
+//! - `text`: its own inline text (a session's title, a list item's line, a
+//!   paragraph's lines, a verbatim block's title), formatting and session
+//!   numbering dropped
+//! - `span`: the source range of the element and everything inside it, as
+//!   `cst::SourceSpan`, or null when it carries no tokens
+//! - `path`: the element's index among its siblings at each level, so
+//!   `[1, 0]` is the first child of the second top-level element
+//! - `checked`: on task list items only, whether the task is done
+//!
+//! Elements are those [`events`] reports; blank lines and the untyped
+//! wrapper containers are left out.
+
+use serde::Serialize;
+
+use crate::ast::elements::events::{events, DocEvent};
+use crate::ast::elements::formatting::inlines::TextTransform;
+use crate::ast::Document;
+use crate::cst::{Position, ScannerToken, ScannerTokenSequence, SourceSpan};
+
+/// One element of the flattened document
+#[derive(Debug, Serialize)]
+struct FlatRecord {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    depth: usize,
+    text: String,
+    span: Option<SourceSpan>,
+    path: Vec<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    checked: Option<bool>,
+}
+
+/// Render the document as a flat JSON array of element records
+pub fn to_flat_json(doc: &Document) -> String {
+    serde_json::to_string(&flat_records(doc)).expect("flat records always serialize")
+}
+
+fn flat_records(doc: &Document) -> Vec<FlatRecord> {
+    let mut records: Vec<FlatRecord> = Vec::new();
+    // Records of the elements currently entered, innermost last
+    let mut open: Vec<usize> = Vec::new();
+    // Children seen so far at each open level, the top level first
+    let mut siblings: Vec<usize> = vec![0];
+
+    for event in events(doc) {
+        if event.is_exit() {
+            let closed = open.pop().expect("exit events follow their enter");
+            siblings.pop();
+            if let (Some(&parent), Some(span)) = (open.last(), records[closed].span.clone()) {
+                widen(&mut records[parent].span, &span);
+            }
+            continue;
+        }
+
+        if let DocEvent::Text(transform) = event {
+            if let Some(&current) = open.last() {
+                records[current].text.push_str(&transform.text_content());
+                widen_with_transform(&mut records[current].span, transform);
+            }
+            continue;
+        }
+
+        let Some((kind, tokens)) = describe(&event) else {
+            continue;
+        };
+        let index = siblings.last_mut().expect("the top level is never closed");
+        let mut path: Vec<usize> = open.iter().map(|&record| records[record].index()).collect();
+        path.push(*index);
+        *index += 1;
+
+        let mut record = FlatRecord {
+            kind,
+            depth: open.len(),
+            text: String::new(),
+            span: None,
+            path,
+            checked: None,
+        };
+        widen_with_tokens(&mut record.span, tokens);
+        if let DocEvent::EnterListItem(item) = event {
+            record.checked = item.checked;
+        }
+        if let DocEvent::Verbatim(verbatim) = event {
+            for transform in &verbatim.title {
+                record.text.push_str(&transform.text_content());
+            }
+        }
+        let span = record.span.clone();
+        records.push(record);
+
+        if event.is_enter() {
+            open.push(records.len() - 1);
+            siblings.push(0);
+        } else if let (Some(&parent), Some(span)) = (open.last(), span) {
+            widen(&mut records[parent].span, &span);
+        }
+    }
+
+    // Session titles keep the line ending they were read with
+    for record in &mut records {
+        let text = record.text.trim_end().len();
+        record.text.truncate(text);
+    }
+    records
+}
+
+impl FlatRecord {
+    /// Index of the element among its siblings
+    fn index(&self) -> usize {
+        *self.path.last().expect("every record has a path")
+    }
+}
+
+/// Record type and own tokens of the element an event opens or stands for
+fn describe<'a>(event: &DocEvent<'a>) -> Option<(&'static str, &'a ScannerTokenSequence)> {
+    Some(match *event {
+        DocEvent::EnterSession(session) => ("session", &session.tokens),
+        DocEvent::EnterParagraph(paragraph) => ("paragraph", &paragraph.tokens),
+        DocEvent::EnterList(list) => ("list", &list.tokens),
+        DocEvent::EnterListItem(item) => ("list_item", &item.tokens),
+        DocEvent::EnterDefinition(definition) => ("definition", &definition.tokens),
+        DocEvent::EnterQuote(quote) => ("quote", &quote.tokens),
+        DocEvent::EnterAnnotation(annotation) => ("annotation", &annotation.tokens),
+        DocEvent::EnterFootnote(footnote) => ("footnote", &footnote.tokens),
+        DocEvent::Verbatim(verbatim) => ("verbatim", &verbatim.tokens),
+        DocEvent::Table(table) => ("table", &table.tokens),
+        _ => return None,
+    })
+}
+
+fn widen_with_transform(span: &mut Option<SourceSpan>, transform: &TextTransform) {
+    match transform {
+        TextTransform::Identity(text)
+        | TextTransform::Code(text)
+        | TextTransform::Math(text)
+        | TextTransform::LineBreak(text) => widen_with_tokens(span, &text.tokens),
+        TextTransform::Emphasis(inner)
+        | TextTransform::Strong(inner)
+        | TextTransform::Composed(inner)
+        | TextTransform::Custom { content: inner, .. } => {
+            for transform in inner {
+                widen_with_transform(span, transform);
+            }
+        }
+    }
+}
+
+/// Widen `span` over the tokens that carry content
+///
+/// Line endings, blank lines and indentation changes are left out, so an
+/// element does not reach into the blank lines after it.
+fn widen_with_tokens(span: &mut Option<SourceSpan>, tokens: &ScannerTokenSequence) {
+    for token in &tokens.tokens {
+        if !matches!(
+            token,
+            ScannerToken::Newline { .. }
+                | ScannerToken::BlankLine { .. }
+                | ScannerToken::Indent { .. }
+                | ScannerToken::Dedent { .. }
+                | ScannerToken::Eof { .. }
+        ) {
+            widen(span, token.span());
+        }
+    }
+}
+
+fn widen(span: &mut Option<SourceSpan>, other: &SourceSpan) {
+    let position = |p: &Position| (p.row, p.column);
+    match span {
+        Some(span) => {
+            if position(&other.start) < position(&span.start) {
+                span.start = other.start;
+            }
+            if position(&other.end) > position(&span.end) {
+                span.end = other.end;
+            }
+        }
+        None => *span = Some(other.clone()),
+    }
+}
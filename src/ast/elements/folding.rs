@@ -0,0 +1,199 @@
+//! Folding ranges for editors
+//!
+//! [`folding_ranges`] lists the regions an editor can collapse: sessions,
+//! lists spanning several lines, verbatim blocks, and definitions and
+//! footnote definitions with a body. This is the data an LSP `textDocument/foldingRange` handler
+//! returns. Lines are 0-indexed rows, as in `cst::Position`, and both ends
+//! are inclusive.
+//!
+//! A region's lines come from the scanner tokens of everything inside it.
+//! Blank lines carry no content, so a session ends on its last line of
+//! text rather than on the blank lines that follow it.
+
+use crate::ast::elements::events::{events, DocEvent};
+use crate::ast::elements::formatting::inlines::TextTransform;
+use crate::ast::elements::table::TableBlock;
+use crate::ast::elements::verbatim::VerbatimBlock;
+use crate::ast::Document;
+use crate::cst::{ScannerToken, ScannerTokenSequence};
+
+/// What a [`FoldingRange`] folds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FoldingRangeKind {
+    Session,
+    List,
+    Verbatim,
+    Definition,
+    Footnote,
+}
+
+/// A collapsible region of the source
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FoldingRange {
+    /// First line of the region (the title or first item)
+    pub start_line: usize,
+
+    /// Last line of the region, inclusive
+    pub end_line: usize,
+
+    pub kind: FoldingRangeKind,
+}
+
+/// First and last line covered by an element, inclusive
+type Lines = Option<(usize, usize)>;
+
+/// An entered element whose lines are still being gathered
+struct Frame {
+    /// What the element folds as, if it folds at all
+    kind: Option<FoldingRangeKind>,
+
+    /// Where its range goes, ahead of the ranges of its children
+    index: usize,
+
+    lines: Lines,
+}
+
+/// List the document's folding ranges, outer regions before inner ones
+pub fn folding_ranges(doc: &Document) -> Vec<FoldingRange> {
+    let mut ranges = Vec::new();
+    let mut frames = vec![Frame {
+        kind: None,
+        index: 0,
+        lines: None,
+    }];
+    for event in events(doc) {
+        let lines = match event {
+            DocEvent::Text(transform) => inline_lines(std::slice::from_ref(transform)),
+            DocEvent::Verbatim(verbatim) => {
+                let lines = verbatim_lines(verbatim);
+                fold(lines, FoldingRangeKind::Verbatim, ranges.len(), &mut ranges);
+                lines
+            }
+            DocEvent::Table(table) => table_lines(table),
+            event if event.is_enter() => {
+                frames.push(Frame {
+                    kind: fold_kind(event),
+                    index: ranges.len(),
+                    lines: start_lines(event),
+                });
+                continue;
+            }
+            _ => {
+                let frame = frames.pop().expect("every exit has an enter");
+                if let Some(kind) = frame.kind {
+                    fold(frame.lines, kind, frame.index, &mut ranges);
+                }
+                frame.lines
+            }
+        };
+        let parent = frames
+            .last_mut()
+            .expect("the document frame is never closed");
+        parent.lines = union(parent.lines, lines);
+    }
+    ranges
+}
+
+fn fold_kind(event: DocEvent) -> Option<FoldingRangeKind> {
+    match event {
+        DocEvent::EnterSession(_) => Some(FoldingRangeKind::Session),
+        DocEvent::EnterList(_) => Some(FoldingRangeKind::List),
+        DocEvent::EnterDefinition(_) => Some(FoldingRangeKind::Definition),
+        DocEvent::EnterFootnote(_) => Some(FoldingRangeKind::Footnote),
+        _ => None,
+    }
+}
+
+/// A footnote folds from its marker line through the end of its body
+fn start_lines(event: DocEvent) -> Lines {
+    match event {
+        DocEvent::EnterFootnote(footnote) => sequence_lines(&footnote.tokens),
+        _ => None,
+    }
+}
+
+/// From the title line through the closing label line
+fn verbatim_lines(verbatim: &VerbatimBlock) -> Lines {
+    union(
+        sequence_lines(&verbatim.tokens),
+        inline_lines(&verbatim.title),
+    )
+}
+
+fn table_lines(table: &TableBlock) -> Lines {
+    table
+        .header
+        .iter()
+        .chain(&table.rows)
+        .flat_map(|row| &row.cells)
+        .map(|cell| inline_lines(&cell.content))
+        .fold(None, union)
+}
+
+/// Record a range at `index`, ahead of the ranges of nested elements,
+/// when it covers more than one line
+fn fold(lines: Lines, kind: FoldingRangeKind, index: usize, out: &mut Vec<FoldingRange>) {
+    if let Some((start_line, end_line)) = lines {
+        if end_line > start_line {
+            out.insert(
+                index,
+                FoldingRange {
+                    start_line,
+                    end_line,
+                    kind,
+                },
+            );
+        }
+    }
+}
+
+fn inline_lines(transforms: &[TextTransform]) -> Lines {
+    transforms
+        .iter()
+        .map(|transform| match transform {
+            TextTransform::Identity(text)
+            | TextTransform::Code(text)
+            | TextTransform::Math(text)
+            | TextTransform::LineBreak(text) => sequence_lines(&text.tokens),
+            TextTransform::Emphasis(inner)
+            | TextTransform::Strong(inner)
+            | TextTransform::Composed(inner)
+            | TextTransform::Custom { content: inner, .. } => inline_lines(inner),
+        })
+        .fold(None, union)
+}
+
+fn sequence_lines(tokens: &ScannerTokenSequence) -> Lines {
+    tokens.tokens.iter().map(token_lines).fold(None, union)
+}
+
+/// Lines a token sits on; a span ending at column 0 stops on the line before
+fn token_lines(token: &ScannerToken) -> Lines {
+    if matches!(
+        token,
+        ScannerToken::Newline { .. }
+            | ScannerToken::BlankLine { .. }
+            | ScannerToken::Indent { .. }
+            | ScannerToken::Dedent { .. }
+            | ScannerToken::Eof { .. }
+    ) {
+        return None;
+    }
+
+    let span = token.span();
+    let end = if span.end.column == 0 && span.end.row > span.start.row {
+        span.end.row - 1
+    } else {
+        span.end.row
+    };
+    Some((span.start.row, end))
+}
+
+fn union(a: Lines, b: Lines) -> Lines {
+    match (a, b) {
+        (Some((a_start, a_end)), Some((b_start, b_end))) => {
+            Some((a_start.min(b_start), a_end.max(b_end)))
+        }
+        (lines, None) | (None, lines) => lines,
+    }
+}
@@ -0,0 +1,123 @@
+//! Footnote Definition Block Element
+//!
+//! A footnote definition starts with a labeled footnote marker and a colon.
+//! The rest of that line is the footnote's first paragraph; further
+//! paragraphs, lists or code follow indented below it.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ast::elements::{
+    annotation::annotation_content::Annotation, components::parameters::Parameters,
+};
+use crate::cst::ScannerTokenSequence;
+
+use super::super::{
+    containers::SimpleContainer,
+    core::{BlockElement, ContainerElement, ElementType, HeaderedBlock, TxxtElement},
+    inlines::TextTransform,
+};
+
+/// Footnote definition block - the note a `[^label]` reference points to
+///
+/// ```txxt
+/// [^source]: Figures are from the 2023 survey.
+///
+///     Later surveys changed the methodology.
+/// ```
+///
+/// Like definitions, the indented body uses a SimpleContainer: paragraphs,
+/// lists and verbatim blocks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FootnoteDefinitionBlock {
+    /// The footnote label, without the caret (`source` for `[^source]`)
+    pub label: String,
+
+    /// Inline content on the marker line, after the colon
+    pub content: Vec<TextTransform>,
+
+    /// Indented content following the marker line
+    pub body: SimpleContainer,
+
+    /// Annotations attached to this footnote
+    #[serde(default)]
+    pub annotations: Vec<Annotation>,
+
+    /// Parameters for this footnote
+    #[serde(default)]
+    pub parameters: Parameters,
+
+    /// Raw tokens of the marker line for source reconstruction
+    #[serde(default)]
+    pub tokens: ScannerTokenSequence,
+}
+
+impl TxxtElement for FootnoteDefinitionBlock {
+    fn element_type(&self) -> ElementType {
+        ElementType::Block
+    }
+
+    fn tokens(&self) -> &ScannerTokenSequence {
+        &self.tokens
+    }
+
+    fn annotations(&self) -> &[Annotation] {
+        &self.annotations
+    }
+
+    fn parameters(&self) -> &Parameters {
+        &self.parameters
+    }
+
+    fn child_nodes(&self) -> Vec<&dyn TxxtElement> {
+        vec![&self.body]
+    }
+}
+
+impl BlockElement for FootnoteDefinitionBlock {
+    fn can_contain_blocks(&self) -> bool {
+        true
+    }
+
+    fn content_summary(&self) -> String {
+        format!("Footnote: {}", self.label)
+    }
+}
+
+impl HeaderedBlock for FootnoteDefinitionBlock {
+    fn header_text(&self) -> String {
+        self.label.clone()
+    }
+
+    fn tail_container(&self) -> Option<&dyn ContainerElement> {
+        Some(&self.body)
+    }
+}
+
+impl FootnoteDefinitionBlock {
+    /// Create a new footnote definition block
+    pub fn new(
+        label: String,
+        content: Vec<TextTransform>,
+        body: SimpleContainer,
+        annotations: Vec<Annotation>,
+        parameters: Parameters,
+        tokens: ScannerTokenSequence,
+    ) -> Self {
+        Self {
+            label,
+            content,
+            body,
+            annotations,
+            parameters,
+            tokens,
+        }
+    }
+
+    /// Plain text of the marker line's content
+    pub fn text(&self) -> String {
+        self.content
+            .iter()
+            .map(TextTransform::text_content)
+            .collect()
+    }
+}
@@ -0,0 +1,8 @@
+//! Footnote Elements
+//!
+//! Footnote definition blocks, the text that `[^label]` references point to.
+
+pub mod block;
+
+// Re-export footnote types
+pub use block::FootnoteDefinitionBlock;
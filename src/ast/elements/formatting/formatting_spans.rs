@@ -22,12 +22,15 @@ pub struct BoldSpan {
     pub content: Vec<TextTransform>,
 
     /// Annotations attached to this span
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this span
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for precise positioning
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -40,12 +43,15 @@ pub struct ItalicSpan {
     pub content: Vec<TextTransform>,
 
     /// Annotations attached to this span
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this span
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for precise positioning
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -58,12 +64,15 @@ pub struct CodeSpan {
     pub content: TextSpan,
 
     /// Annotations attached to this span
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this span
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for precise positioning
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -76,12 +85,15 @@ pub struct MathSpan {
     pub content: TextSpan,
 
     /// Annotations attached to this span
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this span
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for precise positioning
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
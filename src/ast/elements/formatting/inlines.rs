@@ -65,6 +65,7 @@ pub enum Inline {
         attributes: HashMap<String, String>,
 
         /// Raw tokens for precise positioning
+        #[serde(default)]
         tokens: ScannerTokenSequence,
     },
 
@@ -84,6 +85,7 @@ pub enum Inline {
         content: Vec<Inline>,
 
         /// Raw tokens for positioning
+        #[serde(default)]
         tokens: ScannerTokenSequence,
     },
 }
@@ -123,6 +125,11 @@ pub enum TextTransform {
     /// Math cannot contain nested transforms (by design)
     Math(Text),
 
+    /// Hard line break at the end of a paragraph line
+    /// Holds the break marker (two trailing spaces or a backslash) and the
+    /// newline; its text content is always a single newline
+    LineBreak(Text),
+
     /// Composed transform - for complex nested cases
     /// Used when multiple transforms need to be applied
     /// Example: **_bold italic_** becomes Strong(vec![Emphasis(...)])
@@ -135,6 +142,7 @@ pub enum TextTransform {
         name: String,
 
         /// Transform parameters
+        #[serde(default)]
         parameters: HashMap<String, String>,
 
         /// Nested transforms (if applicable)
@@ -158,6 +166,7 @@ pub enum TextTransform {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Text {
     /// Token sequence with character-level precision
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -186,7 +195,7 @@ impl Text {
                         start: Position { row: 0, column: 0 },
                         end: Position {
                             row: 0,
-                            column: content.len(),
+                            column: content.chars().count(),
                         },
                     },
                 }],
@@ -236,6 +245,7 @@ impl TextTransform {
                 .join(""),
             TextTransform::Code(text) => text.content(),
             TextTransform::Math(text) => text.content(),
+            TextTransform::LineBreak(_) => "\n".to_string(),
             TextTransform::Composed(transforms) => transforms
                 .iter()
                 .map(|t| t.text_content())
@@ -258,3 +268,31 @@ impl TextTransform {
         Inline::TextLine(self.clone())
     }
 }
+
+/// Merge runs of adjacent `Identity` transforms into one
+///
+/// Inline parsing emits one `Identity` per text token, so a plain sentence
+/// becomes a run of spans. Merging concatenates their token sequences,
+/// which keeps the text and source positions unchanged. Formatted spans
+/// are never merged with their neighbours, but runs inside them are.
+pub fn coalesce_text(transforms: &mut Vec<TextTransform>) {
+    let mut merged: Vec<TextTransform> = Vec::with_capacity(transforms.len());
+    for mut transform in transforms.drain(..) {
+        match (&mut transform, merged.last_mut()) {
+            (TextTransform::Identity(text), Some(TextTransform::Identity(previous))) => {
+                previous.tokens.tokens.append(&mut text.tokens.tokens);
+                continue;
+            }
+            (
+                TextTransform::Emphasis(inner)
+                | TextTransform::Strong(inner)
+                | TextTransform::Composed(inner)
+                | TextTransform::Custom { content: inner, .. },
+                _,
+            ) => coalesce_text(inner),
+            _ => {}
+        }
+        merged.push(transform);
+    }
+    *transforms = merged;
+}
@@ -24,7 +24,7 @@ use super::core::{ElementType, SpanElement, TxxtElement};
 use super::references::reference_types::ReferenceTarget;
 
 // Re-export inline types from new functional modules
-pub use super::formatting::inlines::{Text, TextTransform};
+pub use super::formatting::inlines::{coalesce_text, Text, TextTransform};
 pub use super::formatting::{BoldSpan, CodeSpan, ItalicSpan, MathSpan};
 pub use super::references::{
     CitationSpan, FootnoteReferenceSpan, PageReferenceSpan, ReferenceSpan, SessionReferenceSpan,
@@ -37,12 +37,15 @@ pub use super::references::{
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TextSpan {
     /// Token sequence with character-level precision
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 
     /// Annotations (rare for text spans)
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters (rare for text spans)
+    #[serde(default)]
     pub parameters: Parameters,
 }
 
@@ -55,12 +58,15 @@ pub struct TextLine {
     pub spans: Vec<TextTransform>,
 
     /// Annotations attached to this line
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this line
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for precise source reconstruction
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -77,12 +83,15 @@ pub struct Link {
     pub attributes: HashMap<String, String>,
 
     /// Annotations attached to this link
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this link
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for precise positioning
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -96,12 +105,15 @@ pub struct Reference {
     pub content: Option<Vec<TextTransform>>,
 
     /// Annotations attached to this reference
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this reference
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for language server support
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -10,7 +10,7 @@ use crate::ast::elements::{
 use crate::cst::ScannerTokenSequence;
 
 use super::super::{
-    containers::ContentContainer,
+    containers::{content::ContentContainerElement, ContentContainer},
     core::{BlockElement, ElementType, TxxtElement},
     inlines::TextTransform,
 };
@@ -27,12 +27,15 @@ pub struct ListBlock {
     pub items: Vec<ListItem>,
 
     /// Annotations attached to this list
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this list
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for source reconstruction
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -75,13 +78,21 @@ pub struct ListItem {
     pub nested: Option<ContentContainer>,
 
     /// Annotations attached to this specific list item
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this list item
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for precise reconstruction
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
+
+    /// Task state from a `[ ]` or `[x]` checkbox opening the item's line,
+    /// None for items without one. The checkbox is not part of `content`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checked: Option<bool>,
 }
 
 impl TxxtElement for ListBlock {
@@ -100,6 +111,13 @@ impl TxxtElement for ListBlock {
     fn parameters(&self) -> &Parameters {
         &self.parameters
     }
+
+    fn child_nodes(&self) -> Vec<&dyn TxxtElement> {
+        self.items
+            .iter()
+            .map(|item| item as &dyn TxxtElement)
+            .collect()
+    }
 }
 
 impl BlockElement for ListBlock {
@@ -124,6 +142,13 @@ impl TxxtElement for ListItem {
     fn parameters(&self) -> &Parameters {
         &self.parameters
     }
+
+    fn child_nodes(&self) -> Vec<&dyn TxxtElement> {
+        self.nested
+            .iter()
+            .map(|nested| nested as &dyn TxxtElement)
+            .collect()
+    }
 }
 
 impl ListBlock {
@@ -163,6 +188,160 @@ impl ListBlock {
     pub fn add_item(&mut self, item: ListItem) {
         self.items.push(item);
     }
+
+    /// Every item, sublists included, depth first with its nesting depth
+    ///
+    /// Items of this list have depth 0 and items of a list nested directly
+    /// in an item's content have one more than that item. Each item comes
+    /// before the items of its sublists.
+    pub fn flat_items(&self) -> impl Iterator<Item = (usize, &ListItem)> {
+        let mut flat = Vec::new();
+        self.collect_flat_items(0, &mut flat);
+        flat.into_iter()
+    }
+
+    fn collect_flat_items<'a>(&'a self, depth: usize, flat: &mut Vec<(usize, &'a ListItem)>) {
+        for item in &self.items {
+            flat.push((depth, item));
+            let Some(nested) = &item.nested else {
+                continue;
+            };
+            for element in &nested.content {
+                if let ContentContainerElement::List(sublist) = element {
+                    sublist.collect_flat_items(depth + 1, flat);
+                }
+            }
+        }
+    }
+
+    /// Rewrite item markers into a consistent ascending sequence
+    ///
+    /// The sequence starts at the first item's value and keeps each marker's
+    /// trailing form (`.` or `)`), so `1. 3. 2.` becomes `1. 2. 3.` and
+    /// `b) d)` becomes `b) c)`. Plain lists and extended (hierarchical)
+    /// markers such as `1.1.` are left unchanged. This is an opt-in transform;
+    /// the parser always preserves markers exactly as written.
+    pub fn renumber(&mut self) {
+        if self.decoration_type.style == NumberingStyle::Plain
+            || self.decoration_type.form == NumberingForm::Full
+        {
+            return;
+        }
+
+        let roman = match self.decoration_type.style {
+            NumberingStyle::Roman => true,
+            // Alphabetical detection also matches roman numerals, so look at
+            // the whole list: "i. ii. iii." is roman, "h. i. j." is not
+            NumberingStyle::Alphabetical => {
+                self.items
+                    .iter()
+                    .all(|item| roman_to_number(split_marker(&item.marker).0).is_some())
+                    && self
+                        .items
+                        .iter()
+                        .any(|item| split_marker(&item.marker).0.len() > 1)
+            }
+            _ => false,
+        };
+
+        let Some(first) = self.items.first() else {
+            return;
+        };
+        let (first_body, _) = split_marker(&first.marker);
+        let uppercase = first_body.chars().any(|c| c.is_uppercase());
+        let start = match (&self.decoration_type.style, roman) {
+            (_, true) => roman_to_number(first_body),
+            (NumberingStyle::Numerical, _) => first_body.parse().ok(),
+            _ => alpha_to_number(first_body),
+        };
+        let Some(start) = start else {
+            return;
+        };
+
+        for (offset, item) in self.items.iter_mut().enumerate() {
+            let value = start + offset as u64;
+            let (_, form) = split_marker(&item.marker);
+            let body = match (&self.decoration_type.style, roman) {
+                (_, true) => number_to_roman(value),
+                (NumberingStyle::Numerical, _) => value.to_string(),
+                _ => number_to_alpha(value),
+            };
+            let body = if uppercase { body.to_uppercase() } else { body };
+            item.marker = format!("{}{}", body, form);
+        }
+    }
+}
+
+/// Split a marker such as `3.` or `b)` into its body and trailing form
+fn split_marker(marker: &str) -> (&str, &str) {
+    match marker.char_indices().last() {
+        Some((index, '.')) | Some((index, ')')) => marker.split_at(index),
+        _ => (marker, ""),
+    }
+}
+
+/// Convert an alphabetical marker body (`a`, `z`, `aa`) to its 1-based value
+fn alpha_to_number(body: &str) -> Option<u64> {
+    if body.is_empty() {
+        return None;
+    }
+    body.chars().try_fold(0u64, |acc, c| {
+        c.is_ascii_alphabetic()
+            .then(|| acc * 26 + (c.to_ascii_lowercase() as u64 - 'a' as u64 + 1))
+    })
+}
+
+/// Convert a 1-based value to a lowercase alphabetical marker body
+fn number_to_alpha(mut value: u64) -> String {
+    let mut letters = Vec::new();
+    while value > 0 {
+        value -= 1;
+        letters.push((b'a' + (value % 26) as u8) as char);
+        value /= 26;
+    }
+    letters.iter().rev().collect()
+}
+
+const ROMAN_NUMERALS: [(u64, &str); 13] = [
+    (1000, "m"),
+    (900, "cm"),
+    (500, "d"),
+    (400, "cd"),
+    (100, "c"),
+    (90, "xc"),
+    (50, "l"),
+    (40, "xl"),
+    (10, "x"),
+    (9, "ix"),
+    (5, "v"),
+    (4, "iv"),
+    (1, "i"),
+];
+
+/// Convert a roman numeral marker body to its value, if it is well-formed
+fn roman_to_number(body: &str) -> Option<u64> {
+    let lower = body.to_lowercase();
+    let mut rest = lower.as_str();
+    let mut value = 0;
+    for (amount, numeral) in ROMAN_NUMERALS {
+        while let Some(remaining) = rest.strip_prefix(numeral) {
+            value += amount;
+            rest = remaining;
+        }
+    }
+    (rest.is_empty() && value > 0 && number_to_roman(value) == lower).then_some(value)
+}
+
+/// Convert a value to a lowercase roman numeral marker body
+fn number_to_roman(mut value: u64) -> String {
+    let mut roman = String::new();
+    for (amount, numeral) in ROMAN_NUMERALS {
+        while value >= amount {
+            roman.push_str(numeral);
+            value -= amount;
+        }
+    }
+    roman
 }
 
 impl ListItem {
@@ -182,6 +361,7 @@ impl ListItem {
             annotations,
             parameters,
             tokens,
+            checked: None,
         }
     }
 
@@ -198,6 +378,11 @@ impl ListItem {
     pub fn has_nested_content(&self) -> bool {
         self.nested.is_some()
     }
+
+    /// Check if this item is a task, checked or not
+    pub fn is_task(&self) -> bool {
+        self.checked.is_some()
+    }
 }
 
 impl Default for ListDecorationType {
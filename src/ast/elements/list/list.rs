@@ -24,12 +24,15 @@ pub struct ListBlock {
     pub items: Vec<ListItem>,
 
     /// Annotations attached to this list
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this list
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for source reconstruction
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -74,12 +77,15 @@ pub struct ListItem {
     pub nested: Option<ContentContainer>,
 
     /// Annotations attached to this specific list item
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this list item
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for precise reconstruction
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
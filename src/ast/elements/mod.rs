@@ -23,7 +23,8 @@
 //! - May contain multiple spans
 //!
 //! ## Block Elements
-//! - Paragraphs, lists, definitions, verbatim, sessions, annotations
+//! - Paragraphs, lists, definitions, verbatim, sessions, annotations, quotes,
+//!   tables, footnote definitions
 //! - Contain one or more lines
 //! - Primary structural units
 //!
@@ -37,6 +38,12 @@ pub mod core;
 
 // Core AST files
 pub mod blocks;
+pub mod events;
+pub mod flat_json;
+pub mod folding;
+pub mod plaintext;
+pub mod semantic_tokens;
+pub mod source_map;
 pub mod traversal;
 
 // Container elements (hold child elements)
@@ -45,9 +52,12 @@ pub mod containers;
 // Block-level elements
 pub mod annotation;
 pub mod definition;
+pub mod footnote;
 pub mod list;
 pub mod paragraph;
+pub mod quote;
 pub mod session;
+pub mod table;
 pub mod verbatim;
 
 // Document-level elements
@@ -26,12 +26,15 @@ pub struct ParagraphBlock {
     pub content: Vec<TextTransform>,
 
     /// Annotations attached to this paragraph
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this paragraph
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for precise source reconstruction
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
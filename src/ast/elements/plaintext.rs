@@ -0,0 +1,110 @@
+//! Plain text extraction
+//!
+//! [`to_plaintext`] renders a document as unformatted text for search
+//! indexing and previews: one line per session title, paragraph, list item,
+//! definition term, footnote, table row and verbatim content line, in
+//! document order.
+//! Formatting is dropped, a paragraph's wrapped lines are joined, and
+//! references keep their content without brackets. Table cells are
+//! separated by tabs.
+//!
+//! Annotations are content too, but some are not meant for readers (review
+//! notes, internal remarks). [`to_plaintext_filtered`] leaves out every
+//! annotation whose label is in an exclude list.
+
+use crate::ast::elements::events::{events, DocEvent};
+use crate::ast::elements::formatting::inlines::TextTransform;
+use crate::ast::elements::table::TableBlock;
+use crate::ast::elements::verbatim::VerbatimBlock;
+use crate::ast::Document;
+
+/// Render the document's text, annotations included
+pub fn to_plaintext(doc: &Document) -> String {
+    to_plaintext_filtered(doc, &[])
+}
+
+/// Render the document's text, leaving out annotations labelled with any of
+/// `exclude_labels`
+///
+/// Labels are matched exactly, namespace included (`org.example.review`).
+pub fn to_plaintext_filtered(doc: &Document, exclude_labels: &[&str]) -> String {
+    let mut extractor = Extractor::default();
+    // Depth inside an excluded annotation; its events are all skipped
+    let mut excluded = 0;
+    for event in events(doc) {
+        if excluded > 0 {
+            if event.is_enter() {
+                excluded += 1;
+            } else if event.is_exit() {
+                excluded -= 1;
+            }
+            continue;
+        }
+        match event {
+            DocEvent::EnterAnnotation(annotation)
+                if exclude_labels.contains(&annotation.name.as_str()) =>
+            {
+                extractor.end_element();
+                excluded = 1;
+            }
+            DocEvent::Text(transform) => extractor.text.push_str(&transform.text_content()),
+            DocEvent::Verbatim(verbatim) => extractor.push_verbatim(verbatim),
+            DocEvent::Table(table) => extractor.push_table(table),
+            _ => extractor.end_element(),
+        }
+    }
+    extractor.end_element();
+    extractor.lines.join("\n")
+}
+
+#[derive(Default)]
+struct Extractor {
+    lines: Vec<String>,
+    /// Inline text of the element being read, pushed when its text ends
+    text: String,
+}
+
+impl Extractor {
+    /// Push the text gathered so far as lines; empty text adds nothing
+    fn end_element(&mut self) {
+        let text = std::mem::take(&mut self.text);
+        self.lines.extend(
+            text.lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(str::to_string),
+        );
+    }
+
+    fn push_verbatim(&mut self, verbatim: &VerbatimBlock) {
+        self.end_element();
+        self.text = inline_text(&verbatim.title);
+        self.end_element();
+        self.lines.extend(
+            verbatim
+                .content
+                .ignore_lines
+                .iter()
+                .map(|line| line.content.clone()),
+        );
+    }
+
+    fn push_table(&mut self, table: &TableBlock) {
+        self.end_element();
+        for row in table.header.iter().chain(&table.rows) {
+            let cells: Vec<String> = row
+                .cells
+                .iter()
+                .map(|cell| inline_text(&cell.content))
+                .collect();
+            self.lines.push(cells.join("\t"));
+        }
+    }
+}
+
+fn inline_text(transforms: &[TextTransform]) -> String {
+    transforms
+        .iter()
+        .map(TextTransform::text_content)
+        .collect::<String>()
+}
@@ -0,0 +1,98 @@
+//! Quote Block Element
+//!
+//! Quotes are runs of lines prefixed with a `>` marker. Once the markers are
+//! stripped, the remaining text is parsed like any other content, so a quote
+//! can hold paragraphs, lists, definitions and further quotes.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ast::elements::{
+    annotation::annotation_content::Annotation, components::parameters::Parameters,
+};
+use crate::cst::ScannerTokenSequence;
+
+use super::super::{
+    containers::ContentContainer,
+    core::{BlockElement, ElementType, TxxtElement},
+};
+
+/// Quote block - content set off with leading `>` markers
+///
+/// ```txxt
+/// > The quoted paragraph,
+/// > spanning two lines.
+/// ```
+///
+/// The quoted content lives in a ContentContainer: quotes can nest any block
+/// except sessions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuoteBlock {
+    /// The quoted content, with the `>` markers stripped
+    pub content: ContentContainer,
+
+    /// Annotations attached to this quote
+    #[serde(default)]
+    pub annotations: Vec<Annotation>,
+
+    /// Parameters for this quote
+    #[serde(default)]
+    pub parameters: Parameters,
+
+    /// Raw tokens for precise source reconstruction (markers included)
+    #[serde(default)]
+    pub tokens: ScannerTokenSequence,
+}
+
+impl TxxtElement for QuoteBlock {
+    fn element_type(&self) -> ElementType {
+        ElementType::Block
+    }
+
+    fn tokens(&self) -> &ScannerTokenSequence {
+        &self.tokens
+    }
+
+    fn annotations(&self) -> &[Annotation] {
+        &self.annotations
+    }
+
+    fn parameters(&self) -> &Parameters {
+        &self.parameters
+    }
+
+    fn child_nodes(&self) -> Vec<&dyn TxxtElement> {
+        vec![&self.content]
+    }
+}
+
+impl BlockElement for QuoteBlock {
+    fn can_contain_blocks(&self) -> bool {
+        true
+    }
+
+    fn content_summary(&self) -> String {
+        format!("Quote with {} elements", self.content.content.len())
+    }
+}
+
+impl QuoteBlock {
+    /// Create a new quote block
+    pub fn new(
+        content: ContentContainer,
+        annotations: Vec<Annotation>,
+        parameters: Parameters,
+        tokens: ScannerTokenSequence,
+    ) -> Self {
+        Self {
+            content,
+            annotations,
+            parameters,
+            tokens,
+        }
+    }
+
+    /// Check if the quote has no content
+    pub fn is_empty(&self) -> bool {
+        self.content.content.is_empty()
+    }
+}
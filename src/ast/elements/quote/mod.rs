@@ -0,0 +1,8 @@
+//! Quote Elements
+//!
+//! Quote elements for `>`-prefixed blocks of quoted content.
+
+pub mod block;
+
+// Re-export quote types
+pub use block::QuoteBlock;
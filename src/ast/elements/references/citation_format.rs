@@ -0,0 +1,92 @@
+//! Citation formatting
+//!
+//! Parsing keeps a citation as written: a key, an optional locator and
+//! optional prefix and suffix text. [`format_citation`] renders one entry
+//! as readers see it, looking the key up in a bibliography:
+//!
+//! - [`CitationStyle::Numeric`]: `[1]`, `[1, p. 45]`
+//! - [`CitationStyle::AuthorYear`]: `(Smith 2023)`, `(Smith 2023, p. 45)`
+//!
+//! Locators are written in their canonical form ("pg 45" becomes "p. 45")
+//! when recognized, and as written otherwise. Prefix and suffix text go
+//! inside the brackets, around the rest: `(see Smith 2023, p. 45 for
+//! details)`. A key missing from the bibliography is rendered as the key
+//! itself, so the gap shows in the output.
+
+use std::collections::HashMap;
+
+use super::reference_types::CitationEntry;
+
+/// What a citation key refers to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BibliographyEntry {
+    /// Position in the reference list, as numeric citations show it
+    pub number: usize,
+
+    /// Author names as author-year citations show them, e.g. "Smith"
+    /// or "Smith and Doe"
+    pub author: String,
+
+    /// Publication year
+    pub year: String,
+}
+
+/// Bibliography entries by citation key
+pub type BibliographyMap = HashMap<String, BibliographyEntry>;
+
+/// How a citation is rendered
+#[derive(Debug, Clone, Copy)]
+pub enum CitationStyle<'a> {
+    /// The entry's number in square brackets: `[1]`
+    Numeric(&'a BibliographyMap),
+
+    /// Author and year in parentheses: `(Smith 2023)`
+    AuthorYear(&'a BibliographyMap),
+}
+
+/// Render a citation entry in the given style
+pub fn format_citation(entry: &CitationEntry, style: CitationStyle<'_>) -> String {
+    let (open, close, source) = match style {
+        CitationStyle::Numeric(bibliography) => (
+            '[',
+            ']',
+            bibliography
+                .get(&entry.key)
+                .map(|work| work.number.to_string()),
+        ),
+        CitationStyle::AuthorYear(bibliography) => (
+            '(',
+            ')',
+            bibliography
+                .get(&entry.key)
+                .map(|work| format!("{} {}", work.author, work.year)),
+        ),
+    };
+
+    let mut text = String::new();
+    text.push(open);
+    if let Some(prefix) = non_empty(&entry.prefix) {
+        text.push_str(prefix);
+        text.push(' ');
+    }
+    text.push_str(source.as_deref().unwrap_or(&entry.key));
+    if let Some(locator) = entry
+        .normalized_locator()
+        .or_else(|| non_empty(&entry.locator).map(str::to_string))
+    {
+        text.push_str(", ");
+        text.push_str(&locator);
+    }
+    if let Some(suffix) = non_empty(&entry.suffix) {
+        text.push(' ');
+        text.push_str(suffix);
+    }
+    text.push(close);
+    text
+}
+
+fn non_empty(text: &Option<String>) -> Option<&str> {
+    text.as_deref()
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+}
@@ -24,12 +24,15 @@ pub struct CitationSpan {
     pub raw_text: String,
 
     /// Annotations attached to this citation
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this citation
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for language server support
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -0,0 +1,123 @@
+//! Footnote registry
+//!
+//! Footnote references (`[^label]`) and footnote definitions
+//! (`[^label]: text`) are parsed independently, anywhere in the document.
+//! [`FootnoteRegistry`] collects both in one pass so renderers can look up
+//! the note a reference points to, and checkers can report notes nobody
+//! references or references with no note.
+//!
+//! Labels match exactly. When a label is defined more than once, the first
+//! definition in document order is the one looked up.
+//!
+//! Auto-generated references (`[^]`) have no label of their own. Once every
+//! footnote has been collected, each gets a unique id (`auto-1`, `auto-2`,
+//! ... in document order), skipping ids the document already uses as
+//! labels.
+
+use std::collections::HashSet;
+
+use crate::ast::elements::events::{events, DocEvent};
+use crate::ast::elements::footnote::FootnoteDefinitionBlock;
+use crate::ast::Document;
+
+use super::occurrences::{collect_references, ReferenceOccurrence};
+use super::reference_types::SimpleReferenceType;
+
+/// Footnote definitions and references of a document
+#[derive(Debug, Clone, Default)]
+pub struct FootnoteRegistry {
+    definitions: Vec<FootnoteDefinitionBlock>,
+    references: Vec<ReferenceOccurrence>,
+    generated: Vec<String>,
+}
+
+impl FootnoteRegistry {
+    /// Collect every footnote definition and reference, in document order
+    pub fn from_document(doc: &Document) -> Self {
+        let definitions: Vec<FootnoteDefinitionBlock> = events(doc)
+            .filter_map(|event| match event {
+                DocEvent::EnterFootnote(footnote) => Some(footnote.clone()),
+                _ => None,
+            })
+            .collect();
+        let mut references: Vec<ReferenceOccurrence> = collect_references(doc)
+            .into_iter()
+            .filter(|reference| reference.kind == SimpleReferenceType::Footnote)
+            .collect();
+
+        // Auto-generated references come with an empty target
+        let used: HashSet<String> = definitions
+            .iter()
+            .map(|definition| definition.label.clone())
+            .chain(references.iter().map(|reference| reference.target.clone()))
+            .collect();
+        let mut ids = (1..)
+            .map(|n| format!("auto-{}", n))
+            .filter(|id| !used.contains(id));
+        let mut generated = Vec::new();
+        for reference in references
+            .iter_mut()
+            .filter(|reference| reference.target.is_empty())
+        {
+            let id = ids.next().expect("auto ids are unbounded");
+            reference.target = id.clone();
+            generated.push(id);
+        }
+
+        Self {
+            definitions,
+            references,
+            generated,
+        }
+    }
+
+    /// The definition for `label`, if the document has one
+    pub fn definition(&self, label: &str) -> Option<&FootnoteDefinitionBlock> {
+        self.definitions
+            .iter()
+            .find(|definition| definition.label == label)
+    }
+
+    /// All definitions, in document order
+    pub fn definitions(&self) -> &[FootnoteDefinitionBlock] {
+        &self.definitions
+    }
+
+    /// All footnote references, in document order
+    ///
+    /// Auto-generated references carry their assigned id as the target.
+    pub fn references(&self) -> &[ReferenceOccurrence] {
+        &self.references
+    }
+
+    /// Whether `id` was generated for an auto-generated reference
+    pub fn is_generated(&self, id: &str) -> bool {
+        self.generated.iter().any(|generated| generated == id)
+    }
+
+    /// Definitions that no reference points to
+    pub fn unreferenced(&self) -> Vec<&FootnoteDefinitionBlock> {
+        self.definitions
+            .iter()
+            .filter(|definition| {
+                !self
+                    .references
+                    .iter()
+                    .any(|reference| reference.target == definition.label)
+            })
+            .collect()
+    }
+
+    /// References whose label has no definition
+    ///
+    /// Auto-generated references have no label to define and are left out.
+    pub fn undefined(&self) -> Vec<&ReferenceOccurrence> {
+        self.references
+            .iter()
+            .filter(|reference| {
+                !self.is_generated(&reference.target)
+                    && self.definition(&reference.target).is_none()
+            })
+            .collect()
+    }
+}
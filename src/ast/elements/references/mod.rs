@@ -2,17 +2,25 @@
 //!
 //! Reference elements for links, citations, footnotes, etc.
 
+pub mod citation_format;
 pub mod citations;
 pub mod footnote_ref;
+pub mod footnotes;
+pub mod occurrences;
 pub mod page_ref;
 pub mod reference_types;
+pub mod session_cycles;
 pub mod session_ref;
 
 // Re-export reference types
+pub use citation_format::{format_citation, BibliographyEntry, BibliographyMap, CitationStyle};
 pub use citations::CitationSpan;
 pub use footnote_ref::FootnoteReferenceSpan;
+pub use footnotes::FootnoteRegistry;
+pub use occurrences::{collect_references, ReferenceOccurrence};
 pub use page_ref::PageReferenceSpan;
 pub use reference_types::*;
+pub use session_cycles::{find_session_cycles, SessionCycle};
 pub use session_ref::SessionReferenceSpan;
 
 // General reference span for links and cross-references
@@ -37,12 +45,15 @@ pub struct ReferenceSpan {
     pub content: Option<Vec<TextTransform>>,
 
     /// Annotations attached to this reference
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this reference
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for language server support
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -59,6 +70,7 @@ pub struct Reference {
     pub content: Option<Vec<TextTransform>>,
 
     /// Source tokens for positioning
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
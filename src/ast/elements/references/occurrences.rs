@@ -0,0 +1,140 @@
+//! Reference enumeration
+//!
+//! Resolution answers "where does this reference point"; link checkers first
+//! need "what references are there". [`collect_references`] lists every
+//! reference in a document, in document order, with its target, kind and
+//! source span.
+//!
+//! References survive parsing as scanner tokens inside the text of each
+//! element (`CitationRef`, `SessionRef`, `RefMarker`, ...), so the walk reads
+//! them out of every element's inline content, as the document's
+//! [`events`](crate::ast::events) report it: session titles, paragraphs,
+//! list items, definition terms, table cells and annotations. Code and math spans are skipped where inline parsing
+//! has marked them, and verbatim content is never read.
+//!
+//! The query traversal (`TraversableDocument`) is not used: its nodes expose
+//! only each element's own tokens, which leave out session titles, list items
+//! and definition bodies.
+
+use crate::ast::elements::events::{events, session_events, DocEvent};
+use crate::ast::elements::formatting::inlines::TextTransform;
+use crate::ast::elements::session::SessionBlock;
+use crate::ast::elements::table::TableBlock;
+use crate::ast::Document;
+use crate::cst::{ScannerToken, SourceSpan};
+use crate::syntax::elements::references::footnote_ref::FootnoteType;
+
+use super::reference_types::{ReferenceClassifier, SimpleReferenceType};
+
+/// One reference as it appears in the source
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceOccurrence {
+    /// Reference content without brackets (`smith2023`, `2`, `./notes.txxt`)
+    ///
+    /// Citation keys drop their `@` and session references their `#`;
+    /// footnotes give their number or label, and auto-generated and inline
+    /// footnotes (`[^]`, `[^: text]`) an empty target until
+    /// [`FootnoteRegistry`] assigns their ids.
+    ///
+    /// [`FootnoteRegistry`]: super::FootnoteRegistry
+    pub target: String,
+
+    /// Kind of reference, as classified by [`ReferenceClassifier`]
+    ///
+    /// Page references (`[p.45]`) are citation locators and report as
+    /// `Citation`; session references (`[#2]`) report as `Section`.
+    pub kind: SimpleReferenceType,
+
+    /// Span of the reference in the source, brackets included
+    pub span: SourceSpan,
+}
+
+/// List every reference in a document, in document order
+pub fn collect_references(doc: &Document) -> Vec<ReferenceOccurrence> {
+    let mut occurrences = Vec::new();
+    for event in events(doc) {
+        collect_event(event, &mut occurrences);
+    }
+    occurrences
+}
+
+/// References a session makes itself: in its title and its content, but
+/// not in the sessions nested in it
+pub(crate) fn own_session_references(session: &SessionBlock) -> Vec<ReferenceOccurrence> {
+    let mut occurrences = Vec::new();
+    // The session's own events sit at depth 1; deeper ones are nested sessions
+    let mut depth = 0;
+    for event in session_events(session) {
+        match event {
+            DocEvent::EnterSession(_) => depth += 1,
+            DocEvent::ExitSession => depth -= 1,
+            event if depth == 1 => collect_event(event, &mut occurrences),
+            _ => {}
+        }
+    }
+    occurrences
+}
+
+/// Verbatim content is never read, only the block's title
+fn collect_event(event: DocEvent, out: &mut Vec<ReferenceOccurrence>) {
+    match event {
+        DocEvent::Text(transform) => collect_inlines(std::slice::from_ref(transform), out),
+        DocEvent::Verbatim(verbatim) => collect_inlines(&verbatim.title, out),
+        DocEvent::Table(table) => walk_table(table, out),
+        _ => {}
+    }
+}
+
+fn walk_table(table: &TableBlock, out: &mut Vec<ReferenceOccurrence>) {
+    for row in table.header.iter().chain(&table.rows) {
+        for cell in &row.cells {
+            collect_inlines(&cell.content, out);
+        }
+    }
+}
+
+fn collect_inlines(transforms: &[TextTransform], out: &mut Vec<ReferenceOccurrence>) {
+    for transform in transforms {
+        match transform {
+            TextTransform::Identity(text) => {
+                out.extend(text.tokens.tokens.iter().filter_map(occurrence_from_token));
+            }
+            TextTransform::Emphasis(inner)
+            | TextTransform::Strong(inner)
+            | TextTransform::Composed(inner) => collect_inlines(inner, out),
+            // A link's bracketed display text is not a reference
+            TextTransform::Custom { name, .. } if name == "link" => {}
+            TextTransform::Custom { content, .. } => collect_inlines(content, out),
+            TextTransform::Code(_) | TextTransform::Math(_) | TextTransform::LineBreak(_) => {}
+        }
+    }
+}
+
+/// Turn a reference scanner token into an occurrence
+fn occurrence_from_token(token: &ScannerToken) -> Option<ReferenceOccurrence> {
+    let (target, kind) = match token {
+        ScannerToken::CitationRef { content, .. } | ScannerToken::PageRef { content, .. } => {
+            (content.clone(), SimpleReferenceType::Citation)
+        }
+        ScannerToken::SessionRef { content, .. } => (content.clone(), SimpleReferenceType::Section),
+        ScannerToken::FootnoteRef { footnote_type, .. } => {
+            let target = match footnote_type {
+                FootnoteType::Naked(number) => number.to_string(),
+                FootnoteType::Labeled(label) => label.clone(),
+                FootnoteType::Auto | FootnoteType::Inline(_) => String::new(),
+            };
+            (target, SimpleReferenceType::Footnote)
+        }
+        ScannerToken::RefMarker { content, .. } => (
+            content.clone(),
+            ReferenceClassifier::shared().classify(content),
+        ),
+        _ => return None,
+    };
+
+    Some(ReferenceOccurrence {
+        target,
+        kind,
+        span: token.span().clone(),
+    })
+}
@@ -10,6 +10,7 @@ use crate::ast::elements::{
 use crate::cst::ScannerTokenSequence;
 
 use super::super::core::{ElementType, SpanElement, TxxtElement};
+use super::reference_types::{PageSpec, PageSpecError};
 
 /// Page reference span for page citations
 ///
@@ -26,12 +27,15 @@ pub struct PageReferenceSpan {
     pub raw_text: String,
 
     /// Annotations attached to this reference
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this reference
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for language server support
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -88,6 +92,13 @@ impl PageReferenceSpan {
             .collect()
     }
 
+    /// Read the specification as a list of pages and ranges
+    ///
+    /// Handles lists like "10, 12, 15-18"; see [`PageSpec::parse_list`].
+    pub fn page_specs(&self) -> Result<Vec<PageSpec>, PageSpecError> {
+        PageSpec::parse_list(&self.page_spec)
+    }
+
     /// Check if this is a page range
     pub fn is_range(&self) -> bool {
         self.page_spec.contains('-')
@@ -20,6 +20,7 @@
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::OnceLock;
 
 use crate::cst::ScannerTokenSequence;
 
@@ -53,6 +54,7 @@ pub enum ReferenceTarget {
         raw: String,
 
         /// Source position
+        #[serde(default)]
         tokens: ScannerTokenSequence,
     },
 
@@ -66,6 +68,7 @@ pub enum ReferenceTarget {
         raw: String,
 
         /// Source position
+        #[serde(default)]
         tokens: ScannerTokenSequence,
     },
 
@@ -75,6 +78,13 @@ pub enum ReferenceTarget {
         /// Full URL or domain
         url: String,
 
+        /// Lowercased scheme (`https`, `mailto`, ...)
+        ///
+        /// `None` for bare domains, email addresses and scheme-relative
+        /// (`//host`) URLs.
+        #[serde(default)]
+        scheme: Option<String>,
+
         /// Optional fragment (#anchor)
         fragment: Option<String>,
 
@@ -82,6 +92,7 @@ pub enum ReferenceTarget {
         raw: String,
 
         /// Source position
+        #[serde(default)]
         tokens: ScannerTokenSequence,
     },
 
@@ -95,6 +106,7 @@ pub enum ReferenceTarget {
         raw: String,
 
         /// Source position
+        #[serde(default)]
         tokens: ScannerTokenSequence,
     },
 
@@ -108,6 +120,7 @@ pub enum ReferenceTarget {
         raw: String,
 
         /// Source position
+        #[serde(default)]
         tokens: ScannerTokenSequence,
     },
 
@@ -121,6 +134,38 @@ pub enum ReferenceTarget {
         raw: String,
 
         /// Source position
+        #[serde(default)]
+        tokens: ScannerTokenSequence,
+    },
+
+    /// Footnote reference resolved through the footnote registry
+    /// Examples: [^] → id assigned when the document's footnotes are collected
+    Footnote {
+        /// Label and generation mode of the footnote
+        footnote: FootnoteTarget,
+
+        /// Raw reference text
+        raw: String,
+
+        /// Source position
+        #[serde(default)]
+        tokens: ScannerTokenSequence,
+    },
+
+    /// Reference matched by a user-registered handler
+    /// Examples: [!issue-123] with an issue-tracker handler registered
+    Custom {
+        /// Name of the handler that claimed this reference
+        kind: String,
+
+        /// Target as produced by the handler's parser function
+        target: String,
+
+        /// Raw reference text
+        raw: String,
+
+        /// Source position
+        #[serde(default)]
         tokens: ScannerTokenSequence,
     },
 
@@ -137,6 +182,7 @@ pub enum ReferenceTarget {
         reason: Option<String>,
 
         /// Source position
+        #[serde(default)]
         tokens: ScannerTokenSequence,
     },
 }
@@ -172,6 +218,25 @@ pub enum SectionIdentifier {
     },
 }
 
+/// Target of a footnote reference
+///
+/// Auto-generated footnotes (`[^]`) have no label: the footnote registry
+/// assigns each one a unique id once the whole document has been collected.
+/// Inline footnotes (`[^: the note text]`) are auto-generated too, but carry
+/// their note in place instead of pointing at a footnote definition.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FootnoteTarget {
+    /// Footnote label, `None` for the auto-generated and inline forms
+    pub label: Option<String>,
+
+    /// Whether the footnote's id is generated rather than written
+    pub auto_generate: bool,
+
+    /// Note text of an inline footnote, `None` for every other form
+    #[serde(default)]
+    pub content: Option<String>,
+}
+
 /// Individual citation entry within a citation reference
 ///
 /// Supports complex citation syntax like [@doe2024; @jones2025] and
@@ -181,10 +246,13 @@ pub struct CitationEntry {
     /// Citation key from bibliography file
     pub key: String,
 
-    /// Optional locator (page, section, etc.)
+    /// Optional locator (page, section, etc.) exactly as written
     /// Examples: "p. 45", "ch. 3", "§2.1"
     pub locator: Option<String>,
 
+    /// Kind of the locator, when it is a single recognized locator
+    pub locator_kind: Option<LocatorKind>,
+
     /// Optional prefix text
     /// Examples: "see", "cf.", "compare"
     pub prefix: Option<String>,
@@ -193,6 +261,210 @@ pub struct CitationEntry {
     pub suffix: Option<String>,
 }
 
+/// Kind of a citation locator
+///
+/// Authors spell locators freely ("p. 45", "pg 45", "page 45"); the kind
+/// plus [`CitationEntry::normalized_locator`] give bibliography output a
+/// single canonical spelling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LocatorKind {
+    Page,
+    Chapter,
+    Section,
+    Paragraph,
+}
+
+impl LocatorKind {
+    /// Recognized spellings, each tried before any shorter prefix of it
+    const SPELLINGS: &'static [(&'static str, LocatorKind)] = &[
+        ("pages", LocatorKind::Page),
+        ("page", LocatorKind::Page),
+        ("pgs", LocatorKind::Page),
+        ("pg", LocatorKind::Page),
+        ("pp", LocatorKind::Page),
+        ("p", LocatorKind::Page),
+        ("chapters", LocatorKind::Chapter),
+        ("chapter", LocatorKind::Chapter),
+        ("chaps", LocatorKind::Chapter),
+        ("chap", LocatorKind::Chapter),
+        ("chs", LocatorKind::Chapter),
+        ("ch", LocatorKind::Chapter),
+        ("sections", LocatorKind::Section),
+        ("section", LocatorKind::Section),
+        ("secs", LocatorKind::Section),
+        ("sec", LocatorKind::Section),
+        ("§§", LocatorKind::Section),
+        ("§", LocatorKind::Section),
+        ("paragraphs", LocatorKind::Paragraph),
+        ("paragraph", LocatorKind::Paragraph),
+        ("paras", LocatorKind::Paragraph),
+        ("para", LocatorKind::Paragraph),
+        ("¶¶", LocatorKind::Paragraph),
+        ("¶", LocatorKind::Paragraph),
+    ];
+
+    /// Canonical abbreviation, plural when the locator is a range
+    pub fn abbreviation(&self, plural: bool) -> &'static str {
+        match (self, plural) {
+            (LocatorKind::Page, false) => "p.",
+            (LocatorKind::Page, true) => "pp.",
+            (LocatorKind::Chapter, false) => "ch.",
+            (LocatorKind::Chapter, true) => "chs.",
+            (LocatorKind::Section, false) => "sec.",
+            (LocatorKind::Section, true) => "secs.",
+            (LocatorKind::Paragraph, false) => "para.",
+            (LocatorKind::Paragraph, true) => "paras.",
+        }
+    }
+
+    /// Split a locator like "pg 45" or "pages 45–47" into kind and value
+    ///
+    /// The value has range dashes (`–`, `—`, `--`) normalized to `-`, and
+    /// may list several values ("pp. 10, 12, 15-18"), rejoined with ", ".
+    /// Returns None for unrecognized or compound locators ("ch. 2, p. 45").
+    pub fn parse(locator: &str) -> Option<(LocatorKind, String)> {
+        let locator = locator.trim();
+
+        let (kind, rest) = Self::SPELLINGS.iter().find_map(|(spelling, kind)| {
+            let prefix = locator.get(..spelling.len())?;
+            if !prefix.eq_ignore_ascii_case(spelling) {
+                return None;
+            }
+            let rest = &locator[spelling.len()..];
+            let rest = rest.strip_prefix('.').unwrap_or(rest);
+            // The spelling must end at a word boundary: "pg 45", not "pgx"
+            (rest.is_empty() || !rest.starts_with(char::is_alphabetic)).then_some((*kind, rest))
+        })?;
+
+        let value = rest
+            .trim()
+            .replace("--", "-")
+            .replace(['\u{2013}', '\u{2014}'], "-");
+        let items: Vec<&str> = value.split(',').map(str::trim).collect();
+        let valid = items.iter().all(|item| {
+            item.chars().next().is_some_and(|c| c.is_alphanumeric())
+                && item
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '.' || c == '-')
+        });
+
+        valid.then(|| (kind, items.join(", ")))
+    }
+}
+
+/// One page or page range in a page locator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PageSpec {
+    Single(u32),
+    /// Inclusive range; `start` is below `end`
+    Range {
+        start: u32,
+        end: u32,
+    },
+}
+
+/// Why a page list could not be read as [`PageSpec`]s
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageSpecError {
+    /// An item is not a page number ("xii", "", "4a")
+    InvalidPage(String),
+
+    /// A range runs backwards ("18-15")
+    DescendingRange { start: u32, end: u32 },
+}
+
+impl std::fmt::Display for PageSpecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PageSpecError::InvalidPage(item) => write!(f, "Invalid page number '{}'", item),
+            PageSpecError::DescendingRange { start, end } => {
+                write!(f, "Page range {}-{} is descending", start, end)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PageSpecError {}
+
+impl PageSpec {
+    /// Read a comma-separated page list like "10, 12, 15-18"
+    ///
+    /// Ranges must ascend; a range whose ends are equal is a single page.
+    pub fn parse_list(value: &str) -> Result<Vec<PageSpec>, PageSpecError> {
+        value
+            .split(',')
+            .map(|item| Self::parse(item.trim()))
+            .collect()
+    }
+
+    fn parse(item: &str) -> Result<PageSpec, PageSpecError> {
+        let page = |text: &str| {
+            text.trim()
+                .parse::<u32>()
+                .map_err(|_| PageSpecError::InvalidPage(item.to_string()))
+        };
+
+        match item.split_once('-') {
+            None => page(item).map(PageSpec::Single),
+            Some((start, end)) => {
+                let (start, end) = (page(start)?, page(end)?);
+                match start.cmp(&end) {
+                    std::cmp::Ordering::Less => Ok(PageSpec::Range { start, end }),
+                    std::cmp::Ordering::Equal => Ok(PageSpec::Single(start)),
+                    std::cmp::Ordering::Greater => {
+                        Err(PageSpecError::DescendingRange { start, end })
+                    }
+                }
+            }
+        }
+    }
+
+    /// Every page the spec covers
+    pub fn pages(&self) -> std::ops::RangeInclusive<u32> {
+        match *self {
+            PageSpec::Single(page) => page..=page,
+            PageSpec::Range { start, end } => start..=end,
+        }
+    }
+}
+
+impl CitationEntry {
+    /// Create a citation entry, classifying its locator
+    pub fn new(key: String, locator: Option<String>) -> Self {
+        let locator_kind = locator
+            .as_deref()
+            .and_then(LocatorKind::parse)
+            .map(|(kind, _)| kind);
+        Self {
+            key,
+            locator,
+            locator_kind,
+            prefix: None,
+            suffix: None,
+        }
+    }
+
+    /// Canonical locator text, e.g. "pg 45" becomes "p. 45" and
+    /// "pages 45–47" becomes "pp. 45-47"
+    ///
+    /// Returns None when there is no locator or it isn't recognized.
+    pub fn normalized_locator(&self) -> Option<String> {
+        let (kind, value) = LocatorKind::parse(self.locator.as_deref()?)?;
+        let plural = value.contains(['-', ',']);
+        Some(format!("{} {}", kind.abbreviation(plural), value))
+    }
+
+    /// Pages of a page locator, e.g. "pp. 10, 12, 15-18"
+    ///
+    /// Returns None when the locator is missing or not a page locator.
+    pub fn page_specs(&self) -> Option<Result<Vec<PageSpec>, PageSpecError>> {
+        match LocatorKind::parse(self.locator.as_deref()?)? {
+            (LocatorKind::Page, value) => Some(PageSpec::parse_list(&value)),
+            _ => None,
+        }
+    }
+}
+
 /// Citation reference for academic/technical documents
 ///
 /// Citations reference external sources and can be formatted according to
@@ -213,6 +485,7 @@ pub struct Citation {
     pub suffix: Option<String>,
 
     /// Raw tokens for positioning
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -232,6 +505,7 @@ pub struct CrossReference {
     pub custom_text: Option<Vec<super::super::formatting::inlines::Inline>>,
 
     /// Raw tokens for positioning
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -295,6 +569,7 @@ pub struct Bibliography {
     pub metadata: HashMap<String, String>,
 
     /// Source position
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -324,6 +599,8 @@ impl ReferenceTarget {
             ReferenceTarget::Citation { raw, .. } => raw,
             ReferenceTarget::NamedAnchor { raw, .. } => raw,
             ReferenceTarget::NakedNumerical { raw, .. } => raw,
+            ReferenceTarget::Footnote { raw, .. } => raw,
+            ReferenceTarget::Custom { raw, .. } => raw,
             ReferenceTarget::Unresolved { raw, .. } => raw,
         }
     }
@@ -337,6 +614,8 @@ impl ReferenceTarget {
             ReferenceTarget::Citation { tokens, .. } => tokens,
             ReferenceTarget::NamedAnchor { tokens, .. } => tokens,
             ReferenceTarget::NakedNumerical { tokens, .. } => tokens,
+            ReferenceTarget::Footnote { tokens, .. } => tokens,
+            ReferenceTarget::Custom { tokens, .. } => tokens,
             ReferenceTarget::Unresolved { tokens, .. } => tokens,
         }
     }
@@ -349,6 +628,7 @@ impl ReferenceTarget {
                 | ReferenceTarget::Section { .. }
                 | ReferenceTarget::NamedAnchor { .. }
                 | ReferenceTarget::NakedNumerical { .. }
+                | ReferenceTarget::Footnote { .. }
         )
     }
 
@@ -417,6 +697,14 @@ impl ReferenceTarget {
             }
             ReferenceTarget::NamedAnchor { anchor, .. } => format!("#{}", anchor),
             ReferenceTarget::NakedNumerical { number, .. } => number.to_string(),
+            ReferenceTarget::Footnote { footnote, .. } => {
+                match (&footnote.label, &footnote.content) {
+                    (Some(label), _) => format!("^{}", label),
+                    (None, Some(content)) => format!("^: {}", content),
+                    (None, None) => "^".to_string(),
+                }
+            }
+            ReferenceTarget::Custom { target, .. } => target.clone(),
             ReferenceTarget::Unresolved { content, .. } => content.clone(),
         }
     }
@@ -448,7 +736,7 @@ impl SectionIdentifier {
 /// This enum provides basic classification of reference content during parsing.
 /// It follows the spec precedence order and maps to the more detailed ReferenceTarget
 /// enum during later parsing phases.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SimpleReferenceType {
     /// URL references (example.com, https://example.com, user@domain.com)
     Url,
@@ -462,15 +750,50 @@ pub enum SimpleReferenceType {
     ToComeTK,
     /// File references (./file.txt, ../dir/file.txt, /absolute/path)
     File,
+    /// References claimed by a registered custom handler, carrying its name
+    Custom(String),
     /// Anchor/other references that don't match specific patterns
     NotSure,
 }
 
-/// Reference classifier that determines the type of a reference based on its content
+/// User-registered reference convention
 ///
-/// This classifier implements the TXXT spec precedence order for reference type detection.
-/// It operates during the parsing phase to classify RefMarker token content.
-pub struct ReferenceClassifier {
+/// Some documents use bracket conventions the spec doesn't know about, such as
+/// `[!issue-123]` pointing into an issue tracker. A handler pairs a predicate
+/// that recognizes the convention with a parser that extracts the target from
+/// the (trimmed) reference content. Plain function pointers keep the classifier
+/// `Send + Sync`, so it can still live in the lexer's shared static.
+#[derive(Debug, Clone)]
+pub struct CustomReferenceHandler {
+    /// Name reported in `SimpleReferenceType::Custom` and `ReferenceTarget::Custom`
+    pub name: String,
+
+    /// Returns true when the content belongs to this convention
+    pub matches: fn(&str) -> bool,
+
+    /// Extracts the target from content accepted by `matches`
+    pub parse: fn(&str) -> String,
+}
+
+impl CustomReferenceHandler {
+    pub fn new(
+        name: impl Into<String>,
+        matches: fn(&str) -> bool,
+        parse: fn(&str) -> String,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            matches,
+            parse,
+        }
+    }
+}
+
+/// Compiled regex patterns for the built-in reference types
+///
+/// Compiling these is the expensive part of a classifier, so they are built
+/// once per process and shared by every `ReferenceClassifier`.
+struct BuiltinPatterns {
     // URL patterns
     url_protocol_regex: Regex,
     url_domain_regex: Regex,
@@ -494,12 +817,24 @@ pub struct ReferenceClassifier {
     file_absolute_regex: Regex,
 }
 
-impl ReferenceClassifier {
-    /// Create a new reference classifier with compiled regex patterns
-    pub fn new() -> Self {
+/// Number of times the built-in patterns have been compiled (debug builds only)
+#[cfg(debug_assertions)]
+static PATTERN_BUILDS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+impl BuiltinPatterns {
+    /// The process-wide patterns, compiled on first use
+    fn get() -> &'static Self {
+        static PATTERNS: OnceLock<BuiltinPatterns> = OnceLock::new();
+        PATTERNS.get_or_init(Self::compile)
+    }
+
+    fn compile() -> Self {
+        #[cfg(debug_assertions)]
+        PATTERN_BUILDS.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
         Self {
             // URL patterns
-            url_protocol_regex: Regex::new(r"^(https?|ftp)://\S+").unwrap(),
+            url_protocol_regex: Regex::new(r"(?i)^((https?|ftp)://|mailto:|//)\S").unwrap(),
             url_domain_regex: Regex::new(r"(?i)^(www\.[a-zA-Z0-9][a-zA-Z0-9.-]*\.[a-zA-Z]{2,}|[a-zA-Z0-9][a-zA-Z0-9-]*(\.[a-zA-Z0-9-]+)*\.(com|org|net|edu|gov|mil|int|info|biz|name|pro|museum|coop|aero|co\.uk|[a-zA-Z]{2}))(/.*)?$")
                 .unwrap(),
             url_email_regex: Regex::new(r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$")
@@ -526,6 +861,62 @@ impl ReferenceClassifier {
             file_absolute_regex: Regex::new(r"^/").unwrap(),
         }
     }
+}
+
+/// How many times the built-in reference patterns have been compiled
+///
+/// Stays at 1 for the life of the process; exposed so tests can check that
+/// classifiers share their patterns.
+#[cfg(debug_assertions)]
+#[doc(hidden)]
+pub fn pattern_build_count() -> usize {
+    PATTERN_BUILDS.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+/// Reference classifier that determines the type of a reference based on its content
+///
+/// This classifier implements the TXXT spec precedence order for reference type detection.
+/// It operates during the parsing phase to classify RefMarker token content.
+///
+/// The built-in patterns are compiled once and shared, so creating a
+/// classifier is cheap; [`ReferenceClassifier::shared`] avoids even that.
+pub struct ReferenceClassifier {
+    // Built-in spec patterns, shared across classifiers
+    patterns: &'static BuiltinPatterns,
+
+    // User-registered handlers, consulted in registration order before the built-ins
+    custom_handlers: Vec<CustomReferenceHandler>,
+}
+
+impl ReferenceClassifier {
+    /// Create a new reference classifier using the shared compiled patterns
+    pub fn new() -> Self {
+        Self {
+            patterns: BuiltinPatterns::get(),
+            custom_handlers: Vec::new(),
+        }
+    }
+
+    /// The process-wide classifier without custom handlers
+    pub fn shared() -> &'static Self {
+        static CLASSIFIER: OnceLock<ReferenceClassifier> = OnceLock::new();
+        CLASSIFIER.get_or_init(Self::new)
+    }
+
+    /// Register a custom handler, dispatched before the built-in spec types
+    ///
+    /// Handlers take precedence so that a convention can claim content the spec
+    /// would otherwise classify (e.g. a handler for `@team` mentions overriding
+    /// citations).
+    pub fn with_custom(mut self, handler: CustomReferenceHandler) -> Self {
+        self.custom_handlers.push(handler);
+        self
+    }
+
+    /// Look up a registered custom handler by name
+    pub fn custom_handler(&self, name: &str) -> Option<&CustomReferenceHandler> {
+        self.custom_handlers.iter().find(|h| h.name == name)
+    }
 
     /// Classify a reference content string according to TXXT spec precedence order
     pub fn classify(&self, content: &str) -> SimpleReferenceType {
@@ -535,6 +926,11 @@ impl ReferenceClassifier {
 
         let content = content.trim();
 
+        // Custom handlers win over the spec types
+        if let Some(handler) = self.custom_handlers.iter().find(|h| (h.matches)(content)) {
+            return SimpleReferenceType::Custom(handler.name.clone());
+        }
+
         // Check in spec order (precedence matters)
 
         // a. URL References
@@ -574,29 +970,36 @@ impl ReferenceClassifier {
 
     /// Check if content is a URL reference
     fn is_url(&self, content: &str) -> bool {
-        self.url_protocol_regex.is_match(content)
-            || self.url_domain_regex.is_match(content)
-            || self.url_email_regex.is_match(content)
+        self.patterns.url_protocol_regex.is_match(content)
+            || self.patterns.url_domain_regex.is_match(content)
+            || self.patterns.url_email_regex.is_match(content)
     }
 
     /// Check if content is a section reference
     fn is_section(&self, content: &str) -> bool {
-        self.section_regex.is_match(content)
+        self.patterns.section_regex.is_match(content)
     }
 
     /// Check if content is a footnote reference
     fn is_footnote(&self, content: &str) -> bool {
-        self.footnote_regex.is_match(content)
+        self.patterns.footnote_regex.is_match(content)
     }
 
     /// Check if content is a citation reference
+    ///
+    /// A group (`@a; @b, p. 3`) is a citation when every entry is one.
     fn is_citation(&self, content: &str) -> bool {
-        self.citation_author_regex.is_match(content) || self.citation_page_regex.is_match(content)
+        self.patterns.citation_author_regex.is_match(content)
+            || self.patterns.citation_page_regex.is_match(content)
+            || (content.contains(';')
+                && content
+                    .split(';')
+                    .all(|entry| self.patterns.citation_author_regex.is_match(entry.trim())))
     }
 
     /// Check if content is a TK reference
     fn is_tk(&self, content: &str) -> bool {
-        if self.tk_naked_regex.is_match(content) {
+        if self.patterns.tk_naked_regex.is_match(content) {
             return true;
         }
 
@@ -617,7 +1020,8 @@ impl ReferenceClassifier {
 
     /// Check if content is a file reference
     fn is_file(&self, content: &str) -> bool {
-        self.file_relative_regex.is_match(content) || self.file_absolute_regex.is_match(content)
+        self.patterns.file_relative_regex.is_match(content)
+            || self.patterns.file_absolute_regex.is_match(content)
     }
 
     /// Basic validation - at least one alphanumeric character
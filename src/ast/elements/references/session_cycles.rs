@@ -0,0 +1,174 @@
+//! Session reference cycles
+//!
+//! Session references (`[#2]`, `[#2.1]`, `[introduction]`) link sessions
+//! to one another. A session that points to another which points back,
+//! directly or through others, sends a reader following references around
+//! in circles, and would loop any resolver that follows references to
+//! their end. [`find_session_cycles`] reports those loops.
+//!
+//! Each session points to the sessions its title and its own content
+//! reference; references in a nested session belong to that session.
+//! Numeric references match a session's numbering, read from its title
+//! marker with a short marker (`1.`) nested under its parent's number, and
+//! named references (`[introduction]` or `[#introduction]`) match its slug.
+//! References that match no session, and a session referencing itself, form
+//! no cycle.
+
+use std::collections::HashMap;
+
+use crate::ast::elements::session::session_container::SessionContainerElement;
+use crate::ast::elements::session::{slugify, SessionBlock, SessionContainer};
+use crate::ast::Document;
+use crate::cst::SourceSpan;
+use crate::semantic::elements::inlines::references::parse_numeric_levels;
+
+use super::occurrences::own_session_references;
+use super::reference_types::SimpleReferenceType;
+
+/// Sessions whose references lead back to the first of them
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionCycle {
+    /// Titles of the sessions in the cycle, in reference order
+    pub sessions: Vec<String>,
+
+    /// The reference that closes the cycle, brackets included
+    pub span: SourceSpan,
+}
+
+/// A session with the number numeric references resolve it by
+struct Node<'a> {
+    session: &'a SessionBlock,
+    levels: Option<Vec<u32>>,
+}
+
+/// List every cycle among session references, in document order
+pub fn find_session_cycles(doc: &Document) -> Vec<SessionCycle> {
+    let mut nodes = Vec::new();
+    collect_nodes(&doc.content, None, &mut nodes);
+
+    let by_number: HashMap<&[u32], usize> = nodes
+        .iter()
+        .enumerate()
+        .filter_map(|(index, node)| Some((node.levels.as_deref()?, index)))
+        .collect();
+    let by_slug: HashMap<String, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(index, node)| (node.session.slug(), index))
+        .collect();
+
+    let edges: Vec<Vec<(usize, SourceSpan)>> = nodes
+        .iter()
+        .enumerate()
+        .map(|(index, node)| {
+            own_session_references(node.session)
+                .into_iter()
+                .filter_map(|occurrence| {
+                    let target = match occurrence.kind {
+                        SimpleReferenceType::Section => {
+                            by_number.get(parse_levels(&occurrence.target)?.as_slice())
+                        }
+                        // Named references are left untyped by the classifier
+                        SimpleReferenceType::NotSure => {
+                            by_slug.get(&slugify(occurrence.target.trim_start_matches('#')))
+                        }
+                        _ => None,
+                    }
+                    .copied()?;
+                    (target != index).then_some((target, occurrence.span))
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut cycles = Vec::new();
+    let mut state = vec![Visit::New; nodes.len()];
+    let mut path = Vec::new();
+    for start in 0..nodes.len() {
+        if state[start] == Visit::New {
+            visit(start, &edges, &mut state, &mut path, &mut |cycle, span| {
+                cycles.push(SessionCycle {
+                    sessions: cycle
+                        .iter()
+                        .map(|&index| nodes[index].session.title_text().trim().to_string())
+                        .collect(),
+                    span: span.clone(),
+                })
+            });
+        }
+    }
+    cycles
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    New,
+    OnPath,
+    Done,
+}
+
+/// Depth-first walk reporting every reference back to a session on the path
+fn visit(
+    node: usize,
+    edges: &[Vec<(usize, SourceSpan)>],
+    state: &mut [Visit],
+    path: &mut Vec<usize>,
+    report: &mut impl FnMut(&[usize], &SourceSpan),
+) {
+    state[node] = Visit::OnPath;
+    path.push(node);
+    for (target, span) in &edges[node] {
+        match state[*target] {
+            Visit::New => visit(*target, edges, state, path, report),
+            Visit::OnPath => {
+                let start = path
+                    .iter()
+                    .position(|&index| index == *target)
+                    .expect("sessions on the path are in it");
+                report(&path[start..], span);
+            }
+            Visit::Done => {}
+        }
+    }
+    path.pop();
+    state[node] = Visit::Done;
+}
+
+/// Sessions depth first, each with its full number when it has one
+fn collect_nodes<'a>(
+    container: &'a SessionContainer,
+    parent: Option<&[u32]>,
+    out: &mut Vec<Node<'a>>,
+) {
+    for element in &container.content {
+        match element {
+            SessionContainerElement::Session(session) => {
+                let levels = session
+                    .numbering_marker()
+                    .and_then(parse_levels)
+                    .map(|own| match parent {
+                        Some(parent) if own.len() == 1 => [parent, &own[..]].concat(),
+                        _ => own,
+                    });
+                out.push(Node {
+                    session,
+                    levels: levels.clone(),
+                });
+                collect_nodes(&session.content, levels.as_deref(), out);
+            }
+            SessionContainerElement::SessionContainer(inner) => collect_nodes(inner, parent, out),
+            _ => {}
+        }
+    }
+}
+
+/// Numbers of a marker or reference: `2.1.` and `2.1` both give `[2, 1]`
+///
+/// Parsed as section references are, so `1..2` or `.1` match no session.
+fn parse_levels(text: &str) -> Option<Vec<u32>> {
+    let text = text.trim();
+    let text = text.strip_suffix(')').unwrap_or(text);
+    parse_numeric_levels(text)
+        .ok()
+        .filter(|levels| !levels.is_empty())
+}
@@ -24,12 +24,15 @@ pub struct SessionReferenceSpan {
     pub raw_text: String,
 
     /// Annotations attached to this reference
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this reference
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for language server support
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
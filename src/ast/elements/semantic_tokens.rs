@@ -0,0 +1,245 @@
+//! Semantic tokens for editor highlighting
+//!
+//! [`semantic_tokens`] lists the highlightable ranges of a document (session
+//! titles, formatting, references, list markers and verbatim lines) with a
+//! [`SemanticTokenKind`] each, in document order. This is the data an LSP
+//! `textDocument/semanticTokens` handler encodes: every token sits on one
+//! line, and spans follow `cst::Position` (0-indexed rows, columns counted
+//! in characters, the lexer's default `ColumnUnit::Chars`).
+//! `SourceMap::position_to_offset` turns them into buffer offsets.
+//!
+//! Spans come from the scanner tokens kept in the tree. Formatting
+//! delimiters are not kept, so an emphasis or code token covers the text
+//! between its delimiters. Tokens never overlap: a formatted span is one
+//! token, and references or formatting nested inside it are not reported
+//! separately.
+
+use crate::ast::elements::events::{events, DocEvent};
+use crate::ast::elements::footnote::FootnoteDefinitionBlock;
+use crate::ast::elements::formatting::inlines::TextTransform;
+use crate::ast::elements::list::ListItem;
+use crate::ast::elements::table::TableBlock;
+use crate::ast::elements::verbatim::VerbatimBlock;
+use crate::ast::Document;
+use crate::cst::{Position, ScannerToken, SourceSpan};
+
+/// Highlighting category of a [`SemanticToken`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SemanticTokenKind {
+    /// Session title
+    Heading,
+    Emphasis,
+    Strong,
+    Code,
+    Math,
+    /// Citation, footnote, session, page or bracketed reference
+    Reference,
+    /// List item marker (`-`, `1.`, `a)`)
+    ListMarker,
+    /// One line of verbatim content
+    Verbatim,
+}
+
+/// One highlightable range of the source
+#[derive(Debug, Clone, PartialEq)]
+pub struct SemanticToken {
+    /// Single-line range the token covers
+    pub span: SourceSpan,
+
+    /// What the range is
+    pub kind: SemanticTokenKind,
+}
+
+/// List the document's semantic tokens, in document order
+pub fn semantic_tokens(doc: &Document) -> Vec<SemanticToken> {
+    let mut tokens = Vec::new();
+    // A session's title is one heading, so its text events add nothing
+    let mut in_title = false;
+    for event in events(doc) {
+        in_title = match event {
+            DocEvent::EnterSession(session) => {
+                let title = session.title.content.iter().flat_map(transform_tokens);
+                push_span(title, SemanticTokenKind::Heading, &mut tokens);
+                true
+            }
+            DocEvent::Text(_) if in_title => true,
+            DocEvent::Text(transform) => {
+                walk_inlines(std::slice::from_ref(transform), &mut tokens);
+                false
+            }
+            DocEvent::EnterListItem(item) => {
+                push_list_marker(item, &mut tokens);
+                false
+            }
+            DocEvent::EnterFootnote(footnote) => {
+                push_footnote_marker(footnote, &mut tokens);
+                false
+            }
+            DocEvent::Verbatim(verbatim) => {
+                walk_verbatim(verbatim, &mut tokens);
+                false
+            }
+            DocEvent::Table(table) => {
+                walk_table(table, &mut tokens);
+                false
+            }
+            _ => false,
+        };
+    }
+    tokens
+}
+
+/// The marker is not kept as a token; the spec puts it right before the
+/// item text, separated by one space
+fn push_list_marker(item: &ListItem, out: &mut Vec<SemanticToken>) {
+    let content_start = item
+        .content
+        .iter()
+        .flat_map(transform_tokens)
+        .find(|token| !is_layout(token))
+        .map(|token| token.span().start);
+    if let Some(start) = content_start {
+        let width = item.marker.chars().count();
+        if let Some(column) = start.column.checked_sub(width + 1) {
+            out.push(SemanticToken {
+                span: line_span(start.row, column, column + width),
+                kind: SemanticTokenKind::ListMarker,
+            });
+        }
+    }
+}
+
+/// The `[^label]` marker highlights like the references that point to it
+fn push_footnote_marker(footnote: &FootnoteDefinitionBlock, out: &mut Vec<SemanticToken>) {
+    let marker = footnote
+        .tokens
+        .tokens
+        .iter()
+        .find(|token| is_reference(token));
+    push_span(marker, SemanticTokenKind::Reference, out);
+}
+
+fn walk_verbatim(verbatim: &VerbatimBlock, out: &mut Vec<SemanticToken>) {
+    walk_inlines(&verbatim.title, out);
+    for line in &verbatim.content.ignore_lines {
+        for token in &line.tokens.tokens {
+            if let ScannerToken::VerbatimContentLine {
+                content,
+                indentation,
+                span,
+            } = token
+            {
+                if content.is_empty() {
+                    continue;
+                }
+                let start = span.start.column + indentation.chars().count();
+                let end = start + content.chars().count();
+                out.push(SemanticToken {
+                    span: line_span(span.start.row, start, end),
+                    kind: SemanticTokenKind::Verbatim,
+                });
+            }
+        }
+    }
+}
+
+fn walk_table(table: &TableBlock, out: &mut Vec<SemanticToken>) {
+    for row in table.header.iter().chain(&table.rows) {
+        for cell in &row.cells {
+            walk_inlines(&cell.content, out);
+        }
+    }
+}
+
+fn walk_inlines(transforms: &[TextTransform], out: &mut Vec<SemanticToken>) {
+    for transform in transforms {
+        let kind = match transform {
+            TextTransform::Identity(text) => {
+                for token in text.tokens.tokens.iter().filter(|t| is_reference(t)) {
+                    push_span(std::iter::once(token), SemanticTokenKind::Reference, out);
+                }
+                continue;
+            }
+            TextTransform::Emphasis(_) => SemanticTokenKind::Emphasis,
+            TextTransform::Strong(_) => SemanticTokenKind::Strong,
+            TextTransform::Code(_) => SemanticTokenKind::Code,
+            TextTransform::Math(_) => SemanticTokenKind::Math,
+            TextTransform::Composed(inner) | TextTransform::Custom { content: inner, .. } => {
+                walk_inlines(inner, out);
+                continue;
+            }
+            TextTransform::LineBreak(_) => continue,
+        };
+        push_span(transform_tokens(transform), kind, out);
+    }
+}
+
+/// Every scanner token under a transform, in source order
+fn transform_tokens(transform: &TextTransform) -> Vec<&ScannerToken> {
+    match transform {
+        TextTransform::Identity(text)
+        | TextTransform::Code(text)
+        | TextTransform::Math(text)
+        | TextTransform::LineBreak(text) => text.tokens.tokens.iter().collect(),
+        TextTransform::Emphasis(inner)
+        | TextTransform::Strong(inner)
+        | TextTransform::Composed(inner)
+        | TextTransform::Custom { content: inner, .. } => {
+            inner.iter().flat_map(transform_tokens).collect()
+        }
+    }
+}
+
+/// Push one token per row covering `tokens`, skipping newlines and indentation
+fn push_span<'a>(
+    tokens: impl IntoIterator<Item = &'a ScannerToken>,
+    kind: SemanticTokenKind,
+    out: &mut Vec<SemanticToken>,
+) {
+    let mut current: Option<SourceSpan> = None;
+    for token in tokens.into_iter().filter(|token| !is_layout(token)) {
+        let span = token.span();
+        match &mut current {
+            Some(line) if line.start.row == span.start.row => line.end = span.end,
+            _ => {
+                if let Some(line) = current.take() {
+                    out.push(SemanticToken { span: line, kind });
+                }
+                current = Some(span.clone());
+            }
+        }
+    }
+    if let Some(line) = current {
+        out.push(SemanticToken { span: line, kind });
+    }
+}
+
+/// Tokens that carry layout rather than text
+fn is_layout(token: &ScannerToken) -> bool {
+    matches!(
+        token,
+        ScannerToken::Newline { .. }
+            | ScannerToken::BlankLine { .. }
+            | ScannerToken::Indent { .. }
+            | ScannerToken::Dedent { .. }
+            | ScannerToken::Eof { .. }
+    )
+}
+
+fn is_reference(token: &ScannerToken) -> bool {
+    matches!(
+        token,
+        ScannerToken::RefMarker { .. }
+            | ScannerToken::CitationRef { .. }
+            | ScannerToken::PageRef { .. }
+            | ScannerToken::SessionRef { .. }
+            | ScannerToken::FootnoteRef { .. }
+    )
+}
+
+fn line_span(row: usize, start: usize, end: usize) -> SourceSpan {
+    SourceSpan {
+        start: Position { row, column: start },
+        end: Position { row, column: end },
+    }
+}
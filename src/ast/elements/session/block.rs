@@ -30,13 +30,20 @@ pub struct SessionBlock {
     pub content: SessionContainer,
 
     /// Annotations attached to this session
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for this session
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Raw tokens for source reconstruction
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
+
+    /// Document-unique slug, set by `assembly::assign_slugs`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
 }
 
 /// Session title with hierarchical numbering support
@@ -49,6 +56,7 @@ pub struct SessionTitle {
     pub numbering: Option<SessionNumbering>,
 
     /// Raw tokens for exact source reconstruction
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -81,6 +89,10 @@ impl TxxtElement for SessionBlock {
     fn parameters(&self) -> &Parameters {
         &self.parameters
     }
+
+    fn child_nodes(&self) -> Vec<&dyn TxxtElement> {
+        vec![&self.title, &self.content]
+    }
 }
 
 impl BlockElement for SessionBlock {
@@ -122,6 +134,16 @@ impl TxxtElement for SessionTitle {
         static EMPTY_PARAMS: OnceLock<Parameters> = OnceLock::new();
         EMPTY_PARAMS.get_or_init(Parameters::default)
     }
+
+    fn untokenized_content(&self) -> Vec<String> {
+        // Titles are assembled without tokens; numbering and text are all there is
+        let marker = self
+            .numbering
+            .as_ref()
+            .map(|numbering| numbering.marker.clone())
+            .unwrap_or_default();
+        vec![marker, self.text_content()]
+    }
 }
 
 impl SessionBlock {
@@ -139,9 +161,20 @@ impl SessionBlock {
             annotations,
             parameters,
             tokens,
+            slug: None,
         }
     }
 
+    /// Anchor name for named references (`[introduction]`)
+    ///
+    /// The slug assigned by `assembly::assign_slugs` if it has run, which
+    /// is unique within the document; otherwise the slug of the title alone.
+    pub fn slug(&self) -> String {
+        self.slug
+            .clone()
+            .unwrap_or_else(|| slugify(&self.title.text_content()))
+    }
+
     /// Get the session title text
     pub fn title_text(&self) -> String {
         self.title.text_content()
@@ -207,3 +240,21 @@ impl SessionNumbering {
         matches!(self.form, NumberingForm::Full)
     }
 }
+
+/// Turn title text into a slug: lowercase, words joined by hyphens
+///
+/// Punctuation is dropped, so `What's new?` becomes `whats-new`. The
+/// numbering marker is not part of the title text and never appears.
+pub fn slugify(title: &str) -> String {
+    title
+        .split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric() || *c == '-' || *c == '_')
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
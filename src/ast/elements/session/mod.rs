@@ -4,7 +4,9 @@
 
 pub mod block;
 pub mod session_container;
+pub mod toc;
 
 // Re-export session types
-pub use block::{SessionBlock, SessionNumbering, SessionTitle};
+pub use block::{slugify, SessionBlock, SessionNumbering, SessionTitle};
 pub use session_container::SessionContainer;
+pub use toc::{generate_toc, Toc, TocEntry};
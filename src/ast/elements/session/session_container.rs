@@ -39,12 +39,15 @@ pub struct SessionContainer {
     pub content: Vec<SessionContainerElement>,
 
     /// Annotations attached to this container
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for metadata
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Source position information
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -69,6 +72,15 @@ pub enum SessionContainerElement {
     /// Annotation blocks
     Annotation(super::super::annotation::AnnotationBlock),
 
+    /// Quote blocks
+    Quote(super::super::quote::QuoteBlock),
+
+    /// Table blocks
+    Table(super::super::table::TableBlock),
+
+    /// Footnote definition blocks
+    FootnoteDefinition(super::super::footnote::FootnoteDefinitionBlock),
+
     /// Session blocks (only allowed in session containers!)
     Session(super::block::SessionBlock),
 
@@ -98,6 +110,10 @@ impl TxxtElement for SessionContainer {
     fn parameters(&self) -> &Parameters {
         &self.parameters
     }
+
+    fn child_nodes(&self) -> Vec<&dyn TxxtElement> {
+        self.child_elements()
+    }
 }
 
 impl ContainerElement for SessionContainer {
@@ -118,6 +134,9 @@ impl ContainerElement for SessionContainer {
                 SessionContainerElement::Definition(d) => d as &dyn TxxtElement,
                 SessionContainerElement::Verbatim(v) => v as &dyn TxxtElement,
                 SessionContainerElement::Annotation(a) => a as &dyn TxxtElement,
+                SessionContainerElement::Quote(q) => q as &dyn TxxtElement,
+                SessionContainerElement::Table(t) => t as &dyn TxxtElement,
+                SessionContainerElement::FootnoteDefinition(f) => f as &dyn TxxtElement,
                 SessionContainerElement::Session(s) => s as &dyn TxxtElement,
                 SessionContainerElement::ContentContainer(c) => c as &dyn TxxtElement,
                 SessionContainerElement::SessionContainer(s) => s as &dyn TxxtElement,
@@ -0,0 +1,133 @@
+//! Table of contents
+//!
+//! [`generate_toc`] lists a document's sessions as a tree of titles, in
+//! document order, for renderers that print a contents page or tools that
+//! insert one as a list.
+//!
+//! Each entry's anchor is the session's position path in the form session
+//! references use (`#2.1` is the first session inside the second top-level
+//! session), so it is valid whether or not the session is numbered.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ast::Document;
+
+use super::session_container::SessionContainerElement;
+
+/// Sessions of a document, nested as in the document
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Toc {
+    /// Top-level sessions, in document order
+    pub entries: Vec<TocEntry>,
+}
+
+/// One session in a [`Toc`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TocEntry {
+    /// Plain text of the session title, trimmed
+    pub title: String,
+
+    /// Numbering marker as written (`1.`, `2.1.`), if the session has one
+    pub numbering: Option<String>,
+
+    /// Session reference target for this session (`#2.1`)
+    pub anchor: String,
+
+    /// 1-based nesting depth; top-level sessions are at depth 1
+    pub depth: usize,
+
+    /// Nested sessions within the depth limit
+    pub children: Vec<TocEntry>,
+}
+
+impl Toc {
+    /// Whether the document has no sessions (within the depth limit)
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Every entry, depth first, in document order
+    pub fn flatten(&self) -> Vec<&TocEntry> {
+        fn push<'a>(entries: &'a [TocEntry], out: &mut Vec<&'a TocEntry>) {
+            for entry in entries {
+                out.push(entry);
+                push(&entry.children, out);
+            }
+        }
+        let mut out = Vec::new();
+        push(&self.entries, &mut out);
+        out
+    }
+}
+
+/// Build the table of contents, keeping sessions up to `max_depth` levels deep
+///
+/// A `max_depth` of 1 lists only top-level sessions; 0 gives an empty TOC.
+pub fn generate_toc(document: &Document, max_depth: usize) -> Toc {
+    let mut entries = Vec::new();
+    collect_entries(
+        &document.content.content,
+        &[],
+        &mut 0,
+        max_depth,
+        &mut entries,
+    );
+    Toc { entries }
+}
+
+/// Add the sessions among `elements`, continuing the sibling `counter`
+///
+/// Nested session containers hold more siblings at the same level, so they
+/// share the counter.
+fn collect_entries(
+    elements: &[SessionContainerElement],
+    parent: &[usize],
+    counter: &mut usize,
+    max_depth: usize,
+    out: &mut Vec<TocEntry>,
+) {
+    if parent.len() >= max_depth {
+        return;
+    }
+
+    for element in elements {
+        match element {
+            SessionContainerElement::Session(session) => {
+                *counter += 1;
+                let mut path = parent.to_vec();
+                path.push(*counter);
+
+                let mut children = Vec::new();
+                collect_entries(
+                    &session.content.content,
+                    &path,
+                    &mut 0,
+                    max_depth,
+                    &mut children,
+                );
+
+                out.push(TocEntry {
+                    title: session.title.text_content().trim().to_string(),
+                    numbering: session
+                        .title
+                        .numbering
+                        .as_ref()
+                        .map(|numbering| numbering.marker.clone()),
+                    anchor: anchor_for(&path),
+                    depth: path.len(),
+                    children,
+                });
+            }
+            SessionContainerElement::SessionContainer(container) => {
+                collect_entries(&container.content, parent, counter, max_depth, out)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Session reference target for a position path: `[2, 1]` is `#2.1`
+fn anchor_for(path: &[usize]) -> String {
+    let numbers: Vec<String> = path.iter().map(|number| number.to_string()).collect();
+    format!("#{}", numbers.join("."))
+}
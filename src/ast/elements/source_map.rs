@@ -0,0 +1,262 @@
+//! Source map between byte offsets and AST nodes
+//!
+//! Tokens carry (row, column) positions, which is what the parser needs, but
+//! editor tooling (hover, goto definition) speaks in byte offsets into the
+//! buffer. The Document itself does not retain its source text, so the map is
+//! built from both: the source provides line starts for offset conversion, the
+//! document provides the node tree and each node's token span.
+//!
+//! Container nodes often have no tokens of their own (the root, session
+//! containers), so a node's range is the union of its own span and its
+//! children's. A lookup returns the innermost node covering the offset.
+//!
+//! Positions follow `cst::Position`: rows and columns are 0-indexed and
+//! columns count characters within the line, the lexer's default
+//! `ColumnUnit`. Documents lexed with another unit need
+//! [`SourceMap::with_column_unit`] and [`SourceText::source_text_in`] so
+//! columns are read the way they were written. Offsets are UTF-8 byte
+//! offsets into the source.
+//!
+//! For one-off lookups, such as quoting a node in an error message,
+//! [`SourceText`] slices a node's text out of the source without building a
+//! map.
+
+use std::ops::Range;
+
+use ego_tree::{NodeId, NodeRef};
+
+use crate::ast::elements::core::TxxtElement;
+use crate::ast::elements::traversal::{ElementWrapper, TraversableDocument};
+use crate::ast::Document;
+use crate::cst::{Position, SourceSpan};
+use crate::syntax::ColumnUnit;
+
+/// Offset index over a document, built once and queried many times
+pub struct SourceMap {
+    /// Tree the node lookups resolve into
+    document: TraversableDocument,
+
+    /// Byte offset at which each line starts
+    line_starts: Vec<usize>,
+
+    /// Byte range of every node that has a position, in tree pre-order
+    ranges: Vec<(NodeId, Range<usize>)>,
+
+    /// The source text, to turn columns into byte offsets
+    source: String,
+
+    /// Unit the document's token columns are counted in
+    column_unit: ColumnUnit,
+}
+
+impl SourceMap {
+    /// Build a source map for a document and the source it was parsed from
+    ///
+    /// Token columns are read as characters, the lexer's default.
+    pub fn new(document: &Document, source: &str) -> Self {
+        Self::with_column_unit(document, source, ColumnUnit::Chars)
+    }
+
+    /// Build a source map for a document lexed with another column unit
+    pub fn with_column_unit(document: &Document, source: &str, column_unit: ColumnUnit) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(
+            source
+                .bytes()
+                .enumerate()
+                .filter(|(_, b)| *b == b'\n')
+                .map(|(i, _)| i + 1),
+        );
+
+        let mut map = Self {
+            document: TraversableDocument::from_document(document),
+            line_starts,
+            ranges: Vec::new(),
+            source: source.to_string(),
+            column_unit,
+        };
+
+        let mut ranges = Vec::new();
+        map.collect_ranges(map.document.root(), &mut ranges);
+        map.ranges = ranges;
+        map
+    }
+
+    /// The traversable tree that returned nodes belong to
+    pub fn document(&self) -> &TraversableDocument {
+        &self.document
+    }
+
+    /// Innermost node whose source range contains the offset
+    pub fn node_at_offset(&self, offset: usize) -> Option<NodeRef<'_, ElementWrapper>> {
+        let mut best: Option<&(NodeId, Range<usize>)> = None;
+        for entry in &self.ranges {
+            if !entry.1.contains(&offset) {
+                continue;
+            }
+            // Pre-order puts descendants after ancestors, so ties go to the deeper node
+            match best {
+                Some(current) if current.1.len() < entry.1.len() => {}
+                _ => best = Some(entry),
+            }
+        }
+        best.and_then(|(id, _)| self.document.get(*id))
+    }
+
+    /// Byte range covered by a node, if it or any descendant has tokens
+    pub fn node_range(&self, node: NodeRef<'_, ElementWrapper>) -> Option<Range<usize>> {
+        self.ranges
+            .iter()
+            .find(|(id, _)| *id == node.id())
+            .map(|(_, range)| range.clone())
+    }
+
+    /// Convert a byte offset into a 0-indexed (line, column) pair
+    ///
+    /// The column is in the map's column unit. Offsets past the end of the
+    /// source are clamped to the end.
+    pub fn offset_to_line_col(&self, offset: usize) -> (usize, usize) {
+        let offset = offset.min(self.source.len());
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(next) => next - 1,
+        };
+        let line_start = self.line_starts[line];
+        let column = self.source[line_start..]
+            .char_indices()
+            .take_while(|(index, _)| line_start + index < offset)
+            .map(|(_, ch)| self.column_unit.width(ch))
+            .sum();
+        (line, column)
+    }
+
+    /// Convert a token position into a byte offset
+    pub fn position_to_offset(&self, position: Position) -> usize {
+        byte_offset(&self.source, position, self.column_unit).unwrap_or(self.source.len())
+    }
+
+    fn collect_ranges(
+        &self,
+        node: NodeRef<'_, ElementWrapper>,
+        ranges: &mut Vec<(NodeId, Range<usize>)>,
+    ) -> Option<Range<usize>> {
+        let slot = ranges.len();
+        ranges.push((node.id(), 0..0));
+
+        let mut range = node
+            .value()
+            .element
+            .tokens()
+            .span()
+            .map(|span| self.position_to_offset(span.start)..self.position_to_offset(span.end));
+
+        for child in node.children() {
+            if let Some(child_range) = self.collect_ranges(child, ranges) {
+                range = Some(match range {
+                    Some(r) => r.start.min(child_range.start)..r.end.max(child_range.end),
+                    None => child_range,
+                });
+            }
+        }
+
+        match &range {
+            Some(r) => ranges[slot].1 = r.clone(),
+            None => {
+                ranges.remove(slot);
+            }
+        }
+        range
+    }
+}
+
+/// Exact source text of a node or element
+pub trait SourceText {
+    /// The slice of `original` this was parsed from
+    ///
+    /// The line ending after the last line is left out. `original` must be
+    /// the source the document was parsed from, with character columns.
+    /// Returns None when there is no position to go by, or when the span
+    /// does not fit `original`.
+    fn source_text<'s>(&self, original: &'s str) -> Option<&'s str> {
+        self.source_text_in(original, ColumnUnit::Chars)
+    }
+
+    /// Like [`source_text`](SourceText::source_text), for a document lexed
+    /// with `column_unit` columns
+    fn source_text_in<'s>(&self, original: &'s str, column_unit: ColumnUnit) -> Option<&'s str>;
+}
+
+/// An element's own tokens; containers without tokens have no text
+impl<T: TxxtElement + ?Sized> SourceText for T {
+    fn source_text_in<'s>(&self, original: &'s str, column_unit: ColumnUnit) -> Option<&'s str> {
+        slice_span(original, &self.tokens().span()?, column_unit)
+    }
+}
+
+/// A tree node covers its own tokens and all of its descendants
+impl SourceText for NodeRef<'_, ElementWrapper> {
+    fn source_text_in<'s>(&self, original: &'s str, column_unit: ColumnUnit) -> Option<&'s str> {
+        let mut spans = self
+            .descendants()
+            .filter_map(|node| node.value().element.tokens().span());
+        let first = spans.next()?;
+        let (start, end) = spans.fold((first.start, first.end), |(start, end), span| {
+            (min_position(start, span.start), max_position(end, span.end))
+        });
+        slice_span(original, &SourceSpan { start, end }, column_unit)
+    }
+}
+
+fn slice_span<'s>(source: &'s str, span: &SourceSpan, column_unit: ColumnUnit) -> Option<&'s str> {
+    let start = byte_offset(source, span.start, column_unit)?;
+    let end = byte_offset(source, span.end, column_unit)?;
+    let text = source.get(start..end)?;
+    Some(
+        text.strip_suffix("\r\n")
+            .or_else(|| text.strip_suffix('\n'))
+            .unwrap_or(text),
+    )
+}
+
+/// Byte offset of a position, if the position lies within `source`
+///
+/// Columns past the end of a line stop at its line ending, and a column
+/// inside a character moves to the character's end.
+fn byte_offset(source: &str, position: Position, column_unit: ColumnUnit) -> Option<usize> {
+    let line_start = if position.row == 0 {
+        0
+    } else {
+        source
+            .match_indices('\n')
+            .nth(position.row - 1)
+            .map(|(index, _)| index + 1)?
+    };
+    let line = &source[line_start..];
+    let line = &line[..line.find('\n').unwrap_or(line.len())];
+    let mut column = 0;
+    let offset = line
+        .char_indices()
+        .find(|&(_, ch)| {
+            let reached = column >= position.column;
+            column += column_unit.width(ch);
+            reached
+        })
+        .map_or(line.len(), |(index, _)| index);
+    Some(line_start + offset)
+}
+
+fn min_position(a: Position, b: Position) -> Position {
+    if (b.row, b.column) < (a.row, a.column) {
+        b
+    } else {
+        a
+    }
+}
+
+fn max_position(a: Position, b: Position) -> Position {
+    if (b.row, b.column) > (a.row, a.column) {
+        b
+    } else {
+        a
+    }
+}
@@ -0,0 +1,175 @@
+//! Table Block Element
+//!
+//! Tables are runs of lines wrapped in pipes (`| a | b |`). An optional
+//! separator row of dashes (`|---|---|`) right after the first row marks that
+//! row as the header and sets the column alignment with `:` markers.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ast::elements::{
+    annotation::annotation_content::Annotation, components::parameters::Parameters,
+    inlines::TextTransform,
+};
+use crate::cst::ScannerTokenSequence;
+
+use super::super::core::{BlockElement, ElementType, TxxtElement};
+
+/// Table block - a grid of cells holding inline content
+///
+/// ```txxt
+/// | Name | Age |
+/// |:-----|----:|
+/// | Ada  |  36 |
+/// ```
+///
+/// Every row has one cell per column: rows with fewer cells than the widest
+/// row are padded with empty cells.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableBlock {
+    /// Header row, present when the first row is followed by a separator row
+    pub header: Option<TableRow>,
+
+    /// Body rows
+    pub rows: Vec<TableRow>,
+
+    /// Alignment of each column (one entry per column)
+    pub alignments: Vec<ColumnAlignment>,
+
+    /// Annotations attached to this table
+    #[serde(default)]
+    pub annotations: Vec<Annotation>,
+
+    /// Parameters for this table
+    #[serde(default)]
+    pub parameters: Parameters,
+
+    /// Raw tokens for precise source reconstruction (separator row included)
+    #[serde(default)]
+    pub tokens: ScannerTokenSequence,
+}
+
+/// A single table row
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableRow {
+    /// The cells of this row, left to right
+    pub cells: Vec<TableCell>,
+
+    /// Raw tokens for this row (pipes included)
+    #[serde(default)]
+    pub tokens: ScannerTokenSequence,
+}
+
+/// A single table cell
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableCell {
+    /// Inline content of the cell, without the surrounding whitespace
+    pub content: Vec<TextTransform>,
+
+    /// Raw tokens for this cell's content (empty for padding cells)
+    #[serde(default)]
+    pub tokens: ScannerTokenSequence,
+}
+
+/// Column alignment, set by `:` markers in the separator row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ColumnAlignment {
+    /// `---`: no explicit alignment
+    #[default]
+    Default,
+    /// `:---`
+    Left,
+    /// `:---:`
+    Center,
+    /// `---:`
+    Right,
+}
+
+impl TxxtElement for TableBlock {
+    fn element_type(&self) -> ElementType {
+        ElementType::Block
+    }
+
+    fn tokens(&self) -> &ScannerTokenSequence {
+        &self.tokens
+    }
+
+    fn annotations(&self) -> &[Annotation] {
+        &self.annotations
+    }
+
+    fn parameters(&self) -> &Parameters {
+        &self.parameters
+    }
+}
+
+impl BlockElement for TableBlock {
+    fn can_contain_blocks(&self) -> bool {
+        false
+    }
+
+    fn content_summary(&self) -> String {
+        format!(
+            "Table with {} rows and {} columns",
+            self.row_count(),
+            self.column_count()
+        )
+    }
+}
+
+impl TableBlock {
+    /// Create a new table block
+    pub fn new(
+        header: Option<TableRow>,
+        rows: Vec<TableRow>,
+        alignments: Vec<ColumnAlignment>,
+        annotations: Vec<Annotation>,
+        parameters: Parameters,
+        tokens: ScannerTokenSequence,
+    ) -> Self {
+        Self {
+            header,
+            rows,
+            alignments,
+            annotations,
+            parameters,
+            tokens,
+        }
+    }
+
+    /// Number of columns
+    pub fn column_count(&self) -> usize {
+        self.alignments.len()
+    }
+
+    /// Number of rows, header included
+    pub fn row_count(&self) -> usize {
+        self.rows.len() + usize::from(self.header.is_some())
+    }
+}
+
+impl TableRow {
+    /// Create a new table row
+    pub fn new(cells: Vec<TableCell>, tokens: ScannerTokenSequence) -> Self {
+        Self { cells, tokens }
+    }
+}
+
+impl TableCell {
+    /// Create a new table cell
+    pub fn new(content: Vec<TextTransform>, tokens: ScannerTokenSequence) -> Self {
+        Self { content, tokens }
+    }
+
+    /// An empty cell, used to pad short rows
+    pub fn empty() -> Self {
+        Self::new(Vec::new(), ScannerTokenSequence::new())
+    }
+
+    /// Plain text of the cell content
+    pub fn text_content(&self) -> String {
+        self.content
+            .iter()
+            .map(|transform| transform.text_content())
+            .collect()
+    }
+}
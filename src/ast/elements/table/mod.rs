@@ -0,0 +1,8 @@
+//! Table Elements
+//!
+//! Table elements for pipe-delimited rows of inline content.
+
+pub mod block;
+
+// Re-export table types
+pub use block::{ColumnAlignment, TableBlock, TableCell, TableRow};
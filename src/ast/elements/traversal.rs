@@ -23,7 +23,7 @@ use regex::Regex;
 use crate::ast::{
     elements::{
         containers::content::{ContentContainer, ContentContainerElement},
-        core::{ElementType, TxxtElement},
+        core::{content_hash, content_hash_all, ElementType, TxxtElement},
         session::{session_container::SessionContainerElement, SessionContainer},
     },
     Document,
@@ -52,8 +52,21 @@ pub struct ElementWrapper {
 
     /// Element type (cached for performance)
     pub element_type: ElementType,
+
+    /// Plain text of the element, for elements that carry text of their own
+    pub text: Option<String>,
+
+    /// Consumer-computed attributes (see [`NodeData`])
+    pub data: NodeData,
 }
 
+/// Arbitrary attributes attached to a traversal node, after Unist's `data`
+///
+/// The parser never sets any. Consumers use it to stash what they compute
+/// about a node (a resolved reference target, a highlight class) without
+/// wrapping the AST types; the values serialize as a JSON object.
+pub type NodeData = HashMap<String, serde_json::Value>;
+
 impl ElementWrapper {
     fn new(element: Box<dyn TxxtElement + Send + Sync>, id: ElementId) -> Self {
         let element_type = element.element_type();
@@ -61,8 +74,15 @@ impl ElementWrapper {
             element,
             id,
             element_type,
+            text: None,
+            data: NodeData::new(),
         }
     }
+
+    fn with_text(mut self, text: String) -> Self {
+        self.text = Some(text);
+        self
+    }
 }
 
 // Manual Debug implementation since TxxtElement doesn't implement Debug
@@ -75,6 +95,64 @@ impl std::fmt::Debug for ElementWrapper {
     }
 }
 
+/// Navigation from a node up to the document root
+///
+/// `NodeRef` already has `parent()` and `ancestors()`, which yields the
+/// enclosing nodes nearest-first and ends at the document node. This adds
+/// the jump straight to the top, for breadcrumbs and scope lookups that
+/// start from a query result rather than from the `TraversableDocument`.
+pub trait NodeRefExt<'a> {
+    /// The document node at the root of the node's tree
+    fn root(&self) -> NodeRef<'a, ElementWrapper>;
+
+    /// Attributes attached with [`TraversableDocument::set_data`]
+    fn data(&self) -> &'a NodeData;
+
+    /// Child indices leading from the document node down to this node
+    ///
+    /// The document node's path is empty. Feed the result back to
+    /// [`TraversableDocument::node_at_path`] to fetch the node again; joined
+    /// with `/` it reads like `0/2/1`.
+    fn path(&self) -> Vec<usize>;
+
+    /// Stable hash of the node's content, see [`content_hash`]
+    ///
+    /// The document node hashes its top-level elements, so its hash equals
+    /// [`Document::content_hash`].
+    fn content_hash(&self) -> u64;
+}
+
+impl<'a> NodeRefExt<'a> for NodeRef<'a, ElementWrapper> {
+    fn root(&self) -> NodeRef<'a, ElementWrapper> {
+        self.tree().root()
+    }
+
+    fn data(&self) -> &'a NodeData {
+        &self.value().data
+    }
+
+    fn path(&self) -> Vec<usize> {
+        let mut path: Vec<usize> = std::iter::once(*self)
+            .chain(self.ancestors())
+            .filter(|node| node.parent().is_some())
+            .map(|node| node.prev_siblings().count())
+            .collect();
+        path.reverse();
+        path
+    }
+
+    fn content_hash(&self) -> u64 {
+        if self.parent().is_none() {
+            content_hash_all(
+                self.children()
+                    .map(|child| child.value().element.as_ref() as _),
+            )
+        } else {
+            content_hash(self.value().element.as_ref())
+        }
+    }
+}
+
 impl TraversableDocument {
     /// Create a traversable document from a TXXT document
     ///
@@ -112,6 +190,35 @@ impl TraversableDocument {
         self.tree.root()
     }
 
+    /// Get a node by its ego-tree id
+    pub fn get(&self, id: ego_tree::NodeId) -> Option<NodeRef<'_, ElementWrapper>> {
+        self.tree.get(id)
+    }
+
+    /// Get a node by its child-index path from the document node
+    ///
+    /// An empty path is the document node itself. Returns `None` when an
+    /// index runs past the children at that level.
+    pub fn node_at_path(&self, path: &[usize]) -> Option<NodeRef<'_, ElementWrapper>> {
+        path.iter()
+            .try_fold(self.root(), |node, &index| node.children().nth(index))
+    }
+
+    /// Attach an attribute to a node, returning the value it replaces
+    ///
+    /// `NodeRef` borrows the tree immutably, so writes go through the
+    /// document by node id. Returns `None` as well when `id` is not a node of
+    /// this document.
+    pub fn set_data(
+        &mut self,
+        id: ego_tree::NodeId,
+        key: impl Into<String>,
+        value: serde_json::Value,
+    ) -> Option<serde_json::Value> {
+        let mut node = self.tree.get_mut(id)?;
+        node.value().data.insert(key.into(), value)
+    }
+
     /// Create a query builder for this document
     pub fn query(&self) -> DocumentQuery<'_> {
         DocumentQuery::new(self)
@@ -150,7 +257,8 @@ impl TraversableDocument {
                 let wrapper = ElementWrapper::new(
                     Box::new(ElementAdapter::from_paragraph(paragraph)),
                     *id_counter,
-                );
+                )
+                .with_text(paragraph.text_content());
                 *id_counter += 1;
                 parent_node.append(wrapper);
             }
@@ -184,6 +292,33 @@ impl TraversableDocument {
                 *id_counter += 1;
                 parent_node.append(wrapper);
             }
+            SessionContainerElement::Table(table) => {
+                let wrapper =
+                    ElementWrapper::new(Box::new(ElementAdapter::from_table(table)), *id_counter);
+                *id_counter += 1;
+                parent_node.append(wrapper);
+            }
+            SessionContainerElement::FootnoteDefinition(footnote) => {
+                let wrapper = ElementWrapper::new(
+                    Box::new(ElementAdapter::from_footnote_definition(footnote)),
+                    *id_counter,
+                )
+                .with_text(footnote.text());
+                *id_counter += 1;
+                parent_node.append(wrapper);
+            }
+            SessionContainerElement::Quote(quote) => {
+                let wrapper =
+                    ElementWrapper::new(Box::new(ElementAdapter::from_quote(quote)), *id_counter);
+                *id_counter += 1;
+                let mut quote_node = parent_node.append(wrapper);
+                Self::build_content_container_recursive(
+                    &quote.content,
+                    &mut quote_node,
+                    id_counter,
+                    node_cache,
+                );
+            }
             SessionContainerElement::Session(session) => {
                 let wrapper = ElementWrapper::new(
                     Box::new(ElementAdapter::from_session(session)),
@@ -255,7 +390,8 @@ impl TraversableDocument {
                 let wrapper = ElementWrapper::new(
                     Box::new(ElementAdapter::from_paragraph(paragraph)),
                     *id_counter,
-                );
+                )
+                .with_text(paragraph.text_content());
                 *id_counter += 1;
                 parent_node.append(wrapper);
             }
@@ -289,6 +425,33 @@ impl TraversableDocument {
                 *id_counter += 1;
                 parent_node.append(wrapper);
             }
+            ContentContainerElement::Table(table) => {
+                let wrapper =
+                    ElementWrapper::new(Box::new(ElementAdapter::from_table(table)), *id_counter);
+                *id_counter += 1;
+                parent_node.append(wrapper);
+            }
+            ContentContainerElement::FootnoteDefinition(footnote) => {
+                let wrapper = ElementWrapper::new(
+                    Box::new(ElementAdapter::from_footnote_definition(footnote)),
+                    *id_counter,
+                )
+                .with_text(footnote.text());
+                *id_counter += 1;
+                parent_node.append(wrapper);
+            }
+            ContentContainerElement::Quote(quote) => {
+                let wrapper =
+                    ElementWrapper::new(Box::new(ElementAdapter::from_quote(quote)), *id_counter);
+                *id_counter += 1;
+                let mut quote_node = parent_node.append(wrapper);
+                Self::build_content_container_recursive(
+                    &quote.content,
+                    &mut quote_node,
+                    id_counter,
+                    node_cache,
+                );
+            }
             ContentContainerElement::Container(content_container) => {
                 let wrapper = ElementWrapper::new(
                     Box::new(ElementAdapter::from_content_container(content_container)),
@@ -439,6 +602,13 @@ impl<'a> DocumentQuery<'a> {
         self
     }
 
+    /// Find elements matching a regex pattern given as a string
+    ///
+    /// Fails if `pattern` does not compile.
+    pub fn text_matches_pattern(self, pattern: &str) -> Result<Self, regex::Error> {
+        Ok(self.text_matches(Regex::new(pattern)?))
+    }
+
     /// Find elements with specific annotation
     pub fn has_annotation(mut self, annotation_type: &str) -> Self {
         self.filters
@@ -487,6 +657,31 @@ impl<'a> DocumentQuery<'a> {
             .filter(move |node| self.matches_filters(*node))
     }
 
+    /// Count matching nodes without collecting them
+    pub fn count(self) -> usize {
+        self.iter().count()
+    }
+
+    /// Check whether any matching node satisfies `predicate`
+    ///
+    /// Stops at the first node that does.
+    pub fn any<F>(self, predicate: F) -> bool
+    where
+        F: FnMut(NodeRef<'a, ElementWrapper>) -> bool,
+    {
+        self.iter().any(predicate)
+    }
+
+    /// Check whether every matching node satisfies `predicate`
+    ///
+    /// Stops at the first node that does not. True when nothing matches.
+    pub fn all<F>(self, predicate: F) -> bool
+    where
+        F: FnMut(NodeRef<'a, ElementWrapper>) -> bool,
+    {
+        self.iter().all(predicate)
+    }
+
     /// Check if a node matches all filters
     fn matches_filters(&self, node: NodeRef<ElementWrapper>) -> bool {
         self.filters.iter().all(|filter| filter.matches(node))
@@ -561,7 +756,14 @@ impl QueryFilter {
     }
 
     /// Extract text content from an element (helper for text-based filters)
+    ///
+    /// Paragraphs match on their plain text; other elements on a
+    /// description of their type.
     fn extract_text_content(&self, node: NodeRef<ElementWrapper>) -> String {
+        if let Some(text) = &node.value().text {
+            return text.clone();
+        }
+
         match &*node.value().element {
             // For adapters, extract text based on the wrapped element type
             element if element.element_type() == ElementType::Block => {
@@ -672,6 +874,9 @@ pub enum ElementAdapter {
     Definition(crate::ast::elements::definition::DefinitionBlock),
     Verbatim(crate::ast::elements::verbatim::VerbatimBlock),
     Annotation(crate::ast::elements::annotation::AnnotationBlock),
+    Quote(crate::ast::elements::quote::QuoteBlock),
+    Table(crate::ast::elements::table::TableBlock),
+    FootnoteDefinition(crate::ast::elements::footnote::FootnoteDefinitionBlock),
     Session(crate::ast::elements::session::SessionBlock),
     ContentContainer(ContentContainer),
     SessionContainer(SessionContainer),
@@ -699,6 +904,20 @@ impl ElementAdapter {
         Self::Annotation(a.clone())
     }
 
+    pub fn from_quote(q: &crate::ast::elements::quote::QuoteBlock) -> Self {
+        Self::Quote(q.clone())
+    }
+
+    pub fn from_table(t: &crate::ast::elements::table::TableBlock) -> Self {
+        Self::Table(t.clone())
+    }
+
+    pub fn from_footnote_definition(
+        f: &crate::ast::elements::footnote::FootnoteDefinitionBlock,
+    ) -> Self {
+        Self::FootnoteDefinition(f.clone())
+    }
+
     pub fn from_session(s: &crate::ast::elements::session::SessionBlock) -> Self {
         Self::Session(s.clone())
     }
@@ -724,6 +943,9 @@ impl TxxtElement for ElementAdapter {
             Self::Definition(d) => d.element_type(),
             Self::Verbatim(v) => v.element_type(),
             Self::Annotation(a) => a.element_type(),
+            Self::Quote(q) => q.element_type(),
+            Self::Table(t) => t.element_type(),
+            Self::FootnoteDefinition(f) => f.element_type(),
             Self::Session(s) => s.element_type(),
             Self::ContentContainer(c) => c.element_type(),
             Self::SessionContainer(s) => s.element_type(),
@@ -738,6 +960,9 @@ impl TxxtElement for ElementAdapter {
             Self::Definition(d) => d.tokens(),
             Self::Verbatim(v) => v.tokens(),
             Self::Annotation(a) => a.tokens(),
+            Self::Quote(q) => q.tokens(),
+            Self::Table(t) => t.tokens(),
+            Self::FootnoteDefinition(f) => f.tokens(),
             Self::Session(s) => s.tokens(),
             Self::ContentContainer(c) => c.tokens(),
             Self::SessionContainer(s) => s.tokens(),
@@ -752,6 +977,9 @@ impl TxxtElement for ElementAdapter {
             Self::Definition(d) => d.annotations(),
             Self::Verbatim(v) => v.annotations(),
             Self::Annotation(a) => a.annotations(),
+            Self::Quote(q) => q.annotations(),
+            Self::Table(t) => t.annotations(),
+            Self::FootnoteDefinition(f) => f.annotations(),
             Self::Session(s) => s.annotations(),
             Self::ContentContainer(c) => c.annotations(),
             Self::SessionContainer(s) => s.annotations(),
@@ -766,12 +994,32 @@ impl TxxtElement for ElementAdapter {
             Self::Definition(d) => d.parameters(),
             Self::Verbatim(v) => v.parameters(),
             Self::Annotation(a) => a.parameters(),
+            Self::Quote(q) => q.parameters(),
+            Self::Table(t) => t.parameters(),
+            Self::FootnoteDefinition(f) => f.parameters(),
             Self::Session(s) => s.parameters(),
             Self::ContentContainer(c) => c.parameters(),
             Self::SessionContainer(s) => s.parameters(),
             Self::BlankLine(b) => b.parameters(),
         }
     }
+
+    fn child_nodes(&self) -> Vec<&dyn TxxtElement> {
+        match self {
+            Self::Paragraph(p) => p.child_nodes(),
+            Self::List(l) => l.child_nodes(),
+            Self::Definition(d) => d.child_nodes(),
+            Self::Verbatim(v) => v.child_nodes(),
+            Self::Annotation(a) => a.child_nodes(),
+            Self::Quote(q) => q.child_nodes(),
+            Self::Table(t) => t.child_nodes(),
+            Self::FootnoteDefinition(f) => f.child_nodes(),
+            Self::Session(s) => s.child_nodes(),
+            Self::ContentContainer(c) => c.child_nodes(),
+            Self::SessionContainer(s) => s.child_nodes(),
+            Self::BlankLine(b) => b.child_nodes(),
+        }
+    }
 }
 
 // Make ElementAdapter Send + Sync for thread safety
@@ -1257,6 +1505,30 @@ pub trait ElementVisitor {
         self.visit_element(annotation)
     }
 
+    /// Visit a quote block
+    fn visit_quote(
+        &mut self,
+        quote: &crate::ast::elements::quote::QuoteBlock,
+    ) -> Result<Self::Result, Self::Error> {
+        self.visit_element(quote)
+    }
+
+    /// Visit a table block
+    fn visit_table(
+        &mut self,
+        table: &crate::ast::elements::table::TableBlock,
+    ) -> Result<Self::Result, Self::Error> {
+        self.visit_element(table)
+    }
+
+    /// Visit a footnote definition block
+    fn visit_footnote_definition(
+        &mut self,
+        footnote: &crate::ast::elements::footnote::FootnoteDefinitionBlock,
+    ) -> Result<Self::Result, Self::Error> {
+        self.visit_element(footnote)
+    }
+
     /// Visit a session block
     fn visit_session(
         &mut self,
@@ -1300,6 +1572,9 @@ impl ElementAdapter {
             ElementAdapter::Definition(d) => visitor.visit_definition(d),
             ElementAdapter::Verbatim(v) => visitor.visit_verbatim(v),
             ElementAdapter::Annotation(a) => visitor.visit_annotation(a),
+            ElementAdapter::Quote(q) => visitor.visit_quote(q),
+            ElementAdapter::Table(t) => visitor.visit_table(t),
+            ElementAdapter::FootnoteDefinition(f) => visitor.visit_footnote_definition(f),
             ElementAdapter::Session(s) => visitor.visit_session(s),
             ElementAdapter::ContentContainer(c) => visitor.visit_content_container(c),
             ElementAdapter::SessionContainer(s) => visitor.visit_session_container(s),
@@ -1380,6 +1655,23 @@ mod tests {
     use crate::ast::elements::session::SessionContainer;
     use crate::ast::{AssemblyInfo, Document, Meta};
 
+    /// A single "Block content" text run for paragraph fixtures
+    fn block_content_text() -> crate::ast::TextTransform {
+        let token = crate::cst::ScannerToken::Text {
+            content: "Block content".to_string(),
+            span: crate::cst::SourceSpan {
+                start: crate::cst::Position { row: 0, column: 0 },
+                end: crate::cst::Position { row: 0, column: 13 },
+            },
+        };
+        crate::ast::TextTransform::Identity(
+            crate::ast::elements::formatting::inlines::Text::simple_with_tokens(
+                "Block content",
+                crate::cst::ScannerTokenSequence::from_tokens(vec![token]),
+            ),
+        )
+    }
+
     #[test]
     fn test_ego_tree_basic_functionality() {
         // Test that ego-tree works as expected
@@ -1398,6 +1690,96 @@ mod tests {
         assert_eq!(first_child.parent().unwrap().value(), &"root");
     }
 
+    /// Document > session container > session container > paragraph
+    fn three_level_document() -> Document {
+        let container = |content| SessionContainer {
+            content,
+            annotations: vec![],
+            parameters: crate::ast::elements::components::parameters::Parameters::default(),
+            tokens: crate::cst::ScannerTokenSequence::new(),
+        };
+        let paragraph = crate::ast::ParagraphBlock::new(
+            vec![block_content_text()],
+            vec![],
+            crate::ast::elements::components::parameters::Parameters::default(),
+            crate::cst::ScannerTokenSequence::new(),
+        );
+        let inner = container(vec![SessionContainerElement::Paragraph(paragraph)]);
+        let outer = container(vec![SessionContainerElement::SessionContainer(inner)]);
+
+        Document {
+            meta: Meta::default(),
+            content: container(vec![SessionContainerElement::SessionContainer(outer)]),
+            assembly_info: AssemblyInfo::default(),
+        }
+    }
+
+    #[test]
+    fn test_ancestors_are_nearest_first() {
+        let traversable = TraversableDocument::from_document(&three_level_document());
+        let paragraph = traversable.leaf_nodes()[0];
+
+        // Ids follow creation order: document 0, outer 1, inner 2, paragraph 3
+        assert_eq!(paragraph.value().id, 3);
+        let ancestors: Vec<ElementId> = paragraph.ancestors().map(|node| node.value().id).collect();
+        assert_eq!(ancestors, vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn test_root_returns_the_document_node() {
+        let traversable = TraversableDocument::from_document(&three_level_document());
+        let paragraph = traversable.leaf_nodes()[0];
+
+        assert_eq!(paragraph.root().id(), traversable.root().id());
+        assert_eq!(traversable.root().root().id(), traversable.root().id());
+        assert!(paragraph.root().parent().is_none());
+    }
+
+    #[test]
+    fn test_node_path_round_trip() {
+        let traversable = TraversableDocument::from_document(&three_level_document());
+        let paragraph = traversable.leaf_nodes()[0];
+
+        assert_eq!(paragraph.path(), vec![0, 0, 0]);
+        assert!(traversable.root().path().is_empty());
+        for node in traversable.root().descendants() {
+            let found = traversable.node_at_path(&node.path()).unwrap();
+            assert_eq!(found.id(), node.id());
+        }
+    }
+
+    #[test]
+    fn test_node_at_path_out_of_range() {
+        let traversable = TraversableDocument::from_document(&three_level_document());
+
+        assert!(traversable.node_at_path(&[1]).is_none());
+        assert!(traversable.node_at_path(&[0, 0, 0, 0]).is_none());
+    }
+
+    #[test]
+    fn test_node_data_round_trip() {
+        let mut traversable = TraversableDocument::from_document(&three_level_document());
+        let paragraph_id = traversable.leaf_nodes()[0].id();
+
+        assert!(traversable.leaf_nodes()[0].data().is_empty());
+        let previous =
+            traversable.set_data(paragraph_id, "highlight", serde_json::json!("warning"));
+        assert_eq!(previous, None);
+        let previous = traversable.set_data(paragraph_id, "highlight", serde_json::json!("error"));
+        assert_eq!(previous, Some(serde_json::json!("warning")));
+        traversable.set_data(paragraph_id, "target", serde_json::json!({ "line": 4 }));
+
+        let paragraph = traversable.get(paragraph_id).unwrap();
+        assert_eq!(paragraph.data()["highlight"], "error");
+        assert!(paragraph.root().data().is_empty());
+
+        let serialized = serde_json::to_value(paragraph.data()).unwrap();
+        assert_eq!(
+            serialized,
+            serde_json::json!({ "highlight": "error", "target": { "line": 4 } })
+        );
+    }
+
     #[test]
     fn test_traversable_document_creation() {
         // Create a minimal document for testing
@@ -1417,6 +1799,7 @@ mod tests {
                 source_path: None,
                 processed_at: None,
                 stats: crate::ast::ProcessingStats::default(),
+                warnings: Vec::new(),
             },
         };
 
@@ -1453,6 +1836,7 @@ mod tests {
                 source_path: None,
                 processed_at: None,
                 stats: crate::ast::ProcessingStats::default(),
+                warnings: Vec::new(),
             },
         };
 
@@ -1489,7 +1873,9 @@ mod tests {
                 tokens: crate::cst::ScannerTokenSequence::new(),
             },
             label: "test".to_string(), // Mandatory label
+            detected_language: None,
             verbatim_type: VerbatimType::InFlow,
+            base_indent: 0,
             annotations: vec![],
             parameters: crate::ast::elements::components::parameters::Parameters::default(),
             tokens: crate::cst::ScannerTokenSequence::new(),
@@ -1521,6 +1907,7 @@ mod tests {
                 source_path: None,
                 processed_at: None,
                 stats: crate::ast::ProcessingStats::default(),
+                warnings: Vec::new(),
             },
         };
 
@@ -1571,7 +1958,7 @@ mod tests {
 
         // Create a document with content for text search testing
         let paragraph = ParagraphBlock {
-            content: vec![], // Empty TextTransform content for test
+            content: vec![block_content_text()],
             annotations: vec![],
             parameters: crate::ast::elements::components::parameters::Parameters::default(),
             tokens: crate::cst::ScannerTokenSequence::new(),
@@ -1593,12 +1980,13 @@ mod tests {
                 source_path: None,
                 processed_at: None,
                 stats: crate::ast::ProcessingStats::default(),
+                warnings: Vec::new(),
             },
         };
 
         let traversable = TraversableDocument::from_document(&document);
 
-        // Test text search - since we have basic text extraction, this should work
+        // Paragraphs are searched by their text, containers by their type
         let block_search = traversable.query().text_contains("Block").collect();
         assert!(!block_search.is_empty()); // Should find our block elements
 
@@ -1653,7 +2041,7 @@ mod tests {
 
         // Create a document with content for XPath testing
         let paragraph = ParagraphBlock {
-            content: vec![], // Empty TextTransform content for test
+            content: vec![block_content_text()],
             annotations: vec![],
             parameters: crate::ast::elements::components::parameters::Parameters::default(),
             tokens: crate::cst::ScannerTokenSequence::new(),
@@ -1675,6 +2063,7 @@ mod tests {
                 source_path: None,
                 processed_at: None,
                 stats: crate::ast::ProcessingStats::default(),
+                warnings: Vec::new(),
             },
         };
 
@@ -1697,6 +2086,122 @@ mod tests {
         assert!(!text_results.is_empty()); // Should find our blocks with "Block content" in the text
     }
 
+    #[test]
+    fn test_text_matches_pattern_filters_paragraphs() {
+        use crate::ast::build::DocBuilder;
+
+        let document = DocBuilder::new()
+            .paragraph("Released in version 1.2.0")
+            .paragraph("No version here")
+            .paragraph("Patched in version 1.2.1")
+            .build();
+        let traversable = TraversableDocument::from_document(&document);
+
+        let matches: Vec<String> = traversable
+            .query()
+            .find_by_type(ElementType::Block)
+            .text_matches_pattern(r"version \d+\.\d+\.\d+")
+            .unwrap()
+            .collect()
+            .into_iter()
+            .filter_map(|node| node.value().text.clone())
+            .collect();
+
+        assert_eq!(
+            matches,
+            vec!["Released in version 1.2.0", "Patched in version 1.2.1"]
+        );
+    }
+
+    #[test]
+    fn test_query_count_paragraphs() {
+        use crate::ast::build::DocBuilder;
+
+        let document = DocBuilder::new()
+            .paragraph("First")
+            .paragraph("Second")
+            .paragraph("Third")
+            .build();
+        let traversable = TraversableDocument::from_document(&document);
+
+        let paragraphs = || traversable.query().find_by_type(ElementType::Block);
+        assert_eq!(paragraphs().count(), 3);
+        assert_eq!(paragraphs().count(), paragraphs().collect().len());
+        assert_eq!(paragraphs().text_contains("Second").count(), 1);
+        assert_eq!(
+            traversable.query().find_by_type(ElementType::Span).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_query_any_finds_annotated_node() {
+        use crate::ast::build::DocBuilder;
+        use crate::ast::elements::annotation::annotation_content::{Annotation, AnnotationContent};
+
+        let mut document = DocBuilder::new()
+            .paragraph("Plain")
+            .paragraph("Reviewed")
+            .build();
+        if let SessionContainerElement::Paragraph(paragraph) = &mut document.content.content[1] {
+            paragraph.annotations.push(Annotation::new(
+                "review".to_string(),
+                AnnotationContent::Empty,
+            ));
+        }
+        let traversable = TraversableDocument::from_document(&document);
+
+        let annotated = |node: NodeRef<ElementWrapper>| {
+            node.value()
+                .element
+                .annotations()
+                .iter()
+                .any(|annotation| annotation.name == "review")
+        };
+        assert!(traversable.query().any(annotated));
+        assert!(!traversable.query().text_contains("Plain").any(annotated));
+    }
+
+    #[test]
+    fn test_query_all_over_homogeneous_blocks() {
+        use crate::ast::build::DocBuilder;
+
+        let document = DocBuilder::new()
+            .paragraph("item one")
+            .paragraph("item two")
+            .paragraph("item three")
+            .build();
+        let traversable = TraversableDocument::from_document(&document);
+
+        let blocks = || traversable.query().find_by_type(ElementType::Block);
+        assert!(blocks().all(|node| node
+            .value()
+            .text
+            .as_deref()
+            .unwrap_or("")
+            .starts_with("item")));
+        assert!(!blocks().all(|node| node.value().text.as_deref() == Some("item one")));
+
+        // Vacuously true when nothing matches
+        assert!(traversable
+            .query()
+            .find_by_type(ElementType::Span)
+            .all(|_| false));
+    }
+
+    #[test]
+    fn test_text_matches_pattern_rejects_invalid_regex() {
+        let document = crate::ast::build::DocBuilder::new()
+            .paragraph("text")
+            .build();
+        let traversable = TraversableDocument::from_document(&document);
+
+        assert!(traversable
+            .query()
+            .text_matches_pattern("(unclosed")
+            .is_err());
+    }
+
     #[test]
     fn test_xpath_error_handling() {
         let parser = XPathParser::new();
@@ -1738,6 +2243,7 @@ mod tests {
                 source_path: None,
                 processed_at: None,
                 stats: crate::ast::ProcessingStats::default(),
+                warnings: Vec::new(),
             },
         };
 
@@ -1818,6 +2324,7 @@ mod tests {
                 source_path: None,
                 processed_at: None,
                 stats: crate::ast::ProcessingStats::default(),
+                warnings: Vec::new(),
             },
         };
 
@@ -1857,6 +2364,7 @@ mod tests {
                 source_path: None,
                 processed_at: None,
                 stats: crate::ast::ProcessingStats::default(),
+                warnings: Vec::new(),
             },
         };
 
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::ast::elements::{
     annotation::annotation_content::Annotation, components::parameters::Parameters,
 };
-use crate::cst::ScannerTokenSequence;
+use crate::cst::{ScannerToken, ScannerTokenSequence};
 
 use super::super::{
     core::{BlockElement, ContainerElement, ElementType, HeaderedBlock, TxxtElement},
@@ -30,16 +30,33 @@ pub struct VerbatimBlock {
     /// Mandatory label for format identification
     pub label: String,
 
+    /// Language guessed from the content when the label is missing
+    ///
+    /// Kept apart from `label` so a heuristic never overrides what the author
+    /// wrote; tooling should prefer the label and fall back to this.
+    pub detected_language: Option<String>,
+
     /// Type of verbatim block (in-flow vs stretched)
     pub verbatim_type: VerbatimType,
 
+    /// Column of the content wall: the indentation stripped from each line
+    ///
+    /// One level past the title for in-flow blocks, 0 for stretched ones.
+    /// Formatters re-indent the content by this much to reproduce the
+    /// source without consulting token spans.
+    #[serde(default)]
+    pub base_indent: usize,
+
     /// Parameters from verbatim block declaration
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Annotations attached to this verbatim block
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Raw tokens for source reconstruction
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -102,7 +119,9 @@ impl VerbatimBlock {
             title,
             content,
             label,
+            detected_language: None,
             verbatim_type,
+            base_indent: 0,
             parameters,
             annotations,
             tokens,
@@ -123,6 +142,32 @@ impl VerbatimBlock {
         self.content.content_text()
     }
 
+    /// Get the content lines paired with their 1-based line numbers
+    ///
+    /// Blank lines are included, so the numbers suit line-numbered listings.
+    pub fn numbered_lines(&self) -> Vec<(usize, &str)> {
+        self.content
+            .ignore_lines
+            .iter()
+            .map(|line| (line.line_number, line.content()))
+            .collect()
+    }
+
+    /// Get the content lines at their source indentation
+    ///
+    /// Puts back the wall stripped during parsing; blank lines stay empty.
+    pub fn source_lines(&self) -> Vec<String> {
+        let wall = " ".repeat(self.base_indent);
+        self.content
+            .ignore_lines
+            .iter()
+            .map(|line| match line.content() {
+                "" => String::new(),
+                content => format!("{}{}", wall, content),
+            })
+            .collect()
+    }
+
     /// Check if this is an in-flow verbatim block
     pub fn is_in_flow(&self) -> bool {
         matches!(self.verbatim_type, VerbatimType::InFlow)
@@ -142,4 +187,19 @@ impl VerbatimBlock {
     pub fn has_title(&self) -> bool {
         !self.title.is_empty() && !self.title_text().trim().is_empty()
     }
+
+    /// Check if the terminator was cut off by the end of input
+    ///
+    /// The parser closes such a block anyway so its content is kept.
+    pub fn is_truncated(&self) -> bool {
+        self.tokens.tokens.iter().any(|token| {
+            matches!(
+                token,
+                ScannerToken::VerbatimBlockEnd {
+                    truncated: true,
+                    ..
+                }
+            )
+        })
+    }
 }
@@ -30,12 +30,15 @@ pub struct IgnoreContainer {
     pub blank_lines: Vec<super::super::core::BlankLine>,
 
     /// Annotations attached to this container
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 
     /// Parameters for metadata (rare for ignore containers)
+    #[serde(default)]
     pub parameters: Parameters,
 
     /// Source position information
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -48,7 +51,14 @@ pub struct IgnoreLine {
     /// Raw content preserved byte-for-byte
     pub content: String,
 
+    /// Line number within the verbatim content, starting at 1
+    ///
+    /// Blank lines are counted, so numbers match the lines as written.
+    #[serde(default)]
+    pub line_number: usize,
+
     /// Source position information
+    #[serde(default)]
     pub tokens: ScannerTokenSequence,
 }
 
@@ -158,8 +168,12 @@ impl IgnoreContainer {
 
 impl IgnoreLine {
     /// Create a new ignore line
-    pub fn new(content: String, tokens: ScannerTokenSequence) -> Self {
-        Self { content, tokens }
+    pub fn new(content: String, line_number: usize, tokens: ScannerTokenSequence) -> Self {
+        Self {
+            content,
+            line_number,
+            tokens,
+        }
     }
 
     /// Get the raw content
@@ -6,7 +6,7 @@ use clap::Parser;
 use std::fs;
 use std::io::{self, Write};
 use std::path::Path;
-use txxt::api::{format_output_unified, process_unified, Format, Stage};
+use txxt::api::{format_output_unified_with, process_unified, Format, OutputOptions, Stage};
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
@@ -22,6 +22,10 @@ struct Args {
     #[arg(long, short, default_value = "json")]
     format: String,
 
+    /// Leave empty annotations, parameters and tokens out of JSON output
+    #[arg(long)]
+    compact: bool,
+
     /// Show available stages and formats
     #[arg(long, help = "Show available stages and formats")]
     help_stages: bool,
@@ -95,7 +99,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let output = process_unified(&content, stage, Some(path.clone()))?;
 
     // Format the output
-    let formatted = format_output_unified(&output, format, Some(&path))?;
+    let options = OutputOptions {
+        compact: args.compact,
+    };
+    let formatted = format_output_unified_with(&output, format, Some(&path), options)?;
 
     // Output to stdout
     print!("{}", formatted);
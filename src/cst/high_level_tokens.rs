@@ -261,6 +261,48 @@ pub enum HighLevelToken {
         /// Scanner tokens that make up this verbatim block
         tokens: ScannerTokenSequence,
     },
+
+    /// Quote semantic token grouping consecutive `>`-prefixed lines
+    /// Composition: (QuoteMarker + line content)+
+    /// The quoted text is analyzed on its own once the markers are stripped,
+    /// so nested blocks (lists, definitions, quotes) keep their structure
+    Quote {
+        /// High-level tokens for the quoted text, including its own
+        /// Indent/Dedent structure
+        content: Vec<HighLevelToken>,
+        /// Source span of the entire quote
+        span: SourceSpan,
+        /// Scanner tokens that make up this quote (markers included)
+        tokens: ScannerTokenSequence,
+    },
+
+    /// Table semantic token grouping consecutive pipe-delimited rows
+    /// Composition: (Pipe + (cell content + Pipe)+)+
+    /// Cells are split at this level; detecting the header separator row is
+    /// left to the table element constructor
+    Table {
+        /// Rows in source order, each holding one TextSpan per cell
+        rows: Vec<Vec<HighLevelToken>>,
+        /// Source span of the entire table
+        span: SourceSpan,
+        /// Scanner tokens that make up this table (pipes included)
+        tokens: ScannerTokenSequence,
+    },
+
+    /// Footnote definition semantic token for a `[^label]: text` line
+    /// Composition: FootnoteRef + Colon + Whitespace? + Text?
+    /// Only the marker line; indented content that follows is parsed as
+    /// the definition's body during AST construction
+    FootnoteDefinition {
+        /// The footnote label, without the caret
+        label: String,
+        /// Text after the colon, if the line has any
+        content: Option<Box<HighLevelToken>>,
+        /// Source span of the marker line
+        span: SourceSpan,
+        /// Scanner tokens that make up the marker line
+        tokens: ScannerTokenSequence,
+    },
 }
 
 /// Numbering style for sequence markers
@@ -384,7 +426,10 @@ impl HighLevelTokenSpan for HighLevelToken {
             | HighLevelToken::Dedent { span, .. }
             | HighLevelToken::Annotation { span, .. }
             | HighLevelToken::Definition { span, .. }
-            | HighLevelToken::VerbatimBlock { span, .. } => span,
+            | HighLevelToken::VerbatimBlock { span, .. }
+            | HighLevelToken::Quote { span, .. }
+            | HighLevelToken::Table { span, .. }
+            | HighLevelToken::FootnoteDefinition { span, .. } => span,
         }
     }
 }
@@ -410,7 +455,10 @@ impl HighLevelToken {
             | HighLevelToken::Dedent { tokens, .. }
             | HighLevelToken::Annotation { tokens, .. }
             | HighLevelToken::Definition { tokens, .. }
-            | HighLevelToken::VerbatimBlock { tokens, .. } => tokens,
+            | HighLevelToken::VerbatimBlock { tokens, .. }
+            | HighLevelToken::Quote { tokens, .. }
+            | HighLevelToken::Table { tokens, .. }
+            | HighLevelToken::FootnoteDefinition { tokens, .. } => tokens,
         }
     }
 }
@@ -908,6 +956,43 @@ impl HighLevelTokenBuilder {
             tokens,
         }
     }
+
+    /// Create a quote semantic token with source tokens
+    pub fn quote_with_tokens(
+        content: Vec<HighLevelToken>,
+        span: SourceSpan,
+        tokens: ScannerTokenSequence,
+    ) -> HighLevelToken {
+        HighLevelToken::Quote {
+            content,
+            span,
+            tokens,
+        }
+    }
+
+    /// Create a table semantic token with source tokens
+    pub fn table_with_tokens(
+        rows: Vec<Vec<HighLevelToken>>,
+        span: SourceSpan,
+        tokens: ScannerTokenSequence,
+    ) -> HighLevelToken {
+        HighLevelToken::Table { rows, span, tokens }
+    }
+
+    /// Create a footnote definition semantic token with source tokens
+    pub fn footnote_definition_with_tokens(
+        label: String,
+        content: Option<HighLevelToken>,
+        span: SourceSpan,
+        tokens: ScannerTokenSequence,
+    ) -> HighLevelToken {
+        HighLevelToken::FootnoteDefinition {
+            label,
+            content: content.map(Box::new),
+            span,
+            tokens,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -20,11 +20,15 @@
 //! 3. **High-Level Tokens** - Semantic grouping of scanner tokens
 //!    - `HighLevelToken`: Line-level syntactic structures
 //!    - Bridges scanner tokens and AST elements
+//!
+//! `TokenCursor` walks a scanner token slice for code that recognizes
+//! custom constructs on top of the lexer.
 
 pub mod high_level_tokens;
 pub mod parameter_scanner;
 pub mod primitives;
 pub mod scanner_tokens;
+pub mod token_cursor;
 
 // Re-export core types for convenience
 pub use high_level_tokens::{
@@ -34,3 +38,4 @@ pub use high_level_tokens::{
 pub use parameter_scanner::scan_parameter_string;
 pub use primitives::{Position, ScannerTokenSequence, SourceSpan};
 pub use scanner_tokens::{ScannerToken, SequenceMarkerType, WallType};
+pub use token_cursor::{TokenCursor, UnexpectedToken};
@@ -49,16 +49,20 @@ fn unescape_text(text: &str) -> String {
 /// Unlike traditional AST source spans, we need both start and end positions
 /// because inline elements don't necessarily start at column 0, and we need
 /// precise boundaries for language server operations.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Position {
     /// Line number (0-indexed)
     pub row: usize,
-    /// Column number (0-indexed, UTF-8 byte offset)
+    /// Column number (0-indexed), in the lexer's [`ColumnUnit`]
+    ///
+    /// Characters unless `ParseOptions::column_unit` says otherwise.
+    ///
+    /// [`ColumnUnit`]: crate::syntax::ColumnUnit
     pub column: usize,
 }
 
 /// Source span covering a range of characters
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SourceSpan {
     /// Start position (inclusive)
     pub start: Position,
@@ -74,6 +78,7 @@ pub struct SourceSpan {
 /// individual scanner tokens.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScannerTokenSequence {
+    #[serde(default)]
     pub tokens: Vec<ScannerToken>,
 }
 
@@ -102,16 +107,17 @@ impl ScannerTokenSequence {
     }
 
     /// Get the text content by concatenating all scanner token content
+    ///
+    /// Escape sequences are resolved, so `\*` reads as `*`; `raw_text`
+    /// keeps them as written.
     pub fn text(&self) -> String {
-        let raw_text = self
-            .tokens
-            .iter()
-            .map(|token| token.content())
-            .collect::<Vec<_>>()
-            .join("");
-
         // Process escape sequences: remove backslashes before special characters
-        unescape_text(&raw_text)
+        unescape_text(&self.raw_text())
+    }
+
+    /// Get the token content as written in the source, escapes included
+    pub fn raw_text(&self) -> String {
+        self.tokens.iter().map(|token| token.content()).collect()
     }
 
     /// Create a scanner token sequence from a vector of scanner tokens
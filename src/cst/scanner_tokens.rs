@@ -100,6 +100,12 @@ pub enum ScannerToken {
         span: SourceSpan,
     },
 
+    /// Quote marker (>) at the start of a line, consuming one following space
+    QuoteMarker { span: SourceSpan },
+
+    /// Cell delimiter (|) inside a table row
+    Pipe { span: SourceSpan },
+
     /// Dash character (-)
     Dash { span: SourceSpan },
 
@@ -197,6 +203,12 @@ pub enum ScannerToken {
         /// Format: "label" or "label:param1=val1,param2=val2"
         /// Supports namespaced labels: "org.example.python:version=3.11"
         label_raw: String,
+        /// The terminator was cut off by the end of input (no closing `::`)
+        ///
+        /// The block is still closed so its content is kept; assembly reports
+        /// it as a recovery warning.
+        #[serde(default)]
+        truncated: bool,
         span: SourceSpan,
     },
 
@@ -237,6 +249,49 @@ impl ScannerToken {
             ScannerToken::Dedent { span } => span,
             ScannerToken::SequenceMarker { span, .. } => span,
             ScannerToken::TxxtMarker { span } => span,
+            ScannerToken::QuoteMarker { span } => span,
+            ScannerToken::Pipe { span } => span,
+            ScannerToken::Dash { span } => span,
+            ScannerToken::Period { span } => span,
+            ScannerToken::LeftBracket { span } => span,
+            ScannerToken::RightBracket { span } => span,
+            ScannerToken::AtSign { span } => span,
+            ScannerToken::LeftParen { span } => span,
+            ScannerToken::RightParen { span } => span,
+            ScannerToken::Colon { span } => span,
+            ScannerToken::Equals { span } => span,
+            ScannerToken::Comma { span } => span,
+            ScannerToken::Identifier { span, .. } => span,
+            ScannerToken::QuotedString { span, .. } => span,
+            ScannerToken::RefMarker { span, .. } => span,
+            ScannerToken::FootnoteRef { span, .. } => span,
+            ScannerToken::VerbatimBlockStart { span, .. } => span,
+            ScannerToken::VerbatimContentLine { span, .. } => span,
+            ScannerToken::VerbatimBlockEnd { span, .. } => span,
+            ScannerToken::BoldDelimiter { span } => span,
+            ScannerToken::ItalicDelimiter { span } => span,
+            ScannerToken::CodeDelimiter { span } => span,
+            ScannerToken::MathDelimiter { span } => span,
+            ScannerToken::CitationRef { span, .. } => span,
+            ScannerToken::PageRef { span, .. } => span,
+            ScannerToken::SessionRef { span, .. } => span,
+            ScannerToken::Eof { span } => span,
+        }
+    }
+
+    /// Get the source span mutably, for adjusting positions after scanning
+    pub fn span_mut(&mut self) -> &mut SourceSpan {
+        match self {
+            ScannerToken::Text { span, .. } => span,
+            ScannerToken::Whitespace { span, .. } => span,
+            ScannerToken::Newline { span } => span,
+            ScannerToken::BlankLine { span, .. } => span,
+            ScannerToken::Indent { span } => span,
+            ScannerToken::Dedent { span } => span,
+            ScannerToken::SequenceMarker { span, .. } => span,
+            ScannerToken::TxxtMarker { span } => span,
+            ScannerToken::QuoteMarker { span } => span,
+            ScannerToken::Pipe { span } => span,
             ScannerToken::Dash { span } => span,
             ScannerToken::Period { span } => span,
             ScannerToken::LeftBracket { span } => span,
@@ -290,6 +345,8 @@ impl ScannerToken {
             ScannerToken::BlankLine { whitespace, .. } => whitespace,
             ScannerToken::Indent { .. } => "",
             ScannerToken::Dedent { .. } => "",
+            ScannerToken::QuoteMarker { .. } => ">",
+            ScannerToken::Pipe { .. } => "|",
             ScannerToken::Dash { .. } => "-",
             ScannerToken::Period { .. } => ".",
             ScannerToken::LeftBracket { .. } => "[",
@@ -0,0 +1,172 @@
+//! Cursor over a scanner token slice
+//!
+//! Custom elements built on top of the lexer mostly walk a token slice
+//! forward: look at the next token, take it if it fits, skip a run of
+//! whitespace. [`TokenCursor`] keeps the index for them:
+//!
+//! ```
+//! use txxt::cst::{Position, ScannerToken, SourceSpan, TokenCursor};
+//!
+//! let span = SourceSpan {
+//!     start: Position { row: 0, column: 0 },
+//!     end: Position { row: 0, column: 1 },
+//! };
+//! let tokens = vec![
+//!     ScannerToken::LeftBracket { span: span.clone() },
+//!     ScannerToken::Text { content: "key".to_string(), span: span.clone() },
+//!     ScannerToken::RightBracket { span },
+//! ];
+//!
+//! let mut cursor = TokenCursor::new(&tokens);
+//! cursor
+//!     .expect(|t| matches!(t, ScannerToken::LeftBracket { .. }), "[")
+//!     .unwrap();
+//! let inner = cursor.consume_while(|t| !matches!(t, ScannerToken::RightBracket { .. }));
+//! assert_eq!(inner.len(), 1);
+//! assert_eq!(cursor.position(), 2);
+//! ```
+//!
+//! Kinds are tested with predicates, the same way delimiter matchers are
+//! configured (`ScannerToken::is_bold_delimiter`, `matches!`), since
+//! scanner tokens carry no separate kind enum.
+
+use super::primitives::Position;
+use super::scanner_tokens::ScannerToken;
+
+/// Forward cursor over a slice of scanner tokens
+#[derive(Debug, Clone)]
+pub struct TokenCursor<'a> {
+    tokens: &'a [ScannerToken],
+    position: usize,
+}
+
+/// A token that did not satisfy [`TokenCursor::expect`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnexpectedToken {
+    /// Description of the expected token, as passed to `expect`
+    pub expected: String,
+
+    /// The token found instead, or None at the end of the slice
+    pub found: Option<ScannerToken>,
+
+    /// Index in the slice where the token was expected
+    pub index: usize,
+}
+
+impl std::fmt::Display for UnexpectedToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.found {
+            Some(token) => write!(
+                f,
+                "expected {} at token {}, found {:?}",
+                self.expected, self.index, token
+            ),
+            None => write!(
+                f,
+                "expected {} at token {}, found end of input",
+                self.expected, self.index
+            ),
+        }
+    }
+}
+
+impl std::error::Error for UnexpectedToken {}
+
+impl<'a> TokenCursor<'a> {
+    /// Start a cursor at the first token
+    pub fn new(tokens: &'a [ScannerToken]) -> Self {
+        Self {
+            tokens,
+            position: 0,
+        }
+    }
+
+    /// The next token, without consuming it
+    pub fn peek(&self) -> Option<&'a ScannerToken> {
+        self.tokens.get(self.position)
+    }
+
+    /// The token `offset` places after the next one, without consuming
+    pub fn peek_nth(&self, offset: usize) -> Option<&'a ScannerToken> {
+        self.tokens.get(self.position + offset)
+    }
+
+    /// Consume the next token if it satisfies `predicate`
+    pub fn next_if(
+        &mut self,
+        predicate: impl FnOnce(&ScannerToken) -> bool,
+    ) -> Option<&'a ScannerToken> {
+        match self.peek() {
+            Some(token) if predicate(token) => self.next(),
+            _ => None,
+        }
+    }
+
+    /// Consume the next token, failing if it does not satisfy `predicate`
+    ///
+    /// `expected` describes the token for the error. On failure the cursor
+    /// does not move.
+    pub fn expect(
+        &mut self,
+        predicate: impl FnOnce(&ScannerToken) -> bool,
+        expected: &str,
+    ) -> Result<&'a ScannerToken, UnexpectedToken> {
+        let index = self.position;
+        let found = self.peek();
+        self.next_if(predicate).ok_or_else(|| UnexpectedToken {
+            expected: expected.to_string(),
+            found: found.cloned(),
+            index,
+        })
+    }
+
+    /// Consume tokens while they satisfy `predicate`, returning them
+    pub fn consume_while(
+        &mut self,
+        mut predicate: impl FnMut(&ScannerToken) -> bool,
+    ) -> &'a [ScannerToken] {
+        let start = self.position;
+        while self.peek().is_some_and(&mut predicate) {
+            self.position += 1;
+        }
+        &self.tokens[start..self.position]
+    }
+
+    /// Index of the next token in the slice
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Move back (or forward) to an index previously read from `position`
+    ///
+    /// Indexes past the end are clamped to the end.
+    pub fn reset(&mut self, position: usize) {
+        self.position = position.min(self.tokens.len());
+    }
+
+    /// Source position where the next token starts
+    pub fn source_position(&self) -> Option<Position> {
+        self.peek().map(|token| token.span().start)
+    }
+
+    /// The tokens not yet consumed
+    pub fn remaining(&self) -> &'a [ScannerToken] {
+        &self.tokens[self.position..]
+    }
+
+    /// Whether every token has been consumed
+    pub fn is_at_end(&self) -> bool {
+        self.position >= self.tokens.len()
+    }
+}
+
+/// `next` consumes and returns the next token
+impl<'a> Iterator for TokenCursor<'a> {
+    type Item = &'a ScannerToken;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = self.peek()?;
+        self.position += 1;
+        Some(token)
+    }
+}
@@ -82,7 +82,7 @@
 //!
 //! ============================================================================
 use std::collections::HashMap;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 pub mod annotation_parser;
 pub mod api;
@@ -135,32 +135,166 @@ impl Txxt {
 pub fn collect_annotations<P: AsRef<Path>>(
     root: P,
 ) -> Result<AnnotationMap, Box<dyn std::error::Error>> {
-    let mut merged: AnnotationMap = HashMap::new();
+    Ok(collect_annotations_verbose(root)?.0)
+}
 
+/// A path annotated differently by more than one `.info` file
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnnotationConflict {
+    pub path: PathBuf,
+
+    /// Every `.info` file's annotation for the path, the one kept first
+    pub annotations: Vec<Annotation>,
+}
+
+/// [`collect_annotations`], also reporting the conflicts it settled
+///
+/// Conflicts come sorted by path.
+pub fn collect_annotations_verbose<P: AsRef<Path>>(
+    root: P,
+) -> Result<(AnnotationMap, Vec<AnnotationConflict>), Box<dyn std::error::Error>> {
+    let mut info_files = Vec::new();
     for entry in walkdir::WalkDir::new(root.as_ref()) {
         let entry = entry?;
         if entry.file_name() == ".info" {
-            let info_file = Txxt::load(entry.path())?;
-
-            for annotation in info_file.annotations {
-                let key = annotation.path.clone();
-
-                match merged.get(&key) {
-                    Some(existing) => {
-                        if is_closer_to_target(&annotation.source_file, &existing.source_file, &key)
-                        {
-                            merged.insert(key, annotation);
-                        }
-                    }
-                    None => {
-                        merged.insert(key, annotation);
-                    }
-                }
+            info_files.push(Txxt::load(entry.path())?);
+        }
+    }
+
+    Ok(merge_annotations(info_files))
+}
+
+/// Merge loaded `.info` files, keeping the closest file's annotation
+///
+/// Annotation paths are read relative to their `.info` file's directory
+/// and made absolute, as are the files' own paths, so a target has one key
+/// however each file spells it. When several files annotate the same path,
+/// the one nearest the path wins, ties going to the lesser file path.
+/// Files that agree on the text are not a conflict.
+pub fn merge_annotations(
+    info_files: impl IntoIterator<Item = Txxt>,
+) -> (AnnotationMap, Vec<AnnotationConflict>) {
+    let mut candidates: HashMap<PathBuf, Vec<Annotation>> = HashMap::new();
+    for info_file in info_files {
+        for mut annotation in info_file.annotations {
+            annotation.source_file = normalize_path(&annotation.source_file);
+            let info_dir = annotation.source_file.parent().unwrap_or(Path::new(""));
+            annotation.path = normalize_path(&info_dir.join(&annotation.path));
+            candidates
+                .entry(annotation.path.clone())
+                .or_default()
+                .push(annotation);
+        }
+    }
+
+    let mut merged = AnnotationMap::new();
+    let mut conflicts = Vec::new();
+    for (key, mut annotations) in candidates {
+        annotations.sort_by(|a, b| {
+            if is_closer_to_target(&a.source_file, &b.source_file, &key) {
+                std::cmp::Ordering::Less
+            } else if is_closer_to_target(&b.source_file, &a.source_file, &key) {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
             }
+        });
+        if annotations
+            .iter()
+            .any(|annotation| annotation.text != annotations[0].text)
+        {
+            conflicts.push(AnnotationConflict {
+                path: key.clone(),
+                annotations: annotations.clone(),
+            });
         }
+        merged.insert(key, annotations.swap_remove(0));
+    }
+    conflicts.sort_by(|a, b| a.path.cmp(&b.path));
+
+    (merged, conflicts)
+}
+
+/// Absolute form of `path`, with symlinks resolved where it exists
+///
+/// Paths that don't exist yet are normalized lexically, `.` and `..`
+/// included, under their canonical parent directory when that exists.
+fn normalize_path(path: &Path) -> PathBuf {
+    let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    let mut normalized = PathBuf::new();
+    for component in absolute.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+
+    if let Ok(canonical) = normalized.canonicalize() {
+        return canonical;
+    }
+    match (normalized.parent(), normalized.file_name()) {
+        (Some(parent), Some(name)) => match parent.canonicalize() {
+            Ok(parent) => parent.join(name),
+            Err(_) => normalized,
+        },
+        _ => normalized,
+    }
+}
+
+/// Sort annotation updates into the `.info` files they belong in
+///
+/// A target belongs to the `.info` file of its nearest ancestor directory
+/// that has one, or to a new `.info` at `root` when none does. Targets may
+/// be given as [`collect_annotations`] reports them or relative to the
+/// working directory; `root`, the targets and the `.info` files found are
+/// all normalized the same way before they are compared. Each annotation
+/// comes back with its `path` relative to its `.info` file's directory, as
+/// `.info` files spell it, and its `source_file` set to the `.info` file.
+pub fn group_annotation_updates<P: AsRef<Path>>(
+    root: P,
+    updates: &[(PathBuf, Annotation)],
+) -> Result<HashMap<PathBuf, Vec<Annotation>>, Box<dyn std::error::Error>> {
+    let root = normalize_path(root.as_ref());
+    let mut info_files = Vec::new();
+    for entry in walkdir::WalkDir::new(&root) {
+        let entry = entry?;
+        if entry.file_name() == ".info" {
+            info_files.push(normalize_path(entry.path()));
+        }
+    }
+
+    let mut groups: HashMap<PathBuf, Vec<Annotation>> = HashMap::new();
+    for (target, annotation) in updates {
+        let target = normalize_path(target);
+        let info_path = info_files
+            .iter()
+            .filter(|info| info.parent().is_some_and(|dir| target.starts_with(dir)))
+            .fold(None, |nearest: Option<&PathBuf>, candidate| match nearest {
+                Some(nearest) if !is_closer_to_target(candidate, nearest, &target) => Some(nearest),
+                _ => Some(candidate),
+            })
+            .cloned()
+            .unwrap_or_else(|| root.join(".info"));
+        let info_dir = info_path.parent().unwrap_or(Path::new(""));
+        let path = target
+            .strip_prefix(info_dir)
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|_| target.clone());
+
+        groups
+            .entry(info_path.clone())
+            .or_default()
+            .push(Annotation {
+                path,
+                text: annotation.text.clone(),
+                source_file: info_path,
+            });
     }
 
-    Ok(merged)
+    Ok(groups)
 }
 
 fn is_closer_to_target(candidate: &Path, existing: &Path, target: &Path) -> bool {
@@ -25,9 +25,14 @@
 //!
 //! See docs/proposals/regex-grammar-engine.txxt for complete design.
 
+use crate::ast::elements::core::BlankLine;
+use crate::ast::Diagnostic;
 use crate::cst::high_level_tokens::HighLevelTokenSpan;
-use crate::cst::{HighLevelToken, HighLevelTokenList};
+use crate::cst::{
+    HighLevelToken, HighLevelTokenList, ScannerToken, ScannerTokenSequence, SourceSpan,
+};
 use crate::semantic::BlockParseError;
+use crate::syntax::ParseOptions;
 
 /// Maximum recursion depth for nested structures to prevent stack overflow
 const MAX_RECURSION_DEPTH: usize = 100;
@@ -43,15 +48,26 @@ pub struct AstConstructor<'a> {
     position: usize,
     /// Current recursion depth (for nested structures)
     recursion_depth: usize,
+    /// Indentation width, blank line and verbatim settings
+    options: ParseOptions,
+    /// Ambiguous constructs read one way and reported
+    diagnostics: Vec<Diagnostic>,
 }
 
 impl<'a> AstConstructor<'a> {
     /// Create a new AST constructor instance
     pub fn new() -> Self {
+        Self::with_options(ParseOptions::default())
+    }
+
+    /// Create a new AST constructor instance for the given parse options
+    pub fn with_options(options: ParseOptions) -> Self {
         Self {
             tokens: &[],
             position: 0,
             recursion_depth: 0,
+            options,
+            diagnostics: Vec::new(),
         }
     }
 
@@ -59,8 +75,7 @@ impl<'a> AstConstructor<'a> {
     pub fn with_tokens(tokens: &'a [HighLevelToken]) -> Self {
         Self {
             tokens,
-            position: 0,
-            recursion_depth: 0,
+            ..Self::new()
         }
     }
 
@@ -102,6 +117,27 @@ impl<'a> AstConstructor<'a> {
                 continue;
             }
 
+            // Quote pattern (standalone token, quoted lines already grouped)
+            // Pattern: <Quote>
+            if let Some(node) = self.try_parse_quote()? {
+                ast_nodes.push(node);
+                continue;
+            }
+
+            // Table pattern (standalone token, rows already grouped)
+            // Pattern: <Table>
+            if let Some(node) = self.try_parse_table()? {
+                ast_nodes.push(node);
+                continue;
+            }
+
+            // Footnote definition pattern (standalone token, optional indented body)
+            // Pattern: <FootnoteDefinition> (<BlankLine>* <Indent> <Content>* <Dedent>)?
+            if let Some(node) = self.try_parse_footnote_definition()? {
+                ast_nodes.push(node);
+                continue;
+            }
+
             // Definition pattern (check before sessions as both can have similar structure)
             // Pattern: <Definition> <Indent> <Content>* <Dedent>
             if let Some((node, _tokens_consumed)) = self.try_parse_definition()? {
@@ -124,8 +160,13 @@ impl<'a> AstConstructor<'a> {
                 continue;
             }
 
-            // Skip standalone blank lines (not part of session/list pattern)
-            if matches!(token, HighLevelToken::BlankLine { .. }) {
+            // Standalone blank lines (not part of session/list pattern)
+            if let HighLevelToken::BlankLine { tokens, .. } = token {
+                if self.options.preserve_blank_lines {
+                    ast_nodes.push(AstNode::BlankLine(BlankLine {
+                        tokens: tokens.clone(),
+                    }));
+                }
                 self.position += 1;
                 continue;
             }
@@ -307,6 +348,24 @@ impl<'a> AstConstructor<'a> {
                 continue;
             }
 
+            // Try quote pattern (standalone token, quoted lines already grouped)
+            if let Some(node) = self.try_parse_quote()? {
+                content_nodes.push(node);
+                continue;
+            }
+
+            // Try table pattern (standalone token, rows already grouped)
+            if let Some(node) = self.try_parse_table()? {
+                content_nodes.push(node);
+                continue;
+            }
+
+            // Try footnote definition pattern (standalone token, optional indented body)
+            if let Some(node) = self.try_parse_footnote_definition()? {
+                content_nodes.push(node);
+                continue;
+            }
+
             // Try definition pattern (explicit marker)
             if let Some((node, _tokens_consumed)) = self.try_parse_definition()? {
                 content_nodes.push(node);
@@ -325,8 +384,13 @@ impl<'a> AstConstructor<'a> {
                 continue;
             }
 
-            // Skip blank lines within content
-            if matches!(token, HighLevelToken::BlankLine { .. }) {
+            // Blank lines within content
+            if let HighLevelToken::BlankLine { tokens, .. } = token {
+                if self.options.preserve_blank_lines {
+                    content_nodes.push(AstNode::BlankLine(BlankLine {
+                        tokens: tokens.clone(),
+                    }));
+                }
                 self.position += 1;
                 continue;
             }
@@ -389,6 +453,7 @@ impl<'a> AstConstructor<'a> {
 
         // Check next token is Indent (NOT BlankLine - that would be a session)
         if self.position + 1 >= self.tokens.len() {
+            self.report_empty_definition_body(current_token);
             return Ok(None);
         }
 
@@ -398,6 +463,7 @@ impl<'a> AstConstructor<'a> {
             }
             HighLevelToken::BlankLine { .. } => {
                 // This is a session pattern (or paragraph + separate block), not definition
+                self.report_empty_definition_body(current_token);
                 return Ok(None);
             }
             _ => {
@@ -406,12 +472,18 @@ impl<'a> AstConstructor<'a> {
             }
         }
 
-        // Extract term from PlainTextLine (remove trailing ":")
-        let term_text = match current_token {
+        // Extract term from PlainTextLine (remove trailing ":"), keeping the
+        // source tokens before the colon so inline parsing sees references
+        let (term_text, term_tokens) = match current_token {
             HighLevelToken::PlainTextLine { content, .. } => match content.as_ref() {
-                HighLevelToken::TextSpan { content: text, .. } => {
-                    text.trim_end().trim_end_matches(':').to_string()
-                }
+                HighLevelToken::TextSpan {
+                    content: text,
+                    tokens,
+                    ..
+                } => (
+                    text.trim_end().trim_end_matches(':').to_string(),
+                    definition_term_tokens(&tokens.tokens),
+                ),
                 _ => return Ok(None),
             },
             HighLevelToken::Definition { .. } => {
@@ -431,11 +503,12 @@ impl<'a> AstConstructor<'a> {
                 }
 
                 // Delegate to definition element constructor
-                let definition_block =
+                let mut definition_block =
                     crate::semantic::elements::definition::create_definition_element(
                         &definition_token_clone,
                         &content_nodes,
                     )?;
+                self.attach_trailing_definition_annotation(&mut definition_block)?;
 
                 let tokens_consumed = self.position - start_pos;
                 return Ok(Some((
@@ -448,11 +521,24 @@ impl<'a> AstConstructor<'a> {
 
         // Create a Definition high-level token from the pattern
         let term_span = current_token.span().clone();
-        #[allow(deprecated)]
-        let term_token = crate::cst::high_level_tokens::HighLevelTokenBuilder::text_span(
-            term_text,
-            term_span.clone(),
-        );
+        let term_token = match (term_tokens.first(), term_tokens.last()) {
+            (Some(first), Some(last)) => {
+                let span = SourceSpan {
+                    start: first.span().start,
+                    end: last.span().end,
+                };
+                crate::cst::high_level_tokens::HighLevelTokenBuilder::text_span_with_tokens(
+                    term_text,
+                    span,
+                    ScannerTokenSequence::from_tokens(term_tokens),
+                )
+            }
+            #[allow(deprecated)]
+            _ => crate::cst::high_level_tokens::HighLevelTokenBuilder::text_span(
+                term_text,
+                term_span.clone(),
+            ),
+        };
         let definition_token = crate::cst::high_level_tokens::HighLevelTokenBuilder::definition(
             term_token, None, // No inline parameters in new syntax
             term_span,
@@ -472,10 +558,12 @@ impl<'a> AstConstructor<'a> {
         }
 
         // Delegate to definition element constructor
-        let definition_block = crate::semantic::elements::definition::create_definition_element(
-            &definition_token,
-            &content_nodes,
-        )?;
+        let mut definition_block =
+            crate::semantic::elements::definition::create_definition_element(
+                &definition_token,
+                &content_nodes,
+            )?;
+        self.attach_trailing_definition_annotation(&mut definition_block)?;
 
         let tokens_consumed = self.position - start_pos;
         Ok(Some((
@@ -484,6 +572,67 @@ impl<'a> AstConstructor<'a> {
         )))
     }
 
+    /// Consume a `:: def.label params ::` line right after a definition body
+    ///
+    /// The annotation carries metadata for the definition it closes, so its
+    /// parameters are merged into the definition's own rather than becoming
+    /// a separate annotation element. Other labels are left for the caller.
+    fn attach_trailing_definition_annotation(
+        &mut self,
+        definition: &mut crate::ast::elements::definition::DefinitionBlock,
+    ) -> Result<(), BlockParseError> {
+        let Some(HighLevelToken::Annotation {
+            label, parameters, ..
+        }) = self.tokens.get(self.position)
+        else {
+            return Ok(());
+        };
+        let label = match label.as_ref() {
+            HighLevelToken::Label { text, .. } => text.trim(),
+            HighLevelToken::TextSpan { content, .. } => content.trim(),
+            _ => return Ok(()),
+        };
+        if label != "def" && !label.starts_with("def.") {
+            return Ok(());
+        }
+
+        let parameters =
+            crate::semantic::elements::parameters::create_parameters_ast(parameters.as_deref())?;
+        for (key, value) in parameters.map {
+            definition.parameters.set(key, value);
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    /// Report a `Term:` line left without a body
+    ///
+    /// The line is read as a paragraph. Followed by blank lines and indented
+    /// content it titles a session, and followed by a list it introduces
+    /// the list; neither is ambiguous, so neither is reported.
+    fn report_empty_definition_body(&mut self, term_line: &HighLevelToken) {
+        let HighLevelToken::PlainTextLine { content, .. } = term_line else {
+            return;
+        };
+        let HighLevelToken::TextSpan { content: text, .. } = content.as_ref() else {
+            return;
+        };
+        let next_block = self.tokens[self.position + 1..]
+            .iter()
+            .find(|token| !matches!(token, HighLevelToken::BlankLine { .. }));
+        if matches!(
+            next_block,
+            Some(HighLevelToken::Indent { .. } | HighLevelToken::SequenceTextLine { .. })
+        ) {
+            return;
+        }
+
+        self.diagnostics.push(Diagnostic::EmptyDefinitionBody {
+            term: text.trim_end().trim_end_matches(':').trim().to_string(),
+            line: term_line.span().start.row + 1,
+        });
+    }
+
     /// Try to parse an annotation pattern
     ///
     /// Annotations can be standalone tokens or have indented content for nesting.
@@ -535,6 +684,65 @@ impl<'a> AstConstructor<'a> {
         Ok(Some(AstNode::Annotation(annotation_block)))
     }
 
+    /// Try to parse a footnote definition pattern
+    ///
+    /// The marker line may be followed by an indented body, separated by
+    /// blank lines when the body holds several paragraphs.
+    ///
+    /// Patterns:
+    /// 1. `<FootnoteDefinition>` (single line)
+    /// 2. `<FootnoteDefinition> <BlankLine>* <Indent> <Content>* <Dedent>` (with body)
+    ///
+    /// Returns: FootnoteDefinitionBlock if matched, None otherwise
+    fn try_parse_footnote_definition(&mut self) -> Result<Option<AstNode>, BlockParseError> {
+        if self.position >= self.tokens.len() {
+            return Ok(None);
+        }
+
+        let token = &self.tokens[self.position];
+        if !matches!(token, HighLevelToken::FootnoteDefinition { .. }) {
+            return Ok(None);
+        }
+
+        let footnote_token_clone = token.clone();
+        self.position += 1; // Consume footnote definition token
+
+        // Look past blank lines for an indented body; without one the blank
+        // lines are left for the caller
+        let mut indent_pos = self.position;
+        while indent_pos < self.tokens.len()
+            && matches!(self.tokens[indent_pos], HighLevelToken::BlankLine { .. })
+        {
+            indent_pos += 1;
+        }
+
+        let mut content_nodes = Vec::new();
+        if indent_pos < self.tokens.len()
+            && matches!(self.tokens[indent_pos], HighLevelToken::Indent { .. })
+        {
+            self.position = indent_pos + 1; // Consume blank lines and Indent
+
+            // Recursively parse the body until we hit Dedent
+            content_nodes = self.parse_until_dedent()?;
+
+            // Consume the Dedent token
+            if self.position < self.tokens.len()
+                && matches!(self.tokens[self.position], HighLevelToken::Dedent { .. })
+            {
+                self.position += 1;
+            }
+        }
+
+        // Delegate to footnote element constructor
+        let footnote_block =
+            crate::semantic::elements::footnote::create_footnote_definition_element(
+                &footnote_token_clone,
+                &content_nodes,
+            )?;
+
+        Ok(Some(AstNode::FootnoteDefinition(footnote_block)))
+    }
+
     /// Try to parse a verbatim block pattern
     ///
     /// Verbatim blocks are standalone tokens - the scanner/tokenizer has already
@@ -559,12 +767,81 @@ impl<'a> AstConstructor<'a> {
         self.position += 1; // Consume verbatim token
 
         // Delegate to verbatim element constructor
-        let verbatim_block =
-            crate::semantic::elements::verbatim::create_verbatim_element(&verbatim_token_clone)?;
+        let verbatim_block = crate::semantic::elements::verbatim::create_verbatim_element_with(
+            &verbatim_token_clone,
+            self.options.indent_size,
+            self.options.verbatim,
+        )?;
 
         Ok(Some(AstNode::Verbatim(verbatim_block)))
     }
 
+    /// Try to parse a quote pattern
+    ///
+    /// The semantic analyzer has already grouped the `>`-prefixed lines and
+    /// analyzed the quoted text on its own, so the token carries a complete
+    /// high-level token stream that is parsed recursively here.
+    ///
+    /// Pattern: <Quote>
+    ///
+    /// Returns: QuoteBlock if matched, None otherwise
+    fn try_parse_quote(&mut self) -> Result<Option<AstNode>, BlockParseError> {
+        if self.position >= self.tokens.len() {
+            return Ok(None);
+        }
+
+        let token = &self.tokens[self.position];
+        let content = match token {
+            HighLevelToken::Quote { content, .. } => content,
+            _ => return Ok(None),
+        };
+
+        if self.recursion_depth + 1 > MAX_RECURSION_DEPTH {
+            return Err(BlockParseError::InvalidStructure(format!(
+                "Maximum nesting depth exceeded ({}). Document has too many nested structures.",
+                MAX_RECURSION_DEPTH
+            )));
+        }
+
+        // Parse the quoted stream with its own constructor, carrying our depth
+        let quoted_tokens = HighLevelTokenList::with_tokens(content.clone());
+        let mut quoted_constructor = AstConstructor::with_options(self.options);
+        quoted_constructor.recursion_depth = self.recursion_depth + 1;
+        let content_nodes = quoted_constructor.parse(&quoted_tokens)?;
+
+        let quote_token_clone = token.clone();
+        self.position += 1; // Consume quote token
+
+        // Delegate to quote element constructor
+        let quote_block = crate::semantic::elements::quote::create_quote_element(
+            &quote_token_clone,
+            &content_nodes,
+        )?;
+
+        Ok(Some(AstNode::Quote(quote_block)))
+    }
+
+    /// Try to parse a table pattern
+    ///
+    /// Pattern: <Table>
+    ///
+    /// Returns: TableBlock if matched, None otherwise
+    fn try_parse_table(&mut self) -> Result<Option<AstNode>, BlockParseError> {
+        if self.position >= self.tokens.len() {
+            return Ok(None);
+        }
+
+        let token = &self.tokens[self.position];
+        if !matches!(token, HighLevelToken::Table { .. }) {
+            return Ok(None);
+        }
+
+        let table_block = crate::semantic::elements::table::create_table_element(token)?;
+        self.position += 1; // Consume table token
+
+        Ok(Some(AstNode::Table(table_block)))
+    }
+
     /// Try to parse a list pattern, supporting nested lists
     ///
     /// Lists are one or more consecutive SequenceTextLine tokens. Nesting is handled
@@ -670,9 +947,25 @@ impl<'a> Default for AstConstructor<'a> {
     }
 }
 
+/// Tokens of a definition's term line up to its colon
+///
+/// Whitespace between the term and the colon is dropped along with the
+/// colon and the line ending.
+fn definition_term_tokens(line: &[ScannerToken]) -> Vec<ScannerToken> {
+    let colon = line
+        .iter()
+        .rposition(|token| matches!(token, ScannerToken::Colon { .. }))
+        .unwrap_or(line.len());
+    let end = line[..colon]
+        .iter()
+        .rposition(|token| !matches!(token, ScannerToken::Whitespace { .. }))
+        .map_or(0, |last| last + 1);
+    line[..end].to_vec()
+}
+
 /// AST node types that can be constructed from semantic tokens
 ///
-/// Currently supports: Paragraph, Session, List, Definition, Annotation.
+/// Currently supports: Paragraph, Session, List, Definition, Annotation, Verbatim, Quote, Table.
 #[derive(Debug, Clone, PartialEq)]
 pub enum AstNode {
     /// Paragraph block node
@@ -687,6 +980,14 @@ pub enum AstNode {
     Annotation(crate::ast::elements::annotation::annotation_block::AnnotationBlock),
     /// Verbatim block node
     Verbatim(crate::ast::elements::verbatim::block::VerbatimBlock),
+    /// Quote block node
+    Quote(crate::ast::elements::quote::QuoteBlock),
+    /// Table block node
+    Table(crate::ast::elements::table::TableBlock),
+    /// Footnote definition block node
+    FootnoteDefinition(crate::ast::elements::footnote::FootnoteDefinitionBlock),
+    /// Standalone blank line, kept only when preserving blank lines
+    BlankLine(crate::ast::elements::core::BlankLine),
 }
 
 impl AstNode {
@@ -711,6 +1012,18 @@ impl AstNode {
             AstNode::Verbatim(block) => {
                 crate::ast::elements::core::ElementNode::VerbatimBlock(block.clone())
             }
+            AstNode::Quote(block) => {
+                crate::ast::elements::core::ElementNode::QuoteBlock(block.clone())
+            }
+            AstNode::Table(block) => {
+                crate::ast::elements::core::ElementNode::TableBlock(block.clone())
+            }
+            AstNode::FootnoteDefinition(block) => {
+                crate::ast::elements::core::ElementNode::FootnoteDefinitionBlock(block.clone())
+            }
+            AstNode::BlankLine(blank_line) => {
+                crate::ast::elements::core::ElementNode::BlankLine(blank_line.clone())
+            }
         }
     }
 }
@@ -720,11 +1033,37 @@ impl AstConstructor<'_> {
     pub fn parse_to_element_nodes(
         semantic_tokens: &HighLevelTokenList,
     ) -> Result<Vec<crate::ast::elements::core::ElementNode>, BlockParseError> {
-        let mut constructor = AstConstructor::new();
+        Self::parse_to_element_nodes_with(semantic_tokens, ParseOptions::default())
+    }
+
+    /// Parse semantic tokens into ElementNodes using the given parse options
+    pub fn parse_to_element_nodes_with(
+        semantic_tokens: &HighLevelTokenList,
+        options: ParseOptions,
+    ) -> Result<Vec<crate::ast::elements::core::ElementNode>, BlockParseError> {
+        Ok(Self::parse_to_element_nodes_with_diagnostics(semantic_tokens, options)?.0)
+    }
+
+    /// Parse semantic tokens into ElementNodes, reporting ambiguous lines
+    ///
+    /// A `Term:` line with no indented body is kept as a paragraph and
+    /// reported as a `Diagnostic::EmptyDefinitionBody`.
+    pub fn parse_to_element_nodes_with_diagnostics(
+        semantic_tokens: &HighLevelTokenList,
+        options: ParseOptions,
+    ) -> Result<
+        (
+            Vec<crate::ast::elements::core::ElementNode>,
+            Vec<Diagnostic>,
+        ),
+        BlockParseError,
+    > {
+        let mut constructor = AstConstructor::with_options(options);
         let ast_nodes = constructor.parse(semantic_tokens)?;
-        Ok(ast_nodes
+        let nodes = ast_nodes
             .into_iter()
             .map(|node| node.to_element_node())
-            .collect())
+            .collect();
+        Ok((nodes, constructor.diagnostics))
     }
 }
@@ -8,7 +8,8 @@
 
 use crate::ast::elements::annotation::annotation_block::{AnnotationBlock, AnnotationContent};
 use crate::ast::elements::containers::content::ContentContainerElement;
-use crate::cst::HighLevelToken;
+use crate::ast::elements::formatting::inlines::{Text, TextTransform};
+use crate::cst::{HighLevelToken, ScannerToken, ScannerTokenSequence};
 use crate::semantic::ast_construction::AstNode;
 use crate::semantic::elements::parameters::create_parameters_ast;
 use crate::semantic::BlockParseError;
@@ -29,6 +30,7 @@ pub fn create_annotation_element(
         HighLevelToken::Annotation {
             label,
             parameters,
+            content: inline_content,
             tokens,
             ..
         } => {
@@ -67,7 +69,14 @@ pub fn create_annotation_element(
                     AstNode::Verbatim(v) => {
                         simple_elements.push(
                             crate::ast::elements::containers::simple::SimpleBlockElement::Verbatim(
-                                v.clone(),
+                                Box::new(v.clone()),
+                            ),
+                        );
+                    }
+                    AstNode::BlankLine(b) => {
+                        simple_elements.push(
+                            crate::ast::elements::containers::simple::SimpleBlockElement::BlankLine(
+                                b.clone(),
                             ),
                         );
                     }
@@ -89,12 +98,32 @@ pub fn create_annotation_element(
                                 .to_string(),
                         ));
                     }
+                    AstNode::Quote(_) => {
+                        return Err(BlockParseError::InvalidStructure(
+                            "Cannot nest Quote inside Annotation (SimpleContainer constraint)"
+                                .to_string(),
+                        ));
+                    }
+                    AstNode::Table(_) => {
+                        return Err(BlockParseError::InvalidStructure(
+                            "Cannot nest Table inside Annotation (SimpleContainer constraint)"
+                                .to_string(),
+                        ));
+                    }
+                    AstNode::FootnoteDefinition(_) => {
+                        return Err(BlockParseError::InvalidStructure(
+                            "Cannot nest FootnoteDefinition inside Annotation (SimpleContainer constraint)"
+                                .to_string(),
+                        ));
+                    }
                 }
             }
 
-            // Determine content type
+            // Indented content makes a block annotation; otherwise the text after
+            // the closing marker is the inline body (inline-parsed later, like
+            // paragraph text)
             let content = if simple_elements.is_empty() {
-                AnnotationContent::Inline(vec![])
+                AnnotationContent::Inline(create_inline_body(inline_content.as_deref()))
             } else {
                 AnnotationContent::Block(
                     crate::ast::elements::containers::simple::SimpleContainer::new(
@@ -120,6 +149,45 @@ pub fn create_annotation_element(
     }
 }
 
+/// Build the inline body of a single-line annotation (`:: note :: text`)
+///
+/// Surrounding whitespace and the line ending are left out of the body's
+/// tokens so inline parsing sees only the text.
+fn create_inline_body(content: Option<&HighLevelToken>) -> Vec<TextTransform> {
+    let Some(HighLevelToken::TextSpan {
+        content, tokens, ..
+    }) = content
+    else {
+        return Vec::new();
+    };
+    if content.is_empty() {
+        return Vec::new();
+    }
+
+    let is_padding = |token: &ScannerToken| {
+        matches!(
+            token,
+            ScannerToken::Whitespace { .. }
+                | ScannerToken::Newline { .. }
+                | ScannerToken::Eof { .. }
+        )
+    };
+    let body = tokens.tokens.as_slice();
+    let start = body
+        .iter()
+        .position(|t| !is_padding(t))
+        .unwrap_or(body.len());
+    let end = body
+        .iter()
+        .rposition(|t| !is_padding(t))
+        .map_or(start, |i| i + 1);
+
+    vec![TextTransform::Identity(Text::simple_with_tokens(
+        content,
+        ScannerTokenSequence::from_tokens(body[start..end].to_vec()),
+    ))]
+}
+
 impl TryFrom<crate::ast::elements::core::ElementNode> for ContentContainerElement {
     type Error = BlockParseError;
 
@@ -140,8 +208,20 @@ impl TryFrom<crate::ast::elements::core::ElementNode> for ContentContainerElemen
             crate::ast::elements::core::ElementNode::AnnotationBlock(block) => {
                 Ok(ContentContainerElement::Annotation(block))
             }
+            crate::ast::elements::core::ElementNode::QuoteBlock(block) => {
+                Ok(ContentContainerElement::Quote(block))
+            }
+            crate::ast::elements::core::ElementNode::TableBlock(block) => {
+                Ok(ContentContainerElement::Table(block))
+            }
+            crate::ast::elements::core::ElementNode::FootnoteDefinitionBlock(block) => {
+                Ok(ContentContainerElement::FootnoteDefinition(block))
+            }
+            crate::ast::elements::core::ElementNode::BlankLine(blank_line) => {
+                Ok(ContentContainerElement::BlankLine(blank_line))
+            }
             other => Err(BlockParseError::InvalidStructure(
-                format!("Element type {:?} not allowed in ContentContainer (only Paragraph, List, Definition, Verbatim, Annotation are allowed)",
+                format!("Element type {:?} not allowed in ContentContainer (only Paragraph, List, Definition, Verbatim, Annotation, Quote, Table are allowed)",
                     std::mem::discriminant(&other)),
             )),
         }
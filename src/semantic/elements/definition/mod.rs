@@ -73,7 +73,14 @@ pub fn create_definition_element(
                     crate::semantic::ast_construction::AstNode::Verbatim(v) => {
                         simple_elements.push(
                             crate::ast::elements::containers::simple::SimpleBlockElement::Verbatim(
-                                v.clone(),
+                                Box::new(v.clone()),
+                            ),
+                        );
+                    }
+                    crate::semantic::ast_construction::AstNode::BlankLine(b) => {
+                        simple_elements.push(
+                            crate::ast::elements::containers::simple::SimpleBlockElement::BlankLine(
+                                b.clone(),
                             ),
                         );
                     }
@@ -95,6 +102,24 @@ pub fn create_definition_element(
                                 .to_string(),
                         ));
                     }
+                    crate::semantic::ast_construction::AstNode::Quote(_) => {
+                        return Err(BlockParseError::InvalidStructure(
+                            "Cannot nest Quote inside Definition (SimpleContainer constraint)"
+                                .to_string(),
+                        ));
+                    }
+                    crate::semantic::ast_construction::AstNode::Table(_) => {
+                        return Err(BlockParseError::InvalidStructure(
+                            "Cannot nest Table inside Definition (SimpleContainer constraint)"
+                                .to_string(),
+                        ));
+                    }
+                    crate::semantic::ast_construction::AstNode::FootnoteDefinition(_) => {
+                        return Err(BlockParseError::InvalidStructure(
+                            "Cannot nest FootnoteDefinition inside Definition (SimpleContainer constraint)"
+                                .to_string(),
+                        ));
+                    }
                 }
             }
 
@@ -0,0 +1,90 @@
+//! Footnote Definition Element Construction
+//!
+//! Converts high-level tokens into footnote definition AST nodes.
+//!
+//! ## Related Files
+//! - **Specification**: `docs/specs/elements/footnote/`
+//! - **AST Node**: `src/ast/elements/footnote/block.rs`
+
+use crate::ast::elements::containers::simple::{SimpleBlockElement, SimpleContainer};
+use crate::ast::elements::footnote::FootnoteDefinitionBlock;
+use crate::ast::elements::formatting::inlines::{Text, TextTransform};
+use crate::cst::HighLevelToken;
+use crate::semantic::ast_construction::AstNode;
+use crate::semantic::BlockParseError;
+
+/// Create a footnote definition from a FootnoteDefinition token and its body
+///
+/// The text on the marker line becomes the footnote's inline content
+/// (inline-parsed later, like paragraph text). The indented body follows the
+/// SimpleContainer rules: paragraphs, lists and verbatim blocks.
+///
+/// # Arguments
+/// * `token` - The FootnoteDefinition token for the marker line
+/// * `content_nodes` - Parsed AST nodes from the indented body
+///
+/// # Returns
+/// * `Result<FootnoteDefinitionBlock, BlockParseError>`
+pub fn create_footnote_definition_element(
+    token: &HighLevelToken,
+    content_nodes: &[AstNode],
+) -> Result<FootnoteDefinitionBlock, BlockParseError> {
+    let HighLevelToken::FootnoteDefinition {
+        label,
+        content,
+        tokens,
+        ..
+    } = token
+    else {
+        return Err(BlockParseError::InvalidStructure(
+            "Expected FootnoteDefinition token for footnote definition".to_string(),
+        ));
+    };
+
+    let inline_content = match content.as_deref() {
+        Some(HighLevelToken::TextSpan {
+            content, tokens, ..
+        }) => vec![TextTransform::Identity(Text::simple_with_tokens(
+            content,
+            tokens.clone(),
+        ))],
+        _ => Vec::new(),
+    };
+
+    let mut body_elements = Vec::new();
+    for node in content_nodes {
+        let element = match node {
+            AstNode::Paragraph(p) => SimpleBlockElement::Paragraph(p.clone()),
+            AstNode::List(l) => SimpleBlockElement::List(l.clone()),
+            AstNode::Verbatim(v) => SimpleBlockElement::Verbatim(Box::new(v.clone())),
+            AstNode::BlankLine(b) => SimpleBlockElement::BlankLine(b.clone()),
+            AstNode::Session(_)
+            | AstNode::Definition(_)
+            | AstNode::Annotation(_)
+            | AstNode::Quote(_)
+            | AstNode::Table(_)
+            | AstNode::FootnoteDefinition(_) => {
+                return Err(BlockParseError::InvalidStructure(
+                    "Footnote bodies can only hold paragraphs, lists and verbatim blocks \
+                     (SimpleContainer constraint)"
+                        .to_string(),
+                ))
+            }
+        };
+        body_elements.push(element);
+    }
+
+    Ok(FootnoteDefinitionBlock::new(
+        label.clone(),
+        inline_content,
+        SimpleContainer::new(
+            body_elements,
+            Vec::new(),
+            Default::default(),
+            Default::default(),
+        ),
+        Vec::new(),
+        Default::default(),
+        tokens.clone(),
+    ))
+}
@@ -11,17 +11,20 @@
 //!
 //! ## Element Types
 //!
-//! ### 1. Strong (Bold)
-//! - **Syntax**: `*content*`
-//! - **Token**: Single asterisk (`*`)
+//! ### 1. Emphasis (Italic)
+//! - **Syntax**: `*content*` or `_content_`
+//! - **Token**: Single asterisk (`*`) or underscore (`_`)
+//! - **Purpose**: Emphasis, foreign words, titles, definitions
+//! - **Nesting**: Can contain other inline types (except emphasis)
+//!
+//! ### 2. Strong (Bold)
+//! - **Syntax**: `**content**` or `__content__`
+//! - **Token**: A run of two asterisks or two underscores
 //! - **Purpose**: Strong importance, key concepts, warnings
 //! - **Nesting**: Can contain other inline types (except strong)
 //!
-//! ### 2. Emphasis (Italic)
-//! - **Syntax**: `_content_`
-//! - **Token**: Single underscore (`_`)
-//! - **Purpose**: Emphasis, foreign words, titles, definitions
-//! - **Nesting**: Can contain other inline types (except emphasis)
+//! A run of three (`***both***`, `___both___`) is strong emphasis. These
+//! are the Markdown forms, so `*a*` and `**a**` differ.
 //!
 //! ### 3. Code
 //! - **Syntax**: `` `content` ``
@@ -44,6 +47,7 @@
 
 use crate::ast::elements::formatting::inlines::{Inline, TextTransform};
 use crate::cst::ScannerToken;
+use crate::semantic::elements::inlines::level1_matchers::find_closing_run;
 use crate::semantic::elements::inlines::InlineParseError;
 
 /// Formatting type being parsed (for preventing same-type nesting)
@@ -52,6 +56,22 @@ enum FormattingContext {
     None,
     Strong,
     Emphasis,
+    StrongEmphasis,
+}
+
+impl FormattingContext {
+    /// Whether a single `*` or `_` (emphasis) may open here
+    fn allows_single(self) -> bool {
+        matches!(self, Self::None | Self::Strong)
+    }
+
+    /// Whether a run of `run` delimiters may open here
+    fn allows_run(self, run: usize) -> bool {
+        match run {
+            2 => matches!(self, Self::None | Self::Emphasis),
+            _ => self == Self::None,
+        }
+    }
 }
 
 /// Parse all formatting elements from a sequence of tokens
@@ -68,8 +88,8 @@ enum FormattingContext {
 /// # Processing Order
 /// 1. **Code spans** (highest priority - prevents conflicts)
 /// 2. **Math expressions** (no further parsing)
-/// 3. **Strong elements** (asterisk tokens)
-/// 4. **Emphasis elements** (underscore tokens)
+/// 3. **Strong and strong emphasis** (runs of two or three `*` or `_`)
+/// 4. **Emphasis elements** (single `*` or `_`)
 /// 5. **Plain text** (default)
 pub fn parse_formatting_elements(
     tokens: &[ScannerToken],
@@ -88,33 +108,43 @@ fn parse_formatting_elements_with_context(
     while i < tokens.len() {
         let token = &tokens[i];
 
-        if token.is_bold_delimiter() && context != FormattingContext::Strong {
-            if let Some(j) = find_closing_token(tokens, i + 1, |t| t.is_bold_delimiter()) {
-                let content_tokens = &tokens[i + 1..j];
+        let run = find_closing_run(tokens, i, ScannerToken::is_bold_delimiter)
+            .or_else(|| find_closing_run(tokens, i, ScannerToken::is_italic_delimiter))
+            .filter(|&(run, close)| {
+                context.allows_run(run) && !contains_newline(&tokens[i + run..close])
+            });
+        let single = token.is_bold_delimiter() || token.is_italic_delimiter();
 
-                // Enforce single-line constraint: inline elements cannot span newlines
-                if contains_newline(content_tokens) {
-                    // Treat as literal delimiter if content spans lines
-                    transforms.push(token_to_identity(token));
-                    i += 1;
-                } else {
-                    let nested_transforms = parse_formatting_elements_with_context(
+        if let Some((run, close)) = run {
+            let content_tokens = &tokens[i + run..close];
+            let transform = if run == 2 {
+                TextTransform::Strong(parse_formatting_elements_with_context(
+                    content_tokens,
+                    FormattingContext::Strong,
+                )?)
+            } else {
+                TextTransform::Strong(vec![TextTransform::Emphasis(
+                    parse_formatting_elements_with_context(
                         content_tokens,
-                        FormattingContext::Strong,
-                    )?;
-                    transforms.push(TextTransform::Strong(nested_transforms));
-                    i = j + 1;
-                }
+                        FormattingContext::StrongEmphasis,
+                    )?,
+                )])
+            };
+            transforms.push(transform);
+            i = close + run;
+        } else if single && context.allows_single() {
+            // The span closes on the same delimiter it opened with
+            let closes = if token.is_bold_delimiter() {
+                ScannerToken::is_bold_delimiter
             } else {
-                transforms.push(token_to_identity(token));
-                i += 1;
-            }
-        } else if token.is_italic_delimiter() && context != FormattingContext::Emphasis {
-            if let Some(j) = find_closing_token(tokens, i + 1, |t| t.is_italic_delimiter()) {
+                ScannerToken::is_italic_delimiter
+            };
+            if let Some(j) = find_closing_token(tokens, i + 1, closes) {
                 let content_tokens = &tokens[i + 1..j];
 
-                // Enforce single-line constraint
+                // Enforce single-line constraint: inline elements cannot span newlines
                 if contains_newline(content_tokens) {
+                    // Treat as literal delimiter if content spans lines
                     transforms.push(token_to_identity(token));
                     i += 1;
                 } else {
@@ -16,6 +16,31 @@
 //!
 //! - Bold: start=`*`, end=`*` (same delimiter)
 //! - Reference: start=`[`, end=`]` (different delimiters)
+//!
+//! ## Closing Delimiters
+//!
+//! A span closes on the first end delimiter after its start, so `*a*b*` is
+//! emphasis `a` followed by the text `b*`. A delimiter right after an escaping
+//! backslash never closes a span. Code and math matchers also take a
+//! doubled delimiter (`` `a `` b` ``) as content, the usual way to write one
+//! inside literal text; the processors turn each pair back into one
+//! delimiter.
+//!
+//! ## Doubled Delimiters
+//!
+//! Bold and italic matchers also read runs: `**a**` and `__a__` open on a
+//! run of two delimiters and close on the next run of exactly two, and
+//! `***a***` likewise with three. Runs of another length inside are
+//! content, which is what lets `**a *b* c**` nest emphasis in strong text. Without a
+//! closing run of the same length the single-delimiter rule applies.
+//!
+//! ## Links
+//!
+//! `[display](target)` does not fit the one-open, one-close shape: the
+//! scanner reads `[display]` as a single reference token, and it only
+//! becomes a link when a `(` follows right after it. [`LinkMatcher`] checks
+//! that, then closes on the first `)` of the line. Without the paren the
+//! brackets stay a reference.
 
 use crate::cst::ScannerToken;
 use crate::semantic::elements::inlines::pipeline::{DelimiterMatcher, SpanMatch};
@@ -30,15 +55,85 @@ fn contains_newline(tokens: &[ScannerToken]) -> bool {
     })
 }
 
+/// Whether a token is text ending in a backslash that escapes what follows
+///
+/// `\\` is an escaped backslash, so only an odd run of trailing
+/// backslashes escapes the next token.
+fn ends_with_escape(token: &ScannerToken) -> bool {
+    match token {
+        ScannerToken::Text { content, .. } => {
+            content.chars().rev().take_while(|&ch| ch == '\\').count() % 2 == 1
+        }
+        _ => false,
+    }
+}
+
 /// Find matching closing delimiter in token stream
-fn find_closing<P>(tokens: &[ScannerToken], start: usize, predicate: P) -> Option<usize>
+///
+/// A delimiter right after an escaping backslash is content, not a close.
+/// With `doubled_escape`, so is a pair of adjacent delimiters.
+fn find_closing<P>(
+    tokens: &[ScannerToken],
+    start: usize,
+    predicate: P,
+    doubled_escape: bool,
+) -> Option<usize>
 where
     P: Fn(&ScannerToken) -> bool,
 {
+    let mut index = start;
+    while index < tokens.len() {
+        if !predicate(&tokens[index]) || (index > start && ends_with_escape(&tokens[index - 1])) {
+            index += 1;
+        } else if doubled_escape && tokens.get(index + 1).is_some_and(&predicate) {
+            index += 2;
+        } else {
+            return Some(index);
+        }
+    }
+    None
+}
+
+/// Number of delimiters in the run starting at `start`
+fn run_length(
+    tokens: &[ScannerToken],
+    start: usize,
+    predicate: fn(&ScannerToken) -> bool,
+) -> usize {
     tokens[start..]
         .iter()
-        .position(predicate)
-        .map(|pos| start + pos)
+        .take_while(|token| predicate(token))
+        .count()
+}
+
+/// Find the closing run of a doubled or tripled delimiter
+///
+/// Returns the run length (2 or 3) and the index of the closing run, the
+/// next run of exactly that length not escaped by a backslash. A single
+/// delimiter or a longer run at `start` opens no run.
+pub fn find_closing_run(
+    tokens: &[ScannerToken],
+    start: usize,
+    predicate: fn(&ScannerToken) -> bool,
+) -> Option<(usize, usize)> {
+    let run = run_length(tokens, start, predicate);
+    if !(2..=3).contains(&run) {
+        return None;
+    }
+
+    let mut index = start + run;
+    while index < tokens.len() {
+        if !predicate(&tokens[index]) {
+            index += 1;
+            continue;
+        }
+        let length = run_length(tokens, index, predicate);
+        if length == run && !ends_with_escape(&tokens[index - 1]) {
+            return Some((run, index));
+        }
+        index += length;
+    }
+    None
 }
 
 /// Generic delimiter matcher - matches any `start...end` pattern
@@ -68,6 +163,8 @@ pub struct GenericDelimiterMatcher {
     name: String,
     start_predicate: fn(&ScannerToken) -> bool,
     end_predicate: fn(&ScannerToken) -> bool,
+    doubled_escape: bool,
+    delimiter_runs: bool,
 }
 
 impl GenericDelimiterMatcher {
@@ -87,8 +184,22 @@ impl GenericDelimiterMatcher {
             name: name.to_string(),
             start_predicate,
             end_predicate,
+            doubled_escape: false,
+            delimiter_runs: false,
         }
     }
+
+    /// Read a doubled end delimiter inside the span as content
+    pub fn with_doubled_escape(mut self) -> Self {
+        self.doubled_escape = true;
+        self
+    }
+
+    /// Open on doubled and tripled delimiters too (see [`find_closing_run`])
+    pub fn with_delimiter_runs(mut self) -> Self {
+        self.delimiter_runs = true;
+        self
+    }
 }
 
 impl DelimiterMatcher for GenericDelimiterMatcher {
@@ -100,9 +211,33 @@ impl DelimiterMatcher for GenericDelimiterMatcher {
         (self.start_predicate)(token)
     }
 
+    fn can_end(&self, token: &ScannerToken) -> bool {
+        (self.end_predicate)(token)
+    }
+
+    fn reads_delimiter_runs(&self) -> bool {
+        self.delimiter_runs
+    }
+
     fn match_span(&self, tokens: &[ScannerToken], start: usize) -> Option<SpanMatch> {
+        if self.delimiter_runs {
+            if let Some((run, close)) = find_closing_run(tokens, start, self.start_predicate) {
+                let inner_tokens = tokens[start + run..close].to_vec();
+                if !contains_newline(&inner_tokens) {
+                    return Some(SpanMatch {
+                        start,
+                        end: close + run,
+                        matcher_name: self.name.clone(),
+                        inner_tokens,
+                        full_tokens: tokens[start..close + run].to_vec(),
+                    });
+                }
+            }
+        }
+
         // Find closing delimiter
-        if let Some(end) = find_closing(tokens, start + 1, self.end_predicate) {
+        if let Some(end) = find_closing(tokens, start + 1, self.end_predicate, self.doubled_escape)
+        {
             let inner_tokens = tokens[start + 1..end].to_vec();
 
             // Enforce single-line constraint
@@ -128,42 +263,96 @@ impl DelimiterMatcher for GenericDelimiterMatcher {
     }
 }
 
+/// Matcher for `[display](target)` links
+///
+/// Starts on the bracketed reference token and spans through the closing
+/// paren. `inner_tokens` holds the target, between the parens; the display
+/// text is the first of `full_tokens`.
+pub struct LinkMatcher;
+
+impl DelimiterMatcher for LinkMatcher {
+    fn name(&self) -> &str {
+        "link"
+    }
+
+    fn can_start(&self, token: &ScannerToken) -> bool {
+        matches!(token, ScannerToken::RefMarker { .. })
+    }
+
+    fn can_end(&self, token: &ScannerToken) -> bool {
+        matches!(token, ScannerToken::RightParen { .. })
+    }
+
+    fn match_span(&self, tokens: &[ScannerToken], start: usize) -> Option<SpanMatch> {
+        if !matches!(tokens.get(start + 1), Some(ScannerToken::LeftParen { .. })) {
+            return None;
+        }
+        let close = start
+            + 2
+            + tokens[start + 2..]
+                .iter()
+                .position(|token| self.can_end(token))?;
+        let inner_tokens = tokens[start + 2..close].to_vec();
+
+        if inner_tokens.is_empty() || contains_newline(&inner_tokens) {
+            return None;
+        }
+
+        Some(SpanMatch {
+            start,
+            end: close + 1,
+            matcher_name: self.name().to_string(),
+            inner_tokens,
+            full_tokens: tokens[start..=close].to_vec(),
+        })
+    }
+}
+
 // Factory functions for common matchers
 
-/// Create a bold delimiter matcher (matches `*...*`)
+/// Create a bold delimiter matcher (matches `*...*`, `**...**`, `***...***`)
 pub fn bold_matcher() -> GenericDelimiterMatcher {
     GenericDelimiterMatcher::new(
         "bold",
         ScannerToken::is_bold_delimiter,
         ScannerToken::is_bold_delimiter,
     )
+    .with_delimiter_runs()
 }
 
-/// Create an italic delimiter matcher (matches `_..._`)
+/// Create an italic delimiter matcher (matches `_..._`, `__...__`, `___...___`)
 pub fn italic_matcher() -> GenericDelimiterMatcher {
     GenericDelimiterMatcher::new(
         "italic",
         ScannerToken::is_italic_delimiter,
         ScannerToken::is_italic_delimiter,
     )
+    .with_delimiter_runs()
 }
 
-/// Create a code delimiter matcher (matches `` `...` ``)
+/// Create a code delimiter matcher (matches `` `...` ``, ` `` ` inside)
 pub fn code_matcher() -> GenericDelimiterMatcher {
     GenericDelimiterMatcher::new(
         "code",
         ScannerToken::is_code_delimiter,
         ScannerToken::is_code_delimiter,
     )
+    .with_doubled_escape()
 }
 
-/// Create a math delimiter matcher (matches `#...#`)
+/// Create a math delimiter matcher (matches `#...#`, `##` inside)
 pub fn math_matcher() -> GenericDelimiterMatcher {
     GenericDelimiterMatcher::new(
         "math",
         ScannerToken::is_math_delimiter,
         ScannerToken::is_math_delimiter,
     )
+    .with_doubled_escape()
+}
+
+/// Create a link matcher (matches `[display](target)`)
+pub fn link_matcher() -> LinkMatcher {
+    LinkMatcher
 }
 
 /// Create a reference delimiter matcher (matches `[...]`)
@@ -269,6 +458,35 @@ mod tests {
         assert_eq!(span.inner_tokens.len(), 1);
     }
 
+    #[test]
+    fn test_reference_matcher_skips_escaped_close() {
+        let tokens = vec![
+            create_text("["),
+            create_text("a \\"),
+            create_text("]"),
+            create_text(" b"),
+            create_text("]"),
+        ];
+
+        let span = reference_matcher().match_span(&tokens, 0).unwrap();
+        assert_eq!(span.end, 5);
+        assert_eq!(span.inner_tokens.len(), 3);
+    }
+
+    #[test]
+    fn test_reference_matcher_closes_after_escaped_backslash() {
+        let tokens = vec![
+            create_text("["),
+            create_text("a \\\\"),
+            create_text("]"),
+            create_text(" b"),
+            create_text("]"),
+        ];
+
+        let span = reference_matcher().match_span(&tokens, 0).unwrap();
+        assert_eq!(span.end, 3);
+    }
+
     // ============================================================================
     // Unit Tests for GenericDelimiterMatcher Infrastructure
     // ============================================================================
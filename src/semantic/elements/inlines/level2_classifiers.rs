@@ -22,6 +22,7 @@ impl TypeClassifier for FormattingClassifier {
             "italic" => Ok(InlineType::Italic),
             "code" => Ok(InlineType::Code),
             "math" => Ok(InlineType::Math),
+            "link" => Ok(InlineType::Link),
             _ => Err(InlineParseError::InvalidStructure(format!(
                 "Unknown formatting matcher: {}",
                 span.matcher_name
@@ -41,9 +42,17 @@ pub struct ReferenceTypeClassifier {
 
 impl ReferenceTypeClassifier {
     pub fn new() -> Self {
-        Self {
-            classifier: ReferenceClassifier::new(),
-        }
+        Self::with_classifier(ReferenceClassifier::new())
+    }
+
+    /// Classify with `classifier`, which may carry custom handlers
+    pub fn with_classifier(classifier: ReferenceClassifier) -> Self {
+        Self { classifier }
+    }
+
+    /// The reference classifier in use
+    pub fn reference_classifier(&self) -> &ReferenceClassifier {
+        &self.classifier
     }
 }
 
@@ -83,6 +92,7 @@ impl TypeClassifier for ReferenceTypeClassifier {
             SimpleReferenceType::Citation => InlineType::Citation,
             SimpleReferenceType::ToComeTK => InlineType::ToComeTK,
             SimpleReferenceType::File => InlineType::File,
+            SimpleReferenceType::Custom(name) => InlineType::Custom(name),
             SimpleReferenceType::NotSure => InlineType::NotSure,
         };
 
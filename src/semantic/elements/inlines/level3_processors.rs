@@ -6,10 +6,15 @@
 //!
 //! ## Processors
 //!
-//! - `BoldProcessor`: Builds Strong text transforms with nested content
-//! - `ItalicProcessor`: Builds Emphasis text transforms with nested content
+//! - `BoldProcessor`: Builds formatting delimited by asterisks
+//! - `ItalicProcessor`: Builds formatting delimited by underscores
+//!
+//! Both build emphasis for a single delimiter (`*a*`, `_a_`), strong text
+//! for a doubled one (`**a**`, `__a__`) and strong emphasis for a tripled
+//! one (`***a***`).
 //! - `CodeProcessor`: Builds Code text transforms (no nesting)
 //! - `MathProcessor`: Builds Math text transforms (no nesting)
+//! - `LinkProcessor`: Builds `[display](target)` links
 //! - `CitationProcessor`: Parses citation keys and locators
 //! - `FootnoteProcessor`: Builds footnote references
 //! - `SectionProcessor`: Builds section references
@@ -18,42 +23,111 @@
 //! - `TKProcessor`: Builds TK placeholder references
 //! - `NotSureProcessor`: Builds unresolved references
 
+use std::collections::HashMap;
+
 use crate::ast::elements::formatting::inlines::{Inline, Text, TextTransform};
 use crate::ast::elements::references::reference_types::*;
 use crate::cst::{ScannerToken, ScannerTokenSequence};
+use crate::semantic::elements::inlines::level1_matchers::find_closing_run;
 use crate::semantic::elements::inlines::pipeline::{InlineProcessor, InlineType, TypedSpan};
+use crate::semantic::elements::inlines::references::{parse_section_identifier, parse_url_scheme};
 use crate::semantic::elements::inlines::InlineParseError;
 
 /// Context for preventing same-type nesting
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum FormattingContext {
+    None,
     Strong,
     Emphasis,
+    StrongEmphasis,
+}
+
+impl FormattingContext {
+    /// Whether a single `*` or `_` (emphasis) may open here
+    fn allows_single(self) -> bool {
+        matches!(self, Self::None | Self::Strong)
+    }
+
+    /// Whether a run of `run` delimiters may open here
+    fn allows_run(self, run: usize) -> bool {
+        match run {
+            2 => matches!(self, Self::None | Self::Emphasis),
+            _ => self == Self::None,
+        }
+    }
 }
 
-/// Bold/Strong processor - builds TextTransform::Strong with nested content
+/// Bold processor - builds the formatting an asterisk run delimits
+///
+/// `*a*` is emphasis, `**a**` strong and `***a***` strong emphasis.
 pub struct BoldProcessor;
 
 impl InlineProcessor for BoldProcessor {
     fn process(&self, typed_span: &TypedSpan) -> Result<Inline, InlineParseError> {
-        // Recursively parse inner content, preventing nested bold
-        let nested = parse_with_context(&typed_span.span.inner_tokens, FormattingContext::Strong)?;
-
-        Ok(Inline::TextLine(TextTransform::Strong(nested)))
+        Ok(Inline::TextLine(delimited_transform(
+            typed_span.span.delimiter_run(),
+            &typed_span.span.inner_tokens,
+        )?))
     }
 }
 
-/// Italic/Emphasis processor - builds TextTransform::Emphasis with nested content
+/// Italic processor - builds the formatting an underscore run delimits
+///
+/// `_a_` is emphasis, `__a__` strong and `___a___` strong emphasis, like
+/// the asterisk forms.
 pub struct ItalicProcessor;
 
 impl InlineProcessor for ItalicProcessor {
     fn process(&self, typed_span: &TypedSpan) -> Result<Inline, InlineParseError> {
-        // Recursively parse inner content, preventing nested italic
-        let nested =
-            parse_with_context(&typed_span.span.inner_tokens, FormattingContext::Emphasis)?;
+        Ok(Inline::TextLine(delimited_transform(
+            typed_span.span.delimiter_run(),
+            &typed_span.span.inner_tokens,
+        )?))
+    }
+}
+
+/// Emphasis for a single delimiter, strong text for a doubled run and
+/// strong emphasis for a tripled one
+///
+/// The content is parsed in the matching context, so emphasis doesn't
+/// nest in emphasis nor strong in strong.
+fn delimited_transform(
+    run: usize,
+    inner: &[ScannerToken],
+) -> Result<TextTransform, InlineParseError> {
+    match run {
+        0 | 1 => Ok(TextTransform::Emphasis(parse_with_context(
+            inner,
+            FormattingContext::Emphasis,
+        )?)),
+        2 => Ok(TextTransform::Strong(parse_with_context(
+            inner,
+            FormattingContext::Strong,
+        )?)),
+        _ => {
+            let nested = parse_with_context(inner, FormattingContext::StrongEmphasis)?;
+            Ok(TextTransform::Strong(vec![TextTransform::Emphasis(nested)]))
+        }
+    }
+}
 
-        Ok(Inline::TextLine(TextTransform::Emphasis(nested)))
+/// Tokens of code or math content with each doubled delimiter read as one
+///
+/// Text content comes from the tokens, so the second delimiter of each pair
+/// is dropped.
+fn literal_tokens(
+    tokens: &[ScannerToken],
+    is_delimiter: fn(&ScannerToken) -> bool,
+) -> ScannerTokenSequence {
+    let mut literal = Vec::with_capacity(tokens.len());
+    let mut tokens = tokens.iter().peekable();
+    while let Some(token) = tokens.next() {
+        literal.push(token.clone());
+        if is_delimiter(token) {
+            tokens.next_if(|next| is_delimiter(next));
+        }
     }
+    ScannerTokenSequence { tokens: literal }
 }
 
 /// Code processor - builds TextTransform::Code (no nesting allowed)
@@ -62,17 +136,16 @@ pub struct CodeProcessor;
 impl InlineProcessor for CodeProcessor {
     fn process(&self, typed_span: &TypedSpan) -> Result<Inline, InlineParseError> {
         // Code content is literal - no further parsing
-        let content = typed_span
-            .span
-            .inner_tokens
+        let token_sequence = literal_tokens(
+            &typed_span.span.inner_tokens,
+            ScannerToken::is_code_delimiter,
+        );
+        let content = token_sequence
+            .tokens
             .iter()
             .map(|t| t.content())
             .collect::<String>();
 
-        let token_sequence = ScannerTokenSequence {
-            tokens: typed_span.span.inner_tokens.clone(),
-        };
-
         Ok(Inline::TextLine(TextTransform::Code(
             Text::simple_with_tokens(&content, token_sequence),
         )))
@@ -85,17 +158,16 @@ pub struct MathProcessor;
 impl InlineProcessor for MathProcessor {
     fn process(&self, typed_span: &TypedSpan) -> Result<Inline, InlineParseError> {
         // Math content is literal - no further parsing
-        let content = typed_span
-            .span
-            .inner_tokens
+        let token_sequence = literal_tokens(
+            &typed_span.span.inner_tokens,
+            ScannerToken::is_math_delimiter,
+        );
+        let content = token_sequence
+            .tokens
             .iter()
             .map(|t| t.content())
             .collect::<String>();
 
-        let token_sequence = ScannerTokenSequence {
-            tokens: typed_span.span.inner_tokens.clone(),
-        };
-
         Ok(Inline::TextLine(TextTransform::Math(
             Text::simple_with_tokens(&content, token_sequence),
         )))
@@ -168,12 +240,7 @@ impl CitationProcessor {
             None
         };
 
-        Ok(Some(CitationEntry {
-            key,
-            locator,
-            prefix: None,
-            suffix: None,
-        }))
+        Ok(Some(CitationEntry::new(key, locator)))
     }
 }
 
@@ -233,8 +300,40 @@ impl InlineProcessor for FootnoteProcessor {
             .collect::<Vec<_>>()
             .join("");
 
-        // Determine if labeled or naked numerical
-        let reference_target = if let Some(stripped) = content.strip_prefix('^') {
+        // Determine if auto-generated, inline, labeled or naked numerical
+        let reference_target = if content == "^" {
+            // Auto-generated footnote [^]: the registry assigns its id
+            ReferenceTarget::Footnote {
+                footnote: FootnoteTarget {
+                    label: None,
+                    auto_generate: true,
+                    content: None,
+                },
+                raw: format!("[{}]", content),
+                tokens: ScannerTokenSequence {
+                    tokens: typed_span.span.full_tokens.clone(),
+                },
+            }
+        } else if let Some(text) = content.strip_prefix("^:") {
+            // Inline footnote [^: text]: carries its note, the registry assigns its id
+            let text = text.trim();
+            if text.is_empty() {
+                return Err(InlineParseError::InvalidStructure(
+                    "Inline footnote has no content".to_string(),
+                ));
+            }
+            ReferenceTarget::Footnote {
+                footnote: FootnoteTarget {
+                    label: None,
+                    auto_generate: true,
+                    content: Some(text.to_string()),
+                },
+                raw: format!("[{}]", content),
+                tokens: ScannerTokenSequence {
+                    tokens: typed_span.span.full_tokens.clone(),
+                },
+            }
+        } else if let Some(stripped) = content.strip_prefix('^') {
             // Labeled footnote [^label]
             ReferenceTarget::NamedAnchor {
                 anchor: stripped.to_string(),
@@ -276,57 +375,6 @@ impl InlineProcessor for FootnoteProcessor {
 /// Section processor - builds section/session references
 pub struct SectionProcessor;
 
-impl SectionProcessor {
-    /// Parse section identifier from content
-    fn parse_identifier(&self, content: &str) -> SectionIdentifier {
-        let content = content.trim();
-
-        // Check for negative indexing
-        let (negative_index, numeric_content) = if let Some(stripped) = content.strip_prefix('-') {
-            (true, stripped)
-        } else {
-            (false, content)
-        };
-
-        // Try to parse as numeric levels (e.g., "1.2.3")
-        if let Ok(levels) = self.parse_numeric_levels(numeric_content) {
-            if !levels.is_empty() {
-                return SectionIdentifier::Numeric {
-                    levels,
-                    negative_index,
-                };
-            }
-        }
-
-        // Fall back to named identifier
-        SectionIdentifier::Named {
-            name: content.to_string(),
-        }
-    }
-
-    /// Parse numeric section levels from string
-    fn parse_numeric_levels(&self, content: &str) -> Result<Vec<u32>, InlineParseError> {
-        if content.is_empty() {
-            return Ok(vec![]);
-        }
-
-        let parts: Vec<&str> = content.split('.').collect();
-        let mut levels = Vec::new();
-
-        for part in parts {
-            if let Ok(level) = part.parse::<u32>() {
-                levels.push(level);
-            } else {
-                return Err(InlineParseError::InvalidStructure(
-                    "Invalid numeric section level".to_string(),
-                ));
-            }
-        }
-
-        Ok(levels)
-    }
-}
-
 impl InlineProcessor for SectionProcessor {
     fn process(&self, typed_span: &TypedSpan) -> Result<Inline, InlineParseError> {
         // Extract content
@@ -343,7 +391,7 @@ impl InlineProcessor for SectionProcessor {
 
         let identifier = if let Some(stripped) = content.strip_prefix('#') {
             // Parse numeric section reference: #3, #2.1, #-1.2
-            self.parse_identifier(stripped)
+            parse_section_identifier(stripped)?
         } else {
             // Named section reference: local-section
             SectionIdentifier::Named {
@@ -371,6 +419,41 @@ impl InlineProcessor for SectionProcessor {
     }
 }
 
+/// Link processor - builds Inline::Link from `[display](target)`
+///
+/// The display text is the bracketed token and is kept as it is; the
+/// target is the text between the parens.
+pub struct LinkProcessor;
+
+impl InlineProcessor for LinkProcessor {
+    fn process(&self, typed_span: &TypedSpan) -> Result<Inline, InlineParseError> {
+        let span = &typed_span.span;
+        let display = span.full_tokens[0].clone();
+        let display_text = display.content().to_string();
+        let target = span
+            .inner_tokens
+            .iter()
+            .map(|token| token.content())
+            .collect::<String>();
+
+        Ok(Inline::Link {
+            target: target.trim().to_string(),
+            content: vec![Inline::TextLine(TextTransform::Identity(
+                Text::simple_with_tokens(
+                    &display_text,
+                    ScannerTokenSequence {
+                        tokens: vec![display],
+                    },
+                ),
+            ))],
+            attributes: HashMap::new(),
+            tokens: ScannerTokenSequence {
+                tokens: span.full_tokens.clone(),
+            },
+        })
+    }
+}
+
 /// URL processor - builds URL references
 pub struct UrlProcessor;
 
@@ -396,9 +479,11 @@ impl InlineProcessor for UrlProcessor {
         } else {
             (content.clone(), None)
         };
+        let scheme = parse_url_scheme(&url)?;
 
         let reference_target = ReferenceTarget::Url {
             url,
+            scheme,
             fragment,
             raw: format!("[{}]", content),
             tokens: ScannerTokenSequence {
@@ -562,37 +647,36 @@ fn parse_formatting_recursive(
     while i < tokens.len() {
         let token = &tokens[i];
 
-        if token.is_bold_delimiter() && context != FormattingContext::Strong {
-            if let Some(j) = find_closing_token(tokens, i + 1, |t| t.is_bold_delimiter()) {
-                let content_tokens = &tokens[i + 1..j];
-                let nested = parse_formatting_recursive(content_tokens, FormattingContext::Strong)?;
-                transforms.push(TextTransform::Strong(nested));
-                i = j + 1;
+        let run = find_closing_run(tokens, i, ScannerToken::is_bold_delimiter)
+            .or_else(|| find_closing_run(tokens, i, ScannerToken::is_italic_delimiter))
+            .filter(|&(run, _)| context.allows_run(run));
+        let single = token.is_bold_delimiter() || token.is_italic_delimiter();
+        if let Some((run, close)) = run {
+            transforms.push(delimited_transform(run, &tokens[i + run..close])?);
+            i = close + run;
+        } else if single && context.allows_single() {
+            // The span closes on the same delimiter it opened with
+            let closes = if token.is_bold_delimiter() {
+                ScannerToken::is_bold_delimiter
             } else {
-                transforms.push(token_to_identity(token));
-                i += 1;
-            }
-        } else if token.is_italic_delimiter() && context != FormattingContext::Emphasis {
-            if let Some(j) = find_closing_token(tokens, i + 1, |t| t.is_italic_delimiter()) {
-                let content_tokens = &tokens[i + 1..j];
-                let nested =
-                    parse_formatting_recursive(content_tokens, FormattingContext::Emphasis)?;
-                transforms.push(TextTransform::Emphasis(nested));
+                ScannerToken::is_italic_delimiter
+            };
+            if let Some(j) = find_closing_token(tokens, i + 1, closes) {
+                transforms.push(delimited_transform(1, &tokens[i + 1..j])?);
                 i = j + 1;
             } else {
                 transforms.push(token_to_identity(token));
                 i += 1;
             }
         } else if token.is_code_delimiter() {
-            if let Some(j) = find_closing_token(tokens, i + 1, |t| t.is_code_delimiter()) {
-                let content_tokens = &tokens[i + 1..j];
-                let text = content_tokens
+            if let Some(j) = find_literal_closing(tokens, i + 1, ScannerToken::is_code_delimiter) {
+                let token_sequence =
+                    literal_tokens(&tokens[i + 1..j], ScannerToken::is_code_delimiter);
+                let text = token_sequence
+                    .tokens
                     .iter()
                     .map(|t| t.content())
                     .collect::<String>();
-                let token_sequence = ScannerTokenSequence {
-                    tokens: content_tokens.to_vec(),
-                };
                 transforms.push(TextTransform::Code(Text::simple_with_tokens(
                     &text,
                     token_sequence,
@@ -603,15 +687,14 @@ fn parse_formatting_recursive(
                 i += 1;
             }
         } else if token.is_math_delimiter() {
-            if let Some(j) = find_closing_token(tokens, i + 1, |t| t.is_math_delimiter()) {
-                let content_tokens = &tokens[i + 1..j];
-                let text = content_tokens
+            if let Some(j) = find_literal_closing(tokens, i + 1, ScannerToken::is_math_delimiter) {
+                let token_sequence =
+                    literal_tokens(&tokens[i + 1..j], ScannerToken::is_math_delimiter);
+                let text = token_sequence
+                    .tokens
                     .iter()
                     .map(|t| t.content())
                     .collect::<String>();
-                let token_sequence = ScannerTokenSequence {
-                    tokens: content_tokens.to_vec(),
-                };
                 transforms.push(TextTransform::Math(Text::simple_with_tokens(
                     &text,
                     token_sequence,
@@ -640,6 +723,25 @@ where
         .map(|pos| start + pos)
 }
 
+/// Closing delimiter of code or math content, skipping doubled delimiters
+fn find_literal_closing(
+    tokens: &[ScannerToken],
+    start: usize,
+    is_delimiter: fn(&ScannerToken) -> bool,
+) -> Option<usize> {
+    let mut index = start;
+    while index < tokens.len() {
+        if !is_delimiter(&tokens[index]) {
+            index += 1;
+        } else if tokens.get(index + 1).is_some_and(is_delimiter) {
+            index += 2;
+        } else {
+            return Some(index);
+        }
+    }
+    None
+}
+
 fn token_to_identity(token: &ScannerToken) -> TextTransform {
     let token_sequence = ScannerTokenSequence {
         tokens: vec![token.clone()],
@@ -654,13 +756,16 @@ pub fn get_processor(inline_type: &InlineType) -> Box<dyn InlineProcessor> {
         InlineType::Italic => Box::new(ItalicProcessor),
         InlineType::Code => Box::new(CodeProcessor),
         InlineType::Math => Box::new(MathProcessor),
+        InlineType::Link => Box::new(LinkProcessor),
         InlineType::Citation => Box::new(CitationProcessor),
         InlineType::Footnote => Box::new(FootnoteProcessor),
         InlineType::Section => Box::new(SectionProcessor),
         InlineType::Url => Box::new(UrlProcessor),
         InlineType::File => Box::new(FileProcessor),
         InlineType::ToComeTK => Box::new(TKProcessor),
-        InlineType::NotSure => Box::new(NotSureProcessor),
+        // Custom references are parsed by the pipeline, which holds their
+        // handlers; on their own they are as unresolved as NotSure
+        InlineType::Custom(_) | InlineType::NotSure => Box::new(NotSureProcessor),
     }
 }
 
@@ -855,6 +960,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_footnote_processor_auto() {
+        let processor = FootnoteProcessor;
+        let typed_span = create_typed_span(InlineType::Footnote, vec![create_text("^")]);
+
+        match processor.process(&typed_span) {
+            Ok(Inline::Reference(reference)) => match &reference.target {
+                ReferenceTarget::Footnote { footnote, .. } => {
+                    assert_eq!(footnote.label, None);
+                    assert!(footnote.auto_generate);
+                }
+                _ => panic!("Expected Footnote target"),
+            },
+            other => panic!("Expected Reference inline, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_footnote_processor_inline() {
+        let processor = FootnoteProcessor;
+        let typed_span =
+            create_typed_span(InlineType::Footnote, vec![create_text("^: the note text")]);
+
+        match processor.process(&typed_span) {
+            Ok(Inline::Reference(reference)) => match &reference.target {
+                ReferenceTarget::Footnote { footnote, .. } => {
+                    assert_eq!(footnote.label, None);
+                    assert!(footnote.auto_generate);
+                    assert_eq!(footnote.content.as_deref(), Some("the note text"));
+                }
+                _ => panic!("Expected Footnote target"),
+            },
+            other => panic!("Expected Reference inline, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_footnote_processor_inline_without_content_error() {
+        let processor = FootnoteProcessor;
+        let typed_span = create_typed_span(InlineType::Footnote, vec![create_text("^:")]);
+
+        let result = processor.process(&typed_span);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_footnote_processor_invalid_format_error() {
         let processor = FootnoteProcessor;
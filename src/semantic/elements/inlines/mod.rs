@@ -29,8 +29,8 @@
 //! ## Element Categories
 //!
 //! ### 1. Formatting Elements
-//! - **Strong (Bold)**: `*content*` - High-priority information
-//! - **Emphasis (Italic)**: `_content_` - Stressed or distinguished content
+//! - **Strong (Bold)**: `**content**` - High-priority information
+//! - **Emphasis (Italic)**: `*content*` or `_content_` - Stressed or distinguished content
 //! - **Code**: `` `content` `` - Technical content and literal text
 //! - **Math**: `#content#` - Mathematical expressions
 //!
@@ -49,8 +49,8 @@
 //! <inline-element> = <token>content<token>
 //!
 //! Formatting Spans:
-//! <bold-span> = <asterisk> <text-content> <asterisk>
-//! <italic-span> = <underscore> <text-content> <underscore>
+//! <bold-span> = <asterisk> <asterisk> <text-content> <asterisk> <asterisk>
+//! <italic-span> = <asterisk> <text-content> <asterisk> | <underscore> <text-content> <underscore>
 //! <code-span> = <backtick> <text-content> <backtick>
 //! <math-span> = <hash> <text-content> <hash>
 //!
@@ -106,9 +106,9 @@
 //!
 //! ### Nesting Rules
 //! ```text
-//! Valid: *strong with `code` inside*
+//! Valid: **strong with `code` inside**
 //! Valid: _emphasis with #math# inside_
-//! Invalid: *strong with *nested strong* inside*
+//! Invalid: **strong with **nested strong** inside**
 //! Invalid: `code with `nested code` inside`
 //! ```
 //!
@@ -208,8 +208,8 @@ pub fn parse_inlines(
 /// * `Result<Vec<crate::ast::elements::formatting::inlines::TextTransform>, InlineParseError>`
 ///
 /// # Formatting Types
-/// * **Strong (Bold)**: `*content*` - Single asterisk tokens
-/// * **Emphasis (Italic)**: `_content_` - Single underscore tokens  
+/// * **Strong (Bold)**: `**content**` - Doubled asterisk or underscore tokens
+/// * **Emphasis (Italic)**: `*content*`, `_content_` - Single asterisk or underscore tokens
 /// * **Code**: `` `content` `` - Single backtick tokens
 /// * **Math**: `#content#` - Single hash tokens
 pub fn parse_formatting(
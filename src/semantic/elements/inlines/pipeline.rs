@@ -24,8 +24,13 @@
 //! - **Extensible**: New inline types added by implementing traits
 //! - **Maintainable**: Clear separation of concerns across levels
 
+use std::collections::HashMap;
+
 use crate::ast::elements::formatting::inlines::{Inline, Text, TextTransform};
+use crate::ast::elements::references::reference_types::{ReferenceClassifier, ReferenceTarget};
+use crate::ast::elements::references::Reference;
 use crate::cst::{ScannerToken, ScannerTokenSequence};
+use crate::semantic::elements::inlines::level2_classifiers::ReferenceTypeClassifier;
 use crate::semantic::elements::inlines::InlineParseError;
 
 /// Level 1: Matched span with delimiter information
@@ -51,6 +56,14 @@ pub struct SpanMatch {
     pub full_tokens: Vec<ScannerToken>,
 }
 
+impl SpanMatch {
+    /// Delimiters on each side of a formatting span: 1 for `*a*`, 2 for
+    /// `**a**`, 3 for `***a***`
+    pub fn delimiter_run(&self) -> usize {
+        (self.full_tokens.len() - self.inner_tokens.len()) / 2
+    }
+}
+
 /// Level 1: Delimiter Matcher trait
 ///
 /// Implementations scan a token stream and identify spans enclosed by
@@ -73,6 +86,29 @@ pub trait DelimiterMatcher {
 
     /// Check if this matcher should process the token at this position
     fn can_start(&self, token: &ScannerToken) -> bool;
+
+    /// Check if the token closes a span this matcher opened
+    ///
+    /// Defaults to `can_start`, for matchers whose spans open and close on
+    /// the same delimiter.
+    fn can_end(&self, token: &ScannerToken) -> bool {
+        self.can_start(token)
+    }
+
+    /// Whether a doubled delimiter opens a span of its own
+    ///
+    /// Matchers that read runs write emphasis with a single delimiter and
+    /// strong text with a doubled one.
+    fn reads_delimiter_runs(&self) -> bool {
+        false
+    }
+}
+
+/// Opening and closing delimiter of a matcher, as written in source
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DelimiterSpec {
+    pub open: String,
+    pub close: String,
 }
 
 /// Level 2: Typed span with semantic information
@@ -100,6 +136,9 @@ pub enum InlineType {
     Code,
     Math,
 
+    /// `[display](target)` link
+    Link,
+
     // Reference elements (classified by ReferenceClassifier)
     Citation,
     Footnote,
@@ -107,6 +146,8 @@ pub enum InlineType {
     Url,
     File,
     ToComeTK,
+    /// Claimed by the custom reference handler of this name
+    Custom(String),
     NotSure,
 }
 
@@ -130,6 +171,83 @@ pub trait InlineProcessor {
     fn process(&self, typed_span: &TypedSpan) -> Result<Inline, InlineParseError>;
 }
 
+/// Conflict between delimiter matchers in a proposed pipeline
+///
+/// Reported by [`InlinePipeline::validate_matchers`]. Neither kind is an
+/// error at parse time: the first matcher simply wins. They usually mean a
+/// custom inline configuration doesn't do what its author expects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatcherConflict {
+    /// Two matchers share a name, so their spans dispatch identically
+    DuplicateName { name: String },
+
+    /// Two matchers start on the same delimiter; the later one never sees it
+    SharedStartDelimiter {
+        delimiter: String,
+        first: String,
+        shadowed: String,
+    },
+}
+
+impl std::fmt::Display for MatcherConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatcherConflict::DuplicateName { name } => {
+                write!(f, "more than one matcher is named '{}'", name)
+            }
+            MatcherConflict::SharedStartDelimiter {
+                delimiter,
+                first,
+                shadowed,
+            } => write!(
+                f,
+                "'{}' shadows '{}' on delimiter '{}'",
+                first, shadowed, delimiter
+            ),
+        }
+    }
+}
+
+/// Tokens a delimiter matcher could plausibly start on
+///
+/// Matchers are predicates, so overlap is found by probing them with every
+/// dedicated delimiter token and every single ASCII punctuation text token.
+fn delimiter_probes() -> Vec<ScannerToken> {
+    use crate::cst::{Position, SourceSpan};
+
+    let span = || SourceSpan {
+        start: Position { row: 0, column: 0 },
+        end: Position { row: 0, column: 1 },
+    };
+
+    let mut probes = vec![
+        ScannerToken::BoldDelimiter { span: span() },
+        ScannerToken::ItalicDelimiter { span: span() },
+        ScannerToken::CodeDelimiter { span: span() },
+        ScannerToken::MathDelimiter { span: span() },
+        ScannerToken::TxxtMarker { span: span() },
+        ScannerToken::Dash { span: span() },
+        ScannerToken::Period { span: span() },
+        ScannerToken::LeftBracket { span: span() },
+        ScannerToken::RightBracket { span: span() },
+        ScannerToken::AtSign { span: span() },
+        ScannerToken::LeftParen { span: span() },
+        ScannerToken::RightParen { span: span() },
+        ScannerToken::Colon { span: span() },
+        ScannerToken::Equals { span: span() },
+        ScannerToken::Comma { span: span() },
+    ];
+    probes.extend(
+        (b'!'..=b'~')
+            .filter(u8::is_ascii_punctuation)
+            .map(|c| ScannerToken::Text {
+                content: (c as char).to_string(),
+                span: span(),
+            }),
+    );
+    probes
+}
+
 /// Multi-level inline parsing pipeline
 ///
 /// Orchestrates the three-level parsing process:
@@ -139,6 +257,9 @@ pub trait InlineProcessor {
 pub struct InlinePipeline {
     /// Level 1: Delimiter matchers
     matchers: Vec<Box<dyn DelimiterMatcher>>,
+
+    /// Level 2: Classifier for reference spans
+    references: ReferenceTypeClassifier,
 }
 
 impl InlinePipeline {
@@ -146,6 +267,7 @@ impl InlinePipeline {
     pub fn new() -> Self {
         Self {
             matchers: Vec::new(),
+            references: ReferenceTypeClassifier::new(),
         }
     }
 
@@ -155,6 +277,100 @@ impl InlinePipeline {
         self
     }
 
+    /// Classify reference spans with `classifier` instead of the default
+    ///
+    /// References its custom handlers claim are parsed by
+    /// `parse_reference_with` into `ReferenceTarget::Custom`.
+    pub fn with_reference_classifier(mut self, classifier: ReferenceClassifier) -> Self {
+        self.references = ReferenceTypeClassifier::with_classifier(classifier);
+        self
+    }
+
+    /// Report every conflict among this pipeline's matchers
+    pub fn validate(&self) -> Vec<MatcherConflict> {
+        Self::validate_matchers(&self.matchers)
+    }
+
+    /// Report every conflict in a proposed set of matchers
+    ///
+    /// Checks the whole set at once, without building a pipeline, so tools
+    /// that let users configure custom inlines can show all problems
+    /// together. Matchers are considered in pipeline priority order.
+    pub fn validate_matchers(matchers: &[Box<dyn DelimiterMatcher>]) -> Vec<MatcherConflict> {
+        let mut conflicts = Vec::new();
+
+        for (index, matcher) in matchers.iter().enumerate() {
+            let name = matcher.name();
+            let earlier = &matchers[..index];
+
+            if earlier.iter().any(|other| other.name() == name)
+                && !conflicts.contains(&MatcherConflict::DuplicateName {
+                    name: name.to_string(),
+                })
+            {
+                conflicts.push(MatcherConflict::DuplicateName {
+                    name: name.to_string(),
+                });
+            }
+
+            for probe in delimiter_probes() {
+                if !matcher.can_start(&probe) {
+                    continue;
+                }
+                if let Some(first) = earlier.iter().find(|other| other.can_start(&probe)) {
+                    let conflict = MatcherConflict::SharedStartDelimiter {
+                        delimiter: probe.content().to_string(),
+                        first: first.name().to_string(),
+                        shadowed: name.to_string(),
+                    };
+                    if !conflicts.contains(&conflict) {
+                        conflicts.push(conflict);
+                    }
+                }
+            }
+        }
+
+        conflicts
+    }
+
+    /// Name and delimiters of every matcher, in priority order
+    ///
+    /// Matchers are predicates, so their delimiters are found by probing
+    /// them the same way `validate_matchers` does. A matcher that opens or
+    /// closes on nothing the probes cover is left out. One that reads
+    /// delimiter runs is reported as what it builds: `strong` for the
+    /// doubled delimiter, then `emphasis` for the single one.
+    pub fn delimiters(&self) -> Vec<(&str, DelimiterSpec)> {
+        let probes = delimiter_probes();
+        let written = |accepts: &dyn Fn(&ScannerToken) -> bool| {
+            probes
+                .iter()
+                .find(|probe| accepts(probe))
+                .map(|probe| probe.content().to_string())
+        };
+
+        let mut delimiters = Vec::new();
+        for matcher in &self.matchers {
+            let (Some(open), Some(close)) = (
+                written(&|token| matcher.can_start(token)),
+                written(&|token| matcher.can_end(token)),
+            ) else {
+                continue;
+            };
+            if matcher.reads_delimiter_runs() {
+                let strong = DelimiterSpec {
+                    open: open.repeat(2),
+                    close: close.repeat(2),
+                };
+                delimiters.push(("strong", strong));
+                delimiters.push(("emphasis", DelimiterSpec { open, close }));
+            } else {
+                delimiters.push((matcher.name(), DelimiterSpec { open, close }));
+            }
+        }
+        delimiters
+    }
+
     /// Parse a token stream into inline elements
     ///
     /// This is the main entry point that orchestrates all three levels:
@@ -168,17 +384,26 @@ impl InlinePipeline {
         while i < tokens.len() {
             // Level 1: Try to match delimiters
             if let Some((span, matcher_name)) = self.try_match_at(tokens, i) {
+                let location = span_location(&span.full_tokens);
+
                 // Level 2: Classify the matched span
-                let typed_span = self.classify_span(span, &matcher_name)?;
+                let typed_span = self
+                    .classify_span(span, &matcher_name)
+                    .inspect_err(|error| warn_span_error(&matcher_name, &location, error))?;
 
                 // Save end position before moving typed_span
                 let next_i = typed_span.span.end;
 
                 // Level 3: Process into final AST
-                let inline = self.process_span(typed_span)?;
+                let inline = self
+                    .process_span(typed_span)
+                    .inspect_err(|error| warn_span_error(&matcher_name, &location, error))?;
 
                 result.push(inline);
                 i = next_i;
+            } else if let Some(inline) = self.custom_reference(&tokens[i]) {
+                result.push(inline);
+                i += 1;
             } else {
                 // No match - treat as plain text
                 let text_inline = self.token_to_text(&tokens[i]);
@@ -190,6 +415,61 @@ impl InlinePipeline {
         Ok(result)
     }
 
+    /// Parse a token stream, keeping it as plain text if parsing fails
+    ///
+    /// Returns the inlines together with the error that forced the fallback,
+    /// if any, so callers can report it instead of losing the content.
+    pub fn parse_or_plain(
+        &self,
+        tokens: &[ScannerToken],
+    ) -> (Vec<Inline>, Option<InlineParseError>) {
+        match self.parse(tokens) {
+            Ok(inlines) => (inlines, None),
+            Err(error) => {
+                let plain = tokens.iter().map(|token| self.token_to_text(token));
+                (plain.collect(), Some(error))
+            }
+        }
+    }
+
+    /// Delimiter tokens that no span claims, with the matcher they belong to
+    ///
+    /// Runs the same Level 1 scan as `parse`, descending into bold and
+    /// italic spans since their content is parsed again. Code, math and
+    /// reference content is literal and is not looked into. The parse
+    /// output is unaffected; these tokens end up as plain text there.
+    pub fn unmatched_delimiters<'t>(
+        &self,
+        tokens: &'t [ScannerToken],
+    ) -> Vec<(&str, &'t ScannerToken)> {
+        let mut unmatched = Vec::new();
+        let mut i = 0;
+
+        while i < tokens.len() {
+            if let Some((span, matcher_name)) = self.try_match_at(tokens, i) {
+                if matches!(matcher_name.as_str(), "bold" | "italic") {
+                    let run = span.delimiter_run();
+                    unmatched.extend(
+                        self.unmatched_delimiters(&tokens[span.start + run..span.end - run]),
+                    );
+                }
+                i = span.end;
+                continue;
+            }
+            let token = &tokens[i];
+            if let Some(matcher) = self
+                .matchers
+                .iter()
+                .find(|matcher| matcher.can_start(token) || matcher.can_end(token))
+            {
+                unmatched.push((matcher.name(), token));
+            }
+            i += 1;
+        }
+
+        unmatched
+    }
+
     /// Level 1: Try to match a delimiter at the given position
     fn try_match_at(&self, tokens: &[ScannerToken], start: usize) -> Option<(SpanMatch, String)> {
         for matcher in &self.matchers {
@@ -208,14 +488,11 @@ impl InlinePipeline {
         span: SpanMatch,
         _matcher_name: &str,
     ) -> Result<TypedSpan, InlineParseError> {
-        use crate::semantic::elements::inlines::level2_classifiers::{
-            FormattingClassifier, ReferenceTypeClassifier,
-        };
+        use crate::semantic::elements::inlines::level2_classifiers::FormattingClassifier;
 
         let inline_type = if span.matcher_name == "reference" {
             // Use reference classifier for references
-            let classifier = ReferenceTypeClassifier::new();
-            classifier.classify(&span)?
+            self.references.classify(&span)?
         } else {
             // Use formatting classifier for formatting elements
             let classifier = FormattingClassifier;
@@ -228,11 +505,39 @@ impl InlinePipeline {
     /// Level 3: Process a typed span into AST
     fn process_span(&self, typed_span: TypedSpan) -> Result<Inline, InlineParseError> {
         use crate::semantic::elements::inlines::level3_processors::get_processor;
+        use crate::semantic::elements::inlines::references::parse_reference_with;
+
+        // Only the pipeline's classifier knows its custom handlers
+        if let InlineType::Custom(_) = typed_span.inline_type {
+            return parse_reference_with(
+                &typed_span.span.full_tokens,
+                self.references.reference_classifier(),
+            );
+        }
 
         let processor = get_processor(&typed_span.inline_type);
         processor.process(&typed_span)
     }
 
+    /// Parse a lexed reference that a custom handler claims
+    ///
+    /// The lexer reads `[...]` as a single `RefMarker`, which no delimiter
+    /// matcher spans; built-in reference types stay text, but a registered
+    /// convention is what its author asked for, so it becomes a reference.
+    fn custom_reference(&self, token: &ScannerToken) -> Option<Inline> {
+        use crate::ast::elements::references::SimpleReferenceType;
+        use crate::semantic::elements::inlines::references::parse_reference_with;
+
+        let ScannerToken::RefMarker { content, .. } = token else {
+            return None;
+        };
+        let classifier = self.references.reference_classifier();
+        if !matches!(classifier.classify(content), SimpleReferenceType::Custom(_)) {
+            return None;
+        }
+        parse_reference_with(std::slice::from_ref(token), classifier).ok()
+    }
+
     /// Convert a token to plain text inline
     fn token_to_text(&self, token: &ScannerToken) -> Inline {
         let token_sequence = ScannerTokenSequence {
@@ -247,6 +552,27 @@ impl InlinePipeline {
     }
 }
 
+/// Source range of a matched span, as `row:column-row:column`
+fn span_location(tokens: &[ScannerToken]) -> String {
+    match (tokens.first(), tokens.last()) {
+        (Some(first), Some(last)) => {
+            let (start, end) = (&first.span().start, &last.span().end);
+            format!("{}:{}-{}:{}", start.row, start.column, end.row, end.column)
+        }
+        _ => "?".to_string(),
+    }
+}
+
+/// Report a span that failed to classify or process
+fn warn_span_error(matcher_name: &str, location: &str, error: &InlineParseError) {
+    log::warn!(
+        "Inline parsing error in {} span at {}: {}",
+        matcher_name,
+        location,
+        error
+    );
+}
+
 impl Default for InlinePipeline {
     fn default() -> Self {
         Self::new()
@@ -259,15 +585,17 @@ impl Default for InlinePipeline {
 /// correct priority order:
 /// 1. Code (highest priority - prevents conflicts)
 /// 2. Math
-/// 3. References (all types: citations, footnotes, etc.)
-/// 4. Bold
-/// 5. Italic
+/// 3. Links (ahead of references, which share their brackets)
+/// 4. References (all types: citations, footnotes, etc.)
+/// 5. Bold
+/// 6. Italic
 pub fn create_standard_pipeline() -> InlinePipeline {
     use crate::semantic::elements::inlines::level1_matchers::*;
 
     InlinePipeline::new()
         .with_matcher(Box::new(code_matcher()))
         .with_matcher(Box::new(math_matcher()))
+        .with_matcher(Box::new(link_matcher()))
         .with_matcher(Box::new(reference_matcher()))
         .with_matcher(Box::new(bold_matcher()))
         .with_matcher(Box::new(italic_matcher()))
@@ -285,16 +613,39 @@ pub fn inlines_to_text_transforms(inlines: Vec<Inline>) -> Vec<TextTransform> {
         .into_iter()
         .map(|inline| match inline {
             Inline::TextLine(transform) => transform,
+            Inline::Reference(Reference {
+                target:
+                    ReferenceTarget::Custom {
+                        kind, target, raw, ..
+                    },
+                tokens,
+                ..
+            }) => {
+                // Keep what the handler resolved, which the tokens don't hold
+                TextTransform::Custom {
+                    name: "reference".to_string(),
+                    parameters: HashMap::from([
+                        ("kind".to_string(), kind),
+                        ("target".to_string(), target),
+                    ]),
+                    content: vec![TextTransform::Identity(Text::simple_with_tokens(
+                        &raw, tokens,
+                    ))],
+                }
+            }
             Inline::Reference(reference) => {
                 // Convert reference to plain text for now
                 // Eventually ParagraphBlock should support Vec<Inline>
                 let text = reference.target.display_text();
                 TextTransform::Identity(Text::simple_with_tokens(&text, reference.tokens))
             }
-            Inline::Link { target, tokens, .. } => {
-                // Convert link to plain text for now
-                TextTransform::Identity(Text::simple_with_tokens(&target, tokens))
-            }
+            Inline::Link {
+                target, content, ..
+            } => TextTransform::Custom {
+                name: "link".to_string(),
+                parameters: HashMap::from([("target".to_string(), target)]),
+                content: inlines_to_text_transforms(content),
+            },
             Inline::Custom { name, tokens, .. } => {
                 // Convert custom inline to plain text
                 TextTransform::Identity(Text::simple_with_tokens(&name, tokens))
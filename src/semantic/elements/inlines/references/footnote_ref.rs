@@ -31,9 +31,10 @@
 //! From [`docs/specs/elements/references/references-general.txxt`]:
 //!
 //! ```text
-//! <footnote-ref> = <footnote-naked> | <footnote-labeled>
+//! <footnote-ref> = <footnote-naked> | <footnote-labeled> | <footnote-auto>
 //! <footnote-naked> = <left-bracket> <digit>+ <right-bracket>
 //! <footnote-labeled> = <left-bracket> <caret> <footnote-label> <right-bracket>
+//! <footnote-auto> = <left-bracket> <caret> <right-bracket>
 //! <footnote-label> = <label-chars>+
 //! ```
 //!
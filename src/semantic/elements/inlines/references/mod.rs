@@ -86,6 +86,7 @@
 //!     │   │   │   └── anchor: Option<String>
 //!     │   │   └── UrlTarget
 //!     │   │       ├── url: String
+//!     │   │       ├── scheme: Option<String>
 //!     │   │       └── display_text: Option<String>
 //!     │   ├── content: Option<Vec<Inline>>
 //!     │   └── tokens: ScannerTokenSequence
@@ -164,7 +165,9 @@ use crate::semantic::elements::inlines::InlineParseError;
 /// Extract reference content from bracketed tokens
 ///
 /// Removes the opening and closing brackets and returns the inner content.
-/// This is used by all reference type parsers.
+/// This is used by all reference type parsers. Escaped brackets and
+/// backslashes (`\]`, `\[`, `\\`) are unescaped. The lexer's `RefMarker`
+/// token, which holds the content of a whole reference, is accepted too.
 ///
 /// # Arguments
 /// * `tokens` - Sequence of tokens containing bracketed reference
@@ -174,6 +177,11 @@ use crate::semantic::elements::inlines::InlineParseError;
 fn extract_reference_content(
     tokens: &[crate::cst::ScannerToken],
 ) -> Result<String, InlineParseError> {
+    // The lexer has already undone escapes in a reference marker's content
+    if let [crate::cst::ScannerToken::RefMarker { content, .. }] = tokens {
+        return Ok(content.clone());
+    }
+
     if tokens.len() < 3 {
         return Err(InlineParseError::InvalidStructure(
             "Reference must have at least opening bracket, content, and closing bracket"
@@ -214,6 +222,7 @@ fn extract_reference_content(
         })
         .collect::<Vec<_>>()
         .join("");
+    let content = unescape_brackets(&content);
 
     if content.trim().is_empty() {
         return Err(InlineParseError::EmptyContent(
@@ -224,6 +233,28 @@ fn extract_reference_content(
     Ok(content)
 }
 
+/// Drop the backslash from `\[`, `\]` and `\\`; other backslashes are kept
+fn unescape_brackets(content: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch == '\\' {
+            if let Some(&next) = chars
+                .peek()
+                .filter(|&&next| matches!(next, '[' | ']' | '\\'))
+            {
+                chars.next();
+                result.push(next);
+                continue;
+            }
+        }
+        result.push(ch);
+    }
+
+    result
+}
+
 /// Parse citation entries from citation content
 ///
 /// Handles formats like "@key1; @key2, p. 123" and extracts individual citations.
@@ -302,24 +333,23 @@ fn parse_single_citation(citation_str: &str) -> Result<Option<CitationEntry>, In
         None
     };
 
-    Ok(Some(CitationEntry {
-        key,
-        locator,
-        prefix: None,
-        suffix: None,
-    }))
+    Ok(Some(CitationEntry::new(key, locator)))
 }
 
 /// Parse section identifier from section reference content
 ///
 /// Handles numeric formats like "3", "2.1", "-1.2" and mixed formats.
+/// Content made only of digits and dots is numeric and must be well-formed
+/// (see [`parse_numeric_levels`]); anything else is a named identifier.
 ///
 /// # Arguments
 /// * `content` - Section reference content (after # symbol)
 ///
 /// # Returns
-/// * `SectionIdentifier` - Parsed section identifier
-fn parse_section_identifier(content: &str) -> SectionIdentifier {
+/// * `Result<SectionIdentifier, InlineParseError>` - Parsed section identifier
+pub(crate) fn parse_section_identifier(
+    content: &str,
+) -> Result<SectionIdentifier, InlineParseError> {
     let content = content.trim();
 
     // Check for negative indexing
@@ -329,48 +359,59 @@ fn parse_section_identifier(content: &str) -> SectionIdentifier {
         (false, content)
     };
 
-    // Try to parse as numeric levels (e.g., "1.2.3")
-    if let Ok(levels) = parse_numeric_levels(numeric_content) {
-        if !levels.is_empty() {
-            return SectionIdentifier::Numeric {
-                levels,
-                negative_index,
-            };
-        }
+    // Parse as numeric levels (e.g., "1.2.3") when it looks numeric
+    let looks_numeric = numeric_content.chars().any(|c| c.is_ascii_digit())
+        && numeric_content
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == '.');
+    if looks_numeric {
+        let levels = parse_numeric_levels(numeric_content)?;
+        return Ok(SectionIdentifier::Numeric {
+            levels,
+            negative_index,
+        });
     }
 
     // Fall back to named identifier
-    SectionIdentifier::Named {
+    Ok(SectionIdentifier::Named {
         name: content.to_string(),
-    }
+    })
 }
 
 /// Parse numeric section levels from string
 ///
-/// Converts "1.2.3" to vec![1, 2, 3].
+/// Converts "1.2.3" to vec![1, 2, 3]. A single trailing dot is allowed and
+/// ignored, so "1.2." (how session numbers are written in titles) is also
+/// vec![1, 2]. Leading or doubled dots (".1", "1..2") leave an empty level
+/// and are an error rather than a silent fallback to a named identifier.
 ///
 /// # Arguments
 /// * `content` - Numeric content string
 ///
 /// # Returns
 /// * `Result<Vec<u32>, InlineParseError>` - Parsed numeric levels
-fn parse_numeric_levels(content: &str) -> Result<Vec<u32>, InlineParseError> {
+pub(crate) fn parse_numeric_levels(content: &str) -> Result<Vec<u32>, InlineParseError> {
     if content.is_empty() {
         return Ok(vec![]);
     }
 
-    let parts: Vec<&str> = content.split('.').collect();
+    let trimmed = content.strip_suffix('.').unwrap_or(content);
     let mut levels = Vec::new();
 
-    for part in parts {
-        if let Ok(level) = part.parse::<u32>() {
-            levels.push(level);
-        } else {
-            // If any part isn't numeric, this isn't a numeric identifier
-            return Err(InlineParseError::InvalidStructure(
-                "Invalid numeric section level".to_string(),
-            ));
+    for part in trimmed.split('.') {
+        if part.is_empty() {
+            return Err(InlineParseError::InvalidStructure(format!(
+                "Empty section level in \"{}\" (leading or doubled dot)",
+                content
+            )));
         }
+        let level = part.parse::<u32>().map_err(|_| {
+            InlineParseError::InvalidStructure(format!(
+                "Invalid numeric section level \"{}\" in \"{}\"",
+                part, content
+            ))
+        })?;
+        levels.push(level);
     }
 
     Ok(levels)
@@ -378,7 +419,7 @@ fn parse_numeric_levels(content: &str) -> Result<Vec<u32>, InlineParseError> {
 
 /// General reference parser that dispatches to specific type parsers
 ///
-/// Uses the ReferenceClassifier to determine reference type and route to
+/// Uses the shared default ReferenceClassifier to determine reference type and route to
 /// the appropriate parser function.
 ///
 /// # Arguments
@@ -391,6 +432,27 @@ pub fn parse_reference(
 ) -> Result<
     crate::ast::elements::formatting::inlines::Inline,
     crate::semantic::elements::inlines::InlineParseError,
+> {
+    parse_reference_with(tokens, ReferenceClassifier::shared())
+}
+
+/// General reference parser using a caller-provided classifier
+///
+/// This is the entry point for documents with custom bracket conventions: the
+/// classifier's registered handlers are consulted before the built-in types.
+///
+/// # Arguments
+/// * `tokens` - Sequence of tokens containing reference
+/// * `classifier` - Classifier, possibly extended via `with_custom`
+///
+/// # Returns
+/// * `Result<crate::ast::elements::formatting::inlines::Inline, InlineParseError>`
+pub fn parse_reference_with(
+    tokens: &[crate::cst::ScannerToken],
+    classifier: &ReferenceClassifier,
+) -> Result<
+    crate::ast::elements::formatting::inlines::Inline,
+    crate::semantic::elements::inlines::InlineParseError,
 > {
     if tokens.is_empty() {
         return Err(InlineParseError::InvalidStructure(
@@ -402,7 +464,6 @@ pub fn parse_reference(
     let content = extract_reference_content(tokens)?;
 
     // Classify reference type using the specification order
-    let classifier = ReferenceClassifier::new();
     let ref_type = classifier.classify(&content);
 
     // Route to appropriate parser based on type
@@ -413,13 +474,51 @@ pub fn parse_reference(
         SimpleReferenceType::Url => parse_url_reference(tokens),
         SimpleReferenceType::File => parse_file_reference(tokens),
         SimpleReferenceType::ToComeTK => parse_tk_reference(tokens),
+        SimpleReferenceType::Custom(name) => parse_custom_reference(tokens, classifier, &name),
         SimpleReferenceType::NotSure => parse_not_sure_reference(tokens),
     }
 }
 
+/// Parse a reference claimed by a registered custom handler
+///
+/// # Arguments
+/// * `tokens` - Sequence of tokens containing the reference
+/// * `classifier` - Classifier holding the handler
+/// * `name` - Name of the handler that claimed the content
+///
+/// # Returns
+/// * `Result<crate::ast::elements::formatting::inlines::Inline, InlineParseError>`
+fn parse_custom_reference(
+    tokens: &[crate::cst::ScannerToken],
+    classifier: &ReferenceClassifier,
+    name: &str,
+) -> Result<crate::ast::elements::formatting::inlines::Inline, InlineParseError> {
+    let content = extract_reference_content(tokens)?;
+
+    let handler = classifier.custom_handler(name).ok_or_else(|| {
+        InlineParseError::InvalidStructure(format!("Unknown custom reference handler: {}", name))
+    })?;
+
+    let reference_target = ReferenceTarget::Custom {
+        kind: handler.name.clone(),
+        target: (handler.parse)(content.trim()),
+        raw: format!("[{}]", content),
+        tokens: ScannerTokenSequence::from_tokens(tokens.to_vec()),
+    };
+
+    let reference = crate::ast::elements::references::Reference {
+        target: reference_target,
+        content: None,
+        tokens: ScannerTokenSequence::from_tokens(tokens.to_vec()),
+    };
+
+    Ok(Inline::Reference(reference))
+}
+
 /// Parse URL reference from tokens
 ///
 /// Handles URL patterns like "https://example.com" or "example.com".
+/// Scheme-prefixed URLs are validated by [`parse_url_scheme`].
 ///
 /// # Arguments
 /// * `tokens` - Sequence of tokens containing URL reference
@@ -439,9 +538,11 @@ fn parse_url_reference(
     } else {
         (content.clone(), None)
     };
+    let scheme = parse_url_scheme(&url)?;
 
     let reference_target = ReferenceTarget::Url {
         url,
+        scheme,
         fragment,
         raw: format!("[{}]", content),
         tokens: ScannerTokenSequence::from_tokens(tokens.to_vec()),
@@ -456,6 +557,60 @@ fn parse_url_reference(
     Ok(Inline::Reference(reference))
 }
 
+/// Schemes a URL reference may spell out
+const URL_SCHEMES: &[&str] = &["http", "https", "ftp", "mailto"];
+
+/// Detect and validate the scheme of a URL reference (fragment removed)
+///
+/// Returns the lowercased scheme for `http`, `https`, `ftp` and `mailto`
+/// URLs, and `None` for scheme-relative (`//host`) URLs, bare domains and
+/// email addresses. A recognized scheme with nothing usable after it
+/// (`http://`, `mailto:`) is an error.
+///
+/// # Arguments
+/// * `url` - URL reference content, without the fragment
+///
+/// # Returns
+/// * `Result<Option<String>, InlineParseError>` - Detected scheme
+pub(crate) fn parse_url_scheme(url: &str) -> Result<Option<String>, InlineParseError> {
+    let url = url.trim();
+    let malformed = || InlineParseError::ReferenceTargetError(format!("Malformed URL: {}", url));
+
+    if let Some(rest) = url.strip_prefix("//") {
+        return if url_host(rest).is_empty() {
+            Err(malformed())
+        } else {
+            Ok(None)
+        };
+    }
+
+    let Some((scheme, rest)) = url.split_once(':') else {
+        return Ok(None);
+    };
+    let scheme = scheme.to_ascii_lowercase();
+    if !URL_SCHEMES.contains(&scheme.as_str()) {
+        return Ok(None);
+    }
+
+    let valid = if scheme == "mailto" {
+        matches!(rest.split_once('@'), Some((user, domain)) if !user.is_empty() && !domain.is_empty())
+    } else {
+        rest.strip_prefix("//")
+            .is_some_and(|authority| !url_host(authority).is_empty())
+    };
+
+    if valid {
+        Ok(Some(scheme))
+    } else {
+        Err(malformed())
+    }
+}
+
+/// The host part of a URL authority (up to the first `/` or `?`)
+fn url_host(authority: &str) -> &str {
+    authority.split(['/', '?']).next().unwrap_or_default()
+}
+
 /// Parse file reference from tokens
 ///
 /// Handles file patterns like "./file.txt" or "../dir/file.txt".
@@ -528,7 +683,9 @@ fn parse_tk_reference(
 
 /// Parse unresolved/not-sure reference from tokens
 ///
-/// Handles references that don't match any specific pattern.
+/// Handles references that don't match any specific pattern. Content that
+/// starts with a URL scheme but is too broken to classify as a URL
+/// (`[http://]`) is rejected rather than left unresolved.
 ///
 /// # Arguments
 /// * `tokens` - Sequence of tokens containing unresolved reference
@@ -539,6 +696,7 @@ fn parse_not_sure_reference(
     tokens: &[crate::cst::ScannerToken],
 ) -> Result<crate::ast::elements::formatting::inlines::Inline, InlineParseError> {
     let content = extract_reference_content(tokens)?;
+    parse_url_scheme(&content)?;
 
     let reference_target = ReferenceTarget::Unresolved {
         content: content.clone(),
@@ -647,8 +805,38 @@ pub fn parse_footnote_ref(
     // Extract content from bracket pattern
     let content = extract_reference_content(tokens)?;
 
-    // Determine if this is naked numerical or labeled footnote
-    let reference_target = if let Some(stripped) = content.strip_prefix('^') {
+    // Determine if this is auto-generated, inline, labeled or naked numerical
+    let reference_target = if content == "^" {
+        // Auto-generated footnote [^]: the registry assigns its id
+        ReferenceTarget::Footnote {
+            footnote: FootnoteTarget {
+                label: None,
+                auto_generate: true,
+                content: None,
+            },
+            raw: format!("[{}]", content),
+            tokens: ScannerTokenSequence::from_tokens(tokens.to_vec()),
+        }
+    } else if let Some(text) = content.strip_prefix("^:") {
+        // Inline footnote [^: text]: carries its note, the registry assigns its id
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(
+                crate::semantic::elements::inlines::InlineParseError::InvalidStructure(
+                    "Inline footnote has no content".to_string(),
+                ),
+            );
+        }
+        ReferenceTarget::Footnote {
+            footnote: FootnoteTarget {
+                label: None,
+                auto_generate: true,
+                content: Some(text.to_string()),
+            },
+            raw: format!("[{}]", content),
+            tokens: ScannerTokenSequence::from_tokens(tokens.to_vec()),
+        }
+    } else if let Some(stripped) = content.strip_prefix('^') {
         // Labeled footnote [^label]
         let label = stripped.to_string();
         ReferenceTarget::NamedAnchor {
@@ -766,7 +954,7 @@ pub fn parse_session_ref(
 
     let identifier = if let Some(stripped) = content.strip_prefix('#') {
         // Parse numeric section reference: #3, #2.1, #-1.2
-        parse_section_identifier(stripped)
+        parse_section_identifier(stripped)?
     } else {
         // Named section reference: local-section
         SectionIdentifier::Named {
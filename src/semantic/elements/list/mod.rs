@@ -9,8 +9,9 @@
 use crate::ast::elements::containers::ContentContainer;
 use crate::ast::elements::inlines::TextTransform;
 use crate::ast::elements::list::block::{ListBlock, ListDecorationType, ListItem};
-use crate::cst::{HighLevelToken, ScannerTokenSequence};
+use crate::cst::{HighLevelToken, ScannerToken, ScannerTokenSequence};
 use crate::semantic::ast_construction::AstNode;
+use crate::semantic::elements::session::check_content_container_child;
 use crate::semantic::BlockParseError;
 use crate::syntax::list_detection;
 
@@ -39,6 +40,8 @@ pub fn create_list_element_with_nesting(
                     ))
                 }
             };
+            let line_tokens = item_line_tokens(marker_token, &source_tokens);
+            let (checked, content, source_tokens) = split_checkbox(content, source_tokens);
             let content_transforms = if !content.is_empty() {
                 vec![TextTransform::Identity(
                     crate::ast::elements::inlines::Text::simple_with_tokens(
@@ -53,6 +56,7 @@ pub fn create_list_element_with_nesting(
             let nested_container = if !nested_nodes.is_empty() {
                 let mut content_elements = Vec::new();
                 for node in nested_nodes {
+                    check_content_container_child(node, "ListItem")?;
                     let element_node = node.to_element_node();
                     match element_node.try_into() {
                         Ok(container_element) => content_elements.push(container_element),
@@ -80,7 +84,8 @@ pub fn create_list_element_with_nesting(
                 nested: nested_container,
                 annotations: vec![],
                 parameters: Default::default(),
-                tokens: Default::default(),
+                tokens: line_tokens,
+                checked,
             });
         }
     }
@@ -89,12 +94,13 @@ pub fn create_list_element_with_nesting(
     } else {
         determine_decoration_type(&items[0].marker)
     };
+    let tokens = list_tokens(&items);
     Ok(ListBlock {
         decoration_type,
         items,
         annotations: vec![],
         parameters: Default::default(),
-        tokens: Default::default(),
+        tokens,
     })
 }
 
@@ -142,6 +148,9 @@ pub fn create_list_element(item_tokens: &[HighLevelToken]) -> Result<ListBlock,
                     ))
                 }
             };
+            let line_tokens = item_line_tokens(marker_token, &source_tokens);
+            let (checked, item_content, source_tokens) =
+                split_checkbox(item_content, source_tokens);
 
             // Create TextTransform for item content, preserving source tokens
             let content_transforms = if !item_content.is_empty() {
@@ -164,13 +173,22 @@ pub fn create_list_element(item_tokens: &[HighLevelToken]) -> Result<ListBlock,
                 annotations: Vec::new(),
                 // FIXME: post-parser - Extract parameters from list item
                 parameters: crate::ast::elements::components::parameters::Parameters::new(),
-                tokens: ScannerTokenSequence::new(),
+                tokens: line_tokens,
+                checked,
             });
         }
     }
 
+    // Tokens other than SequenceTextLine are skipped, so check again
+    if items.len() < 2 {
+        return Err(BlockParseError::InvalidStructure(
+            "Lists require at least 2 items".to_string(),
+        ));
+    }
+
     // Determine decoration type from first item
     let decoration_type = determine_decoration_type(&items[0].marker);
+    let tokens = list_tokens(&items);
 
     Ok(ListBlock {
         decoration_type,
@@ -179,10 +197,71 @@ pub fn create_list_element(item_tokens: &[HighLevelToken]) -> Result<ListBlock,
         annotations: Vec::new(),
         // FIXME: post-parser - Extract parameters from list
         parameters: crate::ast::elements::components::parameters::Parameters::new(),
-        tokens: ScannerTokenSequence::new(),
+        tokens,
     })
 }
 
+/// Source tokens of an item's own line: its marker, then its content
+fn item_line_tokens(
+    marker_token: &HighLevelToken,
+    content_tokens: &ScannerTokenSequence,
+) -> ScannerTokenSequence {
+    let mut tokens = match marker_token {
+        HighLevelToken::SequenceMarker { tokens, .. } => tokens.tokens.clone(),
+        _ => Vec::new(),
+    };
+    tokens.extend(content_tokens.tokens.iter().cloned());
+    ScannerTokenSequence::from_tokens(tokens)
+}
+
+/// Take a task checkbox off the start of an item's content
+///
+/// `[ ]` marks an open task and `[x]` (or `[X]`) a done one; either must be
+/// followed by whitespace. Returns the task state and the content and
+/// tokens that follow the checkbox, or the content unchanged with None.
+fn split_checkbox(
+    content: String,
+    tokens: ScannerTokenSequence,
+) -> (Option<bool>, String, ScannerTokenSequence) {
+    let (checked, length) = match tokens.tokens.as_slice() {
+        [ScannerToken::LeftBracket { .. }, ScannerToken::Whitespace { content: space, .. }, ScannerToken::RightBracket { .. }, ScannerToken::Whitespace { .. }, ..]
+            if space == " " =>
+        {
+            (false, 4)
+        }
+        [ScannerToken::RefMarker { content: mark, .. }, ScannerToken::Whitespace { .. }, ..]
+            if mark == "x" || mark == "X" =>
+        {
+            (true, 2)
+        }
+        _ => return (None, content, tokens),
+    };
+    // A `[x]` reference marker leaves no text in the span's content, so the
+    // checkbox text is only stripped where it is there
+    let rest = ["[ ]", "[x]", "[X]"]
+        .iter()
+        .find_map(|checkbox| content.strip_prefix(checkbox))
+        .unwrap_or(&content);
+    let rest = rest
+        .strip_prefix(tokens.tokens[length - 1].content())
+        .unwrap_or(rest);
+    (
+        Some(checked),
+        rest.to_string(),
+        ScannerTokenSequence::from_tokens(tokens.tokens[length..].to_vec()),
+    )
+}
+
+/// Source tokens of a list: the lines of its items, without nested content
+fn list_tokens(items: &[ListItem]) -> ScannerTokenSequence {
+    ScannerTokenSequence::from_tokens(
+        items
+            .iter()
+            .flat_map(|item| item.tokens.tokens.iter().cloned())
+            .collect(),
+    )
+}
+
 /// Determine list decoration type from the first marker
 fn determine_decoration_type(marker: &str) -> ListDecorationType {
     let decoration = list_detection::determine_decoration_type(marker);
@@ -8,9 +8,12 @@
 //! Block-level element construction (Phase 2.a):
 //! - `annotation` - Annotation block construction
 //! - `definition` - Definition block construction
+//! - `footnote` - Footnote definition block construction
 //! - `list` - List block construction
 //! - `paragraph` - Paragraph block construction
+//! - `quote` - Quote block construction
 //! - `session` - Session block construction
+//! - `table` - Table block construction
 //! - `verbatim` - Verbatim block construction
 //!
 //! Inline-level element parsing (Phase 2.b):
@@ -23,9 +26,12 @@
 // Block-level element construction
 pub mod annotation;
 pub mod definition;
+pub mod footnote;
 pub mod list;
 pub mod paragraph;
+pub mod quote;
 pub mod session;
+pub mod table;
 pub mod verbatim;
 
 // Inline-level element parsing
@@ -2,12 +2,21 @@
 //!
 //! Converts high-level tokens into paragraph AST nodes.
 //!
+//! ## Line Breaks
+//!
+//! Lines of a paragraph are soft-wrapped: each line ending (trailing
+//! whitespace and newline) becomes a single space. A line ending in two or
+//! more trailing spaces, or in a backslash, is a hard break instead and is
+//! kept as a `TextTransform::LineBreak`. The last line's ending is not
+//! content.
+//!
 //! ## Related Files
 //! - **Specification**: `docs/specs/elements/paragraph/`
 //! - **AST Node**: `src/ast/elements/paragraph/block.rs`
 
+use crate::ast::elements::inlines::{Text, TextTransform};
 use crate::ast::elements::paragraph::block::ParagraphBlock;
-use crate::cst::{HighLevelToken, ScannerTokenSequence};
+use crate::cst::{HighLevelToken, Position, ScannerToken, ScannerTokenSequence, SourceSpan};
 use crate::semantic::BlockParseError;
 
 /// Create a paragraph element from a PlainTextLine token
@@ -40,35 +49,21 @@ pub fn create_paragraph_element_multi(
         ));
     }
 
-    let mut content_transforms = Vec::new();
     let mut all_scanner_tokens = Vec::new();
 
     for token in tokens {
         match token {
-            HighLevelToken::PlainTextLine { content, .. } => {
-                // Extract content text and source tokens
-                let (content_text, source_tokens) = match content.as_ref() {
-                    HighLevelToken::TextSpan {
-                        content, tokens, ..
-                    } => (content.clone(), tokens.clone()),
-                    _ => {
-                        return Err(BlockParseError::InvalidStructure(
-                            "Paragraph line content must be a TextSpan".to_string(),
-                        ))
-                    }
-                };
-
-                // Collect scanner tokens for the paragraph block
-                all_scanner_tokens.extend(source_tokens.tokens.clone());
-
-                // Create TextTransform::Identity, preserving source tokens
-                let text = crate::ast::elements::inlines::Text::simple_with_tokens(
-                    &content_text,
-                    source_tokens,
-                );
-                let text_transform = crate::ast::elements::inlines::TextTransform::Identity(text);
-                content_transforms.push(text_transform);
-            }
+            HighLevelToken::PlainTextLine { content, .. } => match content.as_ref() {
+                // Collect the line's source tokens; lines are split again below
+                HighLevelToken::TextSpan { tokens, .. } => {
+                    all_scanner_tokens.extend(tokens.tokens.clone())
+                }
+                _ => {
+                    return Err(BlockParseError::InvalidStructure(
+                        "Paragraph line content must be a TextSpan".to_string(),
+                    ))
+                }
+            },
             _ => {
                 return Err(BlockParseError::InvalidStructure(
                     "Expected PlainTextLine token for paragraph".to_string(),
@@ -77,6 +72,18 @@ pub fn create_paragraph_element_multi(
         }
     }
 
+    let mut content_transforms = Vec::new();
+    for line in split_paragraph_lines(&all_scanner_tokens) {
+        if !line.content.is_empty() {
+            let content_text: String = line.content.iter().map(|t| t.content()).collect();
+            content_transforms.push(TextTransform::Identity(Text::simple_with_tokens(
+                &content_text,
+                ScannerTokenSequence::from_tokens(line.content),
+            )));
+        }
+        content_transforms.extend(line.ending);
+    }
+
     Ok(ParagraphBlock {
         // FIXME: post-parser - Parse inline formatting in content instead of using Text::simple
         content: content_transforms,
@@ -90,3 +97,140 @@ pub fn create_paragraph_element_multi(
         },
     })
 }
+
+/// One source line of a paragraph
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParagraphLine {
+    /// The line's content tokens, without its ending
+    pub content: Vec<ScannerToken>,
+    /// The transform the line ending turns into: a single space for a soft
+    /// wrap or a `LineBreak`. None for the last line.
+    pub ending: Option<TextTransform>,
+}
+
+/// Split a paragraph's scanner tokens into lines and classify their endings
+///
+/// Shared by paragraph construction and inline parsing, which both rebuild
+/// paragraph content from the raw tokens.
+pub fn split_paragraph_lines(tokens: &[ScannerToken]) -> Vec<ParagraphLine> {
+    let mut lines: Vec<Vec<ScannerToken>> = tokens
+        .split_inclusive(|token| matches!(token, ScannerToken::Newline { .. }))
+        .map(|line| line.to_vec())
+        .collect();
+    if lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
+    let line_count = lines.len();
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(index, mut content)| {
+            let mut ending_tokens = Vec::new();
+            while matches!(
+                content.last(),
+                Some(ScannerToken::Newline { .. } | ScannerToken::Whitespace { .. })
+            ) {
+                ending_tokens.insert(0, content.pop().unwrap());
+            }
+
+            if index + 1 == line_count {
+                // The last line's ending belongs to the block, not its content
+                return ParagraphLine {
+                    content,
+                    ending: None,
+                };
+            }
+
+            let trailing_spaces: usize = ending_tokens
+                .iter()
+                .filter_map(|token| match token {
+                    ScannerToken::Whitespace { content, .. } => {
+                        Some(content.chars().filter(|&ch| ch == ' ').count())
+                    }
+                    _ => None,
+                })
+                .sum();
+
+            let ending = if let Some(marker) = take_backslash_marker(&mut content) {
+                ending_tokens.insert(0, marker);
+                line_break(ending_tokens)
+            } else if trailing_spaces >= 2 {
+                line_break(ending_tokens)
+            } else {
+                soft_wrap(&ending_tokens)
+            };
+
+            ParagraphLine {
+                content,
+                ending: Some(ending),
+            }
+        })
+        .collect()
+}
+
+/// Split a hard-break backslash off the end of a line's content
+///
+/// Only an odd run of trailing backslashes is a marker; `\\` is an escaped
+/// backslash.
+fn take_backslash_marker(content: &mut Vec<ScannerToken>) -> Option<ScannerToken> {
+    let (text, span) = match content.last() {
+        Some(ScannerToken::Text { content, span }) => (content.clone(), span.clone()),
+        _ => return None,
+    };
+
+    let backslashes = text.chars().rev().take_while(|&ch| ch == '\\').count();
+    if backslashes % 2 == 0 {
+        return None;
+    }
+
+    content.pop();
+    let marker_start = Position {
+        row: span.end.row,
+        column: span.end.column - 1,
+    };
+    let rest = &text[..text.len() - 1];
+    if !rest.is_empty() {
+        content.push(ScannerToken::Text {
+            content: rest.to_string(),
+            span: SourceSpan {
+                start: span.start,
+                end: marker_start,
+            },
+        });
+    }
+
+    Some(ScannerToken::Text {
+        content: "\\".to_string(),
+        span: SourceSpan {
+            start: marker_start,
+            end: span.end,
+        },
+    })
+}
+
+fn line_break(tokens: Vec<ScannerToken>) -> TextTransform {
+    TextTransform::LineBreak(Text::simple_with_tokens(
+        "\n",
+        ScannerTokenSequence::from_tokens(tokens),
+    ))
+}
+
+/// A soft wrap is a single space spanning the whole line ending
+///
+/// Every line but the last ends in a newline, so `ending_tokens` is never
+/// empty here.
+fn soft_wrap(ending_tokens: &[ScannerToken]) -> TextTransform {
+    let span = SourceSpan {
+        start: ending_tokens[0].span().start,
+        end: ending_tokens[ending_tokens.len() - 1].span().end,
+    };
+    let space = ScannerToken::Whitespace {
+        content: " ".to_string(),
+        span,
+    };
+    TextTransform::Identity(Text::simple_with_tokens(
+        " ",
+        ScannerTokenSequence::from_tokens(vec![space]),
+    ))
+}
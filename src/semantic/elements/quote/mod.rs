@@ -0,0 +1,61 @@
+//! Quote Element Construction
+//!
+//! Converts high-level tokens into quote AST nodes.
+//!
+//! ## Related Files
+//! - **AST Node**: `src/ast/elements/quote/block.rs`
+
+use crate::ast::elements::containers::ContentContainer;
+use crate::ast::elements::quote::QuoteBlock;
+use crate::cst::HighLevelToken;
+use crate::semantic::ast_construction::AstNode;
+use crate::semantic::elements::session::check_content_container_child;
+use crate::semantic::BlockParseError;
+
+/// Create a quote element from a Quote token and its parsed content
+///
+/// # Arguments
+/// * `token` - The Quote token grouping the quoted lines
+/// * `content_nodes` - The parsed AST nodes for the quoted content
+///
+/// # Returns
+/// * `Result<QuoteBlock, BlockParseError>`
+pub fn create_quote_element(
+    token: &HighLevelToken,
+    content_nodes: &[AstNode],
+) -> Result<QuoteBlock, BlockParseError> {
+    match token {
+        HighLevelToken::Quote { tokens, .. } => {
+            // Quotes hold a ContentContainer: everything but sessions
+            let mut content_elements = Vec::new();
+            for node in content_nodes {
+                check_content_container_child(node, "Quote")?;
+                match node.to_element_node().try_into() {
+                    Ok(container_element) => content_elements.push(container_element),
+                    Err(e) => {
+                        return Err(BlockParseError::InvalidStructure(format!(
+                            "Failed to convert quoted element: {}",
+                            e
+                        )));
+                    }
+                }
+            }
+
+            Ok(QuoteBlock {
+                content: ContentContainer::new(
+                    content_elements,
+                    vec![],
+                    Default::default(),
+                    Default::default(),
+                ),
+                // FIXME: post-parser - Parse quote-level annotations
+                annotations: Vec::new(),
+                parameters: Default::default(),
+                tokens: tokens.clone(),
+            })
+        }
+        _ => Err(BlockParseError::InvalidStructure(
+            "Expected Quote token for quote".to_string(),
+        )),
+    }
+}
@@ -122,7 +122,7 @@ pub fn create_session_element(
         vec![]
     };
 
-    // Convert AstNodes to SessionContainerElements
+    // Session containers hold every block, nested sessions included
     let content_elements: Vec<SessionContainerElement> = child_nodes
         .iter()
         .map(|node| match node {
@@ -132,6 +132,12 @@ pub fn create_session_element(
             AstNode::Definition(d) => SessionContainerElement::Definition(d.clone()),
             AstNode::Annotation(a) => SessionContainerElement::Annotation(a.clone()),
             AstNode::Verbatim(v) => SessionContainerElement::Verbatim(v.clone()),
+            AstNode::Quote(q) => SessionContainerElement::Quote(q.clone()),
+            AstNode::Table(t) => SessionContainerElement::Table(t.clone()),
+            AstNode::FootnoteDefinition(f) => {
+                SessionContainerElement::FootnoteDefinition(f.clone())
+            }
+            AstNode::BlankLine(b) => SessionContainerElement::BlankLine(b.clone()),
         })
         .collect();
 
@@ -155,5 +161,21 @@ pub fn create_session_element(
         // FIXME: post-parser - Extract parameters from session
         parameters: crate::ast::elements::components::parameters::Parameters::new(),
         tokens: ScannerTokenSequence::new(),
+        slug: None,
     })
 }
+
+/// Check a child node of a content container against its constraint
+///
+/// Content containers (list items, quotes) hold every block but sessions,
+/// which only session containers may nest. `parent` names the element
+/// whose content this is, for the error.
+pub fn check_content_container_child(node: &AstNode, parent: &str) -> Result<(), BlockParseError> {
+    match node {
+        AstNode::Session(_) => Err(BlockParseError::InvalidStructure(format!(
+            "Cannot nest Session inside {} (ContentContainer constraint)",
+            parent
+        ))),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,144 @@
+//! Table Element Construction
+//!
+//! Converts high-level tokens into table AST nodes.
+//!
+//! ## Related Files
+//! - **Specification**: `docs/specs/elements/table/`
+//! - **AST Node**: `src/ast/elements/table/block.rs`
+
+use crate::ast::elements::inlines::{Text, TextTransform};
+use crate::ast::elements::table::{ColumnAlignment, TableBlock, TableCell, TableRow};
+use crate::cst::{HighLevelToken, HighLevelTokenSpan, ScannerTokenSequence};
+use crate::semantic::BlockParseError;
+
+/// Create a table element from a Table token
+///
+/// A second row made only of dash cells (`|---|:--:|`) is the header
+/// separator: the row above it becomes the header and its `:` markers set the
+/// column alignment. Rows shorter than the widest row are padded with empty
+/// cells.
+///
+/// # Arguments
+/// * `token` - The Table token grouping the rows
+///
+/// # Returns
+/// * `Result<TableBlock, BlockParseError>`
+pub fn create_table_element(token: &HighLevelToken) -> Result<TableBlock, BlockParseError> {
+    let (rows, tokens) = match token {
+        HighLevelToken::Table { rows, tokens, .. } => (rows, tokens),
+        _ => {
+            return Err(BlockParseError::InvalidStructure(
+                "Expected Table token for table".to_string(),
+            ))
+        }
+    };
+
+    let column_count = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+    let separator = rows
+        .get(1)
+        .map(|cells| {
+            cells
+                .iter()
+                .map(parse_separator_cell)
+                .collect::<Option<Vec<_>>>()
+        })
+        .unwrap_or(None);
+
+    let (header, body_rows, mut alignments) = match separator {
+        Some(alignments) => (Some(&rows[0]), &rows[2..], alignments),
+        None => (None, &rows[..], Vec::new()),
+    };
+    alignments.resize(column_count, ColumnAlignment::Default);
+
+    let header = header
+        .map(|cells| create_row(cells, column_count, tokens))
+        .transpose()?;
+    let body_rows = body_rows
+        .iter()
+        .map(|cells| create_row(cells, column_count, tokens))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(TableBlock {
+        header,
+        rows: body_rows,
+        alignments,
+        // FIXME: post-parser - Parse table-level annotations
+        annotations: Vec::new(),
+        parameters: Default::default(),
+        tokens: tokens.clone(),
+    })
+}
+
+/// Build a row from its cell TextSpans, padded to `column_count` cells
+fn create_row(
+    cells: &[HighLevelToken],
+    column_count: usize,
+    table_tokens: &ScannerTokenSequence,
+) -> Result<TableRow, BlockParseError> {
+    let mut row_cells = cells
+        .iter()
+        .map(create_cell)
+        .collect::<Result<Vec<_>, _>>()?;
+    row_cells.resize_with(column_count, TableCell::empty);
+
+    // The row's tokens are the table tokens on its source line
+    let row = cells.first().map(|cell| cell.span().start.row);
+    let row_tokens = table_tokens
+        .tokens
+        .iter()
+        .filter(|token| Some(token.span().start.row) == row)
+        .cloned()
+        .collect();
+
+    Ok(TableRow::new(
+        row_cells,
+        ScannerTokenSequence::from_tokens(row_tokens),
+    ))
+}
+
+/// Build a cell from its TextSpan
+fn create_cell(token: &HighLevelToken) -> Result<TableCell, BlockParseError> {
+    match token {
+        HighLevelToken::TextSpan {
+            content, tokens, ..
+        } => {
+            let content = if content.is_empty() {
+                Vec::new()
+            } else {
+                vec![TextTransform::Identity(Text::simple_with_tokens(
+                    content,
+                    tokens.clone(),
+                ))]
+            };
+            Ok(TableCell::new(content, tokens.clone()))
+        }
+        _ => Err(BlockParseError::InvalidStructure(
+            "Table cell content must be a TextSpan".to_string(),
+        )),
+    }
+}
+
+/// Read the alignment of a separator cell (`---`, `:---`, `---:`, `:---:`)
+///
+/// Returns None when the cell is not a separator cell.
+fn parse_separator_cell(token: &HighLevelToken) -> Option<ColumnAlignment> {
+    let content = match token {
+        HighLevelToken::TextSpan { content, .. } => content.as_str(),
+        _ => return None,
+    };
+
+    let left = content.starts_with(':');
+    let right = content.len() > 1 && content.ends_with(':');
+    let dashes = content.trim_start_matches(':').trim_end_matches(':');
+    if dashes.is_empty() || !dashes.chars().all(|ch| ch == '-') {
+        return None;
+    }
+
+    Some(match (left, right) {
+        (true, true) => ColumnAlignment::Center,
+        (true, false) => ColumnAlignment::Left,
+        (false, true) => ColumnAlignment::Right,
+        (false, false) => ColumnAlignment::Default,
+    })
+}
@@ -7,9 +7,11 @@
 //! - **AST Node**: `src/ast/elements/verbatim/block.rs`
 
 use crate::ast::elements::verbatim::block::{VerbatimBlock, VerbatimType};
+use crate::ast::elements::verbatim::ignore_container::IgnoreLine;
 use crate::cst::{HighLevelToken, ScannerTokenSequence, WallType};
 use crate::semantic::elements::parameters::create_parameters_ast;
 use crate::semantic::BlockParseError;
+use crate::syntax::core::{VerbatimOptions, INDENT_SIZE};
 
 /// Create a verbatim block element from a VerbatimBlock token
 ///
@@ -19,6 +21,25 @@ use crate::semantic::BlockParseError;
 /// # Returns
 /// * `Result<VerbatimBlock, BlockParseError>`
 pub fn create_verbatim_element(token: &HighLevelToken) -> Result<VerbatimBlock, BlockParseError> {
+    create_verbatim_element_with(token, INDENT_SIZE, VerbatimOptions::default())
+}
+
+/// Create a verbatim block element for a document indented `indent_size` spaces per level
+///
+/// In-flow content is wall-stripped one indentation level past the title.
+///
+/// # Arguments
+/// * `token` - The VerbatimBlock token to convert
+/// * `indent_size` - Spaces per indentation level
+/// * `options` - Blank line handling for the content
+///
+/// # Returns
+/// * `Result<VerbatimBlock, BlockParseError>`
+pub fn create_verbatim_element_with(
+    token: &HighLevelToken,
+    indent_size: usize,
+    options: VerbatimOptions,
+) -> Result<VerbatimBlock, BlockParseError> {
     match token {
         HighLevelToken::VerbatimBlock {
             title,
@@ -26,6 +47,7 @@ pub fn create_verbatim_element(token: &HighLevelToken) -> Result<VerbatimBlock,
             label,
             parameters,
             wall_type,
+            tokens,
             ..
         } => {
             // Extract title text and source tokens, convert to TextTransform
@@ -63,8 +85,8 @@ pub fn create_verbatim_element(token: &HighLevelToken) -> Result<VerbatimBlock,
 
             // Determine wall indentation level for stripping
             let wall_indent = match wall_type {
-                WallType::InFlow(indent) => indent + 4, // Content at indent + 4
-                WallType::Stretched => 0,               // No wall stripping for stretched
+                WallType::InFlow(indent) => indent + indent_size, // Content one level past the title
+                WallType::Stretched => 0,                         // No wall stripping for stretched
             };
 
             // Create AST IgnoreLine nodes from high-level IgnoreLine/BlankLine tokens
@@ -95,6 +117,7 @@ pub fn create_verbatim_element(token: &HighLevelToken) -> Result<VerbatimBlock,
                         ignore_lines.push(
                             crate::ast::elements::verbatim::ignore_container::IgnoreLine {
                                 content: stripped_content,
+                                line_number: ignore_lines.len() + 1,
                                 tokens: tokens.clone(),
                             },
                         );
@@ -104,6 +127,7 @@ pub fn create_verbatim_element(token: &HighLevelToken) -> Result<VerbatimBlock,
                         ignore_lines.push(
                             crate::ast::elements::verbatim::ignore_container::IgnoreLine {
                                 content: String::new(),
+                                line_number: ignore_lines.len() + 1,
                                 tokens: tokens.clone(),
                             },
                         );
@@ -114,6 +138,18 @@ pub fn create_verbatim_element(token: &HighLevelToken) -> Result<VerbatimBlock,
                 }
             }
 
+            if options.trim_blank_edges {
+                trim_blank_edges(&mut ignore_lines);
+            }
+
+            // Only guess when the author gave us nothing to go on
+            let detected_language = if label_text.trim().is_empty() || label_text == "unknown" {
+                let lines: Vec<&str> = ignore_lines.iter().map(|line| line.content()).collect();
+                detect_language(&lines)
+            } else {
+                None
+            };
+
             // Extract parameters using unified constructor
             let extracted_params = create_parameters_ast(parameters.as_deref())?;
 
@@ -139,12 +175,14 @@ pub fn create_verbatim_element(token: &HighLevelToken) -> Result<VerbatimBlock,
                 title: title_transforms,
                 content: ignore_container,
                 label: label_text,
+                detected_language,
                 verbatim_type,
+                base_indent: wall_indent,
                 // Parameters extracted using unified constructor
                 parameters: extracted_params,
                 // FIXME: post-parser - Parse block-level annotations
                 annotations: Vec::new(),
-                tokens: ScannerTokenSequence::new(),
+                tokens: tokens.clone(),
             })
         }
         _ => Err(BlockParseError::InvalidStructure(
@@ -152,3 +190,89 @@ pub fn create_verbatim_element(token: &HighLevelToken) -> Result<VerbatimBlock,
         )),
     }
 }
+
+/// Drop blank lines at the start and end of the content, renumbering the rest
+fn trim_blank_edges(lines: &mut Vec<IgnoreLine>) {
+    let is_blank = |line: &IgnoreLine| line.content.trim().is_empty();
+    let end = lines
+        .iter()
+        .rposition(|line| !is_blank(line))
+        .map_or(0, |i| i + 1);
+    lines.truncate(end);
+    let start = lines.iter().position(|line| !is_blank(line)).unwrap_or(0);
+    lines.drain(..start);
+    for (index, line) in lines.iter_mut().enumerate() {
+        line.line_number = index + 1;
+    }
+}
+
+/// Guess the language of unlabeled verbatim content
+///
+/// This is a syntax-highlighting aid, not a classifier: it only looks for a
+/// handful of unambiguous markers (shebangs, `<?php`, Rust/Go entry points,
+/// Python/C imports) and returns None rather than guess on anything else.
+///
+/// # Arguments
+/// * `lines` - Wall-stripped content lines
+///
+/// # Returns
+/// * `Option<String>` - Lowercase language name, if recognized
+pub fn detect_language(lines: &[&str]) -> Option<String> {
+    let first = lines
+        .iter()
+        .map(|line| line.trim())
+        .find(|line| !line.is_empty())?;
+
+    if let Some(shebang) = first.strip_prefix("#!") {
+        return shebang_language(shebang);
+    }
+    if first.starts_with("<?php") {
+        return Some("php".to_string());
+    }
+
+    for line in lines.iter().map(|line| line.trim_start()) {
+        if line.starts_with("fn main(") || line.starts_with("use std::") {
+            return Some("rust".to_string());
+        }
+        if line.starts_with("package main") {
+            return Some("go".to_string());
+        }
+        if line.starts_with("#include <") || line.starts_with("#include \"") {
+            return Some("c".to_string());
+        }
+        // JavaScript and TypeScript also open with `import`, but name the
+        // module in quotes or the bindings in braces
+        if line.starts_with("def ")
+            || (line.starts_with("from ") && line.contains(" import "))
+            || (line.starts_with("import ")
+                && !line.contains(['{', '}', '"', '\'', ';'])
+                && !line.contains(" from "))
+        {
+            return Some("python".to_string());
+        }
+    }
+
+    None
+}
+
+/// Map a shebang interpreter line to a language name
+fn shebang_language(shebang: &str) -> Option<String> {
+    let mut parts = shebang.split_whitespace();
+    let program = parts.next()?.rsplit('/').next()?;
+    // `#!/usr/bin/env python3` names the interpreter in the first argument
+    let interpreter = if program == "env" {
+        parts.find(|arg| !arg.starts_with('-'))?
+    } else {
+        program
+    };
+
+    let language = match interpreter.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.') {
+        "python" => "python",
+        "bash" | "sh" | "zsh" => "shell",
+        "node" => "javascript",
+        "ruby" => "ruby",
+        "perl" => "perl",
+        other => other,
+    };
+    Some(language.to_string())
+}
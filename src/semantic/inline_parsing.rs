@@ -65,6 +65,7 @@
 //!   - https://... → InlineType::Url
 //!   - ./file.txt → InlineType::File
 //!   - TK → InlineType::ToComeTK
+//!   - claimed by a registered custom handler → InlineType::Custom
 //!   - other → InlineType::NotSure
 //!
 //! Responsibilities:
@@ -216,16 +217,25 @@
 //! For block element parsing: src/semantic/mod.rs
 //! For tokenization: src/syntax/mod.rs
 
-use crate::ast::ElementNode;
+use crate::ast::elements::annotation::AnnotationContent;
+use crate::ast::elements::formatting::inlines::{Inline, TextTransform};
+use crate::ast::elements::references::reference_types::ReferenceClassifier;
+use crate::ast::{Diagnostic, ElementNode};
+use crate::cst::ScannerToken;
 use crate::semantic::elements::inlines::pipeline::{
-    create_standard_pipeline, inlines_to_text_transforms,
+    create_standard_pipeline, inlines_to_text_transforms, InlinePipeline,
 };
+use crate::semantic::elements::paragraph::split_paragraph_lines;
 
 /// Inline parser for processing inline elements within blocks
 ///
 /// This parser takes AST block elements and processes any inline
 /// formatting, references, and other inline elements within them.
-pub struct InlineParser;
+/// The standard pipeline is built once per parser and shared by every
+/// block it parses, so one parser can be reused across documents.
+pub struct InlineParser {
+    pipeline: InlinePipeline,
+}
 
 impl Default for InlineParser {
     fn default() -> Self {
@@ -236,7 +246,19 @@ impl Default for InlineParser {
 impl InlineParser {
     /// Create a new inline parser instance
     pub fn new() -> Self {
-        Self
+        Self {
+            pipeline: create_standard_pipeline(),
+        }
+    }
+
+    /// Create an inline parser whose references use `classifier`
+    ///
+    /// Register custom bracket conventions on the classifier with
+    /// `ReferenceClassifier::with_custom`.
+    pub fn with_reference_classifier(classifier: ReferenceClassifier) -> Self {
+        Self {
+            pipeline: create_standard_pipeline().with_reference_classifier(classifier),
+        }
     }
 
     /// Parse inline elements within block AST nodes
@@ -248,29 +270,120 @@ impl InlineParser {
         &self,
         blocks: Vec<ElementNode>,
     ) -> Result<Vec<ElementNode>, InlineParseError> {
-        blocks
+        Ok(self.parse_inlines_with_diagnostics(blocks).0)
+    }
+
+    /// Parse inline elements, reporting lines that fell back to plain text
+    ///
+    /// A line (or table cell) whose inline markup fails to parse is kept as
+    /// plain text; each fallback is returned as a `Diagnostic`.
+    pub fn parse_inlines_with_diagnostics(
+        &self,
+        blocks: Vec<ElementNode>,
+    ) -> (Vec<ElementNode>, Vec<Diagnostic>) {
+        let mut diagnostics = Vec::new();
+        let nodes = blocks
             .into_iter()
-            .map(|node| self.parse_inlines_in_node(node))
-            .collect()
+            .map(|node| self.parse_inlines_in_node(node, &mut diagnostics))
+            .collect();
+        (nodes, diagnostics)
     }
 
-    fn parse_inlines_in_node(&self, node: ElementNode) -> Result<ElementNode, InlineParseError> {
+    fn parse_inlines_in_node(
+        &self,
+        node: ElementNode,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> ElementNode {
         match node {
             ElementNode::ParagraphBlock(mut block) => {
-                // Use the new declarative pipeline to parse all inline elements
-                let pipeline = create_standard_pipeline();
-                let inlines = pipeline.parse(&block.tokens.tokens)?;
+                // Use the new declarative pipeline to parse all inline elements.
+                // Inlines never cross lines, so each line is parsed on its own
+                // and joined back with its soft wrap or hard break.
+                let mut content = Vec::new();
+                for line in split_paragraph_lines(&block.tokens.tokens) {
+                    let inlines = parse_or_report(&self.pipeline, &line.content, diagnostics);
 
-                // Convert to TextTransform for backward compatibility
-                // TODO: Update ParagraphBlock to support Vec<Inline> directly
-                block.content = inlines_to_text_transforms(inlines);
-                Ok(ElementNode::ParagraphBlock(block))
+                    // Convert to TextTransform for backward compatibility
+                    // TODO: Update ParagraphBlock to support Vec<Inline> directly
+                    content.extend(inlines_to_text_transforms(inlines));
+                    content.extend(line.ending);
+                }
+                block.content = content;
+                ElementNode::ParagraphBlock(block)
+            }
+            ElementNode::TableBlock(mut block) => {
+                for row in block.header.iter_mut().chain(block.rows.iter_mut()) {
+                    for cell in &mut row.cells {
+                        if !cell.tokens.tokens.is_empty() {
+                            let inlines =
+                                parse_or_report(&self.pipeline, &cell.tokens.tokens, diagnostics);
+                            cell.content = inlines_to_text_transforms(inlines);
+                        }
+                    }
+                }
+                ElementNode::TableBlock(block)
+            }
+            ElementNode::AnnotationBlock(mut block) => {
+                if let AnnotationContent::Inline(body) = &block.content {
+                    let tokens = unparsed_tokens(body);
+                    if !tokens.is_empty() {
+                        let inlines = parse_or_report(&self.pipeline, &tokens, diagnostics);
+                        block.content =
+                            AnnotationContent::Inline(inlines_to_text_transforms(inlines));
+                    }
+                }
+                ElementNode::AnnotationBlock(block)
+            }
+            ElementNode::DefinitionBlock(mut block) => {
+                let tokens = unparsed_tokens(&block.term.content);
+                if !tokens.is_empty() {
+                    let inlines = parse_or_report(&self.pipeline, &tokens, diagnostics);
+                    block.term.content = inlines_to_text_transforms(inlines);
+                }
+                ElementNode::DefinitionBlock(block)
+            }
+            ElementNode::FootnoteDefinitionBlock(mut block) => {
+                let tokens = unparsed_tokens(&block.content);
+                if !tokens.is_empty() {
+                    let inlines = parse_or_report(&self.pipeline, &tokens, diagnostics);
+                    block.content = inlines_to_text_transforms(inlines);
+                }
+                ElementNode::FootnoteDefinitionBlock(block)
             }
-            _ => Ok(node),
+            _ => node,
         }
     }
 }
 
+/// Source tokens of inline content not yet inline-parsed
+fn unparsed_tokens(content: &[TextTransform]) -> Vec<ScannerToken> {
+    content
+        .iter()
+        .filter_map(|transform| match transform {
+            TextTransform::Identity(text) => Some(&text.tokens.tokens),
+            _ => None,
+        })
+        .flatten()
+        .cloned()
+        .collect()
+}
+
+/// Run the pipeline over one line, recording a diagnostic if it fell back
+fn parse_or_report(
+    pipeline: &InlinePipeline,
+    tokens: &[ScannerToken],
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Vec<Inline> {
+    let (inlines, error) = pipeline.parse_or_plain(tokens);
+    if let Some(error) = error {
+        diagnostics.push(Diagnostic::InlineFallback {
+            line: tokens.first().map_or(0, |token| token.span().start.row + 1),
+            message: error.to_string(),
+        });
+    }
+    inlines
+}
+
 /// Errors that can occur during inline parsing
 #[derive(Debug)]
 pub enum InlineParseError {
@@ -296,22 +296,24 @@ pub fn split_label_and_parameters(label_raw: &str) -> (String, String) {
 /// assert!(!is_document_metadata("warning"));
 /// ```
 pub fn is_document_metadata(label: &str) -> bool {
-    matches!(
-        label,
-        "title"
-            | "author"
-            | "date"
-            | "pub-date"
-            | "version"
-            | "copyright"
-            | "license"
-            | "abstract"
-            | "keywords"
-            | "description"
-            | "bibliography"
-    )
+    RESERVED_LABELS.contains(&label)
 }
 
+/// Annotation labels with a reserved meaning as document metadata
+pub const RESERVED_LABELS: &[&str] = &[
+    "title",
+    "author",
+    "date",
+    "pub-date",
+    "version",
+    "copyright",
+    "license",
+    "abstract",
+    "keywords",
+    "description",
+    "bibliography",
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
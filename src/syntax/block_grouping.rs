@@ -13,6 +13,10 @@ use crate::cst::HighLevelToken;
 /// This function collects consecutive `PlainTextLine` tokens until it encounters
 /// a blank line or other element type. This is used to determine paragraph boundaries.
 ///
+/// A line ending in a backslash continues the paragraph across one blank
+/// line, unless the line after the blank starts a session or definition
+/// (is followed by an indented block).
+///
 /// # Arguments
 /// * `tokens` - Slice of high-level tokens to process
 /// * `start` - Starting index in the token slice
@@ -42,6 +46,12 @@ pub fn group_contiguous_text_lines(
                 grouped_lines.push(token.clone());
                 position += 1;
             }
+            HighLevelToken::BlankLine { .. }
+                if grouped_lines.last().is_some_and(ends_with_continuation)
+                    && continues_paragraph(tokens, position + 1) =>
+            {
+                position += 1;
+            }
             HighLevelToken::BlankLine { .. } => {
                 // Blank line terminates the group
                 break;
@@ -57,6 +67,43 @@ pub fn group_contiguous_text_lines(
     (grouped_lines, consumed)
 }
 
+/// Whether a text line ends in a backslash continuation (`\\` is escaped)
+fn ends_with_continuation(line: &HighLevelToken) -> bool {
+    let HighLevelToken::PlainTextLine { content, .. } = line else {
+        return false;
+    };
+    let HighLevelToken::TextSpan { content, .. } = content.as_ref() else {
+        return false;
+    };
+    content
+        .trim_end()
+        .chars()
+        .rev()
+        .take_while(|&ch| ch == '\\')
+        .count()
+        % 2
+        == 1
+}
+
+/// Whether the token at `position` is a text line that is not the head of
+/// an indented block
+fn continues_paragraph(tokens: &[HighLevelToken], position: usize) -> bool {
+    if !matches!(
+        tokens.get(position),
+        Some(HighLevelToken::PlainTextLine { .. })
+    ) {
+        return false;
+    }
+    !matches!(
+        (tokens.get(position + 1), tokens.get(position + 2)),
+        (Some(HighLevelToken::Indent { .. }), _)
+            | (
+                Some(HighLevelToken::BlankLine { .. }),
+                Some(HighLevelToken::Indent { .. })
+            )
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,6 +128,37 @@ mod tests {
         }
     }
 
+    fn make_continued_line(row: usize) -> HighLevelToken {
+        match make_plain_text_line(row) {
+            HighLevelToken::PlainTextLine {
+                indentation_chars,
+                content,
+                span,
+                tokens,
+            } => {
+                let HighLevelToken::TextSpan {
+                    content: text,
+                    span: text_span,
+                    tokens: text_tokens,
+                } = *content
+                else {
+                    unreachable!()
+                };
+                HighLevelToken::PlainTextLine {
+                    indentation_chars,
+                    content: Box::new(HighLevelToken::TextSpan {
+                        content: format!("{} \\\n", text),
+                        span: text_span,
+                        tokens: text_tokens,
+                    }),
+                    span,
+                    tokens,
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
     fn make_blank_line(row: usize) -> HighLevelToken {
         HighLevelToken::BlankLine {
             span: SourceSpan {
@@ -123,6 +201,36 @@ mod tests {
         assert_eq!(consumed, 3);
     }
 
+    #[test]
+    fn test_group_continues_across_blank_after_backslash() {
+        let tokens = vec![
+            make_continued_line(0),
+            make_blank_line(1),
+            make_plain_text_line(2),
+            make_blank_line(3),
+            make_plain_text_line(4),
+        ];
+        let (grouped, consumed) = group_contiguous_text_lines(&tokens, 0);
+
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(consumed, 3);
+    }
+
+    #[test]
+    fn test_group_continuation_stops_before_indented_block() {
+        let tokens = vec![
+            make_continued_line(0),
+            make_blank_line(1),
+            make_plain_text_line(2),
+            make_blank_line(3),
+            make_indent(4),
+        ];
+        let (grouped, consumed) = group_contiguous_text_lines(&tokens, 0);
+
+        assert_eq!(grouped.len(), 1);
+        assert_eq!(consumed, 1);
+    }
+
     #[test]
     fn test_group_contiguous_text_lines_stops_at_blank() {
         let tokens = vec![
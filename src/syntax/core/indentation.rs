@@ -10,8 +10,11 @@
 //! - **Line-oriented processing**: Each line's indentation is processed once at column 0
 //! - **Verbatim awareness**: Skips indentation processing for verbatim content
 //! - **Multiple dedent support**: Generates multiple Dedent tokens for multi-level decreases
+//! - **Style checking**: Records lines whose indentation mixes tabs and spaces,
+//!   or departs from the style of the first indented line
 
 use crate::cst::{Position, ScannerToken, SourceSpan};
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 /// Standard indentation size (4 spaces) - matches verbatim_scanner.rs
@@ -20,6 +23,70 @@ pub const INDENT_SIZE: usize = 4;
 /// Tab width for tab-to-space conversion  
 pub const TAB_WIDTH: usize = 4;
 
+/// Whitespace character a line is indented with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IndentationStyle {
+    Spaces,
+    Tabs,
+}
+
+impl std::fmt::Display for IndentationStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IndentationStyle::Spaces => write!(f, "spaces"),
+            IndentationStyle::Tabs => write!(f, "tabs"),
+        }
+    }
+}
+
+/// Indentation that parses, but probably not the way the author meant
+///
+/// Tabs are expanded to a fixed width before levels are compared, so a tab
+/// among spaces can silently move a line into or out of a container.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IndentationIssue {
+    /// The line's leading whitespace contains both tabs and spaces
+    MixedLine { line: usize },
+
+    /// The line is indented with one style where the document uses the other
+    ///
+    /// The document's style is set by its first indented line.
+    InconsistentStyle {
+        line: usize,
+        found: IndentationStyle,
+        expected: IndentationStyle,
+    },
+}
+
+impl IndentationIssue {
+    /// The 1-based source line of the issue
+    pub fn line(&self) -> usize {
+        match self {
+            IndentationIssue::MixedLine { line }
+            | IndentationIssue::InconsistentStyle { line, .. } => *line,
+        }
+    }
+}
+
+impl std::fmt::Display for IndentationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IndentationIssue::MixedLine { line } => {
+                write!(f, "Line {} is indented with both tabs and spaces", line)
+            }
+            IndentationIssue::InconsistentStyle {
+                line,
+                found,
+                expected,
+            } => write!(
+                f,
+                "Line {} is indented with {} but the document uses {}",
+                line, found, expected
+            ),
+        }
+    }
+}
+
 /// Indentation tracker that generates Indent and Dedent tokens
 #[derive(Debug, Clone)]
 pub struct IndentationTracker {
@@ -29,18 +96,37 @@ pub struct IndentationTracker {
     pending_dedents: VecDeque<ScannerToken>,
     /// Current position in source
     current_position: Position,
+    /// Spaces a leading tab expands to
+    tab_width: usize,
+    /// Style of the first indented line, once seen
+    style: Option<IndentationStyle>,
+    /// Style problems found so far
+    issues: Vec<IndentationIssue>,
 }
 
 impl IndentationTracker {
     /// Create a new indentation tracker
     pub fn new() -> Self {
+        Self::with_tab_width(TAB_WIDTH)
+    }
+
+    /// Create a tracker that expands leading tabs to `tab_width` spaces
+    pub fn with_tab_width(tab_width: usize) -> Self {
         Self {
             indent_stack: vec![0], // Start with base level 0
             pending_dedents: VecDeque::new(),
             current_position: Position { row: 0, column: 0 },
+            tab_width,
+            style: None,
+            issues: Vec::new(),
         }
     }
 
+    /// Tab/space problems found in the lines processed so far
+    pub fn issues(&self) -> &[IndentationIssue] {
+        &self.issues
+    }
+
     /// Update current position
     pub fn set_position(&mut self, position: Position) {
         self.current_position = position;
@@ -63,8 +149,10 @@ impl IndentationTracker {
             return tokens;
         }
 
+        self.check_style(line);
+
         // Normalize tabs to spaces and calculate indentation level
-        let normalized_line = normalize_indentation(line, TAB_WIDTH);
+        let normalized_line = normalize_indentation(line, self.tab_width);
         let current_indent = count_leading_spaces(&normalized_line);
         let previous_indent = *self.indent_stack.last().unwrap_or(&0);
 
@@ -132,6 +220,37 @@ impl IndentationTracker {
         tokens
     }
 
+    /// Record an issue if the line's leading whitespace mixes tabs and
+    /// spaces, or uses a different style than the document so far
+    fn check_style(&mut self, line: &str) {
+        let leading = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+        let has_tabs = leading.contains('\t');
+        let has_spaces = leading.contains(' ');
+        let row = self.current_position.row + 1;
+
+        let found = match (has_tabs, has_spaces) {
+            (false, false) => return,
+            (true, true) => {
+                self.issues.push(IndentationIssue::MixedLine { line: row });
+                return;
+            }
+            (true, false) => IndentationStyle::Tabs,
+            (false, true) => IndentationStyle::Spaces,
+        };
+
+        match self.style {
+            None => self.style = Some(found),
+            Some(expected) if expected != found => {
+                self.issues.push(IndentationIssue::InconsistentStyle {
+                    line: row,
+                    found,
+                    expected,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+
     /// Finalize indentation processing (emit remaining dedents)
     ///
     /// This should be called at the end of document processing to ensure
@@ -210,14 +329,16 @@ pub fn normalize_indentation(line: &str, tab_width: usize) -> String {
 
 /// Check if an indentation level is valid (multiple of INDENT_SIZE)
 pub fn is_valid_indentation_level(level: usize) -> bool {
-    #[allow(clippy::assertions_on_constants)]
-    {
-        debug_assert!(
-            INDENT_SIZE > 0,
-            "INDENT_SIZE must be greater than zero to avoid division by zero"
-        );
-    }
-    level.is_multiple_of(INDENT_SIZE)
+    is_valid_indentation_for(level, INDENT_SIZE)
+}
+
+/// Check if an indentation level is a multiple of `indent_size`
+pub fn is_valid_indentation_for(level: usize, indent_size: usize) -> bool {
+    debug_assert!(
+        indent_size > 0,
+        "indent_size must be greater than zero to avoid division by zero"
+    );
+    level.is_multiple_of(indent_size)
 }
 
 #[cfg(test)]
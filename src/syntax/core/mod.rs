@@ -5,11 +5,16 @@
 //! ## Core Components
 //!
 //! - [`indentation`] - Indentation tracking and container boundary detection
+//! - [`options`] - Parse options (indentation width)
 //! - [`patterns`] - Core pattern matching utilities for token recognition
 
 pub mod indentation;
+pub mod options;
 pub mod patterns;
 
 // Re-export main interfaces
-pub use indentation::{IndentationTracker, INDENT_SIZE, TAB_WIDTH};
+pub use indentation::{
+    IndentationIssue, IndentationStyle, IndentationTracker, INDENT_SIZE, TAB_WIDTH,
+};
+pub use options::{ColumnUnit, ParseOptions, VerbatimOptions};
 pub use patterns::*;
@@ -0,0 +1,116 @@
+//! Options that change how source text is read
+
+use super::indentation::INDENT_SIZE;
+
+/// Options for parsing a document
+///
+/// The default matches the specification: four spaces per indentation
+/// level. Teams that write with a different width set `indent_size` so
+/// nested lists, sessions and verbatim walls are recognized at their width.
+///
+/// Blank lines separate blocks but are dropped from the tree by default.
+/// Consumers that mirror the source layout set `preserve_blank_lines` to keep
+/// them as `BlankLine` elements.
+///
+/// Token columns count characters. Editors that address text in UTF-8 bytes
+/// or UTF-16 code units set `column_unit` to get columns they can use as is.
+///
+/// `mark_abstract` tags a lead paragraph with a synthetic `abstract`
+/// annotation, for tooling that treats it as the document's abstract.
+///
+/// `normalize_unicode` NFC-normalizes the text tokens carry, so `e` plus a
+/// combining accent and a precomposed `é` compare equal. Verbatim content
+/// is left as written.
+///
+/// `typography` curls straight quotes and turns `--` and `---` into en and
+/// em dashes in prose text, leaving code, math and verbatim content alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Spaces per indentation level
+    ///
+    /// Leading tabs expand to this many spaces.
+    pub indent_size: usize,
+
+    /// Keep standalone blank lines as `BlankLine` elements in their container
+    ///
+    /// Blank lines that are part of a block's own syntax, like the one
+    /// between a session title and its content, are never kept.
+    pub preserve_blank_lines: bool,
+
+    /// Unit that token columns are counted in
+    pub column_unit: ColumnUnit,
+
+    /// How verbatim content is built
+    pub verbatim: VerbatimOptions,
+
+    /// Tag the paragraph that opens the document as its abstract
+    ///
+    /// See `assembly::abstract_marking` for which paragraph counts.
+    pub mark_abstract: bool,
+
+    /// NFC-normalize token text outside verbatim content
+    ///
+    /// Spans still point at the source as written, so a normalized token's
+    /// text can differ from the source under its span.
+    pub normalize_unicode: bool,
+
+    /// Rewrite quotes and dashes in prose text
+    ///
+    /// See `assembly::typography` for the rules.
+    pub typography: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            indent_size: INDENT_SIZE,
+            preserve_blank_lines: false,
+            column_unit: ColumnUnit::default(),
+            verbatim: VerbatimOptions::default(),
+            mark_abstract: false,
+            normalize_unicode: false,
+            typography: false,
+        }
+    }
+}
+
+/// Options for building verbatim blocks
+///
+/// Blank lines inside verbatim content are kept by default, since the
+/// content is reproduced as written. Authors often pad a block with blank
+/// lines for readability, though; `trim_blank_edges` drops those at the
+/// start and end of the content. Blank lines between content lines are
+/// always kept.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VerbatimOptions {
+    /// Drop blank lines before the first and after the last content line
+    pub trim_blank_edges: bool,
+}
+
+/// Unit for the `column` of source positions
+///
+/// The units agree on ASCII text and differ once a line has other
+/// characters before the position: `é` is one character, two UTF-8 bytes
+/// and one UTF-16 code unit, while `🚀` is one character, four bytes and
+/// two code units.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColumnUnit {
+    /// UTF-8 bytes, for slicing Rust strings
+    Bytes,
+    /// Unicode scalar values (`char`s)
+    #[default]
+    Chars,
+    /// UTF-16 code units, as used by the Language Server Protocol
+    Utf16,
+}
+
+impl ColumnUnit {
+    /// Columns `ch` takes up in this unit
+    pub fn width(self, ch: char) -> usize {
+        match self {
+            ColumnUnit::Bytes => ch.len_utf8(),
+            ColumnUnit::Chars => 1,
+            ColumnUnit::Utf16 => ch.len_utf16(),
+        }
+    }
+}
@@ -41,8 +41,9 @@ fn is_likely_identifier_underscore<L: InlineDelimiterLexer>(lexer: &L) -> bool {
             // Look at what comes after the second underscore
             match lexer.peek_at(2) {
                 Some(ch) if ch.is_alphanumeric() => {
-                    // __letter - likely identifier like __test
-                    true
+                    // __letter - likely identifier like __test, unless a
+                    // closing __ follows on the line (__strong__)
+                    !has_closing_double_underscore(lexer, 3)
                 }
                 _ => {
                     // __ at end or followed by non-alphanumeric - likely double delimiter
@@ -83,6 +84,27 @@ fn is_likely_identifier_underscore<L: InlineDelimiterLexer>(lexer: &L) -> bool {
     }
 }
 
+/// Whether a `__` closing a doubled delimiter follows on the line
+///
+/// The closing pair must come right after content, not after whitespace.
+fn has_closing_double_underscore<L: InlineDelimiterLexer>(lexer: &L, from: usize) -> bool {
+    let mut pos = from;
+    while let Some(ch) = lexer.peek_at(pos) {
+        match ch {
+            '\n' | '\r' => return false,
+            '_' if lexer.peek_at(pos + 1) == Some('_')
+                && lexer
+                    .peek_at(pos - 1)
+                    .is_some_and(|prev| !prev.is_whitespace()) =>
+            {
+                return true;
+            }
+            _ => pos += 1,
+        }
+    }
+    false
+}
+
 /// Read inline formatting delimiters (*, _, `, #)
 pub fn read_inline_delimiter<L: InlineDelimiterLexer>(lexer: &mut L) -> Option<ScannerToken> {
     let start_pos = lexer.current_position();
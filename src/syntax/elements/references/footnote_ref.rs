@@ -3,6 +3,8 @@
 //! Handles parsing of footnote references in TXXT syntax:
 //! - Naked numerical format: [1], [2], [42]
 //! - Labeled format: [^note1], [^detailed-explanation]
+//! - Auto format: [^] (the id is generated when footnotes are collected)
+//! - Inline format: [^: the note text] (content given in place, id generated)
 //!
 //! Footnote references provide links to footnote content and supplementary information.
 
@@ -35,7 +37,7 @@ pub trait FootnoteRefLexer {
     fn backtrack(&mut self, position: usize, row: usize, column: usize);
 }
 
-/// Read a footnote reference token ([1], [2], [^label])
+/// Read a footnote reference token ([1], [2], [^label], [^], [^: text])
 pub fn read_footnote_ref<L>(lexer: &mut L) -> Option<ScannerToken>
 where
     L: FootnoteRefLexer,
@@ -100,6 +102,10 @@ pub enum FootnoteType {
     Naked(u32),
     /// Labeled footnote: [^note1], [^explanation]
     Labeled(String),
+    /// Auto-generated footnote: [^]
+    Auto,
+    /// Inline footnote carrying its content, id generated: [^: the note text]
+    Inline(String),
 }
 
 /// Classify footnote content to determine if it's a valid footnote reference
@@ -108,6 +114,20 @@ fn classify_footnote_content(content: &str) -> Option<FootnoteType> {
         return None;
     }
 
+    // Check for auto-generated format: ^ with no label
+    if content == "^" {
+        return Some(FootnoteType::Auto);
+    }
+
+    // Check for inline footnote format: ^: content (content required)
+    if let Some(text) = content.strip_prefix("^:") {
+        let text = text.trim();
+        if text.is_empty() {
+            return None;
+        }
+        return Some(FootnoteType::Inline(text.to_string()));
+    }
+
     // Check for labeled footnote format: ^label
     if let Some(label) = content.strip_prefix('^') {
         if is_valid_footnote_label(label) {
@@ -178,10 +198,20 @@ mod tests {
             Some(FootnoteType::Labeled("methodology_note".to_string()))
         );
 
+        // Auto-generated footnote
+        assert_eq!(classify_footnote_content("^"), Some(FootnoteType::Auto));
+
+        // Inline footnotes
+        assert_eq!(
+            classify_footnote_content("^: the note text"),
+            Some(FootnoteType::Inline("the note text".to_string()))
+        );
+        assert_eq!(classify_footnote_content("^:"), None); // No content
+        assert_eq!(classify_footnote_content("^:   "), None);
+
         // Invalid cases
         assert_eq!(classify_footnote_content(""), None);
         assert_eq!(classify_footnote_content("0"), None); // Zero not allowed
-        assert_eq!(classify_footnote_content("^"), None); // Empty label
         assert_eq!(classify_footnote_content("^123"), None); // Label can't start with digit
         assert_eq!(classify_footnote_content("^-invalid"), None); // Label can't start with dash
         assert_eq!(classify_footnote_content("text"), None); // Not a footnote pattern
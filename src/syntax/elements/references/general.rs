@@ -52,7 +52,17 @@ pub trait ReferenceLexer {
 
         // Read content until ] or end of line
         while let Some(ch) = self.peek() {
-            if ch == ']' {
+            // An escaped bracket or backslash is content, without its backslash
+            let escaped = self
+                .input()
+                .get(self.position() + 1)
+                .copied()
+                .filter(|&next| ch == '\\' && matches!(next, '[' | ']' | '\\'));
+            if let Some(next) = escaped {
+                self.advance();
+                self.advance();
+                content.push(next);
+            } else if ch == ']' {
                 self.advance(); // Consume ]
                 found_closing = true;
                 break;
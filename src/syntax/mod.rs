@@ -43,6 +43,7 @@ pub mod parameter_parsing;
 pub mod verbatim_boundary;
 
 // Re-export main interfaces
+pub use self::core::{ColumnUnit, IndentationIssue, ParseOptions, VerbatimOptions};
 pub use semantic_analysis::{SemanticAnalysisError, SemanticAnalyzer};
 pub use tokenization::Lexer;
 pub use verbatim_scanning::{VerbatimBlock, VerbatimScanner, VerbatimType};
@@ -69,3 +70,9 @@ pub fn tokenize(text: &str) -> Vec<ScannerToken> {
     let mut lexer = Lexer::new(text);
     lexer.tokenize()
 }
+
+/// Tokenization entry point for documents read with non-default options
+pub fn tokenize_with(text: &str, options: ParseOptions) -> Vec<ScannerToken> {
+    let mut lexer = Lexer::with_options(text, options);
+    lexer.tokenize()
+}
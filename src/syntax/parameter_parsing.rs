@@ -15,8 +15,8 @@
 //! - Boolean shorthand: `debug` (implies `debug=true`)
 //! - Quoted values support escape sequences: `\"`, `\\`, `\n`
 
-use crate::cst::ScannerToken;
-use std::collections::HashMap;
+use crate::cst::{ScannerToken, SourceSpan};
+use std::collections::{HashMap, HashSet};
 
 /// Validate a parameter key according to txxt rules
 ///
@@ -281,6 +281,68 @@ pub fn extract_parameters_from_tokens(tokens: &[ScannerToken]) -> HashMap<String
     params
 }
 
+/// Find the first key that appears more than once in parameter tokens
+///
+/// See [`find_duplicate_parameters`], which reports every repeated key.
+///
+/// # Arguments
+/// * `tokens` - Token sequence containing parameters
+///
+/// # Returns
+/// * `Some((key, span))` for the first repeated key
+/// * `None` if every key is unique
+pub fn find_duplicate_parameter(tokens: &[ScannerToken]) -> Option<(String, SourceSpan)> {
+    find_duplicate_parameters(tokens).into_iter().next()
+}
+
+/// Find every key that appears more than once in parameter tokens
+///
+/// [`extract_parameters_from_tokens`] keeps the last value of a repeated
+/// key, so the map alone cannot tell a duplicate happened. This walks the
+/// same pairs in source order and reports each repeated key once, at its
+/// second occurrence, with the span of that key token.
+///
+/// # Arguments
+/// * `tokens` - Token sequence containing parameters
+///
+/// # Returns
+/// * `(key, span)` for each repeated key, in source order
+pub fn find_duplicate_parameters(tokens: &[ScannerToken]) -> Vec<(String, SourceSpan)> {
+    let mut seen = HashSet::new();
+    let mut duplicates: Vec<(String, SourceSpan)> = Vec::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        if let Some((key, _, consumed)) = parse_parameter_pair(tokens, i) {
+            let reported = duplicates.iter().any(|(duplicate, _)| *duplicate == key);
+            if !seen.insert(key.clone()) && !reported {
+                // The key token is the first one the pair did not skip
+                let span = tokens[i..i + consumed].iter().find(|token| {
+                    matches!(
+                        token,
+                        ScannerToken::Identifier { .. } | ScannerToken::Text { .. }
+                    )
+                });
+                if let Some(span) = span {
+                    duplicates.push((key, span.span().clone()));
+                }
+            }
+            i += consumed;
+
+            while i < tokens.len()
+                && (matches!(&tokens[i], ScannerToken::Whitespace { .. })
+                    || matches!(&tokens[i], ScannerToken::Comma { .. }))
+            {
+                i += 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    duplicates
+}
+
 /// Parse a boolean value from string
 ///
 /// Recognizes standard boolean representations:
@@ -320,7 +382,7 @@ pub fn parse_boolean_value(value: &str) -> Option<bool> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::cst::{Position, SourceSpan};
+    use crate::cst::Position;
 
     #[test]
     fn test_is_valid_parameter_key_valid() {
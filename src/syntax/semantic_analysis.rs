@@ -12,6 +12,7 @@ use crate::cst::high_level_tokens::{
 };
 use crate::cst::primitives::ScannerTokenSequence;
 use crate::cst::{Position, ScannerToken, SequenceMarkerType, SourceSpan};
+use crate::syntax::elements::references::FootnoteType;
 use crate::syntax::list_detection;
 
 /// Parsed components of an annotation
@@ -222,6 +223,23 @@ fn find_closing_marker(tokens: &[ScannerToken]) -> Result<usize, String> {
     Err("Annotation must have closing TxxtMarker".to_string())
 }
 
+/// Shift a span right by `columns`, keeping its rows
+///
+/// Used to place spans computed for quoted text (which starts after the
+/// `>` marker) back at their position in the source line.
+fn offset_columns(span: SourceSpan, columns: usize) -> SourceSpan {
+    SourceSpan {
+        start: Position {
+            row: span.start.row,
+            column: span.start.column + columns,
+        },
+        end: Position {
+            row: span.end.row,
+            column: span.end.column + columns,
+        },
+    }
+}
+
 /// Detect if a line pattern represents a session start (context-aware)
 ///
 /// After grammar simplification (issue #139), sessions are identified by:
@@ -512,6 +530,22 @@ impl SemanticAnalyzer {
                     continue;
                 }
 
+                // Quote marker - group the quoted lines and analyze their content
+                ScannerToken::QuoteMarker { .. } => {
+                    let (quote_lines, consumed) = self.recognize_quote_pattern(&scanner_tokens, i);
+                    high_level_tokens.push(self.transform_quote(quote_lines)?);
+                    pending_indentation.clear();
+                    i += consumed;
+                }
+
+                // Pipe at line start - the scanner only emits these for table rows
+                ScannerToken::Pipe { .. } => {
+                    let (table_lines, consumed) = self.recognize_table_pattern(&scanner_tokens, i);
+                    high_level_tokens.push(self.transform_table(table_lines)?);
+                    pending_indentation.clear();
+                    i += consumed;
+                }
+
                 // Process line-level tokens
                 _ => {
                     // First, try to recognize complex patterns (annotations, definitions, verbatim blocks)
@@ -657,12 +691,63 @@ impl SemanticAnalyzer {
                 }
             }
 
+            // Check for footnote definition pattern: FootnoteRef(labeled) + Colon at line start
+            ScannerToken::FootnoteRef {
+                footnote_type: FootnoteType::Labeled(_),
+                ..
+            } => {
+                if let Some((tokens, consumed)) =
+                    self.recognize_footnote_definition_pattern(scanner_tokens, start_index)
+                {
+                    return Ok(Some((tokens, consumed)));
+                }
+            }
+
             _ => {}
         }
 
         Ok(None)
     }
 
+    /// Recognize footnote definition pattern: FootnoteRef + Colon + (content)? + Newline
+    /// Example: "[^note]: The definition text"
+    ///
+    /// Only the marker line is consumed. An indented body on the following
+    /// lines is processed separately, like a definition's content.
+    fn recognize_footnote_definition_pattern(
+        &self,
+        scanner_tokens: &[ScannerToken],
+        start_index: usize,
+    ) -> Option<(Vec<ScannerToken>, usize)> {
+        if !matches!(
+            scanner_tokens.get(start_index + 1),
+            Some(ScannerToken::Colon { .. })
+        ) {
+            return None;
+        }
+
+        // The marker must open its line; mid-line "[^a]:" is paragraph text
+        let at_line_start = scanner_tokens[..start_index]
+            .iter()
+            .rev()
+            .find(|token| !matches!(token, ScannerToken::Whitespace { .. }))
+            .is_none_or(|token| {
+                matches!(
+                    token,
+                    ScannerToken::Newline { .. }
+                        | ScannerToken::BlankLine { .. }
+                        | ScannerToken::Indent { .. }
+                        | ScannerToken::Dedent { .. }
+                )
+            });
+        if !at_line_start {
+            return None;
+        }
+
+        let (line_tokens, consumed) = self.extract_line_tokens(scanner_tokens, start_index).ok()?;
+        Some((line_tokens, consumed))
+    }
+
     /// Recognize definition pattern (NEW syntax after grammar simplification):
     /// Pattern: Text + Colon + Newline + Whitespace + content
     /// Example: "Term:\n    Definition content"
@@ -761,7 +846,7 @@ impl SemanticAnalyzer {
         scanner_tokens: &[ScannerToken],
         start_index: usize,
     ) -> Result<Option<(Vec<ScannerToken>, usize)>, SemanticAnalysisError> {
-        if start_index + 4 >= scanner_tokens.len() {
+        if start_index + 2 >= scanner_tokens.len() {
             return Ok(None);
         }
 
@@ -834,9 +919,57 @@ impl SemanticAnalyzer {
             let pattern_tokens = scanner_tokens[start_index..start_index + consumed].to_vec();
             Ok(Some((pattern_tokens, consumed)))
         } else {
-            // No closing TxxtMarker found
-            Ok(None)
+            // No closing TxxtMarker found: only a truncated document (the
+            // line is the last thing in the input) is recovered as an
+            // annotation; anywhere else the line is ordinary text
+            Ok(Self::recognize_truncated_annotation(
+                scanner_tokens,
+                start_index,
+            ))
+        }
+    }
+
+    /// Recognize an annotation cut off before its closing marker by the end of input
+    ///
+    /// Returns the line's tokens (newline included) when nothing but blank
+    /// lines and dedents follow it.
+    fn recognize_truncated_annotation(
+        scanner_tokens: &[ScannerToken],
+        start_index: usize,
+    ) -> Option<(Vec<ScannerToken>, usize)> {
+        let line_end = scanner_tokens[start_index..]
+            .iter()
+            .position(|token| {
+                matches!(
+                    token,
+                    ScannerToken::Newline { .. } | ScannerToken::Eof { .. }
+                )
+            })
+            .map_or(scanner_tokens.len(), |offset| start_index + offset);
+
+        let consumed = match scanner_tokens.get(line_end) {
+            Some(ScannerToken::Newline { .. }) => line_end + 1 - start_index,
+            _ => line_end - start_index,
+        };
+
+        let rest_is_blank = scanner_tokens[start_index + consumed..]
+            .iter()
+            .all(|token| {
+                matches!(
+                    token,
+                    ScannerToken::BlankLine { .. }
+                        | ScannerToken::Newline { .. }
+                        | ScannerToken::Whitespace { .. }
+                        | ScannerToken::Dedent { .. }
+                        | ScannerToken::Eof { .. }
+                )
+            });
+        if !rest_is_blank {
+            return None;
         }
+
+        let pattern_tokens = scanner_tokens[start_index..start_index + consumed].to_vec();
+        Some((pattern_tokens, consumed))
     }
 
     /// Recognize verbatim block pattern (Issue #132)
@@ -891,6 +1024,258 @@ impl SemanticAnalyzer {
         ))
     }
 
+    /// Recognize a quote: consecutive lines starting with a QuoteMarker
+    ///
+    /// Continuation lines may repeat the indentation in front of the marker.
+    /// Returns the scanner tokens of each quoted line (newline included) and
+    /// the number of tokens consumed.
+    fn recognize_quote_pattern(
+        &self,
+        scanner_tokens: &[ScannerToken],
+        start_index: usize,
+    ) -> (Vec<Vec<ScannerToken>>, usize) {
+        let mut lines = Vec::new();
+        let mut i = start_index;
+
+        loop {
+            let marker_index = match (scanner_tokens.get(i), scanner_tokens.get(i + 1)) {
+                (Some(ScannerToken::QuoteMarker { .. }), _) => i,
+                (Some(ScannerToken::Whitespace { .. }), Some(ScannerToken::QuoteMarker { .. }))
+                    if !lines.is_empty() =>
+                {
+                    i + 1
+                }
+                _ => break,
+            };
+
+            let mut end = marker_index + 1;
+            while end < scanner_tokens.len()
+                && !matches!(
+                    scanner_tokens[end],
+                    ScannerToken::Newline { .. } | ScannerToken::Eof { .. }
+                )
+            {
+                end += 1;
+            }
+            if matches!(scanner_tokens.get(end), Some(ScannerToken::Newline { .. })) {
+                end += 1;
+            }
+
+            lines.push(scanner_tokens[i..end].to_vec());
+            i = end;
+        }
+
+        (lines, i - start_index)
+    }
+
+    /// Transform quoted lines into a Quote semantic token
+    ///
+    /// The markers are stripped and the remaining tokens are analyzed as a
+    /// document of their own. The outer scanner only tracks indentation in
+    /// front of the markers, so Indent/Dedent tokens for the quoted text are
+    /// synthesized here from the whitespace that follows each marker.
+    fn transform_quote(
+        &self,
+        quote_lines: Vec<Vec<ScannerToken>>,
+    ) -> Result<HighLevelToken, SemanticAnalysisError> {
+        use crate::syntax::core::indentation::IndentationTracker;
+
+        let mut quoted_tokens = Vec::new();
+        let mut tracker = IndentationTracker::new();
+
+        for line in &quote_lines {
+            let marker_index = line
+                .iter()
+                .position(|token| matches!(token, ScannerToken::QuoteMarker { .. }))
+                .ok_or_else(|| {
+                    SemanticAnalysisError::AnalysisError(
+                        "Quote line must contain a quote marker".to_string(),
+                    )
+                })?;
+            let rest = &line[marker_index + 1..];
+
+            // A bare `>` line separates blocks inside the quote
+            if rest.iter().all(|token| {
+                matches!(
+                    token,
+                    ScannerToken::Whitespace { .. } | ScannerToken::Newline { .. }
+                )
+            }) {
+                let marker_span = line[marker_index].span();
+                let end = line.last().map_or(marker_span, |token| token.span());
+                quoted_tokens.push(ScannerToken::BlankLine {
+                    whitespace: String::new(),
+                    span: SourceSpan {
+                        start: marker_span.start,
+                        end: end.end,
+                    },
+                });
+                continue;
+            }
+
+            // Indentation spans are relative to where the quoted text starts
+            let content_start = rest[0].span().start;
+            tracker.set_position(content_start);
+            let line_text: String = rest.iter().map(|token| token.content()).collect();
+            for token in tracker.process_line_indentation(&line_text) {
+                quoted_tokens.push(match token {
+                    ScannerToken::Indent { span } => ScannerToken::Indent {
+                        span: offset_columns(span, content_start.column),
+                    },
+                    ScannerToken::Dedent { span } => ScannerToken::Dedent {
+                        span: offset_columns(span, content_start.column),
+                    },
+                    other => other,
+                });
+            }
+            quoted_tokens.extend(rest.iter().cloned());
+        }
+
+        let all_tokens: Vec<ScannerToken> = quote_lines.into_iter().flatten().collect();
+        let span = match (all_tokens.first(), all_tokens.last()) {
+            (Some(first), Some(last)) => SourceSpan {
+                start: first.span().start,
+                end: last.span().end,
+            },
+            _ => {
+                return Err(SemanticAnalysisError::AnalysisError(
+                    "Quote must have at least one line".to_string(),
+                ))
+            }
+        };
+
+        tracker.set_position(span.end);
+        quoted_tokens.extend(tracker.finalize());
+
+        let content = self.analyze(quoted_tokens)?.tokens;
+
+        Ok(HighLevelTokenBuilder::quote_with_tokens(
+            content,
+            span,
+            ScannerTokenSequence::from_tokens(all_tokens),
+        ))
+    }
+
+    /// Recognize a table: consecutive lines starting with a Pipe
+    ///
+    /// Continuation lines may repeat the indentation in front of the first
+    /// pipe. Returns the scanner tokens of each row (newline included) and the
+    /// number of tokens consumed.
+    fn recognize_table_pattern(
+        &self,
+        scanner_tokens: &[ScannerToken],
+        start_index: usize,
+    ) -> (Vec<Vec<ScannerToken>>, usize) {
+        let mut lines = Vec::new();
+        let mut i = start_index;
+
+        loop {
+            let row_start = match (scanner_tokens.get(i), scanner_tokens.get(i + 1)) {
+                (Some(ScannerToken::Pipe { .. }), _) => i,
+                (Some(ScannerToken::Whitespace { .. }), Some(ScannerToken::Pipe { .. }))
+                    if !lines.is_empty() =>
+                {
+                    i + 1
+                }
+                _ => break,
+            };
+
+            let mut end = row_start + 1;
+            while end < scanner_tokens.len()
+                && !matches!(
+                    scanner_tokens[end],
+                    ScannerToken::Newline { .. } | ScannerToken::Eof { .. }
+                )
+            {
+                end += 1;
+            }
+            if matches!(scanner_tokens.get(end), Some(ScannerToken::Newline { .. })) {
+                end += 1;
+            }
+
+            lines.push(scanner_tokens[i..end].to_vec());
+            i = end;
+        }
+
+        (lines, i - start_index)
+    }
+
+    /// Transform table rows into a Table semantic token
+    ///
+    /// Each row is split at its pipes; the text between two pipes becomes a
+    /// cell TextSpan with the surrounding whitespace trimmed.
+    fn transform_table(
+        &self,
+        table_lines: Vec<Vec<ScannerToken>>,
+    ) -> Result<HighLevelToken, SemanticAnalysisError> {
+        let mut rows = Vec::new();
+
+        for line in &table_lines {
+            let pipes: Vec<usize> = line
+                .iter()
+                .enumerate()
+                .filter(|(_, token)| matches!(token, ScannerToken::Pipe { .. }))
+                .map(|(index, _)| index)
+                .collect();
+            if pipes.len() < 2 {
+                return Err(SemanticAnalysisError::AnalysisError(
+                    "Table row must be wrapped in pipes".to_string(),
+                ));
+            }
+
+            let cells = pipes
+                .windows(2)
+                .map(|pair| {
+                    let mut cell = &line[pair[0] + 1..pair[1]];
+                    while let [ScannerToken::Whitespace { .. }, rest @ ..] = cell {
+                        cell = rest;
+                    }
+                    while let [rest @ .., ScannerToken::Whitespace { .. }] = cell {
+                        cell = rest;
+                    }
+
+                    let span = match (cell.first(), cell.last()) {
+                        (Some(first), Some(last)) => SourceSpan {
+                            start: first.span().start,
+                            end: last.span().end,
+                        },
+                        // Empty cells sit right after their opening pipe
+                        _ => {
+                            let end = line[pair[0]].span().end;
+                            SourceSpan { start: end, end }
+                        }
+                    };
+                    let content: String = cell.iter().map(|token| token.content()).collect();
+                    HighLevelTokenBuilder::text_span_with_tokens(
+                        content,
+                        span,
+                        ScannerTokenSequence::from_tokens(cell.to_vec()),
+                    )
+                })
+                .collect();
+            rows.push(cells);
+        }
+
+        let all_tokens: Vec<ScannerToken> = table_lines.into_iter().flatten().collect();
+        let span = match (all_tokens.first(), all_tokens.last()) {
+            (Some(first), Some(last)) => SourceSpan {
+                start: first.span().start,
+                end: last.span().end,
+            },
+            _ => {
+                return Err(SemanticAnalysisError::AnalysisError(
+                    "Table must have at least one row".to_string(),
+                ))
+            }
+        };
+
+        Ok(HighLevelTokenBuilder::table_with_tokens(
+            rows,
+            span,
+            ScannerTokenSequence::from_tokens(all_tokens),
+        ))
+    }
+
     /// Transform complex pattern tokens into semantic tokens
     ///
     /// This method determines which transformation to apply based on the
@@ -901,6 +1286,7 @@ impl SemanticAnalyzer {
     ///
     /// # Returns
     /// * `Result<HighLevelToken, SemanticAnalysisError>` - The transformed semantic token
+    #[allow(clippy::collapsible_match)]
     fn transform_complex_pattern(
         &self,
         pattern_tokens: Vec<ScannerToken>,
@@ -937,27 +1323,29 @@ impl SemanticAnalyzer {
 
             // Annotation pattern: TxxtMarker + Whitespace + (Identifier|Text) + ... + TxxtMarker
             // The middle can include parameters: Colon + Identifier + Equals + Text/QuotedString + Comma + ...
+            // The closing marker may be missing when the input ends mid-annotation
             ScannerToken::TxxtMarker { .. } => {
-                if pattern_tokens.len() >= 5
+                if pattern_tokens.len() >= 3
                     && matches!(pattern_tokens[1], ScannerToken::Whitespace { .. })
                     && matches!(
                         pattern_tokens[2],
                         ScannerToken::Identifier { .. } | ScannerToken::Text { .. }
                     )
                 {
-                    // Find closing TxxtMarker
                     let has_closing_marker = pattern_tokens
                         .iter()
                         .skip(3)
                         .any(|t| matches!(t, ScannerToken::TxxtMarker { .. }));
 
-                    if has_closing_marker {
-                        let span = SourceSpan {
-                            start: pattern_tokens[0].span().start,
-                            end: pattern_tokens[pattern_tokens.len() - 1].span().end,
-                        };
-                        return self.transform_annotation(pattern_tokens, span);
-                    }
+                    let span = SourceSpan {
+                        start: pattern_tokens[0].span().start,
+                        end: pattern_tokens[pattern_tokens.len() - 1].span().end,
+                    };
+                    return if has_closing_marker {
+                        self.transform_annotation(pattern_tokens, span)
+                    } else {
+                        self.transform_truncated_annotation(pattern_tokens, span)
+                    };
                 }
             }
 
@@ -978,6 +1366,14 @@ impl SemanticAnalyzer {
                 }
             }
 
+            // Footnote definition pattern: FootnoteRef + Colon + (content)? + Newline
+            ScannerToken::FootnoteRef {
+                footnote_type: FootnoteType::Labeled(_),
+                ..
+            } if matches!(pattern_tokens.get(1), Some(ScannerToken::Colon { .. })) => {
+                return self.transform_footnote_definition(pattern_tokens);
+            }
+
             _ => {}
         }
 
@@ -1639,6 +2035,89 @@ impl SemanticAnalyzer {
         ))
     }
 
+    /// Transform a footnote definition marker line into a FootnoteDefinition token
+    ///
+    /// The text after the colon, if any, becomes the token's content. The
+    /// body of a multi-paragraph definition is not part of the pattern.
+    pub fn transform_footnote_definition(
+        &self,
+        tokens: Vec<ScannerToken>,
+    ) -> Result<HighLevelToken, SemanticAnalysisError> {
+        let label = match tokens.first() {
+            Some(ScannerToken::FootnoteRef {
+                footnote_type: FootnoteType::Labeled(label),
+                ..
+            }) => label.clone(),
+            _ => {
+                return Err(SemanticAnalysisError::AnalysisError(
+                    "Footnote definition must start with a labeled footnote marker".to_string(),
+                ))
+            }
+        };
+
+        // Content runs from after the colon (and its whitespace) to the newline
+        let content_tokens: Vec<ScannerToken> = tokens
+            .iter()
+            .skip(2)
+            .skip_while(|token| matches!(token, ScannerToken::Whitespace { .. }))
+            .filter(|token| !matches!(token, ScannerToken::Newline { .. }))
+            .cloned()
+            .collect();
+        let content = if content_tokens.is_empty() {
+            None
+        } else {
+            Some(self.tokens_to_text_span_line_level(content_tokens)?)
+        };
+
+        let span = SourceSpan {
+            start: tokens[0].span().start,
+            end: tokens[tokens.len() - 1].span().end,
+        };
+
+        Ok(HighLevelTokenBuilder::footnote_definition_with_tokens(
+            label,
+            content,
+            span,
+            ScannerTokenSequence::from_tokens(tokens),
+        ))
+    }
+
+    /// Transform an annotation cut off before its closing marker
+    ///
+    /// Only produced for the last line of a truncated document (see
+    /// `recognize_truncated_annotation`): everything after the opening marker
+    /// is the label, and there is no content.
+    pub fn transform_truncated_annotation(
+        &self,
+        tokens: Vec<ScannerToken>,
+        span: SourceSpan,
+    ) -> Result<HighLevelToken, SemanticAnalysisError> {
+        let label_end = tokens
+            .iter()
+            .rposition(|t| {
+                !matches!(
+                    t,
+                    ScannerToken::Newline { .. } | ScannerToken::Whitespace { .. }
+                )
+            })
+            .map_or(0, |last| last + 1);
+        if label_end <= 2 {
+            return Err(SemanticAnalysisError::AnalysisError(
+                "Truncated annotation must have a label".to_string(),
+            ));
+        }
+
+        let (label_token, parameters) = self.parse_label_with_parameters(&tokens[2..label_end])?;
+
+        Ok(HighLevelTokenBuilder::annotation_with_tokens(
+            label_token,
+            parameters,
+            None,
+            span,
+            ScannerTokenSequence::from_tokens(tokens),
+        ))
+    }
+
     /// Transform scanner tokens into a Definition semantic token
     ///
     /// This implements the Definition transformation after grammar simplification.
@@ -1835,10 +2314,19 @@ impl SemanticAnalyzer {
         // - Consistent Label + Parameters token creation
         //
         // See: parse_label_and_parameters_from_string() for unified implementation
-        let (label, parameters) = self.parse_label_and_parameters_from_string(
-            &label_raw,
-            tokens[tokens.len() - 1].span().clone(),
-        )?;
+        //
+        // A block cut off before any terminator line has no label at all
+        let end_span = tokens[tokens.len() - 1].span().clone();
+        let (label, parameters) = if label_raw.is_empty() {
+            let label = HighLevelToken::Label {
+                text: String::new(),
+                span: end_span,
+                tokens: ScannerTokenSequence::new(),
+            };
+            (label, None)
+        } else {
+            self.parse_label_and_parameters_from_string(&label_raw, end_span)?
+        };
 
         // Aggregate all source tokens
         let aggregated_tokens = ScannerTokenSequence::from_tokens(tokens);
@@ -4,7 +4,8 @@
 //! positioning for language server support.
 
 use crate::cst::{Position, ScannerToken, SourceSpan};
-use crate::syntax::core::indentation::IndentationTracker;
+use crate::syntax::core::indentation::{IndentationIssue, IndentationTracker};
+use crate::syntax::core::{ColumnUnit, ParseOptions};
 use crate::syntax::elements::components::sequence::read_sequence_marker;
 use crate::syntax::elements::formatting::read_inline_delimiter;
 use crate::syntax::elements::references::{
@@ -12,6 +13,7 @@ use crate::syntax::elements::references::{
     session_ref::read_session_ref,
 };
 use crate::syntax::verbatim_scanning::{VerbatimLexer, VerbatimScanner};
+use unicode_normalization::{is_nfc, UnicodeNormalization};
 
 /// Check if a character is a special delimiter that should terminate text tokens
 fn is_special_delimiter(ch: char) -> bool {
@@ -36,17 +38,34 @@ pub struct Lexer {
     pub(crate) row: usize,
     pub(crate) column: usize,
     pub(crate) indent_tracker: IndentationTracker,
+    /// Whether the current line is a table row, making `|` a cell delimiter
+    pub(crate) in_table_row: bool,
+    /// Spaces per indentation level
+    pub(crate) indent_size: usize,
+    /// Unit token columns are reported in
+    pub(crate) column_unit: ColumnUnit,
+    /// Whether token text is NFC-normalized
+    pub(crate) normalize_unicode: bool,
 }
 
 impl Lexer {
     /// Create a new lexer for the given input text
     pub fn new(input: &str) -> Self {
+        Self::with_options(input, ParseOptions::default())
+    }
+
+    /// Create a lexer that reads indentation with the given options
+    pub fn with_options(input: &str, options: ParseOptions) -> Self {
         Self {
             input: input.chars().collect(),
             position: 0,
             row: 0,
             column: 0,
-            indent_tracker: IndentationTracker::new(),
+            indent_tracker: IndentationTracker::with_tab_width(options.indent_size),
+            in_table_row: false,
+            indent_size: options.indent_size,
+            column_unit: options.column_unit,
+            normalize_unicode: options.normalize_unicode,
         }
     }
 
@@ -56,10 +75,14 @@ impl Lexer {
 
         // First, pre-scan for verbatim block boundaries (NEW - Issue #132)
         let input_text: String = self.input.iter().collect();
-        let verbatim_scanner = VerbatimScanner::new();
+        let verbatim_scanner = VerbatimScanner::with_indent_size(self.indent_size);
         let verbatim_boundaries = verbatim_scanner.scan_boundaries(&input_text);
 
         while !self.is_at_end() {
+            if self.column == 0 {
+                self.in_table_row = false;
+            }
+
             // NEW: Check if we're at a verbatim boundary (title or terminator line)
             let current_line = self.row + 1; // 1-based line numbers
             if let Some(boundary_token) =
@@ -96,8 +119,27 @@ impl Lexer {
                 }
             }
 
-            // Try to read sequence marker at start of line or after indentation
-            if self.column == 0 || self.is_at_line_start_after_indent(&tokens) {
+            // Try to read quote marker where a line's content starts
+            if self.column == 0 || Self::is_at_line_content_start(&tokens) {
+                if let Some(token) = self.read_quote_marker() {
+                    tokens.push(token);
+                    continue;
+                }
+            }
+
+            // A line whose content is wrapped in pipes is a table row
+            if (self.column == 0 || Self::is_at_line_content_start(&tokens))
+                && self.is_at_table_row_start()
+            {
+                self.in_table_row = true;
+            }
+
+            // Try to read sequence marker at start of line, after indentation,
+            // or at the start of quoted content
+            if self.column == 0
+                || self.is_at_line_start_after_indent(&tokens)
+                || Self::is_at_quoted_content_start(&tokens)
+            {
                 if let Some(token) = read_sequence_marker(self) {
                     tokens.push(token);
                     continue;
@@ -138,7 +180,9 @@ impl Lexer {
                 break;
             }
 
-            if let Some(token) = self.read_txxt_marker() {
+            if let Some(token) = self.read_pipe() {
+                tokens.push(token);
+            } else if let Some(token) = self.read_txxt_marker() {
                 tokens.push(token);
             // TODO: Update these to work with atomic tokens from parser level
             } else if let Some(token) = read_citation_ref(self) {
@@ -189,6 +233,30 @@ impl Lexer {
         // The scanner emits basic tokens (Text, Colon, Equals, etc.) and
         // semantic analysis uses scan_parameter_string to parse parameter regions
 
+        // Close a verbatim block cut off by the end of input: its terminator
+        // line lies past the last line, so the loop never reached it. Only
+        // blank lines can follow its content, and those stay outside it.
+        let line_count = input_text.lines().count();
+        for boundary in &verbatim_boundaries {
+            if boundary.terminator_line <= line_count {
+                continue;
+            }
+            if let Some(last) = tokens
+                .iter()
+                .rposition(|token| matches!(token, ScannerToken::VerbatimContentLine { .. }))
+            {
+                let end = tokens[last].span().end;
+                tokens.insert(
+                    last + 1,
+                    ScannerToken::VerbatimBlockEnd {
+                        label_raw: boundary.label_raw.clone(),
+                        truncated: boundary.truncated,
+                        span: SourceSpan { start: end, end },
+                    },
+                );
+            }
+        }
+
         // Finalize indentation processing (emit remaining dedents)
         let final_indent_tokens = self.indent_tracker.finalize();
         tokens.extend(final_indent_tokens);
@@ -201,9 +269,55 @@ impl Lexer {
             },
         });
 
+        self.convert_columns(&mut tokens);
+        if self.normalize_unicode {
+            normalize_token_text(&mut tokens);
+        }
         tokens
     }
 
+    /// Re-express token columns in the configured unit
+    ///
+    /// The lexer counts characters while scanning, since indentation and
+    /// marker detection depend on it; other units are applied once the
+    /// whole input is tokenized.
+    fn convert_columns(&self, tokens: &mut [ScannerToken]) {
+        if self.column_unit == ColumnUnit::Chars {
+            return;
+        }
+
+        // For each line, the column in the target unit at every char index
+        let lines: Vec<Vec<usize>> = self
+            .input
+            .split(|&ch| ch == '\n')
+            .map(|line| {
+                let mut columns = Vec::with_capacity(line.len() + 1);
+                let mut column = 0;
+                columns.push(column);
+                for &ch in line {
+                    column += self.column_unit.width(ch);
+                    columns.push(column);
+                }
+                columns
+            })
+            .collect();
+        let convert = |position: &mut Position| {
+            if let Some(columns) = lines.get(position.row) {
+                let last = columns.len() - 1;
+                position.column = match columns.get(position.column) {
+                    Some(&column) => column,
+                    None => columns[last] + (position.column - last),
+                };
+            }
+        };
+
+        for token in tokens {
+            let span = token.span_mut();
+            convert(&mut span.start);
+            convert(&mut span.end);
+        }
+    }
+
     // ========== NEW VERBATIM BOUNDARY METHODS (Issue #132) ==========
 
     /// Check if we're at a verbatim boundary line (title or terminator) and emit appropriate token
@@ -287,6 +401,7 @@ impl Lexer {
 
         Some(ScannerToken::VerbatimBlockEnd {
             label_raw: boundary.label_raw.clone(),
+            truncated: boundary.truncated,
             span: SourceSpan {
                 start: start_pos,
                 end: end_pos,
@@ -416,6 +531,7 @@ impl Lexer {
                         || next_ch == '\\'
                         || next_ch == '['
                         || next_ch == ']'
+                        || (next_ch == '|' && self.in_table_row)
                     {
                         // Include both backslash and the escaped character
                         content.push(ch);
@@ -432,6 +548,9 @@ impl Lexer {
                     content.push(ch);
                     self.advance();
                 }
+            } else if ch == '|' && self.in_table_row {
+                // Pipes delimit cells inside table rows
+                break;
             } else if (!ch.is_whitespace() && !is_special_delimiter(ch)) || ch == '^' {
                 // Include any non-whitespace, non-delimiter character, plus caret
                 content.push(ch);
@@ -489,6 +608,97 @@ impl Lexer {
         }
     }
 
+    /// Read a quote marker (`>`) at the start of a line
+    fn read_quote_marker(&mut self) -> Option<ScannerToken> {
+        if self.peek() != Some('>') {
+            return None;
+        }
+
+        // Only a marker when followed by a space or the end of the line
+        let next = self.input.get(self.position + 1).copied();
+        if !matches!(next, None | Some(' ') | Some('\n') | Some('\r')) {
+            return None;
+        }
+
+        let start_pos = self.current_position();
+        self.advance();
+        let end_pos = self.current_position();
+
+        // Like sequence markers, the separating space belongs to the marker
+        if self.peek() == Some(' ') {
+            self.advance();
+        }
+
+        Some(ScannerToken::QuoteMarker {
+            span: SourceSpan {
+                start: start_pos,
+                end: end_pos,
+            },
+        })
+    }
+
+    /// Check if the rest of the current line is wrapped in pipes (`| ... |`)
+    fn is_at_table_row_start(&self) -> bool {
+        if self.peek() != Some('|') {
+            return false;
+        }
+
+        let rest: String = self.input[self.position..]
+            .iter()
+            .take_while(|&&ch| ch != '\n' && ch != '\r')
+            .collect();
+        let rest = rest.trim_end();
+        rest.len() >= 2 && rest.ends_with('|')
+    }
+
+    /// Read a cell delimiter (`|`) inside a table row
+    fn read_pipe(&mut self) -> Option<ScannerToken> {
+        if !self.in_table_row || self.peek() != Some('|') {
+            return None;
+        }
+
+        let start_pos = self.current_position();
+        self.advance();
+
+        Some(ScannerToken::Pipe {
+            span: SourceSpan {
+                start: start_pos,
+                end: self.current_position(),
+            },
+        })
+    }
+
+    /// Check if only indentation (or quote markers) precede the current
+    /// position on this line
+    fn is_at_line_content_start(tokens: &[ScannerToken]) -> bool {
+        match tokens {
+            [ScannerToken::Whitespace { .. }] => true,
+            [.., previous, ScannerToken::Whitespace { .. }] => matches!(
+                previous,
+                ScannerToken::Newline { .. }
+                    | ScannerToken::BlankLine { .. }
+                    | ScannerToken::Indent { .. }
+                    | ScannerToken::Dedent { .. }
+                    | ScannerToken::QuoteMarker { .. }
+            ),
+            _ => Self::is_at_quoted_content_start(tokens),
+        }
+    }
+
+    /// Check if the previous tokens are a quote marker, optionally followed by
+    /// the indentation of the quoted content
+    fn is_at_quoted_content_start(tokens: &[ScannerToken]) -> bool {
+        matches!(
+            tokens,
+            [.., ScannerToken::QuoteMarker { .. }]
+                | [
+                    ..,
+                    ScannerToken::QuoteMarker { .. },
+                    ScannerToken::Whitespace { .. }
+                ]
+        )
+    }
+
     /// Read a dash token (standalone -)
     fn read_dash(&mut self) -> Option<ScannerToken> {
         let start_pos = self.current_position();
@@ -935,6 +1145,13 @@ impl Lexer {
         None
     }
 
+    /// Lines whose indentation mixes tabs and spaces, found while tokenizing
+    ///
+    /// Verbatim content is not checked.
+    pub fn indentation_issues(&self) -> &[IndentationIssue] {
+        self.indent_tracker.issues()
+    }
+
     /// Check if we're at the end of input
     pub fn is_at_end(&self) -> bool {
         self.position >= self.input.len()
@@ -976,7 +1193,9 @@ impl Lexer {
 
     /// Check if we're at the start of line content after proper indentation
     fn is_at_line_start_after_indent(&self, tokens: &[ScannerToken]) -> bool {
-        use crate::syntax::core::indentation::is_valid_indentation_level;
+        use crate::syntax::core::indentation::is_valid_indentation_for;
+        let is_valid_indentation_level =
+            |level: usize| is_valid_indentation_for(level, self.indent_size);
 
         // Look back at recent tokens to see if we just processed proper indentation
         if tokens.is_empty() {
@@ -1019,7 +1238,7 @@ impl Lexer {
                 }
                 ScannerToken::Indent { span, .. } => {
                     // We found an Indent token
-                    // Check if the indent itself is valid (multiple of 4)
+                    // Check if the indent itself is valid (multiple of indent_size)
                     let indent_level = span.end.column - span.start.column;
                     if is_valid_indentation_level(indent_level) {
                         // Valid if we've only seen at most one whitespace token since
@@ -1278,13 +1497,7 @@ impl crate::syntax::elements::references::ReferenceLexer for Lexer {
     fn ref_classifier(
         &self,
     ) -> &crate::ast::elements::references::reference_types::ReferenceClassifier {
-        // Create a static classifier instance
-        static CLASSIFIER: std::sync::OnceLock<
-            crate::ast::elements::references::reference_types::ReferenceClassifier,
-        > = std::sync::OnceLock::new();
-        CLASSIFIER.get_or_init(
-            crate::ast::elements::references::reference_types::ReferenceClassifier::new,
-        )
+        crate::ast::elements::references::reference_types::ReferenceClassifier::shared()
     }
 
     fn backtrack(&mut self, position: usize, row: usize, column: usize) {
@@ -1343,3 +1556,24 @@ impl crate::syntax::elements::references::footnote_ref::FootnoteRefLexer for Lex
         self.column = column;
     }
 }
+
+/// NFC-normalize the text of every token that carries authored text
+///
+/// Verbatim tokens are left alone, since verbatim content is reproduced
+/// exactly as written.
+fn normalize_token_text(tokens: &mut [ScannerToken]) {
+    for token in tokens {
+        if let ScannerToken::Text { content, .. }
+        | ScannerToken::Identifier { content, .. }
+        | ScannerToken::QuotedString { content, .. }
+        | ScannerToken::RefMarker { content, .. }
+        | ScannerToken::CitationRef { content, .. }
+        | ScannerToken::PageRef { content, .. }
+        | ScannerToken::SessionRef { content, .. } = token
+        {
+            if !is_nfc(content) {
+                *content = content.nfc().collect();
+            }
+        }
+    }
+}
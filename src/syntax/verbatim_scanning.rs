@@ -103,13 +103,11 @@
 //! 5. **Terminator indent must match title indent exactly**
 
 use crate::cst::{Position, WallType};
+use crate::syntax::core::INDENT_SIZE;
 use crate::syntax::verbatim_boundary::is_stretched_mode;
 // Parameters now handled via cst::parameter_scanner::scan_parameter_string
 use regex::Regex;
 
-/// Standard indentation level in spaces
-const INDENT_SIZE: usize = 4;
-
 // THE src/tokenizer/verbatim_scanner.rs HAS THE RULES IN THE DOCS DO NOT FRAUD, LIE NOR MAKE UP RULES
 
 /// Type of verbatim block based on content indentation
@@ -143,6 +141,8 @@ pub struct VerbatimBoundary {
     pub content_start: Option<usize>,
     /// Last line of verbatim content (1-based, inclusive) - None for empty blocks
     pub content_end: Option<usize>,
+    /// True when the terminator was cut off by the end of input (no closing `::`)
+    pub truncated: bool,
 }
 
 /// DEPRECATED: Old verbatim block structure - use VerbatimBoundary instead
@@ -196,10 +196,14 @@ pub struct VerbatimScanner {
     verbatim_start_re: Regex,
     /// Regex for detecting verbatim terminator (label with optional params)
     verbatim_end_re: Regex,
+    /// Regex for detecting a terminator missing its closing marker
+    truncated_end_re: Regex,
     /// Regex for detecting annotation lines (never verbatim starts)
     annotation_re: Regex,
     /// Regex for detecting definition lines (never verbatim starts)
     definition_re: Regex,
+    /// Spaces per indentation level; in-flow content sits one level past the title
+    indent_size: usize,
 }
 
 impl Default for VerbatimScanner {
@@ -211,16 +215,24 @@ impl Default for VerbatimScanner {
 impl VerbatimScanner {
     /// Create a new verbatim scanner
     pub fn new() -> Self {
+        Self::with_indent_size(INDENT_SIZE)
+    }
+
+    /// Create a verbatim scanner for documents indented `indent_size` spaces per level
+    pub fn with_indent_size(indent_size: usize) -> Self {
         Self {
             // Match line ending with single : (not ::)
             verbatim_start_re: Regex::new(r"^(.*):\s*$").unwrap(),
             // Match terminator: :: label :: or :: label params :: (new unified annotation syntax)
             // After grammar simplification, verbatim terminators are full annotations
             verbatim_end_re: Regex::new(r"^\s*::\s+(.+?)\s+::\s*$").unwrap(),
+            // Match a terminator cut off before its closing marker: :: label
+            truncated_end_re: Regex::new(r"^\s*::\s+(\S.*?)\s*$").unwrap(),
             // Match annotation lines :: label ::
             annotation_re: Regex::new(r"^.*::\s*.*::\s*.*$").unwrap(),
             // Match definition lines ending with :: (OLD syntax, no longer used)
             definition_re: Regex::new(r"^.*::\s*$").unwrap(),
+            indent_size,
         }
     }
 
@@ -245,7 +257,7 @@ impl VerbatimScanner {
 
         // Handle end of document
         if let Err(error) = self.finalize_scan(&mut blocks, state, lines.len()) {
-            eprintln!("Verbatim scanner error: {}", error);
+            log::warn!("Verbatim scanner error: {}", error);
         }
 
         blocks
@@ -276,9 +288,7 @@ impl VerbatimScanner {
         }
 
         // Handle end of document
-        if let Err(error) = self.finalize_scan_boundaries(&mut boundaries, state, lines.len()) {
-            eprintln!("Verbatim scanner error: {}", error);
-        }
+        self.finalize_scan_boundaries(&mut boundaries, state, &lines);
 
         boundaries
     }
@@ -464,7 +474,7 @@ impl VerbatimScanner {
                 title_text,
                 content_start: line_num,
             }
-        } else if line_indent >= title_indent + INDENT_SIZE {
+        } else if line_indent >= title_indent + self.indent_size {
             // In-flow mode: first content at title + 4 or greater (wall = title + 4)
             ScanState::InVerbatimNormal {
                 title_line,
@@ -519,8 +529,8 @@ impl VerbatimScanner {
 
         let line_indent = self.calculate_indentation(line);
 
-        // Content must be at expected indent (title + INDENT_SIZE) or deeper
-        if line_indent >= title_indent + INDENT_SIZE {
+        // Content must be at expected indent (title + indent_size) or deeper
+        if line_indent >= title_indent + self.indent_size {
             ScanState::InVerbatimNormal {
                 title_line,
                 title_indent,
@@ -594,8 +604,43 @@ impl VerbatimScanner {
             return false;
         }
 
-        // Must match terminator pattern
-        self.verbatim_end_re.is_match(line)
+        // Must match terminator pattern, and a `def`-namespaced label closes
+        // a definition with metadata rather than a verbatim block
+        self.verbatim_end_re.is_match(line) && !is_definition_label(&self.extract_label(line))
+    }
+
+    /// Match a terminator line, returning its label and whether it was truncated
+    ///
+    /// A terminator cut off before its closing marker (`:: label`) is only
+    /// accepted as the last non-blank line of the input. Anywhere else that
+    /// line is ordinary text, but at the end of input it is a document that
+    /// was truncated mid-terminator, and closing the block there keeps its
+    /// content verbatim instead of demoting the block to a definition.
+    fn match_terminator(
+        &self,
+        line: &str,
+        line_idx: usize,
+        expected_indent: usize,
+        all_lines: &[&str],
+    ) -> Option<(String, bool)> {
+        if self.is_valid_terminator(line, expected_indent) {
+            return Some((self.extract_label(line), false));
+        }
+
+        if self.calculate_indentation(line) != expected_indent
+            || self.annotation_re.is_match(line)
+            || all_lines
+                .iter()
+                .skip(line_idx + 1)
+                .any(|rest| !rest.trim().is_empty())
+        {
+            return None;
+        }
+
+        // A half-written closing marker (`:: label :`) is part of the cut
+        let label = self.truncated_end_re.captures(line)?.get(1)?.as_str();
+        let label = label.trim_end_matches(':').trim_end();
+        (!label.is_empty() && !is_definition_label(label)).then(|| (label.to_string(), true))
     }
 
     /// Look ahead in remaining lines to check if a terminator exists
@@ -607,14 +652,17 @@ impl VerbatimScanner {
         start_idx: usize,
         expected_indent: usize,
     ) -> bool {
-        for line in all_lines.iter().skip(start_idx) {
+        for (line_idx, line) in all_lines.iter().enumerate().skip(start_idx) {
             // Skip blank lines
             if line.trim().is_empty() {
                 continue;
             }
 
             // Check if this is a valid terminator
-            if self.is_valid_terminator(line, expected_indent) {
+            if self
+                .match_terminator(line, line_idx, expected_indent, all_lines)
+                .is_some()
+            {
                 return true;
             }
 
@@ -643,6 +691,22 @@ impl VerbatimScanner {
         false
     }
 
+    /// Check for verbatim content cut off by the end of input before any terminator
+    ///
+    /// Without a terminator, indented content under a title is a definition
+    /// body, so only stretched content is accepted: no definition body sits
+    /// at the stretched wall. Every non-blank line from `content_idx` to the
+    /// end of input must be stretched content.
+    fn runs_to_end_unterminated(&self, all_lines: &[&str], content_idx: usize) -> bool {
+        let mut indents = all_lines
+            .iter()
+            .skip(content_idx)
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| self.calculate_indentation(line))
+            .peekable();
+        indents.peek().is_some() && indents.all(is_stretched_mode)
+    }
+
     /// Calculate indentation level of a line (number of leading spaces, tabs = 4 spaces)
     fn calculate_indentation(&self, line: &str) -> usize {
         crate::syntax::indentation_analysis::calculate_indentation_level(line)
@@ -815,10 +879,9 @@ impl VerbatimScanner {
         // Check for terminator immediately after title (empty verbatim block)
         // IMPORTANT: This must come BEFORE the annotation check, because terminators
         // also match the annotation regex (:: label ::)
-        if self.is_valid_terminator(line, title_indent) {
-            // Extract label from terminator
-            let label_raw = self.extract_label(line);
-
+        if let Some((label_raw, truncated)) =
+            self.match_terminator(line, line_num - 1, title_indent, all_lines)
+        {
             // This is an empty verbatim block - add boundary and continue scanning
             boundaries.push(VerbatimBoundary {
                 title_line,
@@ -829,6 +892,7 @@ impl VerbatimScanner {
                 title_indent,
                 content_start: None,
                 content_end: None,
+                truncated,
             });
             return ScanState::ScanningNormal;
         }
@@ -843,7 +907,9 @@ impl VerbatimScanner {
         // If no terminator exists, this is a DEFINITION (not verbatim), so return to normal scanning.
         // Note: Start looking from the NEXT line, not the current content line
         let line_idx = line_num; // line_num is 1-based, so this points to next line in 0-based array
-        if !self.has_terminator_ahead(all_lines, line_idx, title_indent) {
+        if !self.has_terminator_ahead(all_lines, line_idx, title_indent)
+            && !self.runs_to_end_unterminated(all_lines, line_idx - 1)
+        {
             // No terminator found - this is a definition, not verbatim
             return ScanState::ScanningNormal;
         }
@@ -858,7 +924,7 @@ impl VerbatimScanner {
                 title_text,
                 content_start: line_num,
             }
-        } else if line_indent >= title_indent + INDENT_SIZE {
+        } else if line_indent >= title_indent + self.indent_size {
             // In-flow mode: first content at title + 4 or greater (wall = title + 4)
             ScanState::InVerbatimNormal {
                 title_line,
@@ -885,7 +951,7 @@ impl VerbatimScanner {
         expected_indent: usize,
         line_num: usize,
         line: &str,
-        _all_lines: &[&str],
+        all_lines: &[&str],
     ) -> ScanState {
         // Allow blank lines
         if line.trim().is_empty() {
@@ -899,10 +965,9 @@ impl VerbatimScanner {
         }
 
         // Check for valid terminator
-        if self.is_valid_terminator(line, title_indent) {
-            // Extract label from terminator
-            let label_raw = self.extract_label(line);
-
+        if let Some((label_raw, truncated)) =
+            self.match_terminator(line, line_num - 1, title_indent, all_lines)
+        {
             // End of verbatim block - create boundary
             boundaries.push(VerbatimBoundary {
                 title_line,
@@ -913,14 +978,15 @@ impl VerbatimScanner {
                 title_indent,
                 content_start: Some(content_start),
                 content_end: Some(line_num - 1),
+                truncated,
             });
             return ScanState::ScanningNormal;
         }
 
         let line_indent = self.calculate_indentation(line);
 
-        // Content must be at expected indent (title + INDENT_SIZE) or deeper
-        if line_indent >= title_indent + INDENT_SIZE {
+        // Content must be at expected indent (title + indent_size) or deeper
+        if line_indent >= title_indent + self.indent_size {
             ScanState::InVerbatimNormal {
                 title_line,
                 title_indent,
@@ -945,7 +1011,7 @@ impl VerbatimScanner {
         content_start: usize,
         line_num: usize,
         line: &str,
-        _all_lines: &[&str],
+        all_lines: &[&str],
     ) -> ScanState {
         // Allow blank lines
         if line.trim().is_empty() {
@@ -960,10 +1026,9 @@ impl VerbatimScanner {
         let line_indent = self.calculate_indentation(line);
 
         // Check for terminator first (must be at title indent)
-        if self.is_valid_terminator(line, title_indent) {
-            // Extract label from terminator
-            let label_raw = self.extract_label(line);
-
+        if let Some((label_raw, truncated)) =
+            self.match_terminator(line, line_num - 1, title_indent, all_lines)
+        {
             boundaries.push(VerbatimBoundary {
                 title_line,
                 terminator_line: line_num,
@@ -973,6 +1038,7 @@ impl VerbatimScanner {
                 title_indent,
                 content_start: Some(content_start),
                 content_end: Some(line_num - 1),
+                truncated,
             });
             return ScanState::ScanningNormal;
         }
@@ -991,21 +1057,62 @@ impl VerbatimScanner {
         ScanState::ScanningNormal
     }
 
-    /// Finalize boundary scan - check for unterminated blocks
+    /// Finalize boundary scan - close blocks cut off by the end of input
+    ///
+    /// A block still open here was let in by `runs_to_end_unterminated`. It
+    /// is closed with no label and marked truncated; its terminator line is
+    /// past the last line of input, and the lexer emits the end token there.
     fn finalize_scan_boundaries(
         &self,
-        _boundaries: &mut Vec<VerbatimBoundary>,
+        boundaries: &mut Vec<VerbatimBoundary>,
         state: ScanState,
-        _total_lines: usize,
-    ) -> Result<(), String> {
-        match state {
-            ScanState::ScanningNormal | ScanState::FoundPotentialStart { .. } => Ok(()),
-            ScanState::InVerbatimNormal { title_line, .. }
-            | ScanState::InVerbatimStretched { title_line, .. } => Err(format!(
-                "Unterminated verbatim block starting at line {}",
-                title_line
-            )),
-        }
+        all_lines: &[&str],
+    ) {
+        let (title_line, title_indent, title, content_start, wall_type) = match state {
+            ScanState::ScanningNormal | ScanState::FoundPotentialStart { .. } => return,
+            ScanState::InVerbatimNormal {
+                title_line,
+                title_indent,
+                title_text,
+                content_start,
+                ..
+            } => (
+                title_line,
+                title_indent,
+                title_text,
+                content_start,
+                WallType::InFlow(title_indent),
+            ),
+            ScanState::InVerbatimStretched {
+                title_line,
+                title_indent,
+                title_text,
+                content_start,
+            } => (
+                title_line,
+                title_indent,
+                title_text,
+                content_start,
+                WallType::Stretched,
+            ),
+        };
+
+        // Trailing blank lines are left to the lexer as blank lines
+        let content_end = all_lines
+            .iter()
+            .rposition(|line| !line.trim().is_empty())
+            .map_or(content_start, |last| last + 1);
+        boundaries.push(VerbatimBoundary {
+            title_line,
+            terminator_line: all_lines.len() + 1,
+            title,
+            label_raw: String::new(),
+            wall_type,
+            title_indent,
+            content_start: Some(content_start),
+            content_end: Some(content_end),
+            truncated: true,
+        });
     }
 
     /// Check if a line number is within verbatim content (boundary version)
@@ -1024,6 +1131,15 @@ impl VerbatimScanner {
     }
 }
 
+/// Whether a terminator's label (with any parameters) is `def`-namespaced
+///
+/// `:: def.label params ::` after an indented body is the trailing annotation
+/// of a definition, not a verbatim terminator.
+fn is_definition_label(label: &str) -> bool {
+    let name = label.split_whitespace().next().unwrap_or("");
+    name == "def" || name.starts_with("def.")
+}
+
 /// Trait for verbatim block tokenization
 pub trait VerbatimLexer: Sized {
     /// Get current position in source
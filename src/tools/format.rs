@@ -0,0 +1,304 @@
+//! Source formatting
+//!
+//! [`format_source`] rewrites a document's source in place, leaving
+//! everything it has no option for exactly as written. With `wrap_width`
+//! set, paragraphs are reflowed: their lines are joined and re-broken
+//! greedily so each fits the width, keeping the paragraph's indentation.
+//!
+//! Lines only break at whitespace outside inline formatting, so a code
+//! span, math span, bold or italic span is never split across lines, and a
+//! word or span longer than the width gets a line of its own. Verbatim
+//! blocks, and everything else that isn't a paragraph, are left alone, as
+//! are quoted paragraphs. Hard breaks (a line ending in two or more spaces
+//! or an unescaped `\`) stay where they are, and only the lines between
+//! them are reflowed. A list marker such as `-` or `1.` is never moved to
+//! the start of a line, where it would begin a list.
+//!
+//! With `blank_lines_between_blocks` or `blank_lines_before_session` set,
+//! every run of blank lines between two blocks is resized: runs right
+//! before a session title take the session count, all others (including
+//! the one after a title) the block count. At least one blank line is
+//! always kept, since blank lines are what separate blocks. Blank lines
+//! inside verbatim content, and at the start or end of the document, are
+//! left as they are.
+
+use std::collections::HashSet;
+
+use crate::api::{run_all_unified, TransformError};
+use crate::ast::events::{events, DocEvent};
+use crate::ast::semantic_tokens::{semantic_tokens, SemanticTokenKind};
+use crate::cst::ScannerToken;
+
+/// What [`format_source`] changes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// Column width to reflow paragraphs to, or None to keep their lines
+    pub wrap_width: Option<usize>,
+
+    /// Blank lines between blocks, or None to keep each run as written
+    pub blank_lines_between_blocks: Option<usize>,
+
+    /// Blank lines before a session title, or None to keep each run as
+    /// written
+    pub blank_lines_before_session: Option<usize>,
+}
+
+/// Format `source` according to `options`
+///
+/// Paragraphs are reflowed first, then blank lines resized, each pass on
+/// the output of the one before.
+pub fn format_source(source: &str, options: &FormatOptions) -> Result<String, TransformError> {
+    let mut formatted = source.to_string();
+    if let Some(width) = options.wrap_width {
+        formatted = reflow_paragraphs(&formatted, width)?;
+    }
+    if options.blank_lines_between_blocks.is_some() || options.blank_lines_before_session.is_some()
+    {
+        formatted = resize_blank_lines(&formatted, options)?;
+    }
+    Ok(formatted)
+}
+
+/// Reflow every paragraph outside quotes to `width`
+fn reflow_paragraphs(source: &str, width: usize) -> Result<String, TransformError> {
+    let document = run_all_unified(source, None)?;
+    let mut lines: Vec<String> = source.split('\n').map(str::to_string).collect();
+
+    // First and last row of each paragraph outside quotes
+    let mut paragraphs = Vec::new();
+    let mut quote_depth = 0;
+    for event in events(&document) {
+        match event {
+            DocEvent::EnterQuote(_) => quote_depth += 1,
+            DocEvent::ExitQuote => quote_depth -= 1,
+            DocEvent::EnterParagraph(paragraph) if quote_depth == 0 => {
+                let rows = paragraph
+                    .tokens
+                    .tokens
+                    .iter()
+                    .filter(|token| {
+                        !matches!(
+                            token,
+                            ScannerToken::Newline { .. }
+                                | ScannerToken::BlankLine { .. }
+                                | ScannerToken::Indent { .. }
+                                | ScannerToken::Dedent { .. }
+                                | ScannerToken::Eof { .. }
+                        )
+                    })
+                    .map(|token| token.span().start.row);
+                if let (Some(first), Some(last)) = (rows.clone().min(), rows.max()) {
+                    paragraphs.push((first, last));
+                }
+            }
+            _ => {}
+        }
+    }
+    paragraphs.sort_unstable();
+    paragraphs.dedup();
+
+    // Last paragraph first, so replacing one doesn't shift the rows of
+    // those before it
+    for &(first, last) in paragraphs.iter().rev() {
+        if last >= lines.len() {
+            continue;
+        }
+        let indent: String = lines[first]
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .collect();
+
+        // Hard breaks and blank lines are fixed; only the runs of lines
+        // between them are reflowed
+        let mut reflowed = Vec::new();
+        let mut segment: Vec<&str> = Vec::new();
+        for line in &lines[first..=last] {
+            if line.trim().is_empty() {
+                reflowed.extend(reflow_segment(&segment, &indent, width, ""));
+                segment.clear();
+                reflowed.push(line.clone());
+                continue;
+            }
+            segment.push(line);
+            if let Some(hard_break) = hard_break(line) {
+                reflowed.extend(reflow_segment(&segment, &indent, width, hard_break));
+                segment.clear();
+            }
+        }
+        reflowed.extend(reflow_segment(&segment, &indent, width, ""));
+        lines.splice(first..=last, reflowed);
+    }
+
+    Ok(lines.join("\n"))
+}
+
+/// Resize the runs of blank lines between blocks
+fn resize_blank_lines(source: &str, options: &FormatOptions) -> Result<String, TransformError> {
+    let document = run_all_unified(source, None)?;
+
+    let title_rows: HashSet<usize> = semantic_tokens(&document)
+        .into_iter()
+        .filter(|token| token.kind == SemanticTokenKind::Heading)
+        .map(|token| token.span.start.row)
+        .collect();
+    let mut verbatim_rows = HashSet::new();
+    for event in events(&document) {
+        if let DocEvent::Verbatim(verbatim) = event {
+            for line in &verbatim.content.ignore_lines {
+                verbatim_rows.extend(line.tokens.tokens.iter().map(|t| t.span().start.row));
+            }
+        }
+    }
+
+    let lines: Vec<&str> = source.split('\n').collect();
+    let is_blank = |row: usize| lines[row].trim().is_empty() && !verbatim_rows.contains(&row);
+
+    let mut resized = Vec::with_capacity(lines.len());
+    let mut row = 0;
+    while row < lines.len() {
+        if !is_blank(row) {
+            resized.push(lines[row]);
+            row += 1;
+            continue;
+        }
+        let start = row;
+        while row < lines.len() && is_blank(row) {
+            row += 1;
+        }
+
+        // Runs at either end of the document separate nothing
+        let count = if start == 0 || row == lines.len() {
+            None
+        } else if title_rows.contains(&row) {
+            options.blank_lines_before_session
+        } else {
+            options.blank_lines_between_blocks
+        };
+        match count {
+            Some(count) => resized.extend(std::iter::repeat_n(lines[start], count.max(1))),
+            None => resized.extend(&lines[start..row]),
+        }
+    }
+
+    Ok(resized.join("\n"))
+}
+
+/// The marker a line's hard break needs kept at its end, if it has one
+///
+/// Two or more trailing spaces keep two; a trailing backslash stays on the
+/// last word by itself, so it needs nothing added. An escaped backslash
+/// (`\\`) is text, not a break.
+fn hard_break(line: &str) -> Option<&'static str> {
+    if line.ends_with("  ") {
+        return Some("  ");
+    }
+    let backslashes = line.chars().rev().take_while(|&c| c == '\\').count();
+    (backslashes % 2 == 1).then_some("")
+}
+
+/// Reflow a run of lines that ends at a hard break or the paragraph's end
+fn reflow_segment(segment: &[&str], indent: &str, width: usize, hard_break: &str) -> Vec<String> {
+    if segment.is_empty() {
+        return Vec::new();
+    }
+    let text = segment
+        .iter()
+        .map(|line| line.trim())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut wrapped = wrap(&breakable_words(&text), indent, width);
+    if let Some(last) = wrapped.last_mut() {
+        last.push_str(hard_break);
+    }
+    wrapped
+}
+
+/// Split text at whitespace that lies outside inline formatting
+///
+/// A delimiter opens a span at the start of a word when the same
+/// delimiter closes it later at the end of one; code and math spans hide
+/// any other delimiters inside them.
+fn breakable_words(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut open: Vec<char> = Vec::new();
+
+    for (index, &c) in chars.iter().enumerate() {
+        if c.is_whitespace() && open.is_empty() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        word.push(c);
+
+        if !matches!(c, '*' | '_' | '`' | '#') {
+            continue;
+        }
+        if open.last() == Some(&c) && closes_at(&chars, index) {
+            open.pop();
+        } else if !matches!(open.last(), Some('`' | '#'))
+            && opens_at(&chars, index)
+            && (index + 1..chars.len()).any(|end| chars[end] == c && closes_at(&chars, end))
+        {
+            open.push(c);
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// A delimiter at `index` starts a word and is followed by text
+fn opens_at(chars: &[char], index: usize) -> bool {
+    let before = index.checked_sub(1).map(|i| chars[i]);
+    let after = chars.get(index + 1);
+    !before.is_some_and(char::is_alphanumeric) && after.is_some_and(|c| !c.is_whitespace())
+}
+
+/// A delimiter at `index` ends a word that has text before it
+fn closes_at(chars: &[char], index: usize) -> bool {
+    let before = index.checked_sub(1).map(|i| chars[i]);
+    let after = chars.get(index + 1);
+    before.is_some_and(|c| !c.is_whitespace()) && !after.is_some_and(|c| c.is_alphanumeric())
+}
+
+/// Fill lines greedily with words, each line starting with `indent`
+fn wrap(words: &[String], indent: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in words {
+        let length = line.chars().count() + 1 + word.chars().count();
+        // A list marker starting a line would turn the rest into a list
+        if !line.is_empty() && length > width && !is_list_marker(word) {
+            lines.push(std::mem::take(&mut line));
+        }
+        if line.is_empty() {
+            line.push_str(indent);
+        } else {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// A word that reads as a list item marker at the start of a line, like
+/// `-`, `1.`, `b)` or `iv.`
+fn is_list_marker(word: &str) -> bool {
+    if word == "-" {
+        return true;
+    }
+    let Some(label) = word.strip_suffix('.').or_else(|| word.strip_suffix(')')) else {
+        return false;
+    };
+    !label.is_empty()
+        && (label.chars().all(|c| c.is_ascii_digit())
+            || label.chars().count() == 1 && label.chars().all(|c| c.is_ascii_alphabetic())
+            || label.chars().all(|c| "ivxlcdmIVXLCDM".contains(c)))
+}
@@ -5,3 +5,9 @@
 
 // Tree visualization tool for AST inspection and debugging
 pub mod treeviz;
+
+// Source formatting, such as reflowing paragraphs to a width
+pub mod format;
+
+// Line-oriented token listing for debugging the lexer
+pub mod token_dump;
@@ -0,0 +1,57 @@
+//! Token dump for debugging tokenization
+//!
+//! [`dump_tokens`] lexes a source and lists its tokens one per line: kind,
+//! span as `row:column-row:column`, and content as a Rust string literal so
+//! whitespace and newlines stay visible. Tokens are grouped under a header
+//! for the source row they start on, which reads far better than `{:?}` on
+//! the whole vector.
+
+use std::fmt::Write;
+
+use crate::cst::ScannerToken;
+use crate::syntax::tokenize;
+
+/// Width of the token kind column, enough for `VerbatimContentLine`
+const KIND_WIDTH: usize = 20;
+
+/// Width of the span column
+const SPAN_WIDTH: usize = 12;
+
+/// List the tokens of `src`, one per line, grouped by source row
+pub fn dump_tokens(src: &str) -> String {
+    let mut dump = String::new();
+    let mut current_row = None;
+
+    for token in tokenize(src) {
+        let span = token.span();
+        if current_row != Some(span.start.row) {
+            current_row = Some(span.start.row);
+            let _ = writeln!(dump, "row {}:", span.start.row);
+        }
+        let range = format!(
+            "{}:{}-{}:{}",
+            span.start.row, span.start.column, span.end.row, span.end.column
+        );
+        let _ = writeln!(
+            dump,
+            "  {:<kind_width$}{:<span_width$}{:?}",
+            token_kind(&token),
+            range,
+            token.content(),
+            kind_width = KIND_WIDTH,
+            span_width = SPAN_WIDTH,
+        );
+    }
+
+    dump
+}
+
+/// The token's variant name, as its `Debug` output starts with
+fn token_kind(token: &ScannerToken) -> String {
+    let debug = format!("{:?}", token);
+    debug
+        .split(|ch: char| !ch.is_alphanumeric())
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
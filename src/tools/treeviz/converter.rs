@@ -6,10 +6,14 @@
 //! of each node type, just how to extract icons, content, and children.
 
 use super::{
-    icons::{extract_content_from_node, get_node_type_name, IconConfig, DEFAULT_ICON_CONFIG},
+    icons::{
+        extract_content_from_node, get_list_item_type_name, get_node_type_name, IconConfig,
+        DEFAULT_ICON_CONFIG,
+    },
     NotationData, TreeNode, TreeVizResult,
 };
 use crate::ast::elements::core::ElementNode;
+use crate::ast::elements::list::ListItem;
 
 /// Convert an AST node to NotationData with configurable mapping
 ///
@@ -77,6 +81,27 @@ fn convert_node_recursive(ast_node: &ElementNode, config: &IconConfig) -> TreeVi
         tree_node.add_child(child_tree_node);
     }
 
+    // List items are not element nodes, so lists add them here
+    if let ElementNode::ListBlock(list) = ast_node {
+        for item in &list.items {
+            tree_node.add_child(convert_list_item(item, config)?);
+        }
+    }
+
+    Ok(tree_node)
+}
+
+/// Convert a list item, with its icon showing its task state if it has one
+fn convert_list_item(item: &ListItem, config: &IconConfig) -> TreeVizResult<TreeNode> {
+    let node_type = get_list_item_type_name(item);
+    let icon = config.get_icon(&node_type);
+    let mut tree_node = TreeNode::new(icon, item.text_content().trim().to_string(), node_type);
+
+    if let Some(nested) = &item.nested {
+        let nested_node = ElementNode::ContentContainer(nested.clone());
+        tree_node.add_child(convert_node_recursive(&nested_node, config)?);
+    }
+
     Ok(tree_node)
 }
 
@@ -113,9 +138,49 @@ fn get_node_children(node: &ElementNode) -> Vec<ElementNode> {
             vec![ElementNode::SessionContainer(session.content.clone())]
         }
         ElementNode::AnnotationBlock(_) => vec![], // Would contain content
+        ElementNode::TableBlock(_) => vec![],      // Cells are inline content
+        ElementNode::FootnoteDefinitionBlock(_) => vec![], // Would contain body
+        ElementNode::QuoteBlock(quote) => {
+            // Extract children from QuoteBlock (just the content container)
+            vec![ElementNode::ContentContainer(quote.content.clone())]
+        }
 
         // Container elements - these are the main ones with children
-        ElementNode::ContentContainer(_) => vec![], // Would contain blocks
+        ElementNode::ContentContainer(container) => {
+            // Extract children from ContentContainer
+            container.content.iter().map(|element| match element {
+                crate::ast::elements::containers::content::ContentContainerElement::Paragraph(p) => {
+                    ElementNode::ParagraphBlock(p.clone())
+                }
+                crate::ast::elements::containers::content::ContentContainerElement::List(l) => {
+                    ElementNode::ListBlock(l.clone())
+                }
+                crate::ast::elements::containers::content::ContentContainerElement::Definition(d) => {
+                    ElementNode::DefinitionBlock(d.clone())
+                }
+                crate::ast::elements::containers::content::ContentContainerElement::Verbatim(v) => {
+                    ElementNode::VerbatimBlock(v.clone())
+                }
+                crate::ast::elements::containers::content::ContentContainerElement::Annotation(a) => {
+                    ElementNode::AnnotationBlock(a.clone())
+                }
+                crate::ast::elements::containers::content::ContentContainerElement::Quote(q) => {
+                    ElementNode::QuoteBlock(q.clone())
+                }
+                crate::ast::elements::containers::content::ContentContainerElement::Table(t) => {
+                    ElementNode::TableBlock(t.clone())
+                }
+                crate::ast::elements::containers::content::ContentContainerElement::FootnoteDefinition(f) => {
+                    ElementNode::FootnoteDefinitionBlock(f.clone())
+                }
+                crate::ast::elements::containers::content::ContentContainerElement::Container(c) => {
+                    ElementNode::ContentContainer(c.clone())
+                }
+                crate::ast::elements::containers::content::ContentContainerElement::BlankLine(b) => {
+                    ElementNode::BlankLine(b.clone())
+                }
+            }).collect()
+        }
         ElementNode::SessionContainer(container) => {
             // Extract children from SessionContainer
             container.content.iter().map(|element| match element {
@@ -134,6 +199,15 @@ fn get_node_children(node: &ElementNode) -> Vec<ElementNode> {
                 crate::ast::elements::session::session_container::SessionContainerElement::Annotation(a) => {
                     ElementNode::AnnotationBlock(a.clone())
                 }
+                crate::ast::elements::session::session_container::SessionContainerElement::Quote(q) => {
+                    ElementNode::QuoteBlock(q.clone())
+                }
+                crate::ast::elements::session::session_container::SessionContainerElement::Table(t) => {
+                    ElementNode::TableBlock(t.clone())
+                }
+                crate::ast::elements::session::session_container::SessionContainerElement::FootnoteDefinition(f) => {
+                    ElementNode::FootnoteDefinitionBlock(f.clone())
+                }
                 crate::ast::elements::session::session_container::SessionContainerElement::Session(s) => {
                     ElementNode::SessionBlock(s.clone())
                 }
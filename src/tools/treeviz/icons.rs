@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::ast::elements::core::{ElementNode, HeaderedBlock};
+use crate::ast::elements::list::ListItem;
 
 /// Configuration for icon mappings and content extraction
 ///
@@ -130,10 +131,15 @@ pub static DEFAULT_ICON_CONFIG: std::sync::LazyLock<IconConfig> = std::sync::Laz
     config.add_icon("ParagraphBlock".to_string(), "¶".to_string());
     config.add_icon("ListBlock".to_string(), "☰".to_string());
     config.add_icon("ListItem".to_string(), "•".to_string());
+    config.add_icon("ListItemUnchecked".to_string(), "☐".to_string());
+    config.add_icon("ListItemChecked".to_string(), "☑".to_string());
     config.add_icon("VerbatimBlock".to_string(), "𝒱".to_string());
     config.add_icon("VerbatimLine".to_string(), "℣".to_string());
     config.add_icon("DefinitionBlock".to_string(), "≔".to_string());
     config.add_icon("ContentContainer".to_string(), "➔".to_string());
+    config.add_icon("QuoteBlock".to_string(), "❝".to_string());
+    config.add_icon("TableBlock".to_string(), "⊞".to_string());
+    config.add_icon("FootnoteDefinitionBlock".to_string(), "†".to_string());
 
     // Inline Elements icons
     config.add_icon("TextSpan".to_string(), "◦".to_string());
@@ -231,6 +237,21 @@ pub static DEFAULT_ICON_CONFIG: std::sync::LazyLock<IconConfig> = std::sync::Laz
         ContentExtractor::with_format("key", "children", "[@{}]"),
     );
 
+    config.add_extractor(
+        "QuoteBlock".to_string(),
+        ContentExtractor::with_format("len", "content", "quote ({} elements)"),
+    );
+
+    config.add_extractor(
+        "TableBlock".to_string(),
+        ContentExtractor::with_format("size", "rows", "table ({})"),
+    );
+
+    config.add_extractor(
+        "FootnoteDefinitionBlock".to_string(),
+        ContentExtractor::with_format("label", "content", "[^{}]"),
+    );
+
     config.add_extractor(
         "SessionContainer".to_string(),
         ContentExtractor::with_format("len", "content", "{} elements"),
@@ -278,6 +299,9 @@ pub fn get_node_type_name(node: &ElementNode) -> String {
         ElementNode::VerbatimBlock(_) => "VerbatimBlock".to_string(),
         ElementNode::SessionBlock(_) => "SessionBlock".to_string(),
         ElementNode::AnnotationBlock(_) => "AnnotationBlock".to_string(),
+        ElementNode::QuoteBlock(_) => "QuoteBlock".to_string(),
+        ElementNode::TableBlock(_) => "TableBlock".to_string(),
+        ElementNode::FootnoteDefinitionBlock(_) => "FootnoteDefinitionBlock".to_string(),
         ElementNode::ContentContainer(_) => "ContentContainer".to_string(),
         ElementNode::SessionContainer(_) => "SessionContainer".to_string(),
         ElementNode::IgnoreContainer(_) => "IgnoreContainer".to_string(),
@@ -285,6 +309,15 @@ pub fn get_node_type_name(node: &ElementNode) -> String {
     }
 }
 
+/// Node type name of a list item, telling task items by their state
+pub fn get_list_item_type_name(item: &ListItem) -> String {
+    match item.checked {
+        None => "ListItem".to_string(),
+        Some(false) => "ListItemUnchecked".to_string(),
+        Some(true) => "ListItemChecked".to_string(),
+    }
+}
+
 /// Extract content from a node using the specified extractor strategy
 ///
 /// This is a simplified implementation that would be expanded with proper
@@ -348,6 +381,11 @@ fn extract_content_by_type(node: &ElementNode, extractor: &ContentExtractor) ->
             // Use HeaderedBlock trait for uniform access
             ann.header_text()
         }
+        ElementNode::QuoteBlock(quote) => quote.content.content.len().to_string(),
+        ElementNode::TableBlock(table) => {
+            format!("{}x{}", table.row_count(), table.column_count())
+        }
+        ElementNode::FootnoteDefinitionBlock(footnote) => footnote.header_text(),
         ElementNode::ContentContainer(_) => "content container".to_string(),
         ElementNode::SessionContainer(container) => container.len().to_string(),
         ElementNode::IgnoreContainer(_) => "ignore container".to_string(),
@@ -105,8 +105,11 @@ pub struct RenderOptions {
     /// Custom tree drawing characters
     pub tree_chars: TreeChars,
 
-    /// Maximum content length before truncation
-    pub max_content_length: Option<usize>,
+    /// Maximum width of a node's content, in characters
+    ///
+    /// Longer content is cut to fit and ends with an ellipsis (`…`), so
+    /// long paragraphs don't wrap and break up the tree.
+    pub content_max_width: Option<usize>,
 
     /// Whether to colorize output (for terminal)
     pub colorize: bool,
@@ -177,14 +180,9 @@ fn render_node_with_options(
     };
 
     // Prepare content with optional truncation
-    let content = if let Some(max_len) = options.max_content_length {
-        if node.content.len() > max_len {
-            format!("{}...", &node.content[..max_len.saturating_sub(3)])
-        } else {
-            node.content.clone()
-        }
-    } else {
-        node.content.clone()
+    let content = match options.content_max_width {
+        Some(max_width) => truncate_content(&node.content, max_width),
+        None => node.content.clone(),
     };
 
     // Render this node
@@ -230,6 +228,22 @@ fn render_node_with_options(
     Ok(())
 }
 
+/// Cut content to at most `max_width` characters, ending with an ellipsis
+///
+/// Counts characters rather than bytes, so a multibyte character is never
+/// split. Content that already fits is returned unchanged.
+pub fn truncate_content(content: &str, max_width: usize) -> String {
+    if content.chars().count() <= max_width {
+        return content.to_string();
+    }
+    if max_width == 0 {
+        return String::new();
+    }
+    let mut truncated: String = content.chars().take(max_width - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
 /// Convert NotationData to JSON string
 ///
 /// Provides an alternative output format for programmatic consumption
@@ -304,4 +318,16 @@ mod tests {
         assert!(output.contains("`-"));
         assert!(output.contains("|"));
     }
+
+    #[test]
+    fn test_truncate_content() {
+        assert_eq!(truncate_content("short", 10), "short");
+        assert_eq!(truncate_content("exactly10!", 10), "exactly10!");
+        assert_eq!(
+            truncate_content("one character over", 17),
+            "one character ov…"
+        );
+        assert_eq!(truncate_content("anything", 1), "…");
+        assert_eq!(truncate_content("anything", 0), "");
+    }
 }
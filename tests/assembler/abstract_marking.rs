@@ -0,0 +1,54 @@
+//! Abstract marking tests
+
+use txxt::api::run_all_with_options;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::Document;
+use txxt::syntax::ParseOptions;
+
+fn parse_marked(source: &str) -> Document {
+    let options = ParseOptions {
+        mark_abstract: true,
+        ..ParseOptions::default()
+    };
+    run_all_with_options(source, None, options).expect("Failed to parse source")
+}
+
+/// Whether each top-level paragraph carries the `abstract` annotation
+fn abstract_flags(document: &Document) -> Vec<bool> {
+    document
+        .content
+        .content
+        .iter()
+        .filter_map(|element| match element {
+            SessionContainerElement::Paragraph(paragraph) => Some(
+                paragraph
+                    .annotations
+                    .iter()
+                    .any(|annotation| annotation.name == "abstract"),
+            ),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_first_paragraph_is_marked() {
+    let document = parse_marked("We study parsing.\n\nA second paragraph.\n\nA third one.\n");
+
+    assert_eq!(abstract_flags(&document), vec![true, false, false]);
+}
+
+#[test]
+fn test_document_starting_with_session_is_not_marked() {
+    let document = parse_marked("1. Introduction\n\n    Inside the session.\n\nAfter it.\n");
+
+    assert!(abstract_flags(&document).iter().all(|marked| !marked));
+}
+
+#[test]
+fn test_marking_is_off_by_default() {
+    let document = run_all_with_options("We study parsing.\n", None, ParseOptions::default())
+        .expect("Failed to parse source");
+
+    assert_eq!(abstract_flags(&document), vec![false]);
+}
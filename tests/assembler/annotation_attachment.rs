@@ -0,0 +1,278 @@
+//! Annotation proximity attachment tests
+
+use txxt::api::run_all_unified;
+use txxt::assembly::{
+    attach_annotations, reattach_annotations, AnnotationAttacher, AttachmentPolicy,
+    TrailingAttachment,
+};
+use txxt::ast::elements::annotation::Annotation;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::Document;
+
+fn attached(source: &str, policy: AttachmentPolicy) -> Document {
+    let mut document = run_all_unified(source, None).expect("Failed to parse source");
+    attach_annotations(&mut document, &policy);
+    document
+}
+
+fn labels(annotations: &[Annotation]) -> Vec<&str> {
+    annotations
+        .iter()
+        .map(|annotation| annotation.name.as_str())
+        .collect()
+}
+
+fn kinds(elements: &[SessionContainerElement]) -> Vec<&'static str> {
+    elements
+        .iter()
+        .map(|element| match element {
+            SessionContainerElement::Paragraph(_) => "paragraph",
+            SessionContainerElement::Session(_) => "session",
+            SessionContainerElement::Annotation(_) => "annotation",
+            _ => "other",
+        })
+        .collect()
+}
+
+fn paragraph_annotations(element: &SessionContainerElement) -> Vec<&str> {
+    match element {
+        SessionContainerElement::Paragraph(paragraph) => labels(&paragraph.annotations),
+        other => panic!("Expected a paragraph, got {:?}", other),
+    }
+}
+
+const SESSION: &str = "\
+Opening paragraph.
+
+1. Section
+
+    :: note :: Read this first.
+
+    Section body.
+
+    :: summary :: Wraps up the section.
+";
+
+#[test]
+fn test_document_start_annotations_attach_to_document() {
+    let source = ":: title :: Report\n:: author :: Jane\n\nFirst paragraph.\n";
+    let document = attached(source, AttachmentPolicy::default());
+
+    assert_eq!(
+        labels(&document.content.annotations),
+        vec!["title", "author"]
+    );
+    assert_eq!(kinds(&document.content.content), vec!["paragraph"]);
+    assert!(paragraph_annotations(&document.content.content[0]).is_empty());
+}
+
+#[test]
+fn test_document_start_rule_can_be_turned_off() {
+    let source = ":: title :: Report\n\nFirst paragraph.\n";
+    let policy = AttachmentPolicy {
+        document_start: false,
+        ..AttachmentPolicy::default()
+    };
+    let document = attached(source, policy);
+
+    assert!(document.content.annotations.is_empty());
+    assert_eq!(
+        paragraph_annotations(&document.content.content[0]),
+        vec!["title"]
+    );
+}
+
+#[test]
+fn test_annotation_attaches_to_next_element() {
+    let document = attached(SESSION, AttachmentPolicy::default());
+
+    assert!(document.content.annotations.is_empty());
+    let SessionContainerElement::Session(session) = &document.content.content[1] else {
+        panic!("Expected a session");
+    };
+    assert_eq!(kinds(&session.content.content), vec!["paragraph"]);
+    assert_eq!(
+        paragraph_annotations(&session.content.content[0]),
+        vec!["note", "summary"]
+    );
+}
+
+#[test]
+fn test_trailing_annotation_can_attach_to_parent() {
+    let policy = AttachmentPolicy {
+        trailing: TrailingAttachment::Parent,
+        ..AttachmentPolicy::default()
+    };
+    let document = attached(SESSION, policy);
+
+    let SessionContainerElement::Session(session) = &document.content.content[1] else {
+        panic!("Expected a session");
+    };
+    assert_eq!(labels(&session.annotations), vec!["summary"]);
+    assert_eq!(
+        paragraph_annotations(&session.content.content[0]),
+        vec!["note"]
+    );
+}
+
+#[test]
+fn test_rules_turned_off_keep_annotations_in_content() {
+    let policy = AttachmentPolicy {
+        document_start: false,
+        next_element: false,
+        trailing: TrailingAttachment::Keep,
+    };
+    let original = run_all_unified(SESSION, None).unwrap();
+
+    assert_eq!(attached(SESSION, policy).content, original.content);
+}
+
+#[test]
+fn test_attacher_without_policy_leaves_document_unchanged() {
+    let original = run_all_unified(SESSION, None).unwrap();
+
+    let unchanged = AnnotationAttacher::new()
+        .attach_annotations(original.clone())
+        .unwrap();
+    assert_eq!(unchanged.content, original.content);
+
+    let attached = AnnotationAttacher::with_policy(AttachmentPolicy::default())
+        .attach_annotations(original)
+        .unwrap();
+    assert_eq!(
+        attached.content,
+        self::attached(SESSION, AttachmentPolicy::default()).content
+    );
+}
+
+const REPORT: &str = "\
+:: title :: Report
+
+First paragraph.
+
+:: note :: Check this.
+Second paragraph.
+
+1. Section
+
+    :: aside :: Inside.
+    Section body.
+";
+
+/// `REPORT` with the note on row 4 edited
+const EDITED: &str = "\
+:: title :: Report
+
+First paragraph.
+
+:: note :: Check this again.
+Second paragraph.
+
+1. Section
+
+    :: aside :: Inside.
+    Section body.
+";
+
+/// The annotation block labeled `name` in a fresh parse of `source`
+fn annotation_block(source: &str, name: &str) -> SessionContainerElement {
+    run_all_unified(source, None)
+        .unwrap()
+        .content
+        .content
+        .into_iter()
+        .find(|element| {
+            matches!(element, SessionContainerElement::Annotation(block) if block.name == name)
+        })
+        .expect("Expected the annotation block")
+}
+
+#[test]
+fn test_reattach_matches_full_attachment_after_edit() {
+    let mut document = attached(REPORT, AttachmentPolicy::default());
+    assert_eq!(
+        kinds(&document.content.content),
+        vec!["paragraph", "paragraph", "session"]
+    );
+
+    // The edited note goes back into the content where it was written
+    document
+        .content
+        .content
+        .insert(1, annotation_block(EDITED, "note"));
+    reattach_annotations(&mut document, 4..5);
+
+    assert_eq!(
+        document.content,
+        attached(EDITED, AttachmentPolicy::default()).content
+    );
+}
+
+#[test]
+fn test_reattach_preserves_attachments_outside_changed_rows() {
+    let mut document = attached(REPORT, AttachmentPolicy::default());
+    document.content.annotations[0].name = "kept-title".to_string();
+    let SessionContainerElement::Session(session) = &mut document.content.content[2] else {
+        panic!("Expected a session");
+    };
+    let SessionContainerElement::Paragraph(body) = &mut session.content.content[0] else {
+        panic!("Expected a paragraph");
+    };
+    body.annotations[0].name = "kept-aside".to_string();
+
+    document
+        .content
+        .content
+        .insert(1, annotation_block(EDITED, "note"));
+    reattach_annotations(&mut document, 4..5);
+
+    assert_eq!(labels(&document.content.annotations), vec!["kept-title"]);
+    assert_eq!(
+        paragraph_annotations(&document.content.content[1]),
+        vec!["note"]
+    );
+    let SessionContainerElement::Session(session) = &document.content.content[2] else {
+        panic!("Expected a session");
+    };
+    assert_eq!(
+        paragraph_annotations(&session.content.content[0]),
+        vec!["kept-aside"]
+    );
+}
+
+#[test]
+fn test_reattach_leaves_blocks_outside_changed_rows_in_content() {
+    let mut document = run_all_unified(REPORT, None).unwrap();
+
+    reattach_annotations(&mut document, 4..5);
+
+    assert!(document.content.annotations.is_empty());
+    assert_eq!(
+        kinds(&document.content.content),
+        vec!["annotation", "paragraph", "paragraph", "session"]
+    );
+    assert_eq!(
+        paragraph_annotations(&document.content.content[2]),
+        vec!["note"]
+    );
+    let SessionContainerElement::Session(session) = &document.content.content[3] else {
+        panic!("Expected a session");
+    };
+    assert_eq!(
+        kinds(&session.content.content),
+        vec!["annotation", "paragraph"]
+    );
+}
+
+#[test]
+fn test_reattach_applies_document_start_rule() {
+    let mut document = run_all_unified(REPORT, None).unwrap();
+
+    reattach_annotations(&mut document, 0..1);
+
+    assert_eq!(labels(&document.content.annotations), vec!["title"]);
+    assert_eq!(
+        kinds(&document.content.content),
+        vec!["paragraph", "annotation", "paragraph", "session"]
+    );
+}
@@ -0,0 +1,85 @@
+//! Adjacent citation merging tests
+
+use txxt::api::run_all_unified;
+use txxt::assembly::merge_adjacent_citations;
+use txxt::ast::elements::references::SimpleReferenceType;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::{collect_references, ReferenceOccurrence};
+use txxt::cst::{Position, SourceSpan};
+
+/// References of `source` after merging, in document order
+fn merged_references(source: &str) -> Vec<ReferenceOccurrence> {
+    let mut document = run_all_unified(source, None).unwrap();
+    merge_adjacent_citations(&mut document);
+    collect_references(&document)
+}
+
+fn span(start: usize, end: usize) -> SourceSpan {
+    SourceSpan {
+        start: Position {
+            row: 0,
+            column: start,
+        },
+        end: Position {
+            row: 0,
+            column: end,
+        },
+    }
+}
+
+#[test]
+fn test_adjacent_citations_merge_into_one_group() {
+    assert_eq!(
+        merged_references("See [@a][@b] now.\n"),
+        vec![ReferenceOccurrence {
+            target: "@a; @b".to_string(),
+            kind: SimpleReferenceType::Citation,
+            span: span(4, 12),
+        }]
+    );
+}
+
+#[test]
+fn test_citations_separated_by_spaces_merge() {
+    let references = merged_references("See [@a] [@b, p. 3]  [@c; @d] now.\n");
+
+    assert_eq!(references.len(), 1);
+    assert_eq!(references[0].target, "@a; @b, p. 3; @c; @d");
+    assert_eq!(references[0].span, span(4, 29));
+}
+
+#[test]
+fn test_citations_separated_by_text_stay_apart() {
+    let references = merged_references("See [@a] and [@b] now.\n");
+
+    let targets: Vec<&str> = references.iter().map(|r| r.target.as_str()).collect();
+    assert_eq!(targets, vec!["a", "b"]);
+}
+
+#[test]
+fn test_other_references_do_not_merge() {
+    let references = merged_references("See [@a] [1] now.\n");
+
+    let kinds: Vec<SimpleReferenceType> = references.iter().map(|r| r.kind.clone()).collect();
+    assert_eq!(
+        kinds,
+        vec![SimpleReferenceType::Citation, SimpleReferenceType::Footnote]
+    );
+}
+
+#[test]
+fn test_text_around_a_merged_group_is_kept() {
+    let mut document = run_all_unified("See [@a] [@b] now.\n", None).unwrap();
+    merge_adjacent_citations(&mut document);
+
+    let paragraph = match &document.content.content[0] {
+        SessionContainerElement::Paragraph(paragraph) => paragraph,
+        other => panic!("Expected a paragraph, got {:?}", other),
+    };
+    let text: String = paragraph
+        .content
+        .iter()
+        .map(|transform| transform.text_content())
+        .collect();
+    assert_eq!(text, "See @a; @b now.");
+}
@@ -0,0 +1,74 @@
+//! List renumbering tests
+
+use txxt::api::run_all_unified;
+use txxt::assembly::renumber_lists;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::{Document, ListBlock};
+
+fn first_list(document: &Document) -> &ListBlock {
+    document
+        .content
+        .content
+        .iter()
+        .find_map(|element| match element {
+            SessionContainerElement::List(list) => Some(list),
+            _ => None,
+        })
+        .expect("Document should contain a list")
+}
+
+fn renumbered_markers(source: &str) -> Vec<String> {
+    let mut document = run_all_unified(source, None).expect("Failed to parse source");
+    renumber_lists(&mut document);
+    first_list(&document)
+        .items
+        .iter()
+        .map(|item| item.marker.clone())
+        .collect()
+}
+
+#[test]
+fn test_parser_preserves_markers() {
+    let document = run_all_unified("1. a\n3. b\n", None).unwrap();
+    let markers: Vec<_> = first_list(&document)
+        .items
+        .iter()
+        .map(|item| item.marker.as_str())
+        .collect();
+
+    assert_eq!(markers, vec!["1.", "3."]);
+}
+
+#[test]
+fn test_renumber_numerical_list() {
+    assert_eq!(renumbered_markers("1. a\n3. b\n"), vec!["1.", "2."]);
+}
+
+#[test]
+fn test_renumber_starts_at_first_value_and_keeps_form() {
+    assert_eq!(
+        renumbered_markers("4) a\n9) b\n2) c\n"),
+        vec!["4)", "5)", "6)"]
+    );
+}
+
+#[test]
+fn test_renumber_alphabetical_list() {
+    assert_eq!(
+        renumbered_markers("B. a\nD. b\nA. c\n"),
+        vec!["B.", "C.", "D."]
+    );
+}
+
+#[test]
+fn test_renumber_roman_list() {
+    assert_eq!(
+        renumbered_markers("i. a\nii. b\nii. c\nx. d\n"),
+        vec!["i.", "ii.", "iii.", "iv."]
+    );
+}
+
+#[test]
+fn test_plain_list_is_left_alone() {
+    assert_eq!(renumbered_markers("- a\n- b\n"), vec!["-", "-"]);
+}
@@ -0,0 +1,84 @@
+//! Metadata validation tests
+
+use txxt::assembly::{validate_meta, MetaWarning};
+use txxt::ast::{Meta, MetaValue};
+
+fn string(value: &str) -> MetaValue {
+    MetaValue::String(value.to_string())
+}
+
+#[test]
+fn test_clean_meta_has_no_warnings() {
+    let mut meta = Meta {
+        title: Some(string("A Clean Document")),
+        authors: vec![string("Ada")],
+        date: Some(string("2024-03-01")),
+        ..Meta::default()
+    };
+    meta.custom.insert("license".to_string(), string("MIT"));
+
+    assert!(validate_meta(&meta).is_empty());
+
+    meta.date = Some(string("2024-03-01T10:00:00Z"));
+    assert!(validate_meta(&meta).is_empty());
+}
+
+#[test]
+fn test_bad_date() {
+    let meta = Meta {
+        date: Some(string("next tuesday")),
+        ..Meta::default()
+    };
+
+    assert_eq!(
+        validate_meta(&meta),
+        vec![MetaWarning::UnparseableDate {
+            value: "next tuesday".to_string()
+        }]
+    );
+}
+
+#[test]
+fn test_two_titles() {
+    let meta = Meta {
+        title: Some(MetaValue::List(vec![string("First"), string("Second")])),
+        ..Meta::default()
+    };
+    assert_eq!(
+        validate_meta(&meta),
+        vec![MetaWarning::MultipleTitles { count: 2 }]
+    );
+
+    // A miscased key holding a title also counts
+    let mut meta = Meta {
+        title: Some(string("First")),
+        ..Meta::default()
+    };
+    meta.custom.insert("Title".to_string(), string("Second"));
+    assert_eq!(
+        validate_meta(&meta),
+        vec![MetaWarning::MultipleTitles { count: 2 }]
+    );
+}
+
+#[test]
+fn test_short_custom_key_is_not_a_near_miss() {
+    let mut meta = Meta::default();
+    meta.custom.insert("data".to_string(), string("raw"));
+
+    assert!(validate_meta(&meta).is_empty());
+}
+
+#[test]
+fn test_suspicious_custom_key() {
+    let mut meta = Meta::default();
+    meta.custom.insert("autor".to_string(), string("Ada"));
+
+    assert_eq!(
+        validate_meta(&meta),
+        vec![MetaWarning::SuspiciousKey {
+            key: "autor".to_string(),
+            suggestion: "author".to_string()
+        }]
+    );
+}
@@ -3,3 +3,16 @@
 //! Tests for Phase 3: Assembler components.
 //!
 //! See src/lib.rs for the full architecture overview.
+
+mod abstract_marking;
+mod annotation_attachment;
+mod citation_merging;
+mod list_numbering;
+mod metadata;
+mod recovery;
+mod session_levels;
+mod session_numbering;
+mod session_slugs;
+mod session_split;
+mod text_coalescing;
+mod typography;
@@ -0,0 +1,250 @@
+//! Recovery from truncated sources
+
+use txxt::api::run_all_unified;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::AssemblyWarning;
+
+#[test]
+fn test_complete_document_has_no_warnings() {
+    let source = "Code:\n    let x = 1;\n:: rust ::\n\n:: note :: Done.\n";
+    let document = run_all_unified(source, None).unwrap();
+
+    assert!(document.assembly_info.warnings.is_empty());
+}
+
+#[test]
+fn test_unterminated_annotation() {
+    let source = "Some text.\n\n:: note severity=high\n";
+    let document = run_all_unified(source, None).unwrap();
+
+    let elements = &document.content.content;
+    assert_eq!(elements.len(), 2);
+    match &elements[1] {
+        SessionContainerElement::Annotation(annotation) => {
+            assert_eq!(annotation.name, "note");
+            assert_eq!(
+                annotation.parameters.get("severity").map(String::as_str),
+                Some("high")
+            );
+            assert!(annotation.is_truncated());
+        }
+        other => panic!("Expected a partial annotation, got {:?}", other),
+    }
+
+    assert_eq!(
+        document.assembly_info.warnings,
+        vec![AssemblyWarning::UnterminatedAnnotation {
+            label: "note".to_string(),
+            line: 3,
+        }]
+    );
+}
+
+#[test]
+fn test_unclosed_annotation_line_mid_document_is_text() {
+    let source = ":: note\nmore text follows\n";
+    let document = run_all_unified(source, None).unwrap();
+
+    assert!(matches!(
+        document.content.content[0],
+        SessionContainerElement::Paragraph(_)
+    ));
+    assert!(document.assembly_info.warnings.is_empty());
+}
+
+#[test]
+fn test_unterminated_verbatim() {
+    let source = "Title\n\n1. Intro\n\n    Some text.\n\n    Code:\n        let x = 1;\n        let y = 2;\n    :: rust";
+    let document = run_all_unified(source, None).unwrap();
+
+    let session = match &document.content.content[1] {
+        SessionContainerElement::Session(session) => session,
+        other => panic!("Expected a session, got {:?}", other),
+    };
+    let verbatim = session
+        .content
+        .content
+        .iter()
+        .find_map(|element| match element {
+            SessionContainerElement::Verbatim(verbatim) => Some(verbatim),
+            _ => None,
+        })
+        .expect("truncated verbatim block should be kept");
+
+    assert_eq!(verbatim.label(), "rust");
+    assert_eq!(verbatim.content_text(), "let x = 1;\nlet y = 2;");
+    assert!(verbatim.is_truncated());
+
+    assert_eq!(
+        document.assembly_info.warnings,
+        vec![AssemblyWarning::UnterminatedVerbatim {
+            title: "Code".to_string(),
+            line: 7,
+        }]
+    );
+}
+
+#[test]
+fn test_duplicate_parameter_keeps_last_value() {
+    let source = ":: note a=1,a=2 :: Hello\n";
+    let document = run_all_unified(source, None).unwrap();
+
+    match &document.content.content[0] {
+        SessionContainerElement::Annotation(annotation) => {
+            assert_eq!(
+                annotation.parameters.get("a").map(String::as_str),
+                Some("2")
+            );
+        }
+        other => panic!("Expected an annotation, got {:?}", other),
+    }
+    assert_eq!(
+        document.assembly_info.warnings,
+        vec![AssemblyWarning::DuplicateParameter {
+            key: "a".to_string(),
+            line: 1,
+        }]
+    );
+}
+
+#[test]
+fn test_distinct_parameters_have_no_warnings() {
+    let source = ":: note a=1,b=2 :: Hello\n";
+    let document = run_all_unified(source, None).unwrap();
+
+    assert!(document.assembly_info.warnings.is_empty());
+}
+
+#[test]
+fn test_unterminated_annotation_in_list_item() {
+    let source = "- one\n- two\n    Text.\n\n    :: note severity=high";
+    let document = run_all_unified(source, None).unwrap();
+
+    assert_eq!(
+        document.assembly_info.warnings,
+        vec![AssemblyWarning::UnterminatedAnnotation {
+            label: "note".to_string(),
+            line: 5,
+        }]
+    );
+}
+
+#[test]
+fn test_unterminated_annotation_in_quote() {
+    let source = "> Quoted\n>\n> :: note severity=high";
+    let document = run_all_unified(source, None).unwrap();
+
+    assert_eq!(
+        document.assembly_info.warnings,
+        vec![AssemblyWarning::UnterminatedAnnotation {
+            label: "note".to_string(),
+            line: 3,
+        }]
+    );
+}
+
+#[test]
+fn test_unterminated_verbatim_in_definition_body() {
+    let source = "Term:\n    Text here.\n\n    Code:\n        let x = 1;\n    :: rust";
+    let document = run_all_unified(source, None).unwrap();
+
+    assert_eq!(
+        document.assembly_info.warnings,
+        vec![AssemblyWarning::UnterminatedVerbatim {
+            title: "Code".to_string(),
+            line: 4,
+        }]
+    );
+}
+
+#[test]
+fn test_every_repeated_key_is_reported() {
+    let source = ":: note a=1,a=2,b=1,b=2,a=3 :: Hello\n";
+    let document = run_all_unified(source, None).unwrap();
+
+    assert_eq!(
+        document.assembly_info.warnings,
+        vec![
+            AssemblyWarning::DuplicateParameter {
+                key: "a".to_string(),
+                line: 1,
+            },
+            AssemblyWarning::DuplicateParameter {
+                key: "b".to_string(),
+                line: 1,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_duplicate_parameter_in_list_item() {
+    let source = "- one\n- two\n    :: note a=1,a=2 :: hi\n";
+    let document = run_all_unified(source, None).unwrap();
+
+    assert_eq!(
+        document.assembly_info.warnings,
+        vec![AssemblyWarning::DuplicateParameter {
+            key: "a".to_string(),
+            line: 3,
+        }]
+    );
+}
+
+#[test]
+fn test_duplicate_parameter_in_definition_body() {
+    let source = "Term:\n    Text.\n\n    Code:\n        let x = 1;\n    :: rust a=1,a=2 ::\n";
+    let document = run_all_unified(source, None).unwrap();
+
+    assert_eq!(
+        document.assembly_info.warnings,
+        vec![AssemblyWarning::DuplicateParameter {
+            key: "a".to_string(),
+            line: 6,
+        }]
+    );
+}
+
+#[test]
+fn test_verbatim_without_terminator() {
+    let source = "Intro.\n\nCode:\n let x = 1;\n let y = 2;\n\n";
+    let document = run_all_unified(source, None).unwrap();
+
+    let verbatim = match &document.content.content[1] {
+        SessionContainerElement::Verbatim(verbatim) => verbatim,
+        other => panic!("Expected a partial verbatim block, got {:?}", other),
+    };
+    // Same content as the block would have had with its terminator
+    let terminated = run_all_unified(
+        "Intro.\n\nCode:\n let x = 1;\n let y = 2;\n:: rust ::\n",
+        None,
+    )
+    .unwrap();
+    let expected = match &terminated.content.content[1] {
+        SessionContainerElement::Verbatim(verbatim) => verbatim.content_text(),
+        other => panic!("Expected a verbatim block, got {:?}", other),
+    };
+    assert_eq!(verbatim.label(), "");
+    assert_eq!(verbatim.content_text(), expected);
+    assert!(verbatim.is_truncated());
+
+    assert_eq!(
+        document.assembly_info.warnings,
+        vec![AssemblyWarning::UnterminatedVerbatim {
+            title: "Code".to_string(),
+            line: 3,
+        }]
+    );
+}
+
+#[test]
+fn test_indented_body_without_terminator_is_definition() {
+    let source = "Term:\n    A definition body.\n";
+    let document = run_all_unified(source, None).unwrap();
+
+    assert!(matches!(
+        document.content.content[0],
+        SessionContainerElement::Definition(_)
+    ));
+    assert!(document.assembly_info.warnings.is_empty());
+}
@@ -0,0 +1,99 @@
+//! Session level shifting tests
+
+use txxt::api::run_all_unified;
+use txxt::assembly::shift_session_levels;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::Document;
+
+const FLAT: &str = "\
+Preamble.
+
+1. One
+
+    Body one.
+
+2. Two
+
+    Body two.
+";
+
+/// Structure of a document as nested session titles; other elements as `.`
+fn outline(elements: &[SessionContainerElement]) -> String {
+    elements
+        .iter()
+        .map(|element| match element {
+            SessionContainerElement::Session(session) => format!(
+                "{}[{}]",
+                session.title.text_content().trim(),
+                outline(&session.content.content)
+            ),
+            _ => ".".to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn parse(source: &str) -> Document {
+    run_all_unified(source, None).expect("Failed to parse source")
+}
+
+#[test]
+fn test_shift_down_nests_sessions_under_untitled_parent() {
+    let mut document = parse(FLAT);
+    shift_session_levels(&mut document, 1);
+
+    assert_eq!(outline(&document.content.content), ". [One[.] Two[.]]");
+}
+
+#[test]
+fn test_shift_down_then_up_restores_structure() {
+    let original = parse(FLAT);
+    let mut document = original.clone();
+
+    shift_session_levels(&mut document, 2);
+    assert_eq!(outline(&document.content.content), ". [[One[.] Two[.]]]");
+
+    shift_session_levels(&mut document, -2);
+    assert_eq!(document.content, original.content);
+}
+
+#[test]
+fn test_shift_up_clamps_at_root() {
+    let mut document = parse(FLAT);
+    shift_session_levels(&mut document, -3);
+
+    assert_eq!(outline(&document.content.content), ". One[.] Two[.]");
+}
+
+#[test]
+fn test_shift_up_lifts_child_sessions_with_following_content() {
+    let source = "\
+1. Parent
+
+    Intro.
+
+    1.1. Child
+
+        Child body.
+
+    Parent tail.
+";
+    let mut document = parse(source);
+    assert_eq!(outline(&document.content.content), "Parent[. Child[.] .]");
+
+    shift_session_levels(&mut document, -1);
+    assert_eq!(outline(&document.content.content), "Parent[.] Child[. .]");
+}
+
+#[test]
+fn test_zero_delta_and_sessionless_documents_are_unchanged() {
+    let original = parse(FLAT);
+    let mut document = original.clone();
+    shift_session_levels(&mut document, 0);
+    assert_eq!(document, original);
+
+    let original = parse("Just a paragraph.\n");
+    let mut document = original.clone();
+    shift_session_levels(&mut document, 1);
+    assert_eq!(document, original);
+}
@@ -0,0 +1,114 @@
+//! Session auto-numbering tests
+
+use txxt::api::run_all_unified;
+use txxt::assembly::{auto_number_sessions, auto_number_sessions_with};
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::{Document, NumberingForm};
+
+const TWO_LEVELS: &str = "\
+Intro
+
+    Opening text.
+
+    Background
+
+        More text.
+
+    Scope
+
+        More text.
+
+Method
+
+    Closing text.
+";
+
+/// (title, marker) for every session, depth first
+fn session_markers(document: &Document) -> Vec<(String, Option<String>)> {
+    fn walk(elements: &[SessionContainerElement], out: &mut Vec<(String, Option<String>)>) {
+        for element in elements {
+            if let SessionContainerElement::Session(session) = element {
+                out.push((
+                    session.title.text_content().trim().to_string(),
+                    session.title.numbering.as_ref().map(|n| n.marker.clone()),
+                ));
+                walk(&session.content.content, out);
+            }
+        }
+    }
+    let mut out = Vec::new();
+    walk(&document.content.content, &mut out);
+    out
+}
+
+fn parse(source: &str) -> Document {
+    run_all_unified(source, None).expect("Failed to parse source")
+}
+
+#[test]
+fn test_two_level_hierarchy_is_numbered_by_position() {
+    let mut document = parse(TWO_LEVELS);
+    auto_number_sessions(&mut document);
+
+    let markers: Vec<_> = session_markers(&document)
+        .into_iter()
+        .map(|(title, marker)| (title, marker.unwrap()))
+        .collect();
+    assert_eq!(
+        markers,
+        vec![
+            ("Intro".to_string(), "1.".to_string()),
+            ("Background".to_string(), "1.1.".to_string()),
+            ("Scope".to_string(), "1.2.".to_string()),
+            ("Method".to_string(), "2.".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_nested_numbers_use_full_form() {
+    let mut document = parse(TWO_LEVELS);
+    auto_number_sessions(&mut document);
+
+    let SessionContainerElement::Session(intro) = &document.content.content[0] else {
+        panic!("Expected the first element to be a session");
+    };
+    assert_eq!(
+        intro.title.numbering.as_ref().unwrap().form,
+        NumberingForm::Short
+    );
+    let nested = intro
+        .content
+        .content
+        .iter()
+        .find_map(|element| match element {
+            SessionContainerElement::Session(session) => Some(session),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(
+        nested.title.numbering.as_ref().unwrap().form,
+        NumberingForm::Full
+    );
+}
+
+#[test]
+fn test_explicit_numbering_is_kept_unless_forced() {
+    let source = "Intro\n\n    Text.\n\n7. Method\n\n    Text.\n";
+
+    let mut document = parse(source);
+    auto_number_sessions(&mut document);
+    let markers: Vec<_> = session_markers(&document)
+        .into_iter()
+        .map(|(_, marker)| marker.unwrap())
+        .collect();
+    assert_eq!(markers, vec!["1.", "7."]);
+
+    let mut document = parse(source);
+    auto_number_sessions_with(&mut document, true);
+    let markers: Vec<_> = session_markers(&document)
+        .into_iter()
+        .map(|(_, marker)| marker.unwrap())
+        .collect();
+    assert_eq!(markers, vec!["1.", "2."]);
+}
@@ -0,0 +1,58 @@
+//! Session slug tests
+
+use txxt::api::run_all_unified;
+use txxt::assembly::assign_slugs;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::{Document, SessionBlock};
+
+fn sessions(document: &Document) -> Vec<&SessionBlock> {
+    fn walk<'a>(elements: &'a [SessionContainerElement], out: &mut Vec<&'a SessionBlock>) {
+        for element in elements {
+            if let SessionContainerElement::Session(session) = element {
+                out.push(session);
+                walk(&session.content.content, out);
+            }
+        }
+    }
+    let mut out = Vec::new();
+    walk(&document.content.content, &mut out);
+    out
+}
+
+fn slugs(source: &str) -> Vec<String> {
+    let mut document = run_all_unified(source, None).expect("Failed to parse source");
+    assign_slugs(&mut document);
+    sessions(&document)
+        .into_iter()
+        .map(SessionBlock::slug)
+        .collect()
+}
+
+#[test]
+fn test_slug_strips_punctuation() {
+    let source = "What's New, Really?\n\n    Text.\n";
+    let document = run_all_unified(source, None).unwrap();
+
+    assert_eq!(sessions(&document)[0].slug(), "whats-new-really");
+}
+
+#[test]
+fn test_identical_titles_get_numbered_suffixes() {
+    let source = "Notes\n\n    First.\n\nSummary\n\n    Middle.\n\nNotes\n\n    Second.\n";
+
+    assert_eq!(slugs(source), vec!["notes-1", "summary", "notes-2"]);
+}
+
+#[test]
+fn test_suffixes_skip_slugs_already_in_use() {
+    let source = "Notes\n\n    First.\n\nNotes\n\n    Second.\n\nNotes 1\n\n    Third.\n";
+
+    assert_eq!(slugs(source), vec!["notes-2", "notes-3", "notes-1"]);
+}
+
+#[test]
+fn test_numbered_session_slug_leaves_out_marker() {
+    let source = "1. Introduction\n\n    Text.\n\n    1.1. Getting Started\n\n        More.\n";
+
+    assert_eq!(slugs(source), vec!["introduction", "getting-started"]);
+}
@@ -0,0 +1,113 @@
+//! Splitting documents by top-level session
+
+use txxt::api::run_all_unified;
+use txxt::assembly::{assign_slugs, split_by_session, PREAMBLE_KEY};
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::Document;
+
+const SOURCE: &str = "\
+Preamble text.
+
+1. Introduction
+
+    Why this exists.
+
+    1.1. Background
+
+        Some history.
+
+2. Usage
+
+    How to use it.
+";
+
+fn parse(source: &str) -> Document {
+    run_all_unified(source, None).expect("Failed to parse source")
+}
+
+/// Titles of a part's top-level sessions
+fn session_titles(document: &Document) -> Vec<String> {
+    document
+        .content
+        .content
+        .iter()
+        .filter_map(|element| match element {
+            SessionContainerElement::Session(session) => {
+                Some(session.title_text().trim().to_string())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_split_gives_preamble_and_one_document_per_session() {
+    let parts = split_by_session(parse(SOURCE));
+
+    let keys: Vec<&str> = parts.iter().map(|(key, _)| key.as_str()).collect();
+    assert_eq!(keys, vec![PREAMBLE_KEY, "introduction", "usage"]);
+
+    assert!(session_titles(&parts[0].1).is_empty());
+    assert!(matches!(
+        parts[0].1.content.content.first(),
+        Some(SessionContainerElement::Paragraph(_))
+    ));
+    assert_eq!(session_titles(&parts[1].1), vec!["Introduction"]);
+    assert_eq!(session_titles(&parts[2].1), vec!["Usage"]);
+}
+
+#[test]
+fn test_split_keeps_nested_sessions_with_their_parent() {
+    let parts = split_by_session(parse(SOURCE));
+
+    let SessionContainerElement::Session(introduction) = &parts[1].1.content.content[0] else {
+        panic!("Expected the introduction session");
+    };
+    assert!(introduction
+        .content
+        .content
+        .iter()
+        .any(|element| matches!(element, SessionContainerElement::Session(_))));
+}
+
+#[test]
+fn test_split_without_preamble_has_no_preamble_document() {
+    let parts = split_by_session(parse("1. Only\n\n    Body.\n"));
+
+    let keys: Vec<&str> = parts.iter().map(|(key, _)| key.as_str()).collect();
+    assert_eq!(keys, vec!["only"]);
+}
+
+#[test]
+fn test_split_keys_are_unique_after_assigning_slugs() {
+    let mut document = parse("Notes\n\n    First.\n\nNotes\n\n    Second.\n");
+    assign_slugs(&mut document);
+
+    let keys: Vec<String> = split_by_session(document)
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+    assert_eq!(keys, vec!["notes-1", "notes-2"]);
+}
+
+#[test]
+fn test_split_keys_are_unique_without_assigning_slugs() {
+    let keys: Vec<String> =
+        split_by_session(parse("Notes\n\n    First.\n\nNotes\n\n    Second.\n"))
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect();
+    assert_eq!(keys, vec!["notes-1", "notes-2"]);
+}
+
+#[test]
+fn test_session_titled_preamble_does_not_take_the_preamble_key() {
+    let source = "Opening words.\n\nPreamble\n\n    The session.\n";
+
+    let parts = split_by_session(parse(source));
+
+    let keys: Vec<&str> = parts.iter().map(|(key, _)| key.as_str()).collect();
+    assert_eq!(keys, vec![PREAMBLE_KEY, "preamble-1"]);
+    assert!(session_titles(&parts[0].1).is_empty());
+    assert_eq!(session_titles(&parts[1].1), vec!["Preamble"]);
+}
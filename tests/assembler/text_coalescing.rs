@@ -0,0 +1,86 @@
+//! Text span coalescing tests
+
+use txxt::api::run_all_unified;
+use txxt::assembly::coalesce_document_text;
+use txxt::ast::elements::inlines::Text;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::{coalesce_text, TextTransform};
+use txxt::cst::{Position, ScannerToken, ScannerTokenSequence, SourceSpan};
+
+/// Plain text span for `content` starting at `column` on row 0
+fn identity(content: &str, column: usize) -> TextTransform {
+    let token = ScannerToken::Text {
+        content: content.to_string(),
+        span: SourceSpan {
+            start: Position { row: 0, column },
+            end: Position {
+                row: 0,
+                column: column + content.len(),
+            },
+        },
+    };
+    TextTransform::Identity(Text::simple_with_tokens(
+        content,
+        ScannerTokenSequence::from_tokens(vec![token]),
+    ))
+}
+
+#[test]
+fn test_adjacent_identities_merge_into_one() {
+    let mut transforms = vec![identity("one", 0), identity(" ", 3), identity("two", 4)];
+
+    coalesce_text(&mut transforms);
+
+    assert_eq!(transforms.len(), 1);
+    match &transforms[0] {
+        TextTransform::Identity(text) => {
+            assert_eq!(text.content(), "one two");
+            assert_eq!(text.tokens.tokens.len(), 3);
+        }
+        other => panic!("Expected an identity span, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_formatted_span_blocks_merging() {
+    let mut transforms = vec![
+        identity("a", 0),
+        identity(" ", 1),
+        TextTransform::Strong(vec![identity("b", 3), identity("c", 4)]),
+        identity(" ", 6),
+        identity("d", 7),
+    ];
+
+    coalesce_text(&mut transforms);
+
+    assert_eq!(transforms.len(), 3);
+    assert_eq!(transforms[0].text_content(), "a ");
+    match &transforms[1] {
+        TextTransform::Strong(inner) => {
+            assert_eq!(inner.len(), 1, "Runs inside a formatted span merge too");
+            assert_eq!(inner[0].text_content(), "bc");
+        }
+        other => panic!("Expected a strong span, got {:?}", other),
+    }
+    assert_eq!(transforms[2].text_content(), " d");
+}
+
+#[test]
+fn test_document_pass_keeps_paragraph_text() {
+    let source = "Hello there\nsecond line with *bold* end.\n";
+    let mut document = run_all_unified(source, None).expect("Failed to parse source");
+    let paragraph_text = |document: &txxt::ast::Document| match &document.content.content[0] {
+        SessionContainerElement::Paragraph(paragraph) => {
+            (paragraph.text_content(), paragraph.content.len())
+        }
+        other => panic!("Expected a paragraph, got {:?}", other),
+    };
+    let (before, spans_before) = paragraph_text(&document);
+
+    coalesce_document_text(&mut document);
+
+    let (after, spans_after) = paragraph_text(&document);
+    assert_eq!(after, before);
+    assert!(spans_before > 3);
+    assert_eq!(spans_after, 3, "text, bold, text");
+}
@@ -0,0 +1,86 @@
+//! Typography tests
+
+use txxt::api::run_all_with_options;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::{Document, TextTransform};
+use txxt::syntax::ParseOptions;
+
+fn parse_typeset(source: &str) -> Document {
+    let options = ParseOptions {
+        typography: true,
+        ..ParseOptions::default()
+    };
+    run_all_with_options(source, None, options).expect("Failed to parse source")
+}
+
+fn first_paragraph(document: &Document) -> &[TextTransform] {
+    document
+        .content
+        .content
+        .iter()
+        .find_map(|element| match element {
+            SessionContainerElement::Paragraph(paragraph) => Some(paragraph.content.as_slice()),
+            _ => None,
+        })
+        .expect("document has a paragraph")
+}
+
+fn paragraph_text(document: &Document) -> String {
+    first_paragraph(document)
+        .iter()
+        .map(|transform| transform.text_content())
+        .collect()
+}
+
+#[test]
+fn test_double_quotes_are_paired() {
+    let document = parse_typeset("She said \"yes\" and \"no\".\n");
+
+    assert_eq!(
+        paragraph_text(&document),
+        "She said \u{201C}yes\u{201D} and \u{201C}no\u{201D}."
+    );
+}
+
+#[test]
+fn test_single_quotes_and_apostrophes() {
+    let document = parse_typeset("It's 'quoted' (and 'this').\n");
+
+    assert_eq!(
+        paragraph_text(&document),
+        "It\u{2019}s \u{2018}quoted\u{2019} (and \u{2018}this\u{2019})."
+    );
+}
+
+#[test]
+fn test_dashes_become_en_and_em_dashes() {
+    let document = parse_typeset("Pages 10--12 --- roughly.\n");
+
+    assert_eq!(
+        paragraph_text(&document),
+        "Pages 10\u{2013}12 \u{2014} roughly."
+    );
+}
+
+#[test]
+fn test_code_span_is_untouched() {
+    let document = parse_typeset("Run `a --flag \"x\"` now -- \"ok\".\n");
+
+    let code: Vec<String> = first_paragraph(&document)
+        .iter()
+        .filter_map(|transform| match transform {
+            TextTransform::Code(text) => Some(text.content()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(code, vec!["a --flag \"x\"".to_string()]);
+    assert!(paragraph_text(&document).ends_with("now \u{2013} \u{201C}ok\u{201D}."));
+}
+
+#[test]
+fn test_typography_is_off_by_default() {
+    let document = run_all_with_options("\"a\" -- b\n", None, ParseOptions::default())
+        .expect("Failed to parse source");
+
+    assert_eq!(paragraph_text(&document), "\"a\" -- b");
+}
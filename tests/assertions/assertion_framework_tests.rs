@@ -362,6 +362,7 @@ mod framework_tests {
             annotations: Vec::new(),
             parameters: Parameters::new(),
             tokens: ScannerTokenSequence::new(),
+            slug: None,
         };
 
         let container = SessionContainer {
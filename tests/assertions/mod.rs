@@ -1008,6 +1008,21 @@ fn extract_text_from_content_container(
                     text.push_str(&extract_text_from_simple_container(c));
                 }
             },
+            ContentContainerElement::Quote(q) => {
+                text.push_str(&extract_text_from_content_container(&q.content));
+            }
+            ContentContainerElement::Table(t) => {
+                for row in t.header.iter().chain(&t.rows) {
+                    let cells: Vec<String> = row.cells.iter().map(|c| c.text_content()).collect();
+                    text.push_str(&cells.join(" "));
+                    text.push('\n');
+                }
+            }
+            ContentContainerElement::FootnoteDefinition(f) => {
+                text.push_str(&f.text());
+                text.push('\n');
+                text.push_str(&extract_text_from_simple_container(&f.body));
+            }
             ContentContainerElement::Container(c) => {
                 text.push_str(&extract_text_from_content_container(c));
             }
@@ -1064,6 +1079,9 @@ fn content_element_type_name(
         ContentContainerElement::Definition(_) => "Definition",
         ContentContainerElement::Verbatim(_) => "Verbatim",
         ContentContainerElement::Annotation(_) => "Annotation",
+        ContentContainerElement::Quote(_) => "Quote",
+        ContentContainerElement::Table(_) => "Table",
+        ContentContainerElement::FootnoteDefinition(_) => "FootnoteDefinition",
         ContentContainerElement::Container(_) => "Container",
         ContentContainerElement::BlankLine(_) => "BlankLine",
     }
@@ -1080,6 +1098,9 @@ fn session_element_type_name(
         SessionContainerElement::Definition(_) => "Definition",
         SessionContainerElement::Verbatim(_) => "Verbatim",
         SessionContainerElement::Annotation(_) => "Annotation",
+        SessionContainerElement::Quote(_) => "Quote",
+        SessionContainerElement::Table(_) => "Table",
+        SessionContainerElement::FootnoteDefinition(_) => "FootnoteDefinition",
         SessionContainerElement::Session(_) => "Session",
         SessionContainerElement::ContentContainer(_) => "ContentContainer",
         SessionContainerElement::SessionContainer(_) => "SessionContainer",
@@ -1478,6 +1499,9 @@ fn element_type_name(element: &SessionContainerElement) -> &'static str {
         SessionContainerElement::Session(_) => "SessionBlock",
         SessionContainerElement::Verbatim(_) => "VerbatimBlock",
         SessionContainerElement::Annotation(_) => "AnnotationBlock",
+        SessionContainerElement::Quote(_) => "QuoteBlock",
+        SessionContainerElement::Table(_) => "TableBlock",
+        SessionContainerElement::FootnoteDefinition(_) => "FootnoteDefinitionBlock",
         SessionContainerElement::BlankLine(_) => "BlankLine",
         SessionContainerElement::ContentContainer(_) => "ContentContainer",
         SessionContainerElement::SessionContainer(_) => "SessionContainer",
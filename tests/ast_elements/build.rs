@@ -0,0 +1,115 @@
+//! Document builder tests
+
+use txxt::api::run_all_unified;
+use txxt::ast::build::DocBuilder;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::{Document, MetaValue};
+
+/// Flatten a document into an indented outline of element kinds and text
+fn outline(document: &Document) -> Vec<String> {
+    let mut lines = Vec::new();
+    outline_elements(&document.content.content, 0, &mut lines);
+    lines
+}
+
+fn outline_elements(elements: &[SessionContainerElement], depth: usize, lines: &mut Vec<String>) {
+    let indent = "  ".repeat(depth);
+    for element in elements {
+        match element {
+            SessionContainerElement::Session(session) => {
+                lines.push(format!("{}Session {}", indent, session.title_text().trim()));
+                outline_elements(&session.content.content, depth + 1, lines);
+            }
+            SessionContainerElement::Paragraph(paragraph) => {
+                lines.push(format!(
+                    "{}Paragraph {}",
+                    indent,
+                    paragraph.text_content().trim()
+                ));
+            }
+            SessionContainerElement::List(list) => {
+                lines.push(format!("{}List {:?}", indent, list.decoration_type.style));
+                for item in &list.items {
+                    lines.push(format!(
+                        "{}  {} {}",
+                        indent,
+                        item.marker,
+                        item.text_content().trim()
+                    ));
+                }
+            }
+            SessionContainerElement::BlankLine(_) => {}
+            other => lines.push(format!("{}{:?}", indent, other)),
+        }
+    }
+}
+
+#[test]
+fn test_built_document_matches_parsed_source() {
+    let built = DocBuilder::new()
+        .session("Intro", |s| s.paragraph("hi").list(&["a", "b"]))
+        .build();
+
+    let parsed = run_all_unified("Intro\n\n    hi\n\n    - a\n    - b\n", None).unwrap();
+
+    assert_eq!(outline(&built), outline(&parsed));
+    assert_eq!(
+        outline(&built),
+        vec![
+            "Session Intro",
+            "  Paragraph hi",
+            "  List Plain",
+            "    - a",
+            "    - b"
+        ]
+    );
+}
+
+#[test]
+fn test_built_nested_sessions_match_parsed_source() {
+    let built = DocBuilder::new()
+        .paragraph("Preamble")
+        .session("Outer", |s| {
+            s.paragraph("Before")
+                .session("Inner", |s| s.numbered_list(&["one", "two"]))
+        })
+        .build();
+
+    let parsed = run_all_unified(
+        "Preamble\n\nOuter\n\n    Before\n\n    Inner\n\n        1. one\n        2. two\n",
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(outline(&built), outline(&parsed));
+}
+
+#[test]
+fn test_multiline_paragraph_is_soft_wrapped() {
+    let document = DocBuilder::new().paragraph("first\nsecond").build();
+
+    match &document.content.content[0] {
+        SessionContainerElement::Paragraph(paragraph) => {
+            assert_eq!(paragraph.content.len(), 3);
+            assert_eq!(paragraph.content[1].text_content(), " ");
+            assert_eq!(paragraph.content[2].text_content(), "second");
+            assert_eq!(paragraph.text_content(), "first second");
+        }
+        other => panic!("Expected paragraph, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_builder_sets_metadata() {
+    let document = DocBuilder::new().title("Report").author("Ada").build();
+
+    assert_eq!(
+        document.meta.title,
+        Some(MetaValue::String("Report".to_string()))
+    );
+    assert_eq!(
+        document.meta.authors,
+        vec![MetaValue::String("Ada".to_string())]
+    );
+    assert!(document.content.content.is_empty());
+}
@@ -0,0 +1,95 @@
+//! Content hashes of documents and traversal nodes
+
+use txxt::api::run_all_unified;
+use txxt::ast::elements::traversal::{NodeRefExt, TraversableDocument};
+use txxt::ast::Document;
+
+const SOURCE: &str = "Introduction\n\n    A paragraph with *bold* text.\n\n    - first item\n    - second item\n\nSecond paragraph here.\n";
+
+fn parse(source: &str) -> Document {
+    run_all_unified(source, None).unwrap()
+}
+
+#[test]
+fn test_hash_is_stable_across_reparse() {
+    assert_eq!(parse(SOURCE).content_hash(), parse(SOURCE).content_hash());
+}
+
+#[test]
+fn test_one_character_change_differs() {
+    let edited = SOURCE.replace("second item", "second iten");
+
+    assert_ne!(parse(SOURCE).content_hash(), parse(&edited).content_hash());
+}
+
+#[test]
+fn test_formatting_change_differs() {
+    let edited = SOURCE.replace("*bold*", "bold");
+
+    assert_ne!(parse(SOURCE).content_hash(), parse(&edited).content_hash());
+}
+
+#[test]
+fn test_whitespace_only_changes_keep_hash() {
+    let spaced = SOURCE
+        .replace("with *bold* text", "with  *bold*   text")
+        .replace(
+            "Second paragraph here.\n",
+            "Second paragraph here.   \n\n\n",
+        );
+
+    assert_eq!(parse(SOURCE).content_hash(), parse(&spaced).content_hash());
+}
+
+#[test]
+fn test_node_hash_matches_document_at_root() {
+    let document = parse(SOURCE);
+    let traversable = TraversableDocument::from_document(&document);
+
+    assert_eq!(traversable.root().content_hash(), document.content_hash());
+}
+
+#[test]
+fn test_node_hash_tracks_only_its_subtree() {
+    let original = TraversableDocument::from_document(&parse(SOURCE));
+    let edited = TraversableDocument::from_document(&parse(
+        &SOURCE.replace("Second paragraph", "Last paragraph"),
+    ));
+    let first = |doc: &TraversableDocument| doc.root().first_child().unwrap().content_hash();
+    let last = |doc: &TraversableDocument| doc.root().last_child().unwrap().content_hash();
+
+    assert_eq!(first(&original), first(&edited));
+    assert_ne!(last(&original), last(&edited));
+}
+
+#[test]
+fn test_session_rename_differs() {
+    let renamed = SOURCE.replace("Introduction", "Conclusion");
+
+    assert_ne!(parse(SOURCE).content_hash(), parse(&renamed).content_hash());
+}
+
+#[test]
+fn test_session_renumber_differs() {
+    let numbered = "1. Introduction\n\n    A paragraph.\n";
+    let renumbered = "2. Introduction\n\n    A paragraph.\n";
+
+    assert_ne!(
+        parse(numbered).content_hash(),
+        parse(renumbered).content_hash()
+    );
+}
+
+#[test]
+fn test_nested_session_rename_changes_node_hash() {
+    let nested = "Outer\n\n    Inner\n\n        A paragraph.\n";
+    let original = TraversableDocument::from_document(&parse(nested));
+    let renamed = TraversableDocument::from_document(&parse(&nested.replace("Inner", "Other")));
+    let first = |doc: &TraversableDocument| doc.root().first_child().unwrap().content_hash();
+
+    assert_ne!(first(&original), first(&renamed));
+    assert_ne!(
+        original.root().content_hash(),
+        renamed.root().content_hash()
+    );
+}
@@ -0,0 +1,51 @@
+//! Flattened list item traversal
+
+use txxt::api::run_all_unified;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::ListBlock;
+
+fn parse_list(source: &str) -> ListBlock {
+    let document = run_all_unified(source, None).unwrap();
+    document
+        .content
+        .content
+        .into_iter()
+        .find_map(|element| match element {
+            SessionContainerElement::List(list) => Some(list),
+            _ => None,
+        })
+        .expect("Source should contain a list")
+}
+
+#[test]
+fn test_flat_items_yields_depth_first_with_depths() {
+    let list = parse_list(
+        "- Fruits\n    - Apple\n    - Pear\n- Vegetables\n    - Leek\n    - Kale\n- Grains\n",
+    );
+
+    let flat: Vec<(usize, String)> = list
+        .flat_items()
+        .map(|(depth, item)| (depth, item.text_content().trim().to_string()))
+        .collect();
+
+    assert_eq!(
+        flat,
+        vec![
+            (0, "Fruits".to_string()),
+            (1, "Apple".to_string()),
+            (1, "Pear".to_string()),
+            (0, "Vegetables".to_string()),
+            (1, "Leek".to_string()),
+            (1, "Kale".to_string()),
+            (0, "Grains".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_flat_items_of_a_flat_list_are_its_items() {
+    let list = parse_list("- one\n- two\n- three\n");
+
+    let depths: Vec<usize> = list.flat_items().map(|(depth, _)| depth).collect();
+    assert_eq!(depths, vec![0, 0, 0]);
+}
@@ -0,0 +1,10 @@
+//! AST element test suite
+//!
+//! Tests for AST element construction and manipulation.
+
+mod build;
+mod content_hash;
+mod list_items;
+mod spans;
+mod task_items;
+mod token_counts;
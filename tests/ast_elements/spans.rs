@@ -0,0 +1,39 @@
+//! Logical and source text of span elements
+
+use txxt::ast::elements::core::SpanElement;
+use txxt::ast::TextSpan;
+use txxt::cst::{ScannerToken, ScannerTokenSequence};
+use txxt::syntax::Lexer;
+
+fn span(source: &str) -> TextSpan {
+    let tokens: Vec<ScannerToken> = Lexer::new(source)
+        .tokenize()
+        .into_iter()
+        .filter(|token| !matches!(token, ScannerToken::Eof { .. }))
+        .collect();
+    TextSpan::simple_with_tokens(source, ScannerTokenSequence::from_tokens(tokens))
+}
+
+#[test]
+fn test_escaped_delimiter_reads_as_the_delimiter() {
+    let escaped = span("\\*");
+
+    assert_eq!(escaped.text_content(), "*");
+    assert_eq!(escaped.raw_text(), "\\*");
+}
+
+#[test]
+fn test_escapes_resolved_within_text() {
+    let escaped = span("not \\*bold\\* and a \\\\ backslash");
+
+    assert_eq!(escaped.text_content(), "not *bold* and a \\ backslash");
+    assert_eq!(escaped.raw_text(), "not \\*bold\\* and a \\\\ backslash");
+}
+
+#[test]
+fn test_plain_text_reads_the_same_both_ways() {
+    let plain = span("C:\\path stays");
+
+    assert_eq!(plain.text_content(), "C:\\path stays");
+    assert_eq!(plain.raw_text(), plain.text_content());
+}
@@ -0,0 +1,102 @@
+//! Task list items: `[ ]` and `[x]` checkboxes on list items
+
+use rstest::rstest;
+use txxt::api::run_all_unified;
+use txxt::ast::elements::core::ElementNode;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::flat_json::to_flat_json;
+use txxt::ast::ListBlock;
+use txxt::tools::treeviz::ast_to_tree_notation;
+
+const TASKS: &str = "- [ ] Write the spec\n- [x] Review the draft\n- Plain item\n";
+
+fn parse_list(source: &str) -> ListBlock {
+    let document = run_all_unified(source, None).unwrap();
+    document
+        .content
+        .content
+        .into_iter()
+        .find_map(|element| match element {
+            SessionContainerElement::List(list) => Some(list),
+            _ => None,
+        })
+        .expect("Source should contain a list")
+}
+
+#[rstest]
+#[case::unchecked(0, Some(false), "Write the spec")]
+#[case::checked(1, Some(true), "Review the draft")]
+#[case::plain(2, None, "Plain item")]
+fn test_checkbox_sets_checked_and_is_stripped(
+    #[case] index: usize,
+    #[case] checked: Option<bool>,
+    #[case] text: &str,
+) {
+    let list = parse_list(TASKS);
+    let item = &list.items[index];
+
+    assert_eq!(item.checked, checked);
+    assert_eq!(item.text_content().trim(), text);
+}
+
+#[test]
+fn test_uppercase_x_is_checked() {
+    let list = parse_list("- [X] Done\n- [ ] Not done\n");
+
+    assert_eq!(list.items[0].checked, Some(true));
+    assert_eq!(list.items[0].text_content().trim(), "Done");
+}
+
+#[test]
+fn test_bracketed_text_is_not_a_checkbox() {
+    let list = parse_list("- [y] Not a task\n- [ ]no space\n");
+
+    assert_eq!(list.items[0].checked, None);
+    assert_eq!(list.items[1].checked, None);
+}
+
+#[test]
+fn test_item_tokens_keep_the_checkbox() {
+    let list = parse_list(TASKS);
+    let columns: Vec<usize> = list.items[1]
+        .tokens
+        .tokens
+        .iter()
+        .map(|token| token.span().start.column)
+        .collect();
+
+    // Marker at 0, checkbox at 2, text from 6
+    assert_eq!(columns[0], 0);
+    assert!(columns.contains(&2));
+}
+
+#[test]
+fn test_flat_json_reports_checked_on_task_items() {
+    let document = run_all_unified(TASKS, None).unwrap();
+    let records: serde_json::Value = serde_json::from_str(&to_flat_json(&document)).unwrap();
+    let checked: Vec<_> = records
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|record| record["type"] == "list_item")
+        .map(|record| record.get("checked").cloned())
+        .collect();
+
+    assert_eq!(
+        checked,
+        vec![
+            Some(serde_json::Value::Bool(false)),
+            Some(serde_json::Value::Bool(true)),
+            None
+        ]
+    );
+}
+
+#[test]
+fn test_treeviz_shows_task_state_icons() {
+    let output = ast_to_tree_notation(&ElementNode::ListBlock(parse_list(TASKS))).unwrap();
+
+    assert!(output.contains("☐ Write the spec"));
+    assert!(output.contains("☑ Review the draft"));
+    assert!(output.contains("• Plain item"));
+}
@@ -0,0 +1,57 @@
+//! Per-element token counts
+
+use txxt::api::run_all_unified;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::{subtree_token_count, Document, TxxtElement};
+
+fn parse(source: &str) -> Document {
+    run_all_unified(source, None).unwrap()
+}
+
+#[test]
+fn test_paragraph_token_count_matches_its_tokens() {
+    let document = parse("A short paragraph.\n");
+    let Some(SessionContainerElement::Paragraph(paragraph)) = document.content.content.first()
+    else {
+        panic!("Expected a paragraph");
+    };
+
+    assert!(paragraph.token_count() > 0);
+    assert_eq!(paragraph.token_count(), paragraph.tokens.tokens.len());
+    assert_eq!(subtree_token_count(paragraph), paragraph.token_count());
+}
+
+#[test]
+fn test_session_sums_its_descendants() {
+    let document = parse("Title\n\n    First paragraph.\n\n    Second one here.\n");
+    let Some(SessionContainerElement::Session(session)) = document.content.content.first() else {
+        panic!("Expected a session");
+    };
+    let paragraphs: usize = session
+        .content
+        .content
+        .iter()
+        .filter_map(|element| match element {
+            SessionContainerElement::Paragraph(paragraph) => Some(paragraph.token_count()),
+            _ => None,
+        })
+        .sum();
+
+    assert!(paragraphs > 0);
+    assert_eq!(session.token_count(), session.tokens.tokens.len());
+    assert_eq!(
+        subtree_token_count(session),
+        session.token_count() + paragraphs
+    );
+}
+
+#[test]
+fn test_list_tokens_shared_with_items_count_once() {
+    let document = parse("- one\n- two\n");
+    let Some(SessionContainerElement::List(list)) = document.content.content.first() else {
+        panic!("Expected a list");
+    };
+    let items: usize = list.items.iter().map(TxxtElement::token_count).sum();
+
+    assert_eq!(subtree_token_count(list), items);
+}
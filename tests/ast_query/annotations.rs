@@ -0,0 +1,98 @@
+//! Annotation enumeration tests
+
+use txxt::api::run_all_unified;
+use txxt::ast::build::DocBuilder;
+use txxt::ast::elements::annotation::annotation_content::{Annotation, AnnotationContent};
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::events::DocEvent;
+use txxt::ast::{collect_annotations_in_ast, AnnotationTarget};
+
+const ANNOTATED: &str = "\
+:: title :: Annotated Document
+
+Opening paragraph.
+
+1. Intro
+
+    :: org.example.review status=draft :: Needs another pass.
+
+    Body text.
+
+    Term:
+        Definition body.
+";
+
+fn attached(label: &str) -> Annotation {
+    Annotation::new(label.to_string(), AnnotationContent::Empty)
+}
+
+#[test]
+fn test_top_level_annotation_belongs_to_document() {
+    let document = run_all_unified(ANNOTATED, None).unwrap();
+    let annotations = collect_annotations_in_ast(&document);
+
+    let title = &annotations[0];
+    assert_eq!(title.label, "title");
+    assert_eq!(title.namespace, None);
+    assert!(title.parameters.map.is_empty());
+    assert_eq!(title.target, AnnotationTarget::Document);
+}
+
+#[test]
+fn test_nested_annotation_belongs_to_its_session() {
+    let document = run_all_unified(ANNOTATED, None).unwrap();
+    let annotations = collect_annotations_in_ast(&document);
+    assert_eq!(annotations.len(), 2);
+
+    let review = &annotations[1];
+    assert_eq!(review.label, "review");
+    assert_eq!(review.namespace, Some("org.example"));
+    assert_eq!(
+        review.parameters.get("status").map(String::as_str),
+        Some("draft")
+    );
+    let AnnotationTarget::Element(DocEvent::EnterSession(session)) = review.target else {
+        panic!("Expected a session target, got {:?}", review.target);
+    };
+    assert_eq!(session.title.text_content().trim(), "Intro");
+}
+
+#[test]
+fn test_attached_annotations_in_document_order() {
+    let mut document = DocBuilder::new()
+        .paragraph("Lead")
+        .session("Intro", |s| s.paragraph("Body"))
+        .build();
+    document.content.annotations.push(attached("author"));
+    let SessionContainerElement::Session(session) = &mut document.content.content[1] else {
+        panic!("Expected a session");
+    };
+    session.annotations.push(attached("org.example.status"));
+    let SessionContainerElement::Paragraph(body) = &mut session.content.content[0] else {
+        panic!("Expected a paragraph");
+    };
+    body.annotations.push(attached("todo"));
+
+    let annotations = collect_annotations_in_ast(&document);
+    let labels: Vec<_> = annotations.iter().map(|a| a.label).collect();
+    assert_eq!(labels, vec!["author", "status", "todo"]);
+
+    assert_eq!(annotations[0].target, AnnotationTarget::Document);
+    assert_eq!(annotations[1].namespace, Some("org.example"));
+    assert!(matches!(
+        annotations[1].target,
+        AnnotationTarget::Element(DocEvent::EnterSession(_))
+    ));
+    let AnnotationTarget::Element(DocEvent::EnterParagraph(paragraph)) = annotations[2].target
+    else {
+        panic!("Expected a paragraph target");
+    };
+    assert_eq!(paragraph.content[0].text_content(), "Body");
+}
+
+#[test]
+fn test_document_without_annotations() {
+    let document = run_all_unified("Just a paragraph.\n", None).unwrap();
+
+    assert!(collect_annotations_in_ast(&document).is_empty());
+}
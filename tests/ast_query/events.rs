@@ -0,0 +1,95 @@
+//! Document event stream tests
+
+use txxt::api::run_all_unified;
+use txxt::ast::events::{events, DocEvent};
+
+const SOURCE: &str = "\
+Intro
+
+    First line.
+
+    - one
+    - two
+
+Term:
+    Body text.
+";
+
+/// Event names, without the elements they borrow
+fn names(source: &str) -> Vec<&'static str> {
+    let document = run_all_unified(source, None).unwrap();
+    events(&document)
+        .map(|event| match event {
+            DocEvent::EnterSession(_) => "EnterSession",
+            DocEvent::ExitSession => "ExitSession",
+            DocEvent::EnterParagraph(_) => "EnterParagraph",
+            DocEvent::ExitParagraph => "ExitParagraph",
+            DocEvent::EnterList(_) => "EnterList",
+            DocEvent::ExitList => "ExitList",
+            DocEvent::EnterListItem(_) => "EnterListItem",
+            DocEvent::ExitListItem => "ExitListItem",
+            DocEvent::EnterDefinition(_) => "EnterDefinition",
+            DocEvent::ExitDefinition => "ExitDefinition",
+            DocEvent::EnterQuote(_) => "EnterQuote",
+            DocEvent::ExitQuote => "ExitQuote",
+            DocEvent::EnterAnnotation(_) => "EnterAnnotation",
+            DocEvent::ExitAnnotation => "ExitAnnotation",
+            DocEvent::Verbatim(_) => "Verbatim",
+            DocEvent::Table(_) => "Table",
+            DocEvent::EnterFootnote(_) => "EnterFootnote",
+            DocEvent::ExitFootnote => "ExitFootnote",
+            DocEvent::Text(_) => "Text",
+        })
+        .collect()
+}
+
+#[test]
+fn test_event_sequence() {
+    assert_eq!(
+        names(SOURCE),
+        vec![
+            "EnterSession",
+            "Text",
+            "EnterParagraph",
+            "Text",
+            "ExitParagraph",
+            "EnterList",
+            "EnterListItem",
+            "Text",
+            "ExitListItem",
+            "EnterListItem",
+            "Text",
+            "ExitListItem",
+            "ExitList",
+            "ExitSession",
+            "EnterDefinition",
+            "Text",
+            "EnterParagraph",
+            "Text",
+            "ExitParagraph",
+            "ExitDefinition",
+        ]
+    );
+}
+
+#[test]
+fn test_every_enter_has_a_matching_exit() {
+    let document = run_all_unified(SOURCE, None).unwrap();
+
+    let mut depth = 0usize;
+    for event in events(&document) {
+        if event.is_enter() {
+            depth += 1;
+        } else if event.is_exit() {
+            depth = depth.checked_sub(1).expect("exit without an enter");
+        }
+    }
+    assert_eq!(depth, 0);
+}
+
+#[test]
+fn test_empty_document_has_no_events() {
+    let document = run_all_unified("", None).unwrap();
+
+    assert_eq!(events(&document).count(), 0);
+}
@@ -0,0 +1,86 @@
+//! Flat JSON export tests
+
+use serde_json::Value;
+use txxt::api::run_all_unified;
+use txxt::ast::flat_json::to_flat_json;
+
+fn flat(source: &str) -> Vec<Value> {
+    let document = run_all_unified(source, None).unwrap();
+    match serde_json::from_str(&to_flat_json(&document)).unwrap() {
+        Value::Array(records) => records,
+        other => panic!("expected an array, got {}", other),
+    }
+}
+
+const SOURCE: &str = "Intro *paragraph*.
+
+1. Session
+
+    Body text.
+
+    - one
+    - two
+
+    1.1. Nested
+
+        Deep paragraph.
+";
+
+#[test]
+fn test_one_record_per_element() {
+    let records = flat(SOURCE);
+    let types: Vec<&str> = records
+        .iter()
+        .map(|record| record["type"].as_str().unwrap())
+        .collect();
+
+    assert_eq!(
+        types,
+        vec![
+            "paragraph",
+            "session",
+            "paragraph",
+            "list",
+            "list_item",
+            "list_item",
+            "session",
+            "paragraph",
+        ]
+    );
+}
+
+#[test]
+fn test_depth_increases_into_sessions() {
+    let records = flat(SOURCE);
+    let depths: Vec<u64> = records
+        .iter()
+        .map(|record| record["depth"].as_u64().unwrap())
+        .collect();
+
+    assert_eq!(depths, vec![0, 0, 1, 1, 2, 2, 1, 2]);
+}
+
+#[test]
+fn test_records_carry_text_and_path() {
+    let records = flat(SOURCE);
+
+    assert_eq!(records[1]["text"], "Session");
+    assert_eq!(records[0]["text"], "Intro paragraph.");
+    assert_eq!(records[2]["text"], "Body text.");
+    assert_eq!(records[5]["path"], serde_json::json!([1, 1, 1]));
+    assert_eq!(records[7]["path"], serde_json::json!([1, 2, 0]));
+}
+
+#[test]
+fn test_session_span_covers_its_content() {
+    let records = flat(SOURCE);
+    let span = &records[1]["span"];
+
+    assert_eq!(span["start"]["row"], 2);
+    assert_eq!(span["end"]["row"], 11);
+}
+
+#[test]
+fn test_empty_document_is_an_empty_array() {
+    assert!(flat("").is_empty());
+}
@@ -0,0 +1,51 @@
+//! Folding range tests
+
+use txxt::api::run_all_unified;
+use txxt::ast::folding::{folding_ranges, FoldingRange, FoldingRangeKind};
+
+fn range(kind: FoldingRangeKind, start_line: usize, end_line: usize) -> FoldingRange {
+    FoldingRange {
+        start_line,
+        end_line,
+        kind,
+    }
+}
+
+#[test]
+fn test_nested_session_and_verbatim_block() {
+    let source = "\
+Outer
+
+    Intro text.
+
+    - one
+    - two
+
+    Inner
+
+        Some text.
+
+        Example:
+            let x = 1;
+            let y = 2;
+        :: rust
+";
+    let document = run_all_unified(source, None).unwrap();
+
+    assert_eq!(
+        folding_ranges(&document),
+        vec![
+            range(FoldingRangeKind::Session, 0, 14),
+            range(FoldingRangeKind::List, 4, 5),
+            range(FoldingRangeKind::Session, 7, 14),
+            range(FoldingRangeKind::Verbatim, 11, 14),
+        ]
+    );
+}
+
+#[test]
+fn test_single_line_list_does_not_fold() {
+    let document = run_all_unified("Text.\n\n- only\n", None).unwrap();
+
+    assert!(folding_ranges(&document).is_empty());
+}
@@ -1,4 +1,14 @@
 //! AST query test suite
 //!
 //! Tests for AST query functionality.
-//! Currently placeholder - will be populated as query implementation progresses.
+
+mod annotations;
+mod events;
+mod flat_json;
+mod folding;
+mod plaintext;
+mod references;
+mod semantic_tokens;
+mod session_cycles;
+mod source_map;
+mod toc;
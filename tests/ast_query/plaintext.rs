@@ -0,0 +1,51 @@
+//! Plain text extraction tests
+
+use txxt::api::run_all_unified;
+use txxt::ast::plaintext::{to_plaintext, to_plaintext_filtered};
+
+const SOURCE: &str = "\
+Intro *text* with [@ref].
+
+:: note :: Kept note
+
+First paragraph
+over two lines.
+
+:: internal :: Secret remark
+
+- one
+- two
+";
+
+#[test]
+fn test_plaintext_includes_every_annotation() {
+    let document = run_all_unified(SOURCE, None).unwrap();
+
+    assert_eq!(
+        to_plaintext(&document),
+        "Intro text with ref.\n\
+         Kept note\n\
+         First paragraph over two lines.\n\
+         Secret remark\n\
+         one\n\
+         two"
+    );
+}
+
+#[test]
+fn test_excluded_annotation_text_is_omitted() {
+    let document = run_all_unified(SOURCE, None).unwrap();
+
+    let text = to_plaintext_filtered(&document, &["internal"]);
+
+    assert!(text.contains("Kept note"));
+    assert!(!text.contains("Secret remark"));
+    assert!(text.contains("First paragraph"));
+}
+
+#[test]
+fn test_verbatim_lines_are_included() {
+    let document = run_all_unified("Example:\n    let x = 1;\n:: rust\n", None).unwrap();
+
+    assert_eq!(to_plaintext(&document), "Example\nlet x = 1;");
+}
@@ -0,0 +1,90 @@
+//! Reference enumeration tests
+
+use std::collections::HashMap;
+
+use txxt::api::run_all_unified;
+use txxt::ast::collect_references;
+use txxt::ast::elements::references::SimpleReferenceType;
+
+const MIXED: &str = "\
+Reference Mix
+
+1. Intro [#2]
+
+    Plain paragraph citing [@smith2023] and [1].
+
+    - item with [https://example.com]
+    - item with [./notes.txxt]
+
+    Term:
+        Defined with [@doe2024].
+
+2. Details
+
+    | kind | ref    |
+    | tk   | [TK]   |
+    | sect | [#1]   |
+
+    :: note :: See [@jones2025] and [example.org].
+";
+
+#[test]
+fn test_collect_references_counts_per_kind() {
+    let document = run_all_unified(MIXED, None).unwrap();
+    let references = collect_references(&document);
+
+    let mut counts: HashMap<SimpleReferenceType, usize> = HashMap::new();
+    for reference in &references {
+        *counts.entry(reference.kind.clone()).or_default() += 1;
+    }
+
+    assert_eq!(counts.get(&SimpleReferenceType::Citation), Some(&3));
+    assert_eq!(counts.get(&SimpleReferenceType::Section), Some(&2));
+    assert_eq!(counts.get(&SimpleReferenceType::Footnote), Some(&1));
+    assert_eq!(counts.get(&SimpleReferenceType::Url), Some(&2));
+    assert_eq!(counts.get(&SimpleReferenceType::File), Some(&1));
+    assert_eq!(counts.get(&SimpleReferenceType::ToComeTK), Some(&1));
+    assert_eq!(references.len(), 10);
+}
+
+#[test]
+fn test_collect_references_in_document_order_with_spans() {
+    let document = run_all_unified(MIXED, None).unwrap();
+    let references = collect_references(&document);
+
+    let targets: Vec<&str> = references.iter().map(|r| r.target.as_str()).collect();
+    assert_eq!(
+        targets,
+        vec![
+            "2",
+            "smith2023",
+            "1",
+            "https://example.com",
+            "./notes.txxt",
+            "doe2024",
+            "TK",
+            "1",
+            "jones2025",
+            "example.org",
+        ]
+    );
+
+    // Spans cover the brackets in the source
+    let lines: Vec<&str> = MIXED.lines().collect();
+    for reference in &references {
+        let span = &reference.span;
+        assert_eq!(span.start.row, span.end.row);
+        let text = &lines[span.start.row][span.start.column..span.end.column];
+        assert!(text.starts_with('[') && text.ends_with(']'), "{:?}", text);
+    }
+}
+
+#[test]
+fn test_document_without_references() {
+    let document = run_all_unified("Just text, no brackets.\n", None).unwrap();
+    assert!(collect_references(&document).is_empty());
+
+    // Brackets inside inline code are not references
+    let document = run_all_unified("Write `[@key]` to cite.\n", None).unwrap();
+    assert!(collect_references(&document).is_empty());
+}
@@ -0,0 +1,81 @@
+//! Semantic token export tests
+
+use txxt::api::run_all_unified;
+use txxt::ast::semantic_tokens::{semantic_tokens, SemanticToken, SemanticTokenKind};
+use txxt::cst::{Position, SourceSpan};
+
+fn token(kind: SemanticTokenKind, row: usize, start: usize, end: usize) -> SemanticToken {
+    SemanticToken {
+        span: SourceSpan {
+            start: Position { row, column: start },
+            end: Position { row, column: end },
+        },
+        kind,
+    }
+}
+
+#[test]
+fn test_paragraph_with_emphasis_and_code() {
+    let document = run_all_unified("Some *word* and `code` here.\n", None).unwrap();
+
+    assert_eq!(
+        semantic_tokens(&document),
+        vec![
+            token(SemanticTokenKind::Emphasis, 0, 6, 10),
+            token(SemanticTokenKind::Code, 0, 17, 21),
+        ]
+    );
+}
+
+#[test]
+fn test_headings_markers_references_and_verbatim() {
+    let source = "\
+Intro
+
+    See [@smith2023].
+
+    - first
+    - second
+
+    Example:
+        let x = 1;
+    :: rust
+";
+    let document = run_all_unified(source, None).unwrap();
+    let kinds: Vec<(SemanticTokenKind, usize)> = semantic_tokens(&document)
+        .into_iter()
+        .map(|token| (token.kind, token.span.start.row))
+        .collect();
+
+    assert_eq!(
+        kinds,
+        vec![
+            (SemanticTokenKind::Heading, 0),
+            (SemanticTokenKind::Reference, 2),
+            (SemanticTokenKind::ListMarker, 4),
+            (SemanticTokenKind::ListMarker, 5),
+            (SemanticTokenKind::Verbatim, 8),
+        ]
+    );
+    let marker = &semantic_tokens(&document)[2];
+    assert_eq!((marker.span.start.column, marker.span.end.column), (4, 5));
+}
+
+#[test]
+fn test_columns_count_characters_on_non_ascii_lines() {
+    let source = "\
+Intro
+
+    Example:
+        naïve 🚀 code
+    :: text
+";
+    let document = run_all_unified(source, None).unwrap();
+
+    let verbatim = semantic_tokens(&document)
+        .into_iter()
+        .find(|token| token.kind == SemanticTokenKind::Verbatim)
+        .unwrap();
+    // Twelve characters, though `ï` and `🚀` take six bytes between them
+    assert_eq!(verbatim, token(SemanticTokenKind::Verbatim, 3, 8, 20));
+}
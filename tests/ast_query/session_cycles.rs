@@ -0,0 +1,72 @@
+//! Session reference cycle tests
+
+use txxt::api::{run_all_checked, run_all_unified};
+use txxt::ast::{find_session_cycles, Diagnostic};
+
+#[test]
+fn test_two_sessions_referencing_each_other_form_a_cycle() {
+    let source = "\
+1. Setup
+
+    Continue with [#2].
+
+2. Usage
+
+    Go back to [#1] first.
+";
+    let cycles = find_session_cycles(&run_all_unified(source, None).unwrap());
+
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(cycles[0].sessions, vec!["Setup", "Usage"]);
+    assert_eq!(cycles[0].span.start.row, 6);
+}
+
+#[test]
+fn test_acyclic_chain_has_no_cycle() {
+    let source = "\
+1. Setup
+
+    Continue with [#2].
+
+2. Usage
+
+    Then read [#3].
+
+3. Reference
+
+    Nothing further.
+";
+    let document = run_all_unified(source, None).unwrap();
+
+    assert!(find_session_cycles(&document).is_empty());
+}
+
+#[test]
+fn test_named_and_nested_references_resolve() {
+    let source = "\
+1. Setup
+
+    See [#2.1].
+
+2. Usage
+
+    1. Details
+
+        Back to [#setup].
+";
+    let cycles = find_session_cycles(&run_all_unified(source, None).unwrap());
+
+    assert_eq!(cycles.len(), 1, "{:?}", cycles);
+    assert_eq!(cycles[0].sessions, vec!["Setup", "Details"]);
+}
+
+#[test]
+fn test_cycle_is_reported_by_checked_parsing() {
+    let source = "1. Setup\n\n    See [#2].\n\n2. Usage\n\n    See [#1].\n";
+    let (_, diagnostics) = run_all_checked(source, None, false).unwrap();
+
+    assert!(diagnostics.contains(&Diagnostic::ReferenceCycle {
+        sessions: vec!["Setup".to_string(), "Usage".to_string()],
+        line: 7,
+    }));
+}
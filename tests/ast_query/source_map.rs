@@ -0,0 +1,108 @@
+//! Source map tests
+//!
+//! Offset lookups against a parsed ensemble document.
+
+use crate::infrastructure::corpora::TxxtCorpora;
+use txxt::api::run_all_unified;
+use txxt::ast::elements::containers::content::ContentContainerElement;
+use txxt::ast::elements::core::ElementType;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::source_map::{SourceMap, SourceText};
+
+fn two_paragraphs() -> (String, SourceMap) {
+    let corpus = TxxtCorpora::load_document("01-two-paragraphs").expect("Failed to load ensemble");
+    let document = run_all_unified(&corpus.source_text, None).expect("Failed to parse ensemble");
+    let map = SourceMap::new(&document, &corpus.source_text);
+    (corpus.source_text, map)
+}
+
+#[test]
+fn test_node_at_mid_paragraph_offset() {
+    let (source, map) = two_paragraphs();
+
+    let first_offset = source.find("plain text").unwrap();
+    let second_offset = source.find("second paragraph").unwrap();
+
+    let first = map.node_at_offset(first_offset).expect("No node at offset");
+    let second = map
+        .node_at_offset(second_offset)
+        .expect("No node at offset");
+
+    assert_eq!(first.value().element_type, ElementType::Block);
+    assert_eq!(second.value().element_type, ElementType::Block);
+    assert_ne!(first.id(), second.id());
+
+    let range = map.node_range(first).unwrap();
+    assert!(range.contains(&first_offset));
+    assert!(!range.contains(&second_offset));
+}
+
+#[test]
+fn test_offset_past_content_has_no_node() {
+    let (source, map) = two_paragraphs();
+    assert!(map.node_at_offset(source.len() + 10).is_none());
+}
+
+#[test]
+fn test_offset_to_line_col() {
+    let (source, map) = two_paragraphs();
+
+    assert_eq!(map.offset_to_line_col(0), (0, 0));
+
+    let second_line = source.find("This is the second").unwrap();
+    assert_eq!(map.offset_to_line_col(second_line), (2, 0));
+    assert_eq!(map.offset_to_line_col(second_line + 5), (2, 5));
+}
+
+#[test]
+fn test_paragraph_source_text() {
+    let (source, map) = two_paragraphs();
+
+    let offset = source.find("second paragraph").unwrap();
+    let paragraph = map.node_at_offset(offset).unwrap();
+
+    let text = paragraph.source_text(&source).unwrap();
+    assert!(text.starts_with("This is the second paragraph."));
+    assert!(text.ends_with("separate paragraphs in txxt."));
+    assert_eq!(
+        source[map.node_range(paragraph).unwrap()].trim_end_matches('\n'),
+        text
+    );
+}
+
+#[test]
+fn test_nested_list_item_source_text() {
+    let source = "Shopping:\n\n- fruit\n    - apples\n    - *ripe* pears\n- bread\n";
+    let document = run_all_unified(source, None).unwrap();
+
+    let list = document
+        .content
+        .content
+        .iter()
+        .find_map(|element| match element {
+            SessionContainerElement::List(list) => Some(list),
+            _ => None,
+        })
+        .expect("Document should contain a list");
+    assert_eq!(list.items[1].source_text(source), Some("- bread"));
+
+    let nested = match list.items[0].nested.as_ref().unwrap().content.as_slice() {
+        [ContentContainerElement::List(nested)] => nested,
+        other => panic!("Expected a nested list, got {:?}", other),
+    };
+    assert_eq!(nested.items[0].source_text(source), Some("- apples"));
+    assert_eq!(nested.items[1].source_text(source), Some("- *ripe* pears"));
+    assert_eq!(
+        nested.source_text(source),
+        Some("- apples\n    - *ripe* pears")
+    );
+}
+
+#[test]
+fn test_source_text_outside_source_is_none() {
+    let (source, map) = two_paragraphs();
+    let offset = source.find("second paragraph").unwrap();
+    let paragraph = map.node_at_offset(offset).unwrap();
+
+    assert_eq!(paragraph.source_text(""), None);
+}
@@ -0,0 +1,78 @@
+//! Table of contents tests
+
+use txxt::api::run_all_unified;
+use txxt::ast::generate_toc;
+
+const SOURCE: &str = "\
+1. Introduction
+
+    Opening text.
+
+    1.1. Background
+
+        More text.
+
+        Details
+
+            Deepest text.
+
+    1.2. Scope
+
+        More text.
+
+2. Method
+
+    Closing text.
+";
+
+#[test]
+fn test_toc_captures_titles_and_numbering_in_order() {
+    let document = run_all_unified(SOURCE, None).unwrap();
+    let toc = generate_toc(&document, usize::MAX);
+
+    let entries: Vec<_> = toc
+        .flatten()
+        .into_iter()
+        .map(|entry| {
+            (
+                entry.numbering.as_deref(),
+                entry.title.as_str(),
+                entry.anchor.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            (Some("1."), "Introduction", "#1"),
+            (Some("1.1."), "Background", "#1.1"),
+            (None, "Details", "#1.1.1"),
+            (Some("1.2."), "Scope", "#1.2"),
+            (Some("2."), "Method", "#2"),
+        ]
+    );
+    assert_eq!(toc.entries.len(), 2);
+    assert_eq!(toc.entries[0].children.len(), 2);
+}
+
+#[test]
+fn test_toc_depth_limit() {
+    let document = run_all_unified(SOURCE, None).unwrap();
+
+    let top_level = generate_toc(&document, 1);
+    assert_eq!(top_level.flatten().len(), 2);
+    assert!(top_level
+        .entries
+        .iter()
+        .all(|entry| entry.children.is_empty()));
+
+    let two_levels = generate_toc(&document, 2);
+    let depths: Vec<usize> = two_levels
+        .flatten()
+        .iter()
+        .map(|entry| entry.depth)
+        .collect();
+    assert_eq!(depths, vec![1, 2, 2, 1]);
+
+    assert!(generate_toc(&document, 0).is_empty());
+}
@@ -541,17 +541,14 @@ impl<'a> CorpusExtractor<'a> {
     fn parse_corpus_label(line: &str) -> Option<(String, HashMap<String, String>)> {
         let trimmed = line.trim();
 
-        // Check if this is a verbatim label line with txxt.core.spec pattern
-        if !trimmed.starts_with("::") || !trimmed.ends_with("::") {
-            return None;
-        }
-
-        // Handle edge case of just "::"
-        if trimmed.len() < 4 {
-            return None;
-        }
-
-        let content = trimmed[2..trimmed.len() - 2].trim();
+        // Check if this is a verbatim label line with txxt.core.spec pattern.
+        // Labels close with a single colon (`:: label :`); the older
+        // double-colon form (`:: label ::`) is still accepted.
+        let content = trimmed.strip_prefix("::")?;
+        let content = content
+            .strip_suffix("::")
+            .or_else(|| content.strip_suffix(':'))?
+            .trim();
 
         if !content.starts_with("txxt.core.spec.") {
             return None;
@@ -755,6 +752,13 @@ mod tests {
         assert_eq!(name, "txxt.core.spec.paragraph.valid.simple");
         assert!(params.is_empty());
 
+        // Test single-colon closing form
+        let (name, params) =
+            CorpusExtractor::parse_corpus_label(":: txxt.core.spec.paragraph.valid.simple :")
+                .unwrap();
+        assert_eq!(name, "txxt.core.spec.paragraph.valid.simple");
+        assert!(params.is_empty());
+
         // Test label with parameters
         let (name, params) = CorpusExtractor::parse_corpus_label(r#":: txxt.core.spec.list.error.singleItem error="ParseError",message="Lists require multiple items" ::"#).unwrap();
         assert_eq!(name, "txxt.core.spec.list.error.singleItem");
@@ -767,6 +771,7 @@ mod tests {
         // Test non-corpus label
         assert!(CorpusExtractor::parse_corpus_label(":: basic ::").is_none());
         assert!(CorpusExtractor::parse_corpus_label("regular text").is_none());
+        assert!(CorpusExtractor::parse_corpus_label("::").is_none());
     }
 
     #[test]
@@ -0,0 +1,65 @@
+//! Block-only parsing tests
+
+use serde_json::Value;
+use txxt::api::{parse_blocks_only, run_all_unified};
+use txxt::ast::elements::formatting::inlines::TextTransform;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::flat_json::to_flat_json;
+use txxt::ast::Document;
+
+const SOURCE: &str = "\
+Intro with **bold** and `code`.
+
+1. Session
+
+    Body citing [@smith2023].
+
+    - first
+    - second
+
+Term:
+    Defined here.
+";
+
+/// Type and path of every element, leaving text out
+fn structure(document: &Document) -> Vec<(Value, Value)> {
+    match serde_json::from_str(&to_flat_json(document)).unwrap() {
+        Value::Array(records) => records
+            .into_iter()
+            .map(|record| (record["type"].clone(), record["path"].clone()))
+            .collect(),
+        other => panic!("expected an array, got {}", other),
+    }
+}
+
+fn first_paragraph(document: &Document) -> &[TextTransform] {
+    match document.content.content.first() {
+        Some(SessionContainerElement::Paragraph(paragraph)) => &paragraph.content,
+        other => panic!("Expected a paragraph, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_structure_matches_full_parse() {
+    let full = run_all_unified(SOURCE, None).unwrap();
+    let blocks = parse_blocks_only(SOURCE).unwrap();
+
+    assert_eq!(structure(&blocks), structure(&full));
+}
+
+#[test]
+fn test_inline_formatting_is_not_expanded() {
+    let full = run_all_unified(SOURCE, None).unwrap();
+    let blocks = parse_blocks_only(SOURCE).unwrap();
+
+    assert!(first_paragraph(&full)
+        .iter()
+        .any(|transform| matches!(transform, TextTransform::Strong(_))));
+    assert!(first_paragraph(&blocks)
+        .iter()
+        .all(|transform| matches!(transform, TextTransform::Identity(_))));
+    assert_eq!(
+        first_paragraph(&blocks)[0].text_content().trim_end(),
+        "Intro with **bold** and `code`."
+    );
+}
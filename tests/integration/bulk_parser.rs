@@ -0,0 +1,60 @@
+//! A reused `Parser` gives the same documents as one-shot parsing
+
+use txxt::api::{run_all_unified, run_all_with_options, ParseOptions, Parser};
+use txxt::ast::Diagnostic;
+use txxt::syntax::IndentationIssue;
+
+const SOURCES: &[&str] = &[
+    "A *bold* and _italic_ paragraph.\n",
+    "1. Session\n\n    Body with `code` and #math#.\n",
+    "- one\n- two\n- three\n",
+    "Term ::\n    Definition with a reference [@citation].\n",
+    "",
+    ":: note :: A standing annotation\n\nAfter it.\n",
+    "Broken *bold and _italic\n",
+];
+
+#[test]
+fn test_reused_parser_matches_one_shot_parse() {
+    let mut parser = Parser::new();
+
+    for _ in 0..3 {
+        for source in SOURCES {
+            let reused = parser.parse_into(source).unwrap();
+            let fresh = run_all_unified(source, None).unwrap();
+            assert_eq!(reused.content, fresh.content, "source: {:?}", source);
+        }
+    }
+}
+
+#[test]
+fn test_reused_parser_keeps_its_options() {
+    let options = ParseOptions {
+        indent_size: 2,
+        ..ParseOptions::default()
+    };
+    let mut parser = Parser::with_options(options);
+    let source = "Session\n\n  Body.\n";
+
+    assert_eq!(
+        parser.parse_into(source).unwrap().content,
+        run_all_with_options(source, None, options).unwrap().content
+    );
+}
+
+#[test]
+fn test_reused_parser_reports_diagnostics_of_the_last_parse() {
+    let mut parser = Parser::new();
+
+    parser
+        .parse_into("Title:\n\n    First line.\n  \tSecond line.\n")
+        .unwrap();
+    assert!(parser
+        .diagnostics()
+        .contains(&Diagnostic::Indentation(IndentationIssue::MixedLine {
+            line: 4
+        })));
+
+    parser.parse_into(SOURCES[0]).unwrap();
+    assert!(parser.diagnostics().is_empty());
+}
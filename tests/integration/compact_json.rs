@@ -0,0 +1,82 @@
+//! Compact JSON output tests
+
+use serde_json::Value;
+use txxt::api::{
+    format_output_unified, format_output_unified_with, process_unified, Format, Output,
+    OutputOptions, Stage,
+};
+use txxt::ast::elements::session::SessionContainer;
+
+const SOURCE: &str = "\
+Intro with *bold* and `code`.
+
+1. Session
+
+    Body citing [@smith2023].
+
+    - first
+    - second
+
+Term:
+    A definition.
+
+:: note :: Reviewed.
+";
+
+const COMPACT: OutputOptions = OutputOptions { compact: true };
+
+fn full_ast() -> Output {
+    process_unified(SOURCE, Stage::AstFull, None).unwrap()
+}
+
+/// Whether any object in the value has one of the defaulted fields empty
+fn has_empty_default(value: &Value) -> bool {
+    match value {
+        Value::Array(items) => items.iter().any(has_empty_default),
+        Value::Object(fields) => fields.iter().any(|(key, value)| {
+            let empty = match value {
+                Value::Array(items) => items.is_empty(),
+                Value::Object(fields) => fields.is_empty(),
+                _ => false,
+            };
+            (empty && matches!(key.as_str(), "annotations" | "parameters" | "tokens"))
+                || has_empty_default(value)
+        }),
+        _ => false,
+    }
+}
+
+#[test]
+fn test_compact_json_is_smaller() {
+    let output = full_ast();
+    let full = format_output_unified(&output, Format::Json, None).unwrap();
+    let compact = format_output_unified_with(&output, Format::Json, None, COMPACT).unwrap();
+
+    assert!(compact.len() < full.len());
+    assert!(has_empty_default(&serde_json::from_str(&full).unwrap()));
+    assert!(!has_empty_default(&serde_json::from_str(&compact).unwrap()));
+}
+
+#[test]
+fn test_compact_json_round_trips() {
+    let output = full_ast();
+    let Output::AstFull(document) = &output else {
+        panic!("Expected a full AST");
+    };
+    let compact = format_output_unified_with(&output, Format::Json, None, COMPACT).unwrap();
+    let value: Value = serde_json::from_str(&compact).unwrap();
+
+    let content: SessionContainer =
+        serde_json::from_value(value["document"]["content"].clone()).unwrap();
+
+    assert_eq!(content, document.content);
+}
+
+#[test]
+fn test_compact_json_keeps_non_empty_tokens() {
+    let output = full_ast();
+    let compact = format_output_unified_with(&output, Format::Json, None, COMPACT).unwrap();
+
+    assert!(compact.contains("\"tokens\""));
+    assert!(compact.contains("smith2023"));
+}
@@ -0,0 +1,79 @@
+//! Empty and whitespace-only input through every phase
+
+use rstest::rstest;
+use txxt::api::{format_output_unified, process_unified, run_all_checked, Format, Output, Stage};
+use txxt::ast::folding::folding_ranges;
+use txxt::ast::plaintext::to_plaintext;
+use txxt::ast::semantic_tokens::semantic_tokens;
+use txxt::ast::source_map::SourceMap;
+use txxt::ast::{collect_references, generate_toc, FootnoteRegistry};
+
+#[rstest]
+#[case::empty("")]
+#[case::spaces("   ")]
+#[case::tab("\t")]
+#[case::single_newline("\n")]
+#[case::blank_lines("\n\n\n")]
+#[case::whitespace_lines("  \n\t\n   \n")]
+#[case::crlf("\r\n")]
+fn test_empty_input_gives_empty_document(#[case] source: &str) {
+    let (document, diagnostics) = run_all_checked(source, None, true).unwrap();
+
+    assert!(
+        document.content.content.is_empty(),
+        "{:?} produced {:?}",
+        source,
+        document.content.content
+    );
+    assert!(document.content.annotations.is_empty());
+    assert!(diagnostics.is_empty());
+}
+
+#[rstest]
+#[case::empty("")]
+#[case::spaces("   ")]
+#[case::single_newline("\n")]
+#[case::whitespace_lines("  \n\t\n   \n")]
+fn test_empty_input_passes_every_stage(#[case] source: &str) {
+    for &stage in Stage::all() {
+        let output = process_unified(source, stage, None).unwrap();
+        match &output {
+            Output::AstBlock(elements) | Output::AstInlines(elements) => {
+                assert!(elements.is_empty(), "{} of {:?}", stage.name(), source)
+            }
+            Output::AstDocument(document) | Output::AstFull(document) => {
+                assert!(document.content.content.is_empty())
+            }
+            Output::ScannerTokens(_) | Output::HighLevelTokens(_) => {}
+        }
+
+        for &format in Format::all() {
+            if stage.supports_format(format) {
+                format_output_unified(&output, format, None).unwrap();
+            }
+        }
+    }
+}
+
+#[rstest]
+#[case::empty("")]
+#[case::spaces("   ")]
+#[case::single_newline("\n")]
+fn test_empty_document_queries(#[case] source: &str) {
+    let (document, _) = run_all_checked(source, None, false).unwrap();
+
+    assert_eq!(to_plaintext(&document), "");
+    assert!(generate_toc(&document, usize::MAX).is_empty());
+    assert!(collect_references(&document).is_empty());
+    assert!(FootnoteRegistry::from_document(&document)
+        .definitions()
+        .is_empty());
+    assert!(folding_ranges(&document).is_empty());
+    assert!(semantic_tokens(&document).is_empty());
+
+    let map = SourceMap::new(&document, source);
+    assert_eq!(
+        map.offset_to_line_col(source.len()).0,
+        source.matches('\n').count()
+    );
+}
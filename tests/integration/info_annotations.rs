@@ -0,0 +1,256 @@
+//! Applying annotations across a tree of `.info` files
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use txxt::{
+    collect_annotations_verbose, group_annotation_updates, merge_annotations, Annotation, Txxt,
+};
+
+fn annotation(text: &str) -> Annotation {
+    Annotation {
+        path: PathBuf::new(),
+        text: text.to_string(),
+        source_file: PathBuf::new(),
+    }
+}
+
+/// `root/.info`, `root/docs/.info`, and `root/docs/guide` and `root/src`
+/// without one
+fn nested_tree() -> tempfile::TempDir {
+    let root = tempfile::tempdir().unwrap();
+    fs::create_dir_all(root.path().join("docs/guide")).unwrap();
+    fs::create_dir_all(root.path().join("src")).unwrap();
+    fs::write(root.path().join(".info"), "").unwrap();
+    fs::write(root.path().join("docs/.info"), "").unwrap();
+    root
+}
+
+/// Target paths each `.info` file was given, sorted
+fn targets_in(
+    groups: &std::collections::HashMap<PathBuf, Vec<Annotation>>,
+    info: &Path,
+) -> Vec<PathBuf> {
+    let mut targets: Vec<PathBuf> = groups[info]
+        .iter()
+        .map(|annotation| annotation.path.clone())
+        .collect();
+    targets.sort();
+    targets
+}
+
+#[test]
+fn test_updates_land_in_nearest_ancestor_info() {
+    let root = nested_tree();
+    let root_path = root.path().canonicalize().unwrap();
+    let updates = vec![
+        (root_path.join("docs/guide/intro.txxt"), annotation("intro")),
+        (root_path.join("docs/index.txxt"), annotation("index")),
+        (root_path.join("src/lib.rs"), annotation("lib")),
+        (root_path.join("README"), annotation("readme")),
+    ];
+
+    let groups = group_annotation_updates(&root_path, &updates).unwrap();
+
+    assert_eq!(groups.len(), 2);
+    assert_eq!(
+        targets_in(&groups, &root_path.join("docs/.info")),
+        vec![
+            PathBuf::from("guide/intro.txxt"),
+            PathBuf::from("index.txxt")
+        ]
+    );
+    assert_eq!(
+        targets_in(&groups, &root_path.join(".info")),
+        vec![PathBuf::from("README"), PathBuf::from("src/lib.rs")]
+    );
+}
+
+#[test]
+fn test_grouped_annotations_record_their_info_file() {
+    let root = nested_tree();
+    let root_path = root.path().canonicalize().unwrap();
+    let target = root_path.join("docs/guide/intro.txxt");
+
+    let groups = group_annotation_updates(&root_path, &[(target, annotation("intro"))]).unwrap();
+
+    let info = root_path.join("docs/.info");
+    assert_eq!(
+        groups[&info],
+        vec![Annotation {
+            path: PathBuf::from("guide/intro.txxt"),
+            text: "intro".to_string(),
+            source_file: info.clone(),
+        }]
+    );
+}
+
+#[test]
+fn test_tree_without_info_uses_root() {
+    let root = tempfile::tempdir().unwrap();
+    fs::create_dir_all(root.path().join("a/b")).unwrap();
+    let target = root.path().join("a/b/file.txxt");
+
+    let groups = group_annotation_updates(root.path(), &[(target, annotation("x"))]).unwrap();
+
+    assert_eq!(
+        groups.keys().collect::<Vec<_>>(),
+        vec![&root.path().canonicalize().unwrap().join(".info")]
+    );
+}
+
+#[test]
+fn test_collected_annotations_group_back_under_a_relative_root() {
+    // A temporary tree, named by a path relative to the working directory
+    let tree = tempfile::tempdir().unwrap();
+    let root = &relative_to_cwd(tree.path());
+    assert!(root.is_relative());
+    fs::create_dir_all(root.join("sub")).unwrap();
+    fs::write(root.join("sub/foo.txt"), "").unwrap();
+    fs::write(root.join(".info"), "").unwrap();
+    fs::write(root.join("sub/.info"), "").unwrap();
+
+    let (collected, _) = merge_annotations(vec![info_file(
+        &root.join(".info"),
+        &[("sub/foo.txt", "foo")],
+    )]);
+    let updates: Vec<(PathBuf, Annotation)> = collected.clone().into_iter().collect();
+    let groups = group_annotation_updates(root, &updates).unwrap();
+
+    let sub_info = root.join("sub/.info").canonicalize().unwrap();
+    assert_eq!(groups.keys().collect::<Vec<_>>(), vec![&sub_info]);
+    assert_eq!(
+        targets_in(&groups, &sub_info),
+        vec![PathBuf::from("foo.txt")]
+    );
+
+    let regrouped = groups.into_iter().map(|(info, annotations)| Txxt {
+        path: info,
+        annotations,
+    });
+    let (recollected, conflicts) = merge_annotations(regrouped);
+    assert_eq!(
+        recollected.keys().collect::<Vec<_>>(),
+        collected.keys().collect::<Vec<_>>()
+    );
+    assert!(conflicts.is_empty());
+}
+
+/// `path` spelled relative to the working directory, whatever that is
+///
+/// Climbs to the filesystem root with `..` and back down, so the tree can
+/// live in a temporary directory outside the working directory.
+fn relative_to_cwd(path: &Path) -> PathBuf {
+    let cwd = std::env::current_dir().unwrap();
+    let mut relative: PathBuf = cwd.components().skip(1).map(|_| "..").collect();
+    relative.push(path.strip_prefix("/").unwrap());
+    relative
+}
+
+/// An `.info` file at `info` holding `(path, text)` annotations
+fn info_file(info: &Path, annotations: &[(&str, &str)]) -> Txxt {
+    let mut file = Txxt::new(info.to_path_buf());
+    file.annotations = annotations
+        .iter()
+        .map(|(path, text)| Annotation {
+            path: PathBuf::from(path),
+            text: text.to_string(),
+            source_file: info.to_path_buf(),
+        })
+        .collect();
+    file
+}
+
+#[test]
+fn test_conflict_is_reported_and_closer_file_wins() {
+    let root = nested_tree();
+    let root_path = root.path().canonicalize().unwrap();
+    let files = vec![
+        info_file(&root_path.join(".info"), &[("docs/a.txxt", "from root")]),
+        info_file(&root_path.join("docs/.info"), &[("a.txxt", "from docs")]),
+    ];
+
+    let (merged, conflicts) = merge_annotations(files);
+
+    let key = root_path.join("docs/a.txxt");
+    assert_eq!(merged[&key].text, "from docs");
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].path, key);
+    let sources: Vec<PathBuf> = conflicts[0]
+        .annotations
+        .iter()
+        .map(|annotation| annotation.source_file.clone())
+        .collect();
+    assert_eq!(
+        sources,
+        vec![root_path.join("docs/.info"), root_path.join(".info")]
+    );
+}
+
+#[test]
+fn test_matching_text_is_not_a_conflict() {
+    let root = nested_tree();
+    let root_path = root.path().canonicalize().unwrap();
+    let files = vec![
+        info_file(&root_path.join(".info"), &[("docs/a.txxt", "same")]),
+        info_file(
+            &root_path.join("docs/.info"),
+            &[("a.txxt", "same"), ("b.txxt", "only here")],
+        ),
+    ];
+
+    let (merged, conflicts) = merge_annotations(files);
+
+    assert_eq!(merged.len(), 2);
+    assert_eq!(
+        merged[&root_path.join("docs/a.txxt")].source_file,
+        root_path.join("docs/.info")
+    );
+    assert!(conflicts.is_empty());
+}
+
+#[test]
+fn test_paths_relative_to_different_info_files_share_a_key() {
+    let root = tempfile::tempdir().unwrap();
+    fs::create_dir_all(root.path().join("subdir")).unwrap();
+    fs::write(root.path().join("subdir/foo.txt"), "").unwrap();
+    fs::write(root.path().join("subdir/.info"), "").unwrap();
+    let files = vec![
+        info_file(&root.path().join(".info"), &[("subdir/foo.txt", "same")]),
+        info_file(&root.path().join("subdir/.info"), &[("./foo.txt", "same")]),
+    ];
+
+    let (merged, conflicts) = merge_annotations(files);
+
+    let key = root.path().join("subdir/foo.txt").canonicalize().unwrap();
+    assert_eq!(merged.keys().collect::<Vec<_>>(), vec![&key]);
+    assert_eq!(
+        merged[&key].source_file,
+        root.path().join("subdir/.info").canonicalize().unwrap()
+    );
+    assert!(conflicts.is_empty());
+}
+
+#[test]
+fn test_missing_targets_are_normalized_lexically() {
+    let root = nested_tree();
+    let root_path = root.path().canonicalize().unwrap();
+    let files = vec![info_file(
+        &root_path.join("docs/.info"),
+        &[("guide/../missing.txxt", "gone")],
+    )];
+
+    let (merged, _) = merge_annotations(files);
+
+    assert!(merged.contains_key(&root_path.join("docs/missing.txxt")));
+}
+
+#[test]
+fn test_collect_annotations_verbose_walks_the_tree() {
+    let root = nested_tree();
+
+    let (merged, conflicts) = collect_annotations_verbose(root.path()).unwrap();
+
+    assert!(merged.is_empty());
+    assert!(conflicts.is_empty());
+}
@@ -0,0 +1,43 @@
+//! Line length lint
+
+use txxt::api::lint_line_length;
+use txxt::ast::Diagnostic;
+
+#[test]
+fn test_long_prose_line_is_flagged() {
+    let source = "Short line.\n\nThis paragraph line runs past the limit.\n";
+
+    assert_eq!(
+        lint_line_length(source, 20),
+        vec![Diagnostic::LineTooLong {
+            line: 3,
+            length: 40,
+            max: 20,
+        }]
+    );
+}
+
+#[test]
+fn test_long_verbatim_line_is_not_flagged() {
+    let source = "\
+Intro text.
+
+Example:
+    let configuration = load_configuration_from_disk(path)?;
+:: rust
+";
+
+    assert!(lint_line_length(source, 20).is_empty());
+}
+
+#[test]
+fn test_line_at_the_maximum_is_not_flagged() {
+    assert!(lint_line_length("Exactly ten\n", 11).is_empty());
+    assert_eq!(lint_line_length("Exactly ten\n", 10).len(), 1);
+}
+
+#[test]
+fn test_columns_count_characters() {
+    // Five characters, ten bytes
+    assert!(lint_line_length("ééééé\n", 5).is_empty());
+}
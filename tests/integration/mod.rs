@@ -3,4 +3,15 @@
 //! These tests demonstrate integration patterns and provide examples for
 //! testing complex scenarios and full document processing.
 
+mod blocks_only;
+mod bulk_parser;
+mod compact_json;
+mod empty_documents;
 mod ensemble_documents_example;
+mod info_annotations;
+mod line_length;
+mod parse_result;
+mod pipeline;
+mod strict_mode;
+mod unmatched_delimiters;
+mod validate;
@@ -0,0 +1,64 @@
+//! Consolidated parse diagnostics
+
+use txxt::api::parse;
+use txxt::ast::Severity;
+
+/// One problem per phase: mixed indentation (lexing), a term with no body
+/// (block construction), an unresolvable reference (reference checks) and
+/// a truncated annotation (assembly)
+const SOURCE: &str = "\
+Orphan term:
+
+See [not sure] here.
+
+Session
+
+ \tTabbed body.
+
+:: note severity=high
+";
+
+#[test]
+fn test_diagnostics_from_every_phase_land_together() {
+    let result = parse(SOURCE).unwrap();
+
+    let codes: Vec<&str> = result.diagnostics.iter().map(|d| d.code()).collect();
+    assert_eq!(
+        codes,
+        vec![
+            "empty-definition-body",
+            "unresolved-reference",
+            "mixed-indentation",
+            "unterminated-annotation",
+        ]
+    );
+    assert!(!result.document.content.content.is_empty());
+}
+
+#[test]
+fn test_diagnostics_carry_severity_span_and_message() {
+    let result = parse(SOURCE).unwrap();
+
+    let reference = result
+        .diagnostics
+        .iter()
+        .find(|d| d.code() == "unresolved-reference")
+        .unwrap();
+    assert_eq!(reference.severity(), Severity::Warning);
+    assert_eq!(reference.span().map(|span| span.start.row), Some(2));
+    assert_eq!(
+        reference.to_string(),
+        "Unresolved reference '[not sure]' at line 3"
+    );
+
+    let truncated = result.diagnostics.last().unwrap();
+    assert_eq!(truncated.severity(), Severity::Error);
+    assert_eq!(truncated.span(), None);
+}
+
+#[test]
+fn test_clean_document_has_no_diagnostics() {
+    let result = parse("A clean paragraph.\n").unwrap();
+
+    assert!(result.diagnostics.is_empty());
+}
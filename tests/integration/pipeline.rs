@@ -0,0 +1,81 @@
+//! Cached pipeline tests
+
+use txxt::api::{process_unified, run_all_unified, Output, Pipeline, Stage};
+use txxt::ast::ElementNode;
+use txxt::cst::ScannerToken;
+
+const SOURCE: &str = "\
+Intro with *bold* text.
+
+1. Session
+
+    Body paragraph.
+";
+
+#[test]
+fn test_each_stage_matches_process_unified() {
+    let mut pipeline = Pipeline::new(SOURCE);
+
+    let Output::ScannerTokens(expected) =
+        process_unified(SOURCE, Stage::ScannerTokens, None).unwrap()
+    else {
+        unreachable!()
+    };
+    assert_eq!(pipeline.to_scanner_tokens().unwrap(), expected.as_slice());
+    assert!(matches!(
+        pipeline.to_scanner_tokens().unwrap().last(),
+        Some(ScannerToken::Eof { .. })
+    ));
+
+    let Output::HighLevelTokens(expected) =
+        process_unified(SOURCE, Stage::HighLevelTokens, None).unwrap()
+    else {
+        unreachable!()
+    };
+    assert_eq!(pipeline.to_high_level_tokens().unwrap(), &expected);
+
+    let blocks = pipeline.to_block_ast().unwrap();
+    assert!(matches!(blocks[0], ElementNode::ParagraphBlock(_)));
+    assert!(matches!(blocks.last(), Some(ElementNode::SessionBlock(_))));
+
+    let document = pipeline.to_document().unwrap();
+    let expected = run_all_unified(SOURCE, None).unwrap();
+    // assembly_info carries a timestamp, so compare the rest
+    assert_eq!(
+        serde_json::to_value(&document.content).unwrap(),
+        serde_json::to_value(&expected.content).unwrap()
+    );
+    assert_eq!(document.meta, expected.meta);
+}
+
+#[test]
+fn test_to_document_fills_every_cache() {
+    let mut pipeline = Pipeline::new(SOURCE);
+    assert!(!pipeline.is_cached(Stage::ScannerTokens));
+
+    pipeline.to_document().unwrap();
+
+    for stage in [
+        Stage::ScannerTokens,
+        Stage::HighLevelTokens,
+        Stage::AstBlock,
+        Stage::AstInlines,
+        Stage::AstDocument,
+        Stage::AstFull,
+    ] {
+        assert!(pipeline.is_cached(stage), "{} not cached", stage.name());
+    }
+}
+
+#[test]
+fn test_to_document_reuses_earlier_stages() {
+    let mut pipeline = Pipeline::new(SOURCE);
+    let tokens = pipeline.to_scanner_tokens().unwrap().as_ptr();
+    let blocks = pipeline.to_block_ast().unwrap().as_ptr();
+    assert!(!pipeline.is_cached(Stage::AstInlines));
+
+    pipeline.to_document().unwrap();
+
+    assert_eq!(pipeline.to_scanner_tokens().unwrap().as_ptr(), tokens);
+    assert_eq!(pipeline.to_block_ast().unwrap().as_ptr(), blocks);
+}
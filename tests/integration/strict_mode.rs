@@ -0,0 +1,219 @@
+//! Lenient and strict parsing diagnostics
+
+use txxt::api::{run_all_checked, TransformError};
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::{AssemblyWarning, Diagnostic};
+use txxt::cst::{Position, SourceSpan};
+
+/// An unresolvable reference on line 1 and a truncated annotation on line 3
+const MALFORMED: &str = "See [http:// x] for details.\n\n:: note severity=high\n";
+
+/// The reference `[http:// x]`, columns 4 to 15 of line 1
+fn unresolved_reference() -> Diagnostic {
+    Diagnostic::UnresolvedReference {
+        target: "http:// x".to_string(),
+        line: 1,
+        span: SourceSpan {
+            start: Position { row: 0, column: 4 },
+            end: Position { row: 0, column: 15 },
+        },
+    }
+}
+
+#[test]
+fn test_lenient_mode_reports_diagnostics() {
+    let (document, diagnostics) = run_all_checked(MALFORMED, None, false).unwrap();
+
+    assert_eq!(document.content.content.len(), 2);
+    assert_eq!(
+        diagnostics,
+        vec![
+            unresolved_reference(),
+            Diagnostic::Recovery(AssemblyWarning::UnterminatedAnnotation {
+                label: "note".to_string(),
+                line: 3,
+            }),
+        ]
+    );
+}
+
+#[test]
+fn test_strict_mode_fails_on_first_diagnostic() {
+    match run_all_checked(MALFORMED, None, true) {
+        Err(TransformError::Strict(diagnostic)) => assert_eq!(diagnostic, unresolved_reference()),
+        other => panic!("Expected a strict mode error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_unresolved_reference_span_covers_the_reference() {
+    let source = "Intro.\n\nThen see [not sure] here.\n";
+    let (_, diagnostics) = run_all_checked(source, None, false).unwrap();
+
+    let [Diagnostic::UnresolvedReference { line, span, .. }] = diagnostics.as_slice() else {
+        panic!("Expected one unresolved reference, got {:?}", diagnostics);
+    };
+    assert_eq!(*line, 3);
+    assert_eq!(span.start.row, 2);
+    let text = source.lines().nth(2).unwrap();
+    let reference: String = text
+        .chars()
+        .skip(span.start.column)
+        .take(span.end.column - span.start.column)
+        .collect();
+    assert_eq!(reference, "[not sure]");
+}
+
+#[test]
+fn test_clean_document_passes_strict_mode() {
+    let source = "See [@smith2023] for details.\n\n:: note :: Done.\n";
+    let (_, diagnostics) = run_all_checked(source, None, true).unwrap();
+
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn test_strict_mode_rejects_duplicate_parameter() {
+    match run_all_checked(":: note a=1,a=2 :: Hello\n", None, true) {
+        Err(TransformError::Strict(diagnostic)) => assert_eq!(
+            diagnostic,
+            Diagnostic::Recovery(AssemblyWarning::DuplicateParameter {
+                key: "a".to_string(),
+                line: 1,
+            })
+        ),
+        other => panic!("Expected a strict mode error, got {:?}", other),
+    }
+
+    assert!(run_all_checked(":: note a=1,b=2 :: Hello\n", None, true).is_ok());
+}
+
+#[test]
+fn test_consistent_list_has_no_style_diagnostic() {
+    let source = "Steps:\n\n1. first\n2. second\n3. third\n";
+    let (_, diagnostics) = run_all_checked(source, None, true).unwrap();
+
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn test_mixed_list_style_is_reported() {
+    let source = "Steps:\n\n1. first\n2. second\nc) third\n";
+    let (document, diagnostics) = run_all_checked(source, None, false).unwrap();
+
+    // The list is still built, all three items included
+    let Some(SessionContainerElement::List(list)) = document.content.content.get(1) else {
+        panic!("Expected a list, got {:?}", document.content.content);
+    };
+    assert_eq!(list.items.len(), 3);
+    assert_eq!(
+        diagnostics,
+        vec![Diagnostic::MixedListStyle {
+            line: 5,
+            expected: "1.".to_string(),
+            found: "c)".to_string(),
+        }]
+    );
+
+    assert!(matches!(
+        run_all_checked(source, None, true),
+        Err(TransformError::Strict(Diagnostic::MixedListStyle {
+            line: 5,
+            ..
+        }))
+    ));
+}
+
+#[test]
+fn test_term_with_indented_body_is_a_definition() {
+    let (document, diagnostics) = run_all_checked("Term:\n    The meaning.\n", None, true).unwrap();
+
+    assert!(matches!(
+        document.content.content.as_slice(),
+        [SessionContainerElement::Definition(_)]
+    ));
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn test_term_without_body_is_a_paragraph_with_a_diagnostic() {
+    let source = "Intro.\n\nTerm:\n\nNot indented.\n";
+    let (document, diagnostics) = run_all_checked(source, None, false).unwrap();
+
+    assert!(document
+        .content
+        .content
+        .iter()
+        .all(|element| matches!(element, SessionContainerElement::Paragraph(_))));
+    assert_eq!(
+        diagnostics,
+        vec![Diagnostic::EmptyDefinitionBody {
+            term: "Term".to_string(),
+            line: 3,
+        }]
+    );
+
+    assert!(matches!(
+        run_all_checked(source, None, true),
+        Err(TransformError::Strict(Diagnostic::EmptyDefinitionBody {
+            line: 3,
+            ..
+        }))
+    ));
+}
+
+#[test]
+fn test_term_at_end_of_input_is_reported() {
+    let (_, diagnostics) = run_all_checked("Last term:\n", None, false).unwrap();
+
+    assert_eq!(
+        diagnostics,
+        vec![Diagnostic::EmptyDefinitionBody {
+            term: "Last term".to_string(),
+            line: 1,
+        }]
+    );
+}
+
+#[test]
+fn test_colon_lines_that_title_or_introduce_are_not_reported() {
+    let session = "Chapter:\n\n    Body.\n";
+    let list_lead_in = "Steps:\n\n- first\n- second\n";
+
+    assert!(run_all_checked(session, None, true).is_ok());
+    assert!(run_all_checked(list_lead_in, None, true).is_ok());
+}
+
+fn label_diagnostics(source: &str) -> Vec<Diagnostic> {
+    let (_, diagnostics) = run_all_checked(source, None, false).unwrap();
+    diagnostics
+        .into_iter()
+        .filter(|diagnostic| matches!(diagnostic, Diagnostic::UnknownAnnotationLabel { .. }))
+        .collect()
+}
+
+#[test]
+fn test_misspelled_reserved_labels_get_a_suggestion() {
+    assert_eq!(
+        label_diagnostics(":: titel :: My Document\n\n:: auther :: Jane Doe\n"),
+        vec![
+            Diagnostic::UnknownAnnotationLabel {
+                got: "titel".to_string(),
+                suggestion: "title".to_string(),
+                line: 1,
+            },
+            Diagnostic::UnknownAnnotationLabel {
+                got: "auther".to_string(),
+                suggestion: "author".to_string(),
+                line: 3,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_custom_and_reserved_labels_are_not_reported() {
+    assert!(label_diagnostics(":: reviewer :: Jane Doe\n").is_empty());
+    assert!(label_diagnostics(":: title :: My Document\n").is_empty());
+    assert!(label_diagnostics(":: data :: 42 rows\n").is_empty());
+}
@@ -0,0 +1,64 @@
+//! Unmatched formatting delimiters are reported without touching the parse
+
+use txxt::api::{lint_unmatched_delimiters, run_all_unified};
+use txxt::ast::Diagnostic;
+
+fn lint(source: &str) -> Vec<Diagnostic> {
+    lint_unmatched_delimiters(&run_all_unified(source, None).unwrap())
+}
+
+fn delimiters(diagnostics: &[Diagnostic]) -> Vec<(&str, usize, usize)> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| match diagnostic {
+            Diagnostic::UnmatchedDelimiter {
+                delimiter,
+                line,
+                span,
+            } => (delimiter.as_str(), *line, span.start.column),
+            other => panic!("unexpected diagnostic {:?}", other),
+        })
+        .collect()
+}
+
+#[test]
+fn test_unmatched_opener_is_reported() {
+    let diagnostics = lint("Some *bold that never closes.\n");
+
+    assert_eq!(delimiters(&diagnostics), vec![("*", 1, 5)]);
+}
+
+#[test]
+fn test_unmatched_closer_is_reported() {
+    let diagnostics = lint("First line.\nA stray_ closer.\n");
+
+    assert_eq!(delimiters(&diagnostics), vec![("_", 2, 7)]);
+}
+
+#[test]
+fn test_balanced_delimiters_report_nothing() {
+    assert!(lint("A *bold* word with _italic_, `code` and #x#.\n").is_empty());
+}
+
+#[test]
+fn test_unmatched_delimiter_inside_bold_is_reported() {
+    let diagnostics = lint("A *bold `half* paragraph.\n");
+
+    assert_eq!(delimiters(&diagnostics), vec![("`", 1, 8)]);
+}
+
+#[test]
+fn test_code_content_is_not_checked() {
+    assert!(lint("Multiply with `a * b` here.\n").is_empty());
+}
+
+#[test]
+fn test_lint_leaves_the_document_unchanged() {
+    let source = "Some *bold that never closes.\n";
+    let document = run_all_unified(source, None).unwrap();
+    let before = document.content.clone();
+
+    lint_unmatched_delimiters(&document);
+
+    assert_eq!(document.content, before);
+}
@@ -0,0 +1,110 @@
+//! Whole-document validation aggregates the structural checks
+
+use txxt::api::{run_all_checked, run_all_unified, validate, validate_with, ValidationConfig};
+use txxt::ast::Diagnostic;
+
+const PROBLEMATIC: &str = "\
+:: note a=1,a=2 :: Hello
+
+Term:
+
+Some *bold that never closes.
+
+See [not sure] here.
+
+Steps:
+
+1. first
+2. second
+c) third
+";
+
+fn codes(diagnostics: &[Diagnostic]) -> Vec<(&'static str, usize)> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.code(), diagnostic.line()))
+        .collect()
+}
+
+#[test]
+fn test_problematic_document_reports_every_check() {
+    let document = run_all_unified(PROBLEMATIC, None).unwrap();
+
+    assert_eq!(
+        codes(&validate(&document)),
+        vec![
+            ("duplicate-parameter", 1),
+            ("empty-definition-body", 3),
+            ("unmatched-delimiter", 5),
+            ("unresolved-reference", 7),
+            ("mixed-list-style", 13),
+        ]
+    );
+}
+
+#[test]
+fn test_disabled_checks_are_skipped() {
+    let document = run_all_unified(PROBLEMATIC, None).unwrap();
+    let config = ValidationConfig {
+        unmatched_delimiters: false,
+        conflicting_list_styles: false,
+        ..ValidationConfig::default()
+    };
+
+    assert_eq!(
+        codes(&validate_with(&document, &config)),
+        vec![
+            ("duplicate-parameter", 1),
+            ("empty-definition-body", 3),
+            ("unresolved-reference", 7),
+        ]
+    );
+}
+
+#[test]
+fn test_empty_definitions_match_the_parser() {
+    for source in [
+        "Intro.\n\nTerm:\n\nNot indented.\n",
+        "Last term:\n",
+        "Chapter:\n\n    Body.\n",
+        "Steps:\n\n- first\n- second\n",
+    ] {
+        let (document, diagnostics) = run_all_checked(source, None, false).unwrap();
+        let config = ValidationConfig {
+            duplicate_parameters: false,
+            empty_definitions: true,
+            unmatched_delimiters: false,
+            unresolved_references: false,
+            conflicting_list_styles: false,
+        };
+
+        assert_eq!(validate_with(&document, &config), diagnostics, "{}", source);
+    }
+}
+
+#[test]
+fn test_clean_document_validates() {
+    let document = run_all_unified("See [@smith2023] for *details*.\n", None).unwrap();
+
+    assert!(validate(&document).is_empty());
+}
+
+#[test]
+fn test_duplicate_parameters_found_without_assembly_warnings() {
+    let document = run_all_unified(PROBLEMATIC, None).unwrap();
+    let json = serde_json::to_string(&document).unwrap();
+    let mut restored: txxt::ast::Document = serde_json::from_str(&json).unwrap();
+    restored.assembly_info.warnings.clear();
+    let config = ValidationConfig {
+        duplicate_parameters: true,
+        empty_definitions: false,
+        unmatched_delimiters: false,
+        unresolved_references: false,
+        conflicting_list_styles: false,
+    };
+
+    assert_eq!(
+        codes(&validate_with(&restored, &config)),
+        vec![("duplicate-parameter", 1)]
+    );
+}
@@ -0,0 +1,167 @@
+//! Column units for token positions
+//!
+//! Columns count characters by default; byte and UTF-16 columns are opt-in.
+
+use txxt::api::{run_all_unified, run_all_with_options, ColumnUnit, ParseOptions};
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::source_map::{SourceMap, SourceText};
+use txxt::cst::ScannerToken;
+use txxt::syntax::tokenize_with;
+
+const MIXED: &str = "café 🚀 done\nnaïve";
+
+/// (content, row, start column, end column) of every Text token
+fn text_columns(unit: ColumnUnit) -> Vec<(String, usize, usize, usize)> {
+    let options = ParseOptions {
+        column_unit: unit,
+        ..ParseOptions::default()
+    };
+    tokenize_with(MIXED, options)
+        .into_iter()
+        .filter_map(|token| match token {
+            ScannerToken::Text { content, span } => {
+                Some((content, span.start.row, span.start.column, span.end.column))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn expected(columns: &[(&str, usize, usize, usize)]) -> Vec<(String, usize, usize, usize)> {
+    columns
+        .iter()
+        .map(|&(content, row, start, end)| (content.to_string(), row, start, end))
+        .collect()
+}
+
+#[test]
+fn test_columns_count_chars_by_default() {
+    assert_eq!(
+        text_columns(ColumnUnit::default()),
+        expected(&[
+            ("café", 0, 0, 4),
+            ("🚀", 0, 5, 6),
+            ("done", 0, 7, 11),
+            ("naïve", 1, 0, 5),
+        ])
+    );
+}
+
+#[test]
+fn test_byte_columns() {
+    assert_eq!(
+        text_columns(ColumnUnit::Bytes),
+        expected(&[
+            ("café", 0, 0, 5),
+            ("🚀", 0, 6, 10),
+            ("done", 0, 11, 15),
+            ("naïve", 1, 0, 6),
+        ])
+    );
+}
+
+#[test]
+fn test_utf16_columns() {
+    assert_eq!(
+        text_columns(ColumnUnit::Utf16),
+        expected(&[
+            ("café", 0, 0, 4),
+            ("🚀", 0, 5, 7),
+            ("done", 0, 8, 12),
+            ("naïve", 1, 0, 5),
+        ])
+    );
+}
+
+#[test]
+fn test_byte_columns_slice_the_source() {
+    let options = ParseOptions {
+        column_unit: ColumnUnit::Bytes,
+        ..ParseOptions::default()
+    };
+    let first_line = MIXED.lines().next().unwrap();
+    for token in tokenize_with(MIXED, options) {
+        if let ScannerToken::Text { content, span } = token {
+            if span.start.row == 0 {
+                assert_eq!(&first_line[span.start.column..span.end.column], content);
+            }
+        }
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_simple_text_end_column_counts_chars() {
+    let text = txxt::ast::elements::inlines::Text::simple("é🚀");
+    let span = text.tokens.span().unwrap();
+    assert_eq!(span.end.column, 2);
+}
+
+#[test]
+fn test_source_text_with_non_ascii() {
+    let source = "Préface 🚀 ici.\n\nÉpilogue.\n";
+    let document = run_all_unified(source, None).unwrap();
+
+    let texts: Vec<Option<&str>> = document
+        .content
+        .content
+        .iter()
+        .map(|element| match element {
+            SessionContainerElement::Paragraph(paragraph) => paragraph.source_text(source),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(texts, vec![Some("Préface 🚀 ici."), Some("Épilogue.")]);
+}
+
+#[test]
+fn test_source_text_in_other_units() {
+    let source = "Préface 🚀 ici.\n\nÉpilogue 🚀.\n";
+    for unit in [ColumnUnit::Bytes, ColumnUnit::Utf16] {
+        let options = ParseOptions {
+            column_unit: unit,
+            ..ParseOptions::default()
+        };
+        let document = run_all_with_options(source, None, options).unwrap();
+
+        let texts: Vec<Option<&str>> = document
+            .content
+            .content
+            .iter()
+            .map(|element| match element {
+                SessionContainerElement::Paragraph(paragraph) => {
+                    paragraph.source_text_in(source, unit)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            texts,
+            vec![Some("Préface 🚀 ici."), Some("Épilogue 🚀.")],
+            "{:?}",
+            unit
+        );
+    }
+}
+
+#[test]
+fn test_source_map_with_utf16_columns() {
+    let source = "Préface 🚀 ici.\n";
+    let options = ParseOptions {
+        column_unit: ColumnUnit::Utf16,
+        ..ParseOptions::default()
+    };
+    let document = run_all_with_options(source, None, options).unwrap();
+    let map = SourceMap::with_column_unit(&document, source, ColumnUnit::Utf16);
+
+    let ici = source.find("ici").unwrap();
+    assert_eq!(map.offset_to_line_col(ici), (0, 11));
+    assert_eq!(
+        map.position_to_offset(txxt::cst::Position { row: 0, column: 11 }),
+        ici
+    );
+    assert_eq!(
+        map.node_range(map.node_at_offset(ici).unwrap()),
+        Some(0..source.len())
+    );
+}
@@ -44,7 +44,9 @@ mod tests {
                         i, content, indentation, span
                     );
                 }
-                ScannerToken::VerbatimBlockEnd { label_raw, span } => {
+                ScannerToken::VerbatimBlockEnd {
+                    label_raw, span, ..
+                } => {
                     println!(
                         "  {}: VerbatimBlockEnd {{ label_raw: {:?}, span: {:?} }}",
                         i, label_raw, span
@@ -308,3 +308,45 @@ fn test_escape_sequences_in_inline_code() {
         }
     }
 }
+
+/// Content of the reference markers in `input`
+fn ref_marker_contents(input: &str) -> Vec<String> {
+    tokenize(input)
+        .into_iter()
+        .filter_map(|t| match t {
+            ScannerToken::RefMarker { content, .. } => Some(content),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_escaped_bracket_inside_reference() {
+    assert_eq!(
+        ref_marker_contents(r"See [text with \] bracket] here"),
+        vec!["text with ] bracket"]
+    );
+    assert_eq!(
+        ref_marker_contents(r"See [a \[b\] c] here"),
+        vec!["a [b] c"]
+    );
+}
+
+#[test]
+fn test_escaped_backslash_before_reference_close() {
+    let tokens = tokenize(r"See [dir\\] here");
+
+    let marker = tokens
+        .iter()
+        .find_map(|t| match t {
+            ScannerToken::RefMarker { content, span } => Some((content.clone(), span.clone())),
+            _ => None,
+        })
+        .expect("reference marker");
+    assert_eq!(marker.0, r"dir\");
+    // The span still covers the source as written, escapes included
+    assert_eq!(
+        marker.1.end.column - marker.1.start.column,
+        r"[dir\\]".len()
+    );
+}
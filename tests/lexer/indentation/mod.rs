@@ -3,3 +3,4 @@
 //! Comprehensive test suite for indentation tracking and Indent/Dedent token generation.
 
 pub mod integration;
+pub mod tab_consistency;
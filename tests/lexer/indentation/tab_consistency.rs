@@ -0,0 +1,76 @@
+//! Tab and space indentation mismatches
+//!
+//! The lexer expands tabs before comparing levels, so mixing the two parses
+//! without error. These tests check that the mismatch is still reported.
+
+use txxt::api::run_all_checked;
+use txxt::ast::Diagnostic;
+use txxt::cst::ScannerToken;
+use txxt::syntax::core::{IndentationIssue, IndentationStyle};
+use txxt::syntax::{Lexer, ParseOptions};
+
+fn issues_for(source: &str) -> Vec<IndentationIssue> {
+    let mut lexer = Lexer::new(source);
+    lexer.tokenize();
+    lexer.indentation_issues().to_vec()
+}
+
+#[test]
+fn test_tab_indented_block_in_space_document() {
+    let source = "First:\n\n    Indented with spaces.\n\nSecond:\n\n\tIndented with a tab.\n";
+
+    assert_eq!(
+        issues_for(source),
+        vec![IndentationIssue::InconsistentStyle {
+            line: 7,
+            found: IndentationStyle::Tabs,
+            expected: IndentationStyle::Spaces,
+        }]
+    );
+}
+
+#[test]
+fn test_mixed_line_is_reported() {
+    let source = "Title:\n\n    First line.\n  \tSecond line.\n";
+
+    assert_eq!(
+        issues_for(source),
+        vec![IndentationIssue::MixedLine { line: 4 }]
+    );
+
+    let (_, diagnostics) = run_all_checked(source, None, false).unwrap();
+    assert!(
+        diagnostics.contains(&Diagnostic::Indentation(IndentationIssue::MixedLine {
+            line: 4
+        }))
+    );
+}
+
+#[test]
+fn test_consistent_space_document_has_no_issues() {
+    let source = "Title:\n\n    - one\n        - nested\n    - two\n";
+
+    assert!(issues_for(source).is_empty());
+    let (_, diagnostics) = run_all_checked(source, None, true).unwrap();
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn test_tabs_expand_to_indent_size() {
+    let options = ParseOptions {
+        indent_size: 2,
+        ..ParseOptions::default()
+    };
+    let mut lexer = Lexer::with_options("Title:\n\n\tContent\n", options);
+    let tokens = lexer.tokenize();
+
+    let indent = tokens
+        .iter()
+        .find_map(|token| match token {
+            ScannerToken::Indent { span } => Some(span.end.column),
+            _ => None,
+        })
+        .expect("tab should open an indentation level");
+    assert_eq!(indent, 2);
+    assert!(lexer.indentation_issues().is_empty());
+}
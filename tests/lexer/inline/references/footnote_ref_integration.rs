@@ -80,7 +80,6 @@ fn test_footnote_ref_labeled_passing(#[case] input: &str, #[case] expected_label
 }
 
 #[rstest]
-#[case("[^]")] // Empty label
 #[case("[^123]")] // Label can't start with digit
 #[case("[^-invalid]")] // Label can't start with dash
 #[case("[^invalid.label]")] // Dots not allowed
@@ -161,6 +160,22 @@ fn test_footnote_ref_with_surrounding_text() {
     }
 }
 
+#[test]
+fn test_footnote_ref_auto() {
+    let tokens = tokenize("[^]");
+
+    assert_eq!(tokens.len(), 2); // FootnoteRef + EOF
+
+    match &tokens[0] {
+        ScannerToken::FootnoteRef { footnote_type, span } => {
+            assert_eq!(footnote_type, &FootnoteType::Auto);
+            assert_eq!(span.start.column, 0);
+            assert_eq!(span.end.column, 3);
+        }
+        _ => panic!("Expected FootnoteRef token, got {:?}", tokens[0]),
+    }
+}
+
 #[test]
 fn test_footnote_ref_labeled_with_text() {
     let input = "Important point[^methodology-note] explained here.";
@@ -11,10 +11,12 @@ mod list;
 mod parameters;
 
 // Infrastructure and core tests
+mod column_units;
 mod core;
 mod debug;
 mod escape_sequences;
 mod inline;
+mod unicode_normalization;
 mod verbatim;
 
 // Bug reproduction tests
@@ -0,0 +1,62 @@
+//! Unicode normalization of token text
+//!
+//! Off by default; with `normalize_unicode` set, token text is NFC-normalized
+//! and verbatim content is left as written.
+
+use txxt::api::ParseOptions;
+use txxt::cst::ScannerToken;
+use txxt::syntax::{tokenize, tokenize_with};
+
+/// `café` with the accent as a combining character
+const DECOMPOSED: &str = "cafe\u{301}";
+const PRECOMPOSED: &str = "caf\u{e9}";
+
+fn normalize() -> ParseOptions {
+    ParseOptions {
+        normalize_unicode: true,
+        ..ParseOptions::default()
+    }
+}
+
+fn text_contents(tokens: &[ScannerToken]) -> Vec<String> {
+    tokens
+        .iter()
+        .filter_map(|token| match token {
+            ScannerToken::Text { content, .. } => Some(content.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_text_is_precomposed_when_enabled() {
+    let source = format!("A {} order.\n", DECOMPOSED);
+
+    let tokens = tokenize_with(&source, normalize());
+
+    assert!(text_contents(&tokens).contains(&PRECOMPOSED.to_string()));
+    assert!(!text_contents(&tokens).contains(&DECOMPOSED.to_string()));
+}
+
+#[test]
+fn test_text_is_kept_as_written_by_default() {
+    let source = format!("A {} order.\n", DECOMPOSED);
+
+    let tokens = tokenize(&source);
+
+    assert!(text_contents(&tokens).contains(&DECOMPOSED.to_string()));
+}
+
+#[test]
+fn test_verbatim_content_is_not_normalized() {
+    let source = format!("Menu:\n    {}\n:: text\n", DECOMPOSED);
+
+    let tokens = tokenize_with(&source, normalize());
+
+    let content: Vec<&ScannerToken> = tokens
+        .iter()
+        .filter(|token| matches!(token, ScannerToken::VerbatimContentLine { .. }))
+        .collect();
+    assert_eq!(content.len(), 1);
+    assert!(content[0].content().contains(DECOMPOSED));
+}
@@ -656,6 +656,8 @@ fn get_token_span(token: &ScannerToken) -> &txxt::cst::SourceSpan {
         | ScannerToken::Colon { span }
         | ScannerToken::Equals { span }
         | ScannerToken::Comma { span }
+        | ScannerToken::QuoteMarker { span }
+        | ScannerToken::Pipe { span }
         | ScannerToken::Dash { span }
         | ScannerToken::Period { span }
         | ScannerToken::Newline { span }
@@ -0,0 +1,120 @@
+//! Keeping or dropping standalone blank lines
+
+use txxt::api::{run_all_unified, run_all_with_options, ParseOptions};
+use txxt::ast::elements::containers::simple::SimpleBlockElement;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::Document;
+
+const DOCUMENT: &str = "First paragraph.\n\nSecond paragraph.\n\n\n1. Section\n\n    Inside the section.\n\n    Still inside.\n\nTerm:\n    Body one.\n\n    Body two.\n";
+
+fn preserving() -> ParseOptions {
+    ParseOptions {
+        preserve_blank_lines: true,
+        ..ParseOptions::default()
+    }
+}
+
+fn element_kinds(elements: &[SessionContainerElement]) -> Vec<&'static str> {
+    elements
+        .iter()
+        .map(|element| match element {
+            SessionContainerElement::Paragraph(_) => "paragraph",
+            SessionContainerElement::Session(_) => "session",
+            SessionContainerElement::Definition(_) => "definition",
+            SessionContainerElement::BlankLine(_) => "blank",
+            _ => "other",
+        })
+        .collect()
+}
+
+fn has_blank_lines(document: &Document) -> bool {
+    fn in_session(elements: &[SessionContainerElement]) -> bool {
+        elements.iter().any(|element| match element {
+            SessionContainerElement::BlankLine(_) => true,
+            SessionContainerElement::Session(session) => in_session(&session.content.content),
+            SessionContainerElement::Definition(definition) => definition
+                .content
+                .content
+                .iter()
+                .any(|element| matches!(element, SimpleBlockElement::BlankLine(_))),
+            _ => false,
+        })
+    }
+    in_session(&document.content.content)
+}
+
+#[test]
+fn test_blank_lines_dropped_by_default() {
+    let document = run_all_unified(DOCUMENT, None).unwrap();
+
+    assert!(!has_blank_lines(&document));
+    assert_eq!(
+        element_kinds(&document.content.content),
+        vec!["paragraph", "paragraph", "session", "definition"]
+    );
+}
+
+#[test]
+fn test_blank_lines_dropped_when_option_off() {
+    let options = ParseOptions {
+        preserve_blank_lines: false,
+        ..ParseOptions::default()
+    };
+    let document = run_all_with_options(DOCUMENT, None, options).unwrap();
+
+    assert!(!has_blank_lines(&document));
+}
+
+#[test]
+fn test_blank_lines_kept_when_preserving() {
+    let document = run_all_with_options(DOCUMENT, None, preserving()).unwrap();
+
+    // The blank line before the session belongs to the session syntax
+    assert_eq!(
+        element_kinds(&document.content.content),
+        vec![
+            "paragraph",
+            "blank",
+            "paragraph",
+            "blank",
+            "session",
+            "definition"
+        ]
+    );
+
+    let SessionContainerElement::Session(session) = &document.content.content[4] else {
+        panic!("Expected a session");
+    };
+    assert_eq!(
+        element_kinds(&session.content.content),
+        vec!["paragraph", "blank", "paragraph", "blank"]
+    );
+
+    let SessionContainerElement::Definition(definition) = &document.content.content[5] else {
+        panic!("Expected a definition");
+    };
+    assert!(matches!(
+        definition.content.content.as_slice(),
+        [
+            SimpleBlockElement::Paragraph(_),
+            SimpleBlockElement::BlankLine(_),
+            SimpleBlockElement::Paragraph(_)
+        ]
+    ));
+}
+
+#[test]
+fn test_preserving_does_not_change_blocks() {
+    let dropped = run_all_unified(DOCUMENT, None).unwrap();
+    let kept = run_all_with_options(DOCUMENT, None, preserving()).unwrap();
+
+    let without_blanks: Vec<_> = element_kinds(&kept.content.content)
+        .into_iter()
+        .filter(|kind| *kind != "blank")
+        .collect();
+    assert_eq!(without_blanks, element_kinds(&dropped.content.content));
+    assert_eq!(
+        kept.assembly_info.stats.block_count,
+        dropped.assembly_info.stats.block_count
+    );
+}
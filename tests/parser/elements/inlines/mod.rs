@@ -1 +1,6 @@
+mod test_doubled_delimiters;
+mod test_doubled_emphasis;
 mod test_formatting;
+mod test_links;
+mod test_pipeline_fallback;
+mod test_pipeline_validation;
@@ -0,0 +1,64 @@
+//! Doubled delimiters inside code and math, and first-match closing
+
+use rstest::rstest;
+use txxt::api::run_all_unified;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::TextTransform;
+
+/// The transforms of a one-paragraph document, as `kind:text` strings
+fn transforms(source: &str) -> Vec<String> {
+    let document = run_all_unified(source, None).unwrap();
+    let Some(SessionContainerElement::Paragraph(paragraph)) = document.content.content.first()
+    else {
+        panic!("Expected a paragraph");
+    };
+    paragraph
+        .content
+        .iter()
+        .map(|transform| {
+            let kind = match transform {
+                TextTransform::Identity(_) => "text",
+                TextTransform::Strong(_) => "bold",
+                TextTransform::Emphasis(_) => "italic",
+                TextTransform::Code(_) => "code",
+                TextTransform::Math(_) => "math",
+                _ => "other",
+            };
+            format!("{}:{}", kind, transform.text_content())
+        })
+        .collect()
+}
+
+fn spans_of(source: &str, kind: &str) -> Vec<String> {
+    let prefix = format!("{}:", kind);
+    transforms(source)
+        .into_iter()
+        .filter_map(|transform| transform.strip_prefix(&prefix).map(str::to_string))
+        .collect()
+}
+
+#[rstest]
+#[case::code_interior("Run `a `` b` now.\n", "code", "a ` b")]
+#[case::code_leading("Run ``` x` now.\n", "code", "` x")]
+#[case::math_interior("So #x ## y# holds.\n", "math", "x # y")]
+#[case::backslash_escape("Run `a \\` b` now.\n", "code", "a ` b")]
+#[case::plain_code("Run `ls -la` now.\n", "code", "ls -la")]
+fn test_doubled_delimiter_is_literal(
+    #[case] source: &str,
+    #[case] kind: &str,
+    #[case] expected: &str,
+) {
+    assert_eq!(spans_of(source, kind), vec![expected.to_string()]);
+}
+
+#[test]
+fn test_unclosed_doubled_delimiter_stays_text() {
+    assert!(spans_of("Run `a `` b now.\n", "code").is_empty());
+}
+
+#[test]
+fn test_emphasis_closes_on_first_delimiter() {
+    let transforms = transforms("Say *a*b* twice.\n");
+
+    assert_eq!(transforms[2..5], ["italic:a", "text:b", "text:*"]);
+}
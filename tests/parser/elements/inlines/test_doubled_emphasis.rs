@@ -0,0 +1,91 @@
+//! Doubled and tripled bold and italic delimiters
+//!
+//! A single `*` or `_` is emphasis, a run of two of either is strong, and a
+//! run of three strong emphasis.
+
+use rstest::rstest;
+use txxt::api::run_all_unified;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::TextTransform;
+
+/// Formatting structure of a transform, as `bold(...)`/`italic(...)` nesting
+fn shape(transform: &TextTransform) -> String {
+    let nested = |kind: &str, inner: &[TextTransform]| {
+        let inner: Vec<String> = inner.iter().map(shape).collect();
+        format!("{}({})", kind, inner.concat())
+    };
+    match transform {
+        TextTransform::Strong(inner) => nested("bold", inner),
+        TextTransform::Emphasis(inner) => nested("italic", inner),
+        other => other.text_content(),
+    }
+}
+
+/// Shape of a one-paragraph document's content
+fn paragraph_shape(source: &str) -> String {
+    let document = run_all_unified(source, None).unwrap();
+    let Some(SessionContainerElement::Paragraph(paragraph)) = document.content.content.first()
+    else {
+        panic!("Expected a paragraph");
+    };
+    paragraph.content.iter().map(shape).collect::<String>()
+}
+
+#[rstest]
+#[case::single_asterisk("A *word* here.\n", "A italic(word) here.")]
+#[case::single_underscore("A _word_ here.\n", "A italic(word) here.")]
+#[case::double_asterisk("A **word** here.\n", "A bold(word) here.")]
+#[case::double_underscore("A __word__ here.\n", "A bold(word) here.")]
+#[case::triple_asterisk("A ***both*** here.\n", "A bold(italic(both)) here.")]
+#[case::triple_underscore("A ___both___ here.\n", "A bold(italic(both)) here.")]
+fn test_delimiter_runs(#[case] source: &str, #[case] expected: &str) {
+    assert_eq!(paragraph_shape(source).trim_end(), expected);
+}
+
+#[test]
+fn test_doubled_bold_nests_single_emphasis() {
+    assert_eq!(
+        paragraph_shape("A **loud *louder* loud** word.\n").trim_end(),
+        "A bold(loud italic(louder) loud) word."
+    );
+}
+
+#[test]
+fn test_doubled_bold_and_single_italic_coexist() {
+    assert_eq!(
+        paragraph_shape("Both **bold** and _italic_ here.\n").trim_end(),
+        "Both bold(bold) and italic(italic) here."
+    );
+}
+
+#[test]
+fn test_unbalanced_run_falls_back_to_single_delimiters() {
+    assert_eq!(
+        paragraph_shape("A **word* here.\n").trim_end(),
+        "A *italic(word) here."
+    );
+}
+
+#[test]
+fn test_dunder_without_closing_pair_stays_text() {
+    assert_eq!(
+        paragraph_shape("Call __init with care.\n").trim_end(),
+        "Call __init with care."
+    );
+}
+
+#[test]
+fn test_emphasis_does_not_nest_in_emphasis() {
+    assert_eq!(
+        paragraph_shape("An *outer _inner_ text* here.\n").trim_end(),
+        "An italic(outer _inner_ text) here."
+    );
+}
+
+#[test]
+fn test_single_and_doubled_asterisks_differ() {
+    assert_ne!(
+        paragraph_shape("A *word* here.\n"),
+        paragraph_shape("A **word** here.\n")
+    );
+}
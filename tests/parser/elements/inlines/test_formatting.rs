@@ -93,7 +93,7 @@ fn create_newline(row: usize, column: usize) -> ScannerToken {
 }
 
 #[test]
-fn test_simple_bold() {
+fn test_single_asterisk_is_emphasis() {
     let tokens = vec![
         create_bold_delimiter(0, 1),
         create_text("hello", 1, 6),
@@ -101,6 +101,31 @@ fn test_simple_bold() {
     ];
     let result = parse_formatting_elements(&tokens).unwrap();
 
+    // Verify structure and content
+    assert_eq!(result.len(), 1);
+    if let TextTransform::Emphasis(inner) = &result[0] {
+        assert_eq!(inner.len(), 1);
+        if let TextTransform::Identity(text) = &inner[0] {
+            assert_eq!(text.content(), "hello");
+        } else {
+            panic!("Expected Identity transform");
+        }
+    } else {
+        panic!("Expected Emphasis transform");
+    }
+}
+
+#[test]
+fn test_simple_bold() {
+    let tokens = vec![
+        create_bold_delimiter(0, 1),
+        create_bold_delimiter(1, 2),
+        create_text("hello", 2, 7),
+        create_bold_delimiter(7, 8),
+        create_bold_delimiter(8, 9),
+    ];
+    let result = parse_formatting_elements(&tokens).unwrap();
+
     // Verify structure and content
     assert_eq!(result.len(), 1);
     if let TextTransform::Strong(inner) = &result[0] {
@@ -115,6 +140,28 @@ fn test_simple_bold() {
     }
 }
 
+#[test]
+fn test_single_and_doubled_asterisks_differ() {
+    let single = parse_formatting_elements(&[
+        create_bold_delimiter(0, 1),
+        create_text("a", 1, 2),
+        create_bold_delimiter(2, 3),
+    ])
+    .unwrap();
+    let doubled = parse_formatting_elements(&[
+        create_bold_delimiter(0, 1),
+        create_bold_delimiter(1, 2),
+        create_text("a", 2, 3),
+        create_bold_delimiter(3, 4),
+        create_bold_delimiter(4, 5),
+    ])
+    .unwrap();
+
+    assert!(matches!(single.as_slice(), [TextTransform::Emphasis(_)]));
+    assert!(matches!(doubled.as_slice(), [TextTransform::Strong(_)]));
+    assert_ne!(single, doubled);
+}
+
 #[test]
 fn test_simple_italic() {
     let tokens = vec![
@@ -162,10 +209,12 @@ fn test_simple_code() {
 fn test_nested_bold_italic() {
     let tokens = vec![
         create_bold_delimiter(0, 1),
-        create_italic_delimiter(1, 2),
-        create_text("hello", 2, 7),
-        create_italic_delimiter(7, 8),
-        create_bold_delimiter(8, 9),
+        create_bold_delimiter(1, 2),
+        create_italic_delimiter(2, 3),
+        create_text("hello", 3, 8),
+        create_italic_delimiter(8, 9),
+        create_bold_delimiter(9, 10),
+        create_bold_delimiter(10, 11),
     ];
     let result = parse_formatting_elements(&tokens).unwrap();
 
@@ -189,14 +238,14 @@ fn test_nested_bold_italic() {
 }
 
 #[test]
-fn test_same_type_nesting_prevented_bold() {
-    // Per spec: *outer *inner* text* should break at second asterisk (closing first bold)
-    // Because nested bold-in-bold is prevented, the second * closes the first bold
-    // Result: Strong("outer "), Identity("inner"), Strong(" text")
+fn test_same_type_nesting_prevented_asterisk() {
+    // Per spec: *outer *inner* text* should break at second asterisk (closing the first)
+    // Because emphasis can't nest in emphasis, the second * closes the first span
+    // Result: Emphasis("outer "), Identity("inner"), Emphasis(" text")
     let tokens = vec![
         create_bold_delimiter(0, 1),
         create_text("outer ", 1, 7),
-        create_bold_delimiter(7, 8), // This closes the first bold (can't nest bold-in-bold)
+        create_bold_delimiter(7, 8), // This closes the first span
         create_text("inner", 8, 13),
         create_bold_delimiter(13, 14), // Unmatched (would need nesting)
         create_text(" text", 14, 19),
@@ -204,20 +253,20 @@ fn test_same_type_nesting_prevented_bold() {
     ];
     let result = parse_formatting_elements(&tokens).unwrap();
 
-    // Parser produces: Strong("outer "), "*", "inner", "*", " text", "*"
+    // Parser produces: Emphasis("outer "), "*", "inner", "*", " text", "*"
     // Actually, unmatched delimiters become Identity transforms
     assert_eq!(result.len(), 3);
 
-    // First element: Strong("outer ")
-    if let TextTransform::Strong(inner) = &result[0] {
+    // First element: Emphasis("outer ")
+    if let TextTransform::Emphasis(inner) = &result[0] {
         assert_eq!(inner.len(), 1);
         if let TextTransform::Identity(text) = &inner[0] {
             assert_eq!(text.content(), "outer ");
         } else {
-            panic!("Expected Identity in strong");
+            panic!("Expected Identity in emphasis");
         }
     } else {
-        panic!("Expected Strong transform");
+        panic!("Expected Emphasis transform");
     }
 
     // Second element: Identity("inner")  (unmatched delimiters become plain text)
@@ -227,16 +276,16 @@ fn test_same_type_nesting_prevented_bold() {
         panic!("Expected Identity for unmatched content");
     }
 
-    // Third element: Strong(" text")
-    if let TextTransform::Strong(inner) = &result[2] {
+    // Third element: Emphasis(" text")
+    if let TextTransform::Emphasis(inner) = &result[2] {
         assert_eq!(inner.len(), 1);
         if let TextTransform::Identity(text) = &inner[0] {
             assert_eq!(text.content(), " text");
         } else {
-            panic!("Expected Identity in strong");
+            panic!("Expected Identity in emphasis");
         }
     } else {
-        panic!("Expected Strong transform");
+        panic!("Expected Emphasis transform");
     }
 }
 
@@ -431,3 +480,37 @@ fn test_multiline_math_rejected() {
         panic!("Expected Identity for opening delimiter");
     }
 }
+
+#[test]
+fn test_doubled_and_tripled_runs() {
+    // **a** is strong, ***b*** strong emphasis
+    let tokens = vec![
+        create_bold_delimiter(0, 1),
+        create_bold_delimiter(1, 2),
+        create_text("a", 2, 3),
+        create_bold_delimiter(3, 4),
+        create_bold_delimiter(4, 5),
+        create_text(" ", 5, 6),
+        create_bold_delimiter(6, 7),
+        create_bold_delimiter(7, 8),
+        create_bold_delimiter(8, 9),
+        create_text("b", 9, 10),
+        create_bold_delimiter(10, 11),
+        create_bold_delimiter(11, 12),
+        create_bold_delimiter(12, 13),
+    ];
+    let result = parse_formatting_elements(&tokens).unwrap();
+
+    assert_eq!(result.len(), 3);
+    match &result[0] {
+        TextTransform::Strong(inner) => assert_eq!(inner[0].text_content(), "a"),
+        other => panic!("Expected Strong transform, got {:?}", other),
+    }
+    match &result[2] {
+        TextTransform::Strong(inner) => match inner.as_slice() {
+            [TextTransform::Emphasis(inner)] => assert_eq!(inner[0].text_content(), "b"),
+            other => panic!("Expected Emphasis inside Strong, got {:?}", other),
+        },
+        other => panic!("Expected Strong transform, got {:?}", other),
+    }
+}
@@ -0,0 +1,108 @@
+//! `[display](target)` links, told apart from references by the paren
+
+use txxt::api::run_all_unified;
+use txxt::ast::elements::formatting::inlines::Inline;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::{collect_references, TextTransform};
+use txxt::cst::ScannerToken;
+use txxt::semantic::elements::inlines::pipeline::create_standard_pipeline;
+use txxt::syntax::Lexer;
+
+fn links(line: &str) -> Vec<(String, String)> {
+    let tokens: Vec<ScannerToken> = Lexer::new(line)
+        .tokenize()
+        .into_iter()
+        .filter(|token| !matches!(token, ScannerToken::Eof { .. }))
+        .collect();
+    create_standard_pipeline()
+        .parse(&tokens)
+        .unwrap()
+        .into_iter()
+        .filter_map(|inline| match inline {
+            Inline::Link {
+                target, content, ..
+            } => {
+                let display = content
+                    .iter()
+                    .map(|inline| match inline {
+                        Inline::TextLine(transform) => transform.text_content(),
+                        other => panic!("unexpected display inline {:?}", other),
+                    })
+                    .collect();
+                Some((display, target))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn paragraph_content(source: &str) -> Vec<TextTransform> {
+    let document = run_all_unified(source, None).unwrap();
+    match document.content.content.first() {
+        Some(SessionContainerElement::Paragraph(paragraph)) => paragraph.content.clone(),
+        other => panic!("Expected a paragraph, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_link_with_display_text() {
+    assert_eq!(
+        links("See [the docs](https://example.com/guide) today."),
+        vec![(
+            "the docs".to_string(),
+            "https://example.com/guide".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_link_is_kept_in_paragraph_content() {
+    let content = paragraph_content("See [the docs](https://example.com/guide) today.\n");
+    let link = content
+        .iter()
+        .find_map(|transform| match transform {
+            TextTransform::Custom {
+                name, parameters, ..
+            } if name == "link" => Some(parameters["target"].clone()),
+            _ => None,
+        })
+        .expect("Expected a link transform");
+
+    assert_eq!(link, "https://example.com/guide");
+    assert_eq!(
+        content
+            .iter()
+            .map(TextTransform::text_content)
+            .collect::<String>(),
+        "See the docs today."
+    );
+}
+
+#[test]
+fn test_link_display_text_is_not_a_reference() {
+    let document = run_all_unified("See [the docs](https://example.com).\n", None).unwrap();
+
+    assert!(collect_references(&document).is_empty());
+}
+
+#[test]
+fn test_brackets_without_paren_stay_a_reference() {
+    assert!(links("See [the docs] (later).").is_empty());
+
+    let document = run_all_unified("See [the docs] today.\n", None).unwrap();
+    let targets: Vec<String> = collect_references(&document)
+        .into_iter()
+        .map(|occurrence| occurrence.target)
+        .collect();
+    assert_eq!(targets, vec!["the docs"]);
+}
+
+#[test]
+fn test_unclosed_link_stays_text() {
+    assert!(links("See [a](").is_empty());
+
+    let content = paragraph_content("See [a](\n");
+    assert!(content
+        .iter()
+        .all(|transform| matches!(transform, TextTransform::Identity(_))));
+}
@@ -0,0 +1,101 @@
+//! Inline pipeline plain-text fallback tests
+
+use std::sync::{Mutex, OnceLock};
+
+use txxt::ast::elements::formatting::inlines::{Inline, TextTransform};
+use txxt::cst::{Position, ScannerToken, SourceSpan};
+use txxt::semantic::elements::inlines::pipeline::create_standard_pipeline;
+
+fn text(content: &str) -> ScannerToken {
+    ScannerToken::Text {
+        content: content.to_string(),
+        span: SourceSpan {
+            start: Position { row: 0, column: 0 },
+            end: Position {
+                row: 0,
+                column: content.len(),
+            },
+        },
+    }
+}
+
+#[test]
+fn test_parse_or_plain_keeps_failed_line_as_text() {
+    let tokens = vec![text("see"), text("["), text("https:///x"), text("]")];
+
+    let (inlines, error) = create_standard_pipeline().parse_or_plain(&tokens);
+
+    assert!(error.is_some());
+    let contents: Vec<String> = inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::TextLine(TextTransform::Identity(text)) => text.content(),
+            other => panic!("Expected plain text, got {:?}", other),
+        })
+        .collect();
+    assert_eq!(contents, vec!["see", "[", "https:///x", "]"]);
+}
+
+#[test]
+fn test_parse_or_plain_passes_through_success() {
+    let tokens = vec![text("plain")];
+
+    let (inlines, error) = create_standard_pipeline().parse_or_plain(&tokens);
+
+    assert!(error.is_none());
+    assert_eq!(inlines.len(), 1);
+}
+
+/// Test logger that keeps every warning
+struct CapturingLogger {
+    warnings: Mutex<Vec<String>>,
+}
+
+impl log::Log for CapturingLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::Level::Warn
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            self.warnings
+                .lock()
+                .unwrap()
+                .push(record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Install the capturing logger once for the whole test binary
+fn capturing_logger() -> &'static CapturingLogger {
+    static LOGGER: OnceLock<&'static CapturingLogger> = OnceLock::new();
+    LOGGER.get_or_init(|| {
+        let logger: &'static CapturingLogger = Box::leak(Box::new(CapturingLogger {
+            warnings: Mutex::new(Vec::new()),
+        }));
+        log::set_logger(logger).expect("no other logger is installed");
+        log::set_max_level(log::LevelFilter::Warn);
+        logger
+    })
+}
+
+#[test]
+fn test_malformed_span_logs_warning_and_parsing_continues() {
+    let logger = capturing_logger();
+    let tokens = vec![text("["), text("https:///warned"), text("]"), text("after")];
+
+    let (inlines, error) = create_standard_pipeline().parse_or_plain(&tokens);
+
+    assert!(error.is_some());
+    assert_eq!(inlines.len(), 4);
+    let warnings = logger.warnings.lock().unwrap();
+    assert!(
+        warnings.iter().any(|warning| warning
+            .starts_with("Inline parsing error in reference span at 0:0-0:1")
+            && warning.contains("https:///warned")),
+        "no warning for the malformed span in {:?}",
+        *warnings
+    );
+}
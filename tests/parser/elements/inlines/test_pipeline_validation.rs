@@ -0,0 +1,89 @@
+//! Inline pipeline matcher validation tests
+
+use txxt::cst::ScannerToken;
+use txxt::semantic::elements::inlines::level1_matchers::*;
+use txxt::semantic::elements::inlines::pipeline::{
+    create_standard_pipeline, DelimiterMatcher, DelimiterSpec, InlinePipeline, MatcherConflict,
+};
+
+#[test]
+fn test_standard_pipeline_has_no_conflicts() {
+    assert_eq!(create_standard_pipeline().validate(), vec![]);
+}
+
+#[test]
+fn test_validate_reports_all_conflicts_at_once() {
+    let matchers: Vec<Box<dyn DelimiterMatcher>> = vec![
+        Box::new(bold_matcher()),
+        Box::new(reference_matcher()),
+        Box::new(GenericDelimiterMatcher::new(
+            "strong",
+            ScannerToken::is_bold_delimiter,
+            ScannerToken::is_bold_delimiter,
+        )),
+        Box::new(GenericDelimiterMatcher::new(
+            "wikilink",
+            |t| matches!(t, ScannerToken::Text { content, .. } if content == "["),
+            |t| matches!(t, ScannerToken::Text { content, .. } if content == "]"),
+        )),
+        Box::new(italic_matcher()),
+        Box::new(italic_matcher()),
+    ];
+
+    let conflicts = InlinePipeline::validate_matchers(&matchers);
+
+    assert_eq!(
+        conflicts,
+        vec![
+            MatcherConflict::SharedStartDelimiter {
+                delimiter: "*".to_string(),
+                first: "bold".to_string(),
+                shadowed: "strong".to_string(),
+            },
+            MatcherConflict::SharedStartDelimiter {
+                delimiter: "[".to_string(),
+                first: "reference".to_string(),
+                shadowed: "wikilink".to_string(),
+            },
+            MatcherConflict::DuplicateName {
+                name: "italic".to_string(),
+            },
+            MatcherConflict::SharedStartDelimiter {
+                delimiter: "_".to_string(),
+                first: "italic".to_string(),
+                shadowed: "italic".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_validate_does_not_need_a_pipeline() {
+    let matchers: Vec<Box<dyn DelimiterMatcher>> =
+        vec![Box::new(code_matcher()), Box::new(math_matcher())];
+
+    assert!(InlinePipeline::validate_matchers(&matchers).is_empty());
+}
+
+#[test]
+fn test_standard_pipeline_reports_its_delimiters() {
+    let pipeline = create_standard_pipeline();
+    let delimiters = pipeline.delimiters();
+    let spec = |open: &str, close: &str| DelimiterSpec {
+        open: open.to_string(),
+        close: close.to_string(),
+    };
+
+    assert_eq!(
+        delimiters,
+        vec![
+            ("code", spec("`", "`")),
+            ("math", spec("#", "#")),
+            ("reference", spec("[", "]")),
+            ("strong", spec("**", "**")),
+            ("emphasis", spec("*", "*")),
+            ("strong", spec("__", "__")),
+            ("emphasis", spec("_", "_")),
+        ]
+    );
+}
@@ -4,8 +4,12 @@
 //! (citations, footnotes, sessions, files, URLs) using the TxxtCorpora framework.
 
 use txxt::ast::elements::references::reference_types::*;
+use txxt::ast::elements::references::{
+    format_citation, BibliographyEntry, BibliographyMap, CitationStyle,
+};
 use txxt::cst::{Position, ScannerToken, SourceSpan};
 use txxt::semantic::elements::inlines::references::*;
+use txxt::semantic::elements::inlines::InlineParseError;
 
 /// Helper function to create a test source span
 fn test_span() -> SourceSpan {
@@ -80,6 +84,176 @@ fn test_parse_citation_with_locator() {
     }
 }
 
+/// Test locator kind detection and normalization across spellings
+#[rstest::rstest]
+#[case::p("p. 45", LocatorKind::Page, "p. 45")]
+#[case::p_no_dot("p 45", LocatorKind::Page, "p. 45")]
+#[case::pg("pg 45", LocatorKind::Page, "p. 45")]
+#[case::page("page 45", LocatorKind::Page, "p. 45")]
+#[case::page_capitalized("Page 45", LocatorKind::Page, "p. 45")]
+#[case::pp_range("pp. 45-47", LocatorKind::Page, "pp. 45-47")]
+#[case::pages_en_dash_range("pages 45\u{2013}47", LocatorKind::Page, "pp. 45-47")]
+#[case::chapter("chapter 3", LocatorKind::Chapter, "ch. 3")]
+#[case::chap("chap. IV", LocatorKind::Chapter, "ch. IV")]
+#[case::section_sign("§2.1", LocatorKind::Section, "sec. 2.1")]
+#[case::sec("sec. 3.1", LocatorKind::Section, "sec. 3.1")]
+#[case::para("para. 4", LocatorKind::Paragraph, "para. 4")]
+#[case::pilcrow("¶ 4", LocatorKind::Paragraph, "para. 4")]
+#[case::page_list("pp. 10,12, 15\u{2013}18", LocatorKind::Page, "pp. 10, 12, 15-18")]
+fn test_citation_locator_normalization(
+    #[case] locator: &str,
+    #[case] kind: LocatorKind,
+    #[case] normalized: &str,
+) {
+    let tokens = create_bracketed_tokens(&format!("@smith2023, {}", locator));
+
+    let result = parse_citation(&tokens);
+
+    match result {
+        Ok(txxt::ast::elements::formatting::inlines::Inline::Reference(reference)) => {
+            match &reference.target {
+                ReferenceTarget::Citation { citations, .. } => {
+                    assert_eq!(citations[0].locator.as_deref(), Some(locator));
+                    assert_eq!(citations[0].locator_kind, Some(kind));
+                    assert_eq!(
+                        citations[0].normalized_locator().as_deref(),
+                        Some(normalized)
+                    );
+                }
+                other => panic!("Expected Citation reference target, got {:?}", other),
+            }
+        }
+        other => panic!("Expected Reference inline, got {:?}", other),
+    }
+}
+
+/// Test that unrecognized and compound locators are kept but not classified
+#[rstest::rstest]
+#[case::unknown_kind("fig. 3")]
+#[case::compound("ch. 2, p. 45")]
+#[case::no_value("page")]
+fn test_citation_locator_unrecognized(#[case] locator: &str) {
+    let entry = CitationEntry::new("smith2023".to_string(), Some(locator.to_string()));
+
+    assert_eq!(entry.locator.as_deref(), Some(locator));
+    assert_eq!(entry.locator_kind, None);
+    assert_eq!(entry.normalized_locator(), None);
+}
+
+/// Page locators read as lists of pages and ranges
+#[test]
+fn test_citation_page_specs() {
+    let pages = |locator: &str| {
+        CitationEntry::new("smith2023".to_string(), Some(locator.to_string())).page_specs()
+    };
+
+    assert_eq!(pages("p. 45"), Some(Ok(vec![PageSpec::Single(45)])));
+    assert_eq!(
+        pages("pp. 10, 12, 15-18"),
+        Some(Ok(vec![
+            PageSpec::Single(10),
+            PageSpec::Single(12),
+            PageSpec::Range { start: 15, end: 18 },
+        ]))
+    );
+    assert_eq!(pages("ch. 3"), None);
+}
+
+/// Test that a page range must ascend
+#[test]
+fn test_citation_page_specs_descending_range() {
+    let entry = CitationEntry::new("smith2023".to_string(), Some("pp. 10, 18-15".to_string()));
+
+    assert_eq!(
+        entry.page_specs(),
+        Some(Err(PageSpecError::DescendingRange { start: 18, end: 15 }))
+    );
+}
+
+fn bibliography() -> BibliographyMap {
+    BibliographyMap::from([(
+        "smith2023".to_string(),
+        BibliographyEntry {
+            number: 1,
+            author: "Smith".to_string(),
+            year: "2023".to_string(),
+        },
+    )])
+}
+
+/// Test citation rendering in both styles, with and without a locator
+#[rstest::rstest]
+#[case::numeric(None, false, "[1]")]
+#[case::numeric_locator(Some("pg 45"), false, "[1, p. 45]")]
+#[case::author_year(None, true, "(Smith 2023)")]
+#[case::author_year_locator(Some("p. 45"), true, "(Smith 2023, p. 45)")]
+#[case::unrecognized_locator(Some("fig. 3"), true, "(Smith 2023, fig. 3)")]
+fn test_format_citation(
+    #[case] locator: Option<&str>,
+    #[case] author_year: bool,
+    #[case] expected: &str,
+) {
+    let bibliography = bibliography();
+    let style = if author_year {
+        CitationStyle::AuthorYear(&bibliography)
+    } else {
+        CitationStyle::Numeric(&bibliography)
+    };
+    let entry = CitationEntry::new("smith2023".to_string(), locator.map(str::to_string));
+
+    assert_eq!(format_citation(&entry, style), expected);
+}
+
+/// Test that prefix and suffix wrap the citation inside its brackets
+#[test]
+fn test_format_citation_prefix_and_suffix() {
+    let bibliography = bibliography();
+    let mut entry = CitationEntry::new("smith2023".to_string(), Some("p. 45".to_string()));
+    entry.prefix = Some("see".to_string());
+    entry.suffix = Some("for details".to_string());
+
+    assert_eq!(
+        format_citation(&entry, CitationStyle::AuthorYear(&bibliography)),
+        "(see Smith 2023, p. 45 for details)"
+    );
+    assert_eq!(
+        format_citation(&entry, CitationStyle::Numeric(&bibliography)),
+        "[see 1, p. 45 for details]"
+    );
+}
+
+/// Test that a key missing from the bibliography renders as itself
+#[test]
+fn test_format_citation_unknown_key() {
+    let bibliography = bibliography();
+    let entry = CitationEntry::new("doe2024".to_string(), None);
+
+    assert_eq!(
+        format_citation(&entry, CitationStyle::Numeric(&bibliography)),
+        "[doe2024]"
+    );
+}
+
+/// Test a page list parsed through the citation parser
+#[test]
+fn test_parse_citation_with_page_list() {
+    let tokens = create_bracketed_tokens("@smith2023, pp. 10, 12, 15-18");
+
+    match parse_citation(&tokens) {
+        Ok(txxt::ast::elements::formatting::inlines::Inline::Reference(reference)) => {
+            match &reference.target {
+                ReferenceTarget::Citation { citations, .. } => {
+                    let specs = citations[0].page_specs().unwrap().unwrap();
+                    let pages: Vec<u32> = specs.iter().flat_map(PageSpec::pages).collect();
+                    assert_eq!(pages, vec![10, 12, 15, 16, 17, 18]);
+                }
+                other => panic!("Expected Citation reference target, got {:?}", other),
+            }
+        }
+        other => panic!("Expected Reference inline, got {:?}", other),
+    }
+}
+
 /// Test multiple citations
 #[test]
 fn test_parse_multiple_citations() {
@@ -144,6 +318,59 @@ fn test_parse_footnote_labeled() {
     }
 }
 
+/// Test auto-generated footnote reference
+#[test]
+fn test_parse_footnote_auto() {
+    let tokens = create_bracketed_tokens("^");
+
+    let result = parse_footnote_ref(&tokens);
+    assert!(result.is_ok());
+
+    if let Ok(txxt::ast::elements::formatting::inlines::Inline::Reference(reference)) = result {
+        match &reference.target {
+            ReferenceTarget::Footnote { footnote, raw, .. } => {
+                assert_eq!(footnote.label, None);
+                assert!(footnote.auto_generate);
+                assert_eq!(raw, "[^]");
+            }
+            _ => panic!("Expected Footnote reference target"),
+        }
+    } else {
+        panic!("Expected Reference inline");
+    }
+}
+
+/// Test inline footnote reference carrying its note
+#[test]
+fn test_parse_footnote_inline() {
+    let tokens = create_bracketed_tokens("^: the note text");
+
+    let result = parse_footnote_ref(&tokens);
+    assert!(result.is_ok());
+
+    if let Ok(txxt::ast::elements::formatting::inlines::Inline::Reference(reference)) = result {
+        match &reference.target {
+            ReferenceTarget::Footnote { footnote, raw, .. } => {
+                assert_eq!(footnote.label, None);
+                assert!(footnote.auto_generate);
+                assert_eq!(footnote.content.as_deref(), Some("the note text"));
+                assert_eq!(raw, "[^: the note text]");
+            }
+            _ => panic!("Expected Footnote reference target"),
+        }
+    } else {
+        panic!("Expected Reference inline");
+    }
+}
+
+/// Test inline footnote without a note is rejected
+#[test]
+fn test_parse_footnote_inline_empty() {
+    let tokens = create_bracketed_tokens("^:");
+
+    assert!(parse_footnote_ref(&tokens).is_err());
+}
+
 /// Test session reference numeric
 #[test]
 fn test_parse_session_ref_numeric() {
@@ -230,6 +457,45 @@ fn test_parse_session_ref_negative() {
     }
 }
 
+/// Test session reference with a single trailing dot, as written in titles
+#[test]
+fn test_parse_session_ref_trailing_dot() {
+    let tokens = create_bracketed_tokens("#1.2.");
+
+    let result = parse_session_ref(&tokens);
+
+    match result {
+        Ok(txxt::ast::elements::formatting::inlines::Inline::Reference(reference)) => {
+            match &reference.target {
+                ReferenceTarget::Section {
+                    identifier: SectionIdentifier::Numeric { levels, .. },
+                    ..
+                } => assert_eq!(levels, &vec![1, 2]),
+                other => panic!("Expected numeric Section target, got {:?}", other),
+            }
+        }
+        other => panic!("Expected Reference inline, got {:?}", other),
+    }
+}
+
+/// Test that empty section levels are rejected instead of becoming names
+#[rstest::rstest]
+#[case::leading_dot("#.1")]
+#[case::double_dot("#1..2")]
+#[case::double_trailing_dot("#1.2..")]
+fn test_parse_session_ref_malformed_numeric(#[case] content: &str) {
+    let tokens = create_bracketed_tokens(content);
+
+    let result = parse_session_ref(&tokens);
+
+    assert!(
+        result.is_err(),
+        "Expected error for {}, got {:?}",
+        content,
+        result
+    );
+}
+
 /// Test session reference named
 #[test]
 fn test_parse_session_ref_named() {
@@ -278,6 +544,10 @@ fn test_reference_classification() {
         classifier.classify("@smith2023, p. 45"),
         SimpleReferenceType::Citation
     );
+    assert_eq!(
+        classifier.classify("@smith2023; @jones2025, p. 3"),
+        SimpleReferenceType::Citation
+    );
 
     // Test section classification
     assert_eq!(classifier.classify("#3"), SimpleReferenceType::Section);
@@ -339,6 +609,59 @@ fn test_parse_reference_url() {
     }
 }
 
+/// Parse a URL reference and return its url and scheme
+fn parse_url_target(content: &str) -> (String, Option<String>) {
+    let tokens = create_bracketed_tokens(content);
+    match parse_reference(&tokens) {
+        Ok(txxt::ast::elements::formatting::inlines::Inline::Reference(reference)) => {
+            match reference.target {
+                ReferenceTarget::Url { url, scheme, .. } => (url, scheme),
+                other => panic!("Expected Url reference target, got {:?}", other),
+            }
+        }
+        other => panic!("Expected Reference inline, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_reference_url_https_scheme() {
+    let (url, scheme) = parse_url_target("https://example.com/docs");
+    assert_eq!(url, "https://example.com/docs");
+    assert_eq!(scheme, Some("https".to_string()));
+}
+
+#[test]
+fn test_parse_reference_url_mailto_scheme() {
+    let (url, scheme) = parse_url_target("mailto:someone@example.com");
+    assert_eq!(url, "mailto:someone@example.com");
+    assert_eq!(scheme, Some("mailto".to_string()));
+}
+
+#[test]
+fn test_parse_reference_url_bare_domain_has_no_scheme() {
+    let (url, scheme) = parse_url_target("example.com");
+    assert_eq!(url, "example.com");
+    assert_eq!(scheme, None);
+
+    let (_, scheme) = parse_url_target("//cdn.example.com/lib.js");
+    assert_eq!(scheme, None);
+}
+
+#[test]
+fn test_parse_reference_url_malformed_is_rejected() {
+    for content in ["http://", "https:///path", "mailto:"] {
+        let tokens = create_bracketed_tokens(content);
+        assert!(
+            matches!(
+                parse_reference(&tokens),
+                Err(InlineParseError::ReferenceTargetError(_))
+            ),
+            "{} should be rejected",
+            content
+        );
+    }
+}
+
 /// Test file reference parsing
 #[test]
 fn test_parse_reference_file() {
@@ -418,3 +741,210 @@ fn test_footnote_invalid_format_error() {
 
 // REMOVED: Empty placeholder test with no implementation
 // TxxtCorpora testing should be added when the feature is implemented
+
+/// Test custom reference handlers registered on the classifier
+#[test]
+fn test_parse_reference_custom_handler() {
+    fn is_issue(content: &str) -> bool {
+        content.starts_with("!issue-")
+    }
+
+    fn issue_number(content: &str) -> String {
+        content.trim_start_matches("!issue-").to_string()
+    }
+
+    let classifier = ReferenceClassifier::new().with_custom(CustomReferenceHandler::new(
+        "issue",
+        is_issue,
+        issue_number,
+    ));
+
+    assert_eq!(
+        classifier.classify("!issue-7"),
+        SimpleReferenceType::Custom("issue".to_string())
+    );
+    // Built-ins still apply to content the handler doesn't claim
+    assert_eq!(classifier.classify("#3"), SimpleReferenceType::Section);
+
+    let tokens = create_bracketed_tokens("!issue-7");
+    let result = parse_reference_with(&tokens, &classifier);
+
+    if let Ok(txxt::ast::elements::formatting::inlines::Inline::Reference(reference)) = result {
+        match &reference.target {
+            ReferenceTarget::Custom {
+                kind, target, raw, ..
+            } => {
+                assert_eq!(kind, "issue");
+                assert_eq!(target, "7");
+                assert_eq!(raw, "[!issue-7]");
+            }
+            _ => panic!("Expected Custom reference target"),
+        }
+    } else {
+        panic!("Expected Reference inline");
+    }
+
+    // Without the handler the same content stays unresolved
+    let result = parse_reference(&tokens);
+    assert!(matches!(
+        result,
+        Ok(txxt::ast::elements::formatting::inlines::Inline::Reference(ref r))
+            if matches!(r.target, ReferenceTarget::Unresolved { .. })
+    ));
+}
+
+/// Classifier patterns are compiled once per process, not per reference
+#[cfg(debug_assertions)]
+#[test]
+fn test_reference_patterns_are_compiled_once() {
+    let kinds = [
+        "@smith2023",
+        "#3.1",
+        "42",
+        "https://example.com",
+        "./notes.txxt",
+    ];
+
+    // Make sure the shared patterns exist before counting
+    parse_reference(&create_bracketed_tokens(kinds[0])).unwrap();
+    let builds = pattern_build_count();
+
+    for i in 0..10_000 {
+        let tokens = create_bracketed_tokens(kinds[i % kinds.len()]);
+        parse_reference(&tokens).unwrap();
+        let _ = ReferenceClassifier::new().classify(kinds[i % kinds.len()]);
+    }
+
+    assert_eq!(pattern_build_count(), builds);
+    assert_eq!(builds, 1);
+}
+
+/// Content of the unresolved reference parsed from `tokens`
+fn unresolved_content(tokens: &[ScannerToken]) -> String {
+    match parse_reference(tokens) {
+        Ok(txxt::ast::elements::formatting::inlines::Inline::Reference(reference)) => {
+            match reference.target {
+                ReferenceTarget::Unresolved { content, .. } => content,
+                other => panic!("Expected Unresolved reference target, got {:?}", other),
+            }
+        }
+        other => panic!("Expected Reference inline, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_reference_unescapes_brackets() {
+    let tokens = create_bracketed_tokens(r"text with \] bracket");
+    assert_eq!(unresolved_content(&tokens), "text with ] bracket");
+
+    let tokens = create_bracketed_tokens(r"a \[b\] c");
+    assert_eq!(unresolved_content(&tokens), "a [b] c");
+}
+
+#[test]
+fn test_parse_reference_keeps_literal_backslash() {
+    let tokens = create_bracketed_tokens(r"dir\\");
+    assert_eq!(unresolved_content(&tokens), r"dir\");
+
+    let tokens = create_bracketed_tokens(r"a\b note");
+    assert_eq!(unresolved_content(&tokens), r"a\b note");
+}
+
+fn issue_classifier() -> ReferenceClassifier {
+    fn is_issue(content: &str) -> bool {
+        content.starts_with("!issue-")
+    }
+
+    fn issue_link(content: &str) -> String {
+        format!("issue #{}", content.trim_start_matches("!issue-"))
+    }
+
+    ReferenceClassifier::new()
+        .with_custom(CustomReferenceHandler::new("issue", is_issue, issue_link))
+}
+
+/// Test that the inline pipeline dispatches to custom handlers
+#[test]
+fn test_pipeline_routes_custom_references() {
+    use txxt::ast::elements::formatting::inlines::Inline;
+    use txxt::semantic::elements::inlines::pipeline::create_standard_pipeline;
+
+    let tokens = txxt::syntax::Lexer::new("See [!issue-7].").tokenize();
+    let inlines = create_standard_pipeline()
+        .with_reference_classifier(issue_classifier())
+        .parse(&tokens)
+        .unwrap();
+
+    let target = inlines
+        .iter()
+        .find_map(|inline| match inline {
+            Inline::Reference(reference) => Some(&reference.target),
+            _ => None,
+        })
+        .expect("reference should be parsed");
+    match target {
+        ReferenceTarget::Custom { kind, target, .. } => {
+            assert_eq!(kind, "issue");
+            assert_eq!(target, "issue #7");
+        }
+        other => panic!("Expected Custom reference target, got {:?}", other),
+    }
+}
+
+/// Test that escapes in a custom reference are undone once, by the lexer
+#[test]
+fn test_custom_reference_keeps_escaped_backslash() {
+    use txxt::ast::elements::formatting::inlines::Inline;
+    use txxt::semantic::elements::inlines::pipeline::create_standard_pipeline;
+
+    // `\\` is a literal backslash and `\]` a literal bracket
+    let tokens = txxt::syntax::Lexer::new(r"See [!issue-x\\\]y].").tokenize();
+    let inlines = create_standard_pipeline()
+        .with_reference_classifier(issue_classifier())
+        .parse(&tokens)
+        .unwrap();
+
+    let target = inlines
+        .iter()
+        .find_map(|inline| match inline {
+            Inline::Reference(reference) => Some(&reference.target),
+            _ => None,
+        })
+        .expect("reference should be parsed");
+    match target {
+        ReferenceTarget::Custom { target, .. } => assert_eq!(target, r"issue #x\]y"),
+        other => panic!("Expected Custom reference target, got {:?}", other),
+    }
+}
+
+/// Test that a document parser configured with a classifier reaches its handlers
+#[test]
+fn test_document_parser_uses_custom_handlers() {
+    use txxt::api::Parser;
+    use txxt::ast::elements::formatting::inlines::TextTransform;
+    use txxt::ast::elements::session::session_container::SessionContainerElement;
+
+    fn reference_targets(parser: &mut Parser) -> Vec<String> {
+        let document = parser.parse_into("See [!issue-7] for details.\n").unwrap();
+        let paragraph = match &document.content.content[0] {
+            SessionContainerElement::Paragraph(paragraph) => paragraph,
+            other => panic!("Expected a paragraph, got {:?}", other),
+        };
+        paragraph
+            .content
+            .iter()
+            .filter_map(|transform| match transform {
+                TextTransform::Custom {
+                    name, parameters, ..
+                } if name == "reference" => Some(parameters["target"].clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    let mut parser = Parser::new().with_reference_classifier(issue_classifier());
+    assert_eq!(reference_targets(&mut parser), vec!["issue #7".to_string()]);
+
+    // Without the handler the reference stays text
+    assert!(reference_targets(&mut Parser::new()).is_empty());
+}
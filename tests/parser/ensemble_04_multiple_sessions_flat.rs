@@ -189,6 +189,9 @@ fn element_type_name(
         SessionContainerElement::List(_) => "List",
         SessionContainerElement::Definition(_) => "Definition",
         SessionContainerElement::Annotation(_) => "Annotation",
+        SessionContainerElement::Quote(_) => "Quote",
+        SessionContainerElement::Table(_) => "Table",
+        SessionContainerElement::FootnoteDefinition(_) => "FootnoteDefinition",
         SessionContainerElement::Verbatim(_) => "Verbatim",
         SessionContainerElement::ContentContainer(_) => "ContentContainer",
         SessionContainerElement::SessionContainer(_) => "SessionContainer",
@@ -0,0 +1,72 @@
+//! Parsing documents written with a non-default indentation width
+
+use txxt::api::{run_all_unified, run_all_with_options, ParseOptions};
+use txxt::ast::elements::containers::content::ContentContainerElement;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::{Document, ListBlock};
+
+const TWO_SPACE_LIST: &str = "Intro text.\n\n- outer one\n  - inner a\n  - inner b\n- outer two\n";
+
+fn two_spaces() -> ParseOptions {
+    ParseOptions {
+        indent_size: 2,
+        ..ParseOptions::default()
+    }
+}
+
+fn only_list(document: &Document) -> &ListBlock {
+    document
+        .content
+        .content
+        .iter()
+        .find_map(|element| match element {
+            SessionContainerElement::List(list) => Some(list),
+            _ => None,
+        })
+        .expect("Document should contain a list")
+}
+
+#[test]
+fn test_two_space_nested_list() {
+    let document = run_all_with_options(TWO_SPACE_LIST, None, two_spaces()).unwrap();
+    let list = only_list(&document);
+
+    assert_eq!(list.items.len(), 2);
+    let nested = list.items[0]
+        .nested
+        .as_ref()
+        .expect("First item should have nested content");
+    match nested.content.as_slice() {
+        [ContentContainerElement::List(inner)] => assert_eq!(inner.items.len(), 2),
+        other => panic!("Expected a single nested list, got {:?}", other),
+    }
+    assert!(list.items[1].nested.is_none());
+}
+
+#[test]
+fn test_default_width_does_not_nest_two_space_list() {
+    let document = run_all_unified(TWO_SPACE_LIST, None).unwrap();
+    let nested = only_list(&document).items[0].nested.as_ref().unwrap();
+
+    assert!(!nested
+        .content
+        .iter()
+        .any(|element| matches!(element, ContentContainerElement::List(_))));
+}
+
+#[test]
+fn test_two_space_verbatim_wall() {
+    let source = "Intro text.\n\nCode:\n  let x = 1;\n    nested();\n:: rust ::\n";
+    let document = run_all_with_options(source, None, two_spaces()).unwrap();
+
+    let verbatim = document
+        .content
+        .content
+        .iter()
+        .find_map(|element| match element {
+            SessionContainerElement::Verbatim(verbatim) => Some(verbatim),
+            _ => None,
+        })
+        .expect("Document should contain a verbatim block");
+    assert_eq!(verbatim.content_text(), "let x = 1;\n  nested();");
+}
@@ -4,6 +4,8 @@ mod elements;
 mod semantic_analysis;
 
 // Parser integration and bug tests
+mod blank_lines;
+mod indent_size;
 mod issue_26_indented_sequence_markers;
 mod session_numbering;
 // TODO: Update for new API
@@ -31,6 +31,7 @@ fn test_verbatim_block_v2_simple_in_flow() {
         },
         ScannerToken::VerbatimBlockEnd {
             label_raw: "python".to_string(),
+            truncated: false,
             span: SourceSpan {
                 start: Position { row: 2, column: 0 },
                 end: Position { row: 3, column: 0 },
@@ -126,6 +127,7 @@ fn test_verbatim_block_v2_multiple_content_lines() {
         },
         ScannerToken::VerbatimBlockEnd {
             label_raw: "python".to_string(),
+            truncated: false,
             span: SourceSpan {
                 start: Position { row: 3, column: 0 },
                 end: Position { row: 4, column: 0 },
@@ -211,6 +213,7 @@ fn test_verbatim_block_v2_with_blank_lines() {
         },
         ScannerToken::VerbatimBlockEnd {
             label_raw: "code".to_string(),
+            truncated: false,
             span: SourceSpan {
                 start: Position { row: 4, column: 0 },
                 end: Position { row: 5, column: 0 },
@@ -279,6 +282,7 @@ fn test_verbatim_block_v2_empty_block() {
         },
         ScannerToken::VerbatimBlockEnd {
             label_raw: "empty".to_string(),
+            truncated: false,
             span: SourceSpan {
                 start: Position { row: 1, column: 0 },
                 end: Position { row: 2, column: 0 },
@@ -332,6 +336,7 @@ fn test_verbatim_block_v2_with_parameters() {
         ScannerToken::VerbatimBlockEnd {
             // New grammar (issue #139): whitespace separator instead of colon
             label_raw: "python version=3.11,style=pep8".to_string(),
+            truncated: false,
             span: SourceSpan {
                 start: Position { row: 2, column: 0 },
                 end: Position { row: 3, column: 0 },
@@ -400,6 +405,7 @@ fn test_verbatim_block_v2_stretched_mode() {
         },
         ScannerToken::VerbatimBlockEnd {
             label_raw: "label".to_string(),
+            truncated: false,
             span: SourceSpan {
                 start: Position { row: 2, column: 0 },
                 end: Position { row: 3, column: 0 },
@@ -462,6 +468,7 @@ fn test_verbatim_block_v2_indented_block() {
         },
         ScannerToken::VerbatimBlockEnd {
             label_raw: "label".to_string(),
+            truncated: false,
             span: SourceSpan {
                 start: Position { row: 2, column: 4 },
                 end: Position { row: 3, column: 0 },
@@ -501,6 +508,7 @@ fn test_verbatim_block_v2_error_no_start() {
         },
         ScannerToken::VerbatimBlockEnd {
             label_raw: "label".to_string(),
+            truncated: false,
             span: SourceSpan {
                 start: Position { row: 1, column: 0 },
                 end: Position { row: 2, column: 0 },
@@ -587,6 +595,7 @@ fn test_verbatim_block_v2_integration_with_analyzer() {
         },
         ScannerToken::VerbatimBlockEnd {
             label_raw: "label".to_string(),
+            truncated: false,
             span: SourceSpan {
                 start: Position { row: 2, column: 0 },
                 end: Position { row: 3, column: 0 },
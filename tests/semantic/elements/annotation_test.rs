@@ -64,3 +64,91 @@ fn test_create_annotation_element_rejects_wrong_token() {
     let result = create_annotation_element(&plain_token, &[]);
     assert!(result.is_err());
 }
+
+/// Parse a source fragment and return its first annotation block
+fn parse_single_annotation(source: &str) -> txxt::ast::AnnotationBlock {
+    use txxt::ast::elements::session::session_container::SessionContainerElement;
+
+    let document = txxt::api::run_all_unified(source, None).expect("Failed to parse source");
+
+    document
+        .content
+        .content
+        .into_iter()
+        .find_map(|element| match element {
+            SessionContainerElement::Annotation(annotation) => Some(annotation),
+            _ => None,
+        })
+        .expect("Source should contain an annotation")
+}
+
+/// Test that a dotted label splits into namespace and local label
+#[test]
+fn test_annotation_namespace_from_dotted_label() {
+    let annotation = parse_single_annotation(":: def.note :: Note scoped to the def namespace\n");
+
+    assert_eq!(annotation.namespace.as_deref(), Some("def"));
+    assert_eq!(annotation.local_label(), "note");
+}
+
+/// Test that multi-level namespaces keep everything before the last dot
+#[test]
+fn test_annotation_namespace_multi_level() {
+    let annotation =
+        parse_single_annotation(":: org.example.custom :: Note with a multi-level namespace\n");
+
+    assert_eq!(annotation.namespace.as_deref(), Some("org.example"));
+    assert_eq!(annotation.local_label(), "custom");
+}
+
+/// Test that a bare label has no namespace
+#[test]
+fn test_annotation_namespace_absent_for_bare_label() {
+    let annotation = parse_single_annotation(":: note :: Simple note\n");
+
+    assert_eq!(annotation.namespace, None);
+    assert_eq!(annotation.local_label(), "note");
+}
+
+/// Test that a single-line body becomes inline content with its formatting parsed
+#[test]
+fn test_single_line_annotation_body_is_inline() {
+    use txxt::ast::elements::annotation::AnnotationContent;
+    use txxt::ast::TextTransform;
+
+    let annotation = parse_single_annotation(":: note :: see *the* docs\n");
+
+    match &annotation.content {
+        AnnotationContent::Inline(body) => {
+            assert!(body
+                .iter()
+                .any(|transform| matches!(transform, TextTransform::Emphasis(_))));
+        }
+        other => panic!("Expected inline content, got {:?}", other),
+    }
+    assert_eq!(annotation.content_text(), "see the docs");
+}
+
+/// Test that indented content makes a block annotation
+#[test]
+fn test_indented_annotation_body_is_block() {
+    use txxt::ast::elements::annotation::AnnotationContent;
+
+    let annotation = parse_single_annotation(
+        ":: note ::\n    First paragraph.\n\n    - item one\n    - item two\n",
+    );
+
+    match &annotation.content {
+        AnnotationContent::Block(container) => assert_eq!(container.len(), 2),
+        other => panic!("Expected block content, got {:?}", other),
+    }
+}
+
+/// Test that an annotation without a body has empty inline content
+#[test]
+fn test_label_only_annotation_has_empty_inline_body() {
+    let annotation = parse_single_annotation("Some text.\n\n:: note ::\n");
+
+    assert!(annotation.is_inline());
+    assert_eq!(annotation.content_text(), "");
+}
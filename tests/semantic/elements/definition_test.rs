@@ -3,6 +3,7 @@
 //!
 //! Tests that definition tokens are correctly converted to definition AST nodes.
 
+use txxt::ast::elements::session::session_container::SessionContainerElement;
 use txxt::cst::high_level_tokens::HighLevelTokenBuilder;
 use txxt::cst::{Position, SourceSpan};
 use txxt::semantic::elements::definition::create_definition_element;
@@ -55,3 +56,62 @@ fn test_create_definition_element_rejects_wrong_token() {
     let result = create_definition_element(&plain_token, &[]);
     assert!(result.is_err());
 }
+
+fn parse_elements(source: &str) -> Vec<SessionContainerElement> {
+    txxt::api::run_all_unified(source, None)
+        .expect("Failed to parse source")
+        .content
+        .content
+}
+
+/// A `:: def.label ::` line after the body carries the definition's metadata
+#[test]
+fn test_trailing_definition_annotation_sets_parameters() {
+    let elements = parse_elements("Term:\n    The body.\n:: def.label sort=3 ::\n");
+
+    assert_eq!(elements.len(), 1, "annotation should not stand alone");
+    let SessionContainerElement::Definition(definition) = &elements[0] else {
+        panic!("Expected a definition, got {:?}", elements[0]);
+    };
+    assert_eq!(definition.parameters.get("sort"), Some(&"3".to_string()));
+    assert_eq!(definition.content.content.len(), 1);
+}
+
+/// Any other label still terminates a verbatim block
+#[test]
+fn test_trailing_non_definition_label_is_verbatim() {
+    let elements = parse_elements("Example:\n    let x = 1;\n:: rust sort=3 ::\n");
+
+    assert!(matches!(elements[0], SessionContainerElement::Verbatim(_)));
+}
+
+/// A reference in the term is parsed and reported like one in a paragraph
+#[test]
+fn test_term_session_reference_is_resolved() {
+    let source = "1. Intro\n\n    1.2. Detail\n\n        Text.\n\nAPI [#1.2]:\n    The body.\n";
+    let document = txxt::api::run_all_unified(source, None).expect("Failed to parse source");
+
+    let SessionContainerElement::Definition(definition) = &document.content.content[1] else {
+        panic!(
+            "Expected a definition, got {:?}",
+            document.content.content[1]
+        );
+    };
+    let term: String = definition
+        .term
+        .content
+        .iter()
+        .map(|transform| transform.text_content())
+        .collect();
+    assert!(term.starts_with("API"));
+    assert!(!term.contains(':'));
+
+    let references = txxt::ast::collect_references(&document);
+    assert_eq!(references.len(), 1);
+    assert_eq!(references[0].target, "1.2");
+    assert_eq!(
+        references[0].kind,
+        txxt::ast::elements::references::SimpleReferenceType::Section
+    );
+    assert_eq!(references[0].span.start, Position { row: 6, column: 4 });
+}
@@ -0,0 +1,187 @@
+//! Tests for footnote definition construction
+//!
+//! Footnote definitions are parsed end to end: the scanner emits the
+//! `[^label]` marker and colon, semantic analysis groups the marker line, and
+//! AST construction attaches the indented body. The registry then pairs
+//! definitions with the references that point to them.
+
+use txxt::ast::elements::containers::simple::SimpleBlockElement;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::{Document, FootnoteDefinitionBlock, FootnoteRegistry};
+
+fn parse(source: &str) -> Document {
+    txxt::api::run_all_unified(source, None).expect("Failed to parse source")
+}
+
+fn footnotes(document: &Document) -> Vec<&FootnoteDefinitionBlock> {
+    document
+        .content
+        .content
+        .iter()
+        .filter_map(|element| match element {
+            SessionContainerElement::FootnoteDefinition(footnote) => Some(footnote),
+            _ => None,
+        })
+        .collect()
+}
+
+fn body_text(element: &SimpleBlockElement) -> String {
+    match element {
+        SimpleBlockElement::Paragraph(paragraph) => paragraph.text_content().trim_end().to_string(),
+        other => panic!("Expected a paragraph, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_simple_footnote_definition() {
+    let document = parse(
+        "The figures are estimates[^source].\n\n\
+         [^source]: Taken from the *2023* survey.\n",
+    );
+
+    let footnotes = footnotes(&document);
+    assert_eq!(footnotes.len(), 1);
+    assert_eq!(footnotes[0].label, "source");
+    assert_eq!(footnotes[0].text(), "Taken from the 2023 survey.");
+    assert!(footnotes[0].body.content.is_empty());
+
+    let registry = FootnoteRegistry::from_document(&document);
+    assert_eq!(
+        registry
+            .definition("source")
+            .map(|footnote| footnote.text()),
+        Some("Taken from the 2023 survey.".to_string())
+    );
+    assert!(registry.unreferenced().is_empty());
+    assert!(registry.undefined().is_empty());
+}
+
+#[test]
+fn test_multi_paragraph_footnote_definition() {
+    let document = parse(
+        "See the note[^method].\n\n\
+         [^method]: The first paragraph.\n\n    \
+             A second paragraph.\n\n    \
+             A third paragraph.\n\n\
+         Back in the main text.\n",
+    );
+
+    let footnotes = footnotes(&document);
+    assert_eq!(footnotes.len(), 1);
+    assert_eq!(footnotes[0].text(), "The first paragraph.");
+    let body: Vec<String> = footnotes[0]
+        .body
+        .content
+        .iter()
+        .filter(|element| !matches!(element, SimpleBlockElement::BlankLine(_)))
+        .map(body_text)
+        .collect();
+    assert_eq!(body, vec!["A second paragraph.", "A third paragraph."]);
+
+    // The text after the body is back at the top level
+    assert!(matches!(
+        document.content.content.last(),
+        Some(SessionContainerElement::Paragraph(_))
+    ));
+}
+
+#[test]
+fn test_footnote_definition_with_body_only() {
+    let document = parse(
+        "[^long]:\n    \
+             All of the note is indented.\n\n\
+         After.\n",
+    );
+
+    let footnotes = footnotes(&document);
+    assert_eq!(footnotes.len(), 1);
+    assert!(footnotes[0].content.is_empty());
+    assert_eq!(
+        body_text(&footnotes[0].body.content[0]),
+        "All of the note is indented."
+    );
+}
+
+#[test]
+fn test_footnote_definition_without_reference() {
+    let document = parse(
+        "Nothing points to the note.\n\n\
+         [^orphan]: A note nobody references.\n",
+    );
+
+    let registry = FootnoteRegistry::from_document(&document);
+    assert_eq!(registry.definitions().len(), 1);
+    let unreferenced: Vec<&str> = registry
+        .unreferenced()
+        .iter()
+        .map(|footnote| footnote.label.as_str())
+        .collect();
+    assert_eq!(unreferenced, vec!["orphan"]);
+}
+
+#[test]
+fn test_footnote_reference_without_definition() {
+    let document = parse("A dangling note[^missing].\n");
+
+    let registry = FootnoteRegistry::from_document(&document);
+    assert!(registry.definitions().is_empty());
+    let undefined: Vec<&str> = registry
+        .undefined()
+        .iter()
+        .map(|reference| reference.target.as_str())
+        .collect();
+    assert_eq!(undefined, vec!["missing"]);
+}
+
+#[test]
+fn test_footnote_marker_mid_line_is_not_a_definition() {
+    let document = parse("A reference [^note]: followed by a colon.\n");
+
+    assert!(footnotes(&document).is_empty());
+}
+
+#[test]
+fn test_auto_footnotes_get_distinct_ids() {
+    let document = parse("First[^] and second[^], then a named one[^named].\n");
+
+    let registry = FootnoteRegistry::from_document(&document);
+    let targets: Vec<&str> = registry
+        .references()
+        .iter()
+        .map(|reference| reference.target.as_str())
+        .collect();
+    assert_eq!(targets, vec!["auto-1", "auto-2", "named"]);
+    assert!(registry.is_generated("auto-1"));
+    assert!(!registry.is_generated("named"));
+
+    // Only the named reference lacks a definition
+    let undefined: Vec<&str> = registry
+        .undefined()
+        .iter()
+        .map(|reference| reference.target.as_str())
+        .collect();
+    assert_eq!(undefined, vec!["named"]);
+}
+
+#[test]
+fn test_auto_footnote_ids_skip_used_labels() {
+    let document = parse("A note[^] next to[^auto-1].\n");
+
+    let registry = FootnoteRegistry::from_document(&document);
+    assert_eq!(registry.references()[0].target, "auto-2");
+}
+
+#[test]
+fn test_inline_footnotes_get_generated_ids() {
+    let document = parse("A fact[^: the note text], a label[^named] and an empty one[^:].\n");
+
+    let registry = FootnoteRegistry::from_document(&document);
+    let targets: Vec<&str> = registry
+        .references()
+        .iter()
+        .map(|reference| reference.target.as_str())
+        .collect();
+    // `[^:]` has no note, so it is not read as a footnote at all
+    assert_eq!(targets, vec!["auto-1", "named"]);
+    assert!(registry.is_generated("auto-1"));
+}
@@ -7,3 +7,22 @@
 
 // REMOVED: Empty TODO tests with no implementation
 // List element creation is tested in actual list parsing tests
+
+use txxt::cst::high_level_tokens::HighLevelTokenBuilder;
+use txxt::cst::{Position, SourceSpan};
+use txxt::semantic::elements::list::create_list_element;
+
+#[test]
+fn test_list_without_items_is_an_error() {
+    let span = SourceSpan {
+        start: Position { row: 0, column: 0 },
+        end: Position { row: 0, column: 0 },
+    };
+    let tokens = vec![
+        HighLevelTokenBuilder::blank_line(span.clone()),
+        HighLevelTokenBuilder::blank_line(span),
+    ];
+
+    assert!(create_list_element(&[]).is_err());
+    assert!(create_list_element(&tokens).is_err());
+}
@@ -6,7 +6,10 @@
 
 mod annotation_test;
 mod definition_test;
+mod footnote_test;
 mod list_test;
 mod paragraph_test;
+mod quote_test;
 mod session_test;
+mod table_test;
 mod verbatim_test;
@@ -3,10 +3,32 @@
 //!
 //! Tests that paragraph tokens are correctly converted to paragraph AST nodes.
 
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::{ParagraphBlock, TextTransform};
 use txxt::cst::high_level_tokens::HighLevelTokenBuilder;
 use txxt::cst::{Position, SourceSpan};
 use txxt::semantic::elements::paragraph::create_paragraph_element;
 
+fn parse_paragraphs(source: &str) -> Vec<ParagraphBlock> {
+    let document = txxt::api::run_all_unified(source, None).expect("Failed to parse source");
+
+    fn collect(elements: &[SessionContainerElement], paragraphs: &mut Vec<ParagraphBlock>) {
+        for element in elements {
+            match element {
+                SessionContainerElement::Paragraph(paragraph) => paragraphs.push(paragraph.clone()),
+                SessionContainerElement::Session(session) => {
+                    collect(&session.content.content, paragraphs)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut paragraphs = Vec::new();
+    collect(&document.content.content, &mut paragraphs);
+    paragraphs
+}
+
 /// Test that paragraph elements are created correctly from plain text line tokens
 #[test]
 fn test_create_paragraph_element() {
@@ -68,3 +90,113 @@ fn test_create_paragraph_element_rejects_wrong_token() {
     let result = create_paragraph_element(&annotation_token);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_continuation_lines_are_soft_wrapped() {
+    let paragraphs = parse_paragraphs("The first line\nand its continuation.\n");
+
+    assert_eq!(paragraphs.len(), 1);
+    assert_eq!(
+        paragraphs[0].text_content(),
+        "The first line and its continuation."
+    );
+    assert!(!paragraphs[0]
+        .content
+        .iter()
+        .any(|transform| matches!(transform, TextTransform::LineBreak(_))));
+}
+
+#[test]
+fn test_trailing_space_before_soft_wrap_is_folded() {
+    let paragraphs = parse_paragraphs("One trailing space \nstill wraps.\n");
+
+    assert_eq!(
+        paragraphs[0].text_content(),
+        "One trailing space still wraps."
+    );
+}
+
+#[test]
+fn test_two_trailing_spaces_are_a_hard_break() {
+    let paragraphs = parse_paragraphs("Roses are red,  \nviolets are blue.\n");
+
+    let paragraph = &paragraphs[0];
+    assert_eq!(
+        paragraph.text_content(),
+        "Roses are red,\nviolets are blue."
+    );
+    let breaks: Vec<&TextTransform> = paragraph
+        .content
+        .iter()
+        .filter(|transform| matches!(transform, TextTransform::LineBreak(_)))
+        .collect();
+    assert_eq!(breaks.len(), 1);
+
+    // The break keeps its source tokens: the trailing spaces and the newline
+    match breaks[0] {
+        TextTransform::LineBreak(text) => assert_eq!(text.tokens.tokens.len(), 2),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn test_trailing_backslash_is_a_hard_break() {
+    let paragraphs = parse_paragraphs("Line one\\\nline two\nline three\n");
+
+    let paragraph = &paragraphs[0];
+    assert_eq!(paragraph.text_content(), "Line one\nline two line three");
+    let line_break = paragraph
+        .content
+        .iter()
+        .find_map(|transform| match transform {
+            TextTransform::LineBreak(text) => Some(text),
+            _ => None,
+        })
+        .expect("Backslash should produce a line break");
+    assert_eq!(line_break.tokens.tokens[0].content(), "\\");
+}
+
+#[test]
+fn test_nested_paragraphs_get_line_breaks_too() {
+    let source = "Session\n\n    Indented line,  \n    broken here\n    and wrapped.\n";
+    let paragraphs = parse_paragraphs(source);
+
+    assert_eq!(
+        paragraphs.last().unwrap().text_content(),
+        "Indented line,\nbroken here and wrapped."
+    );
+}
+
+#[test]
+fn test_trailing_backslash_continues_across_blank_line() {
+    let source = "First stanza ends here\\\n\nand the paragraph goes on.\n\nA new paragraph.\n";
+    let paragraphs = parse_paragraphs(source);
+
+    assert_eq!(paragraphs.len(), 2);
+    assert_eq!(
+        paragraphs[0].text_content(),
+        "First stanza ends here\nand the paragraph goes on."
+    );
+    assert_eq!(paragraphs[1].text_content(), "A new paragraph.");
+}
+
+#[test]
+fn test_escaped_backslash_does_not_continue() {
+    let paragraphs = parse_paragraphs("Ends in a backslash\\\\\n\nSeparate paragraph.\n");
+
+    assert_eq!(paragraphs.len(), 2);
+}
+
+#[test]
+fn test_continuation_does_not_swallow_session_title() {
+    let source = "Intro line\\\n\nSession\n\n    Body.\n";
+    let document = txxt::api::run_all_unified(source, None).unwrap();
+
+    assert!(matches!(
+        document.content.content.as_slice(),
+        [
+            SessionContainerElement::Paragraph(_),
+            SessionContainerElement::Session(_)
+        ]
+    ));
+}
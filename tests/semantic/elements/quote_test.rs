@@ -0,0 +1,141 @@
+//! Tests for quote element construction
+//!
+//! Quotes are parsed end to end: the scanner emits the `>` markers, semantic
+//! analysis groups the quoted lines, and AST construction parses the quoted
+//! content recursively.
+
+use txxt::ast::elements::containers::content::ContentContainerElement;
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::QuoteBlock;
+
+fn parse_single_quote(source: &str) -> QuoteBlock {
+    let document = txxt::api::run_all_unified(source, None).expect("Failed to parse source");
+
+    document
+        .content
+        .content
+        .into_iter()
+        .find_map(|element| match element {
+            SessionContainerElement::Quote(quote) => Some(quote),
+            _ => None,
+        })
+        .expect("Source should contain a quote")
+}
+
+fn paragraph_text(element: &ContentContainerElement) -> String {
+    match element {
+        ContentContainerElement::Paragraph(paragraph) => {
+            paragraph.text_content().trim_end().to_string()
+        }
+        other => panic!("Expected a paragraph, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_single_line_quote() {
+    let quote = parse_single_quote("> To be or not to be\n");
+
+    assert_eq!(quote.content.content.len(), 1);
+    assert_eq!(
+        paragraph_text(&quote.content.content[0]),
+        "To be or not to be"
+    );
+}
+
+#[test]
+fn test_multi_line_quote() {
+    let source = "Before the quote.\n\n\
+                  > The first quoted line\n\
+                  > continues the same paragraph.\n\
+                  >\n\
+                  > A second paragraph.\n\
+                  \n\
+                  After the quote.\n";
+    let document = txxt::api::run_all_unified(source, None).expect("Failed to parse source");
+    let kinds: Vec<&str> = document
+        .content
+        .content
+        .iter()
+        .map(|element| match element {
+            SessionContainerElement::Paragraph(_) => "paragraph",
+            SessionContainerElement::Quote(_) => "quote",
+            _ => "other",
+        })
+        .collect();
+    assert_eq!(kinds, vec!["paragraph", "quote", "paragraph"]);
+
+    let quote = parse_single_quote(source);
+    assert_eq!(quote.content.content.len(), 2);
+    assert!(paragraph_text(&quote.content.content[0]).starts_with("The first quoted line"));
+    assert_eq!(
+        paragraph_text(&quote.content.content[1]),
+        "A second paragraph."
+    );
+}
+
+#[test]
+fn test_quote_with_nested_list() {
+    let quote = parse_single_quote(
+        "> Shopping\n\
+         >\n\
+         > - fruit\n\
+         >     - apple\n\
+         >     - pear\n\
+         > - bread\n",
+    );
+
+    assert_eq!(quote.content.content.len(), 2);
+    assert_eq!(paragraph_text(&quote.content.content[0]), "Shopping");
+
+    let list = match &quote.content.content[1] {
+        ContentContainerElement::List(list) => list,
+        other => panic!("Expected a list, got {:?}", other),
+    };
+    let items: Vec<String> = list
+        .items
+        .iter()
+        .map(|item| item.text_content().trim_end().to_string())
+        .collect();
+    assert_eq!(items, vec!["fruit", "bread"]);
+
+    let nested = list.items[0]
+        .nested
+        .as_ref()
+        .expect("First item should hold the nested list");
+    match &nested.content[..] {
+        [ContentContainerElement::List(inner)] => {
+            let inner_items: Vec<String> = inner
+                .items
+                .iter()
+                .map(|item| item.text_content().trim_end().to_string())
+                .collect();
+            assert_eq!(inner_items, vec!["apple", "pear"]);
+        }
+        other => panic!("Expected a single nested list, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_nested_quote() {
+    let quote = parse_single_quote("> Outer\n>\n> > Inner\n");
+
+    assert_eq!(quote.content.content.len(), 2);
+    match &quote.content.content[1] {
+        ContentContainerElement::Quote(inner) => {
+            assert_eq!(paragraph_text(&inner.content.content[0]), "Inner");
+        }
+        other => panic!("Expected a nested quote, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_greater_than_inside_text_is_not_a_quote() {
+    let document =
+        txxt::api::run_all_unified("Use a > b and >this.\n", None).expect("Failed to parse source");
+
+    assert!(!document
+        .content
+        .content
+        .iter()
+        .any(|element| matches!(element, SessionContainerElement::Quote(_))));
+}
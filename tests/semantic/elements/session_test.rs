@@ -3,9 +3,12 @@
 //!
 //! Tests that session components are correctly converted to session AST nodes.
 
-use txxt::cst::high_level_tokens::HighLevelTokenBuilder;
-use txxt::cst::{Position, SourceSpan};
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::cst::high_level_tokens::{HighLevelToken, HighLevelTokenBuilder};
+use txxt::cst::{Position, ScannerTokenSequence, SourceSpan};
 use txxt::semantic::ast_construction::AstNode;
+use txxt::semantic::elements::list::create_list_element_with_nesting;
+use txxt::semantic::elements::quote::create_quote_element;
 use txxt::semantic::elements::session::create_session_element;
 
 /// Test that session elements are created correctly from title token and child nodes
@@ -103,3 +106,81 @@ fn test_create_session_element_rejects_wrong_token() {
     let result = create_session_element(&annotation_token, &child_nodes);
     assert!(result.is_err());
 }
+
+fn line_span(row: usize) -> SourceSpan {
+    SourceSpan {
+        start: Position { row, column: 0 },
+        end: Position { row, column: 12 },
+    }
+}
+
+fn title(text: &str, row: usize) -> HighLevelToken {
+    HighLevelTokenBuilder::plain_text_line(
+        String::new(),
+        HighLevelTokenBuilder::text_span(text.to_string(), line_span(row)),
+        line_span(row),
+    )
+}
+
+fn session_node(text: &str, row: usize) -> AstNode {
+    AstNode::Session(create_session_element(&title(text, row), &[]).unwrap())
+}
+
+/// Sessions may nest sessions (SessionContainer rules)
+#[test]
+fn test_session_accepts_nested_session() {
+    let inner = session_node("Inner", 2);
+
+    let outer = create_session_element(&title("Outer", 0), &[inner]).unwrap();
+
+    assert!(matches!(
+        outer.content.content.as_slice(),
+        [SessionContainerElement::Session(inner)] if inner.title_text().trim() == "Inner"
+    ));
+}
+
+/// Quotes hold a ContentContainer, which may not nest sessions
+#[test]
+fn test_quote_rejects_nested_session() {
+    let quote =
+        HighLevelTokenBuilder::quote_with_tokens(vec![], line_span(0), ScannerTokenSequence::new());
+
+    let result = create_quote_element(&quote, &[session_node("Inner", 1)]);
+
+    let error = result.unwrap_err().to_string();
+    assert!(
+        error.contains("Cannot nest Session inside Quote"),
+        "{}",
+        error
+    );
+}
+
+/// List items hold a ContentContainer, which may not nest sessions
+#[test]
+fn test_list_item_rejects_nested_session() {
+    let item = |text: &str, row: usize| {
+        HighLevelTokenBuilder::sequence_text_line(
+            String::new(),
+            HighLevelTokenBuilder::sequence_marker(
+                txxt::cst::high_level_tokens::HighLevelNumberingStyle::Plain,
+                txxt::cst::high_level_tokens::HighLevelNumberingForm::Regular,
+                "-".to_string(),
+                line_span(row),
+            ),
+            HighLevelTokenBuilder::text_span(text.to_string(), line_span(row)),
+            line_span(row),
+        )
+    };
+
+    let result = create_list_element_with_nesting(&[
+        (item("First", 0), vec![session_node("Inner", 1)]),
+        (item("Second", 3), vec![]),
+    ]);
+
+    let error = result.unwrap_err().to_string();
+    assert!(
+        error.contains("Cannot nest Session inside ListItem"),
+        "{}",
+        error
+    );
+}
@@ -0,0 +1,120 @@
+//! Tests for table element construction
+//!
+//! Tables are parsed end to end: the scanner emits pipes only on lines wrapped
+//! in `|`, semantic analysis splits the rows into cells, and AST construction
+//! picks out the header separator row.
+
+use txxt::ast::elements::session::session_container::SessionContainerElement;
+use txxt::ast::{ColumnAlignment, TableBlock, TableRow};
+
+fn parse_single_table(source: &str) -> TableBlock {
+    let document = txxt::api::run_all_unified(source, None).expect("Failed to parse source");
+
+    document
+        .content
+        .content
+        .into_iter()
+        .find_map(|element| match element {
+            SessionContainerElement::Table(table) => Some(table),
+            _ => None,
+        })
+        .expect("Source should contain a table")
+}
+
+fn row_texts(row: &TableRow) -> Vec<String> {
+    row.cells.iter().map(|cell| cell.text_content()).collect()
+}
+
+#[test]
+fn test_two_by_two_table() {
+    let table = parse_single_table("| a | b |\n| c | d |\n");
+
+    assert!(table.header.is_none());
+    assert_eq!(table.column_count(), 2);
+    assert_eq!(table.rows.len(), 2);
+    assert_eq!(row_texts(&table.rows[0]), vec!["a", "b"]);
+    assert_eq!(row_texts(&table.rows[1]), vec!["c", "d"]);
+    assert_eq!(table.alignments, vec![ColumnAlignment::Default; 2]);
+}
+
+#[test]
+fn test_header_and_alignment_markers() {
+    let source = "| Left | Center | Right | Plain |\n\
+                  |:-----|:------:|------:|-------|\n\
+                  | 1 | 2 | 3 | 4 |\n";
+    let table = parse_single_table(source);
+
+    let header = table.header.as_ref().expect("Separator row marks a header");
+    assert_eq!(row_texts(header), vec!["Left", "Center", "Right", "Plain"]);
+    assert_eq!(table.rows.len(), 1);
+    assert_eq!(row_texts(&table.rows[0]), vec!["1", "2", "3", "4"]);
+    assert_eq!(
+        table.alignments,
+        vec![
+            ColumnAlignment::Left,
+            ColumnAlignment::Center,
+            ColumnAlignment::Right,
+            ColumnAlignment::Default,
+        ]
+    );
+}
+
+#[test]
+fn test_ragged_rows_are_padded() {
+    let source = "| a | b | c |\n\
+                  | d |\n\
+                  | e | f |\n";
+    let table = parse_single_table(source);
+
+    assert_eq!(table.column_count(), 3);
+    assert_eq!(row_texts(&table.rows[0]), vec!["a", "b", "c"]);
+    assert_eq!(row_texts(&table.rows[1]), vec!["d", "", ""]);
+    assert_eq!(row_texts(&table.rows[2]), vec!["e", "f", ""]);
+    assert!(table.rows[1].cells[2].tokens.tokens.is_empty());
+}
+
+#[test]
+fn test_cells_hold_inline_content() {
+    let table = parse_single_table("| *bold* | `code` |\n");
+
+    let cells = &table.rows[0].cells;
+    assert_eq!(cells[0].text_content(), "bold");
+    assert_eq!(cells[1].text_content(), "code");
+}
+
+#[test]
+fn test_table_inside_session() {
+    let source = "Results\n\n    | name | score |\n    |---|---|\n    | ada | 10 |\n";
+    let document = txxt::api::run_all_unified(source, None).expect("Failed to parse source");
+
+    let session = match &document.content.content[0] {
+        SessionContainerElement::Session(session) => session,
+        other => panic!("Expected a session, got {:?}", other),
+    };
+    let table = session
+        .content
+        .content
+        .iter()
+        .find_map(|element| match element {
+            SessionContainerElement::Table(table) => Some(table),
+            _ => None,
+        })
+        .expect("Session should contain a table");
+
+    assert_eq!(
+        row_texts(table.header.as_ref().unwrap()),
+        vec!["name", "score"]
+    );
+    assert_eq!(row_texts(&table.rows[0]), vec!["ada", "10"]);
+}
+
+#[test]
+fn test_pipes_in_text_are_not_tables() {
+    let document = txxt::api::run_all_unified("The |x| function and a|b.\n", None)
+        .expect("Failed to parse source");
+
+    assert!(matches!(
+        document.content.content[0],
+        SessionContainerElement::Paragraph(_)
+    ));
+}
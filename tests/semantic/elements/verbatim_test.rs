@@ -5,7 +5,9 @@
 
 use txxt::cst::high_level_tokens::HighLevelTokenBuilder;
 use txxt::cst::{Position, SourceSpan};
-use txxt::semantic::elements::verbatim::create_verbatim_element;
+use txxt::semantic::elements::verbatim::{create_verbatim_element, create_verbatim_element_with};
+use txxt::syntax::core::INDENT_SIZE;
+use txxt::syntax::VerbatimOptions;
 
 /// Test that verbatim elements are created correctly from verbatim block tokens
 #[test]
@@ -79,3 +81,267 @@ fn test_create_verbatim_element_rejects_wrong_token() {
     let result = create_verbatim_element(&plain_token);
     assert!(result.is_err());
 }
+
+/// Test that in-flow content is stripped to one indent level past the title,
+/// regardless of how deeply the verbatim block itself is nested
+#[rstest::rstest]
+#[case::top_level(0)]
+#[case::one_level(1)]
+#[case::two_levels(2)]
+fn test_create_verbatim_element_strips_wall_at_depth(#[case] depth: usize) {
+    use txxt::syntax::core::INDENT_SIZE;
+
+    let title_indent = depth * INDENT_SIZE;
+    let wall = " ".repeat(title_indent + INDENT_SIZE);
+
+    let span = SourceSpan {
+        start: Position { row: 0, column: 0 },
+        end: Position { row: 3, column: 0 },
+    };
+
+    let verbatim_token = HighLevelTokenBuilder::verbatim_block(
+        HighLevelTokenBuilder::text_span("Code Example".to_string(), span.clone()),
+        HighLevelTokenBuilder::text_span(String::new(), span.clone()),
+        vec![
+            HighLevelTokenBuilder::ignore_line(format!("{}fn main() {{", wall), span.clone()),
+            HighLevelTokenBuilder::ignore_line(format!("{}    body();", wall), span.clone()),
+        ],
+        HighLevelTokenBuilder::label("rust".to_string(), span.clone()),
+        None,
+        txxt::cst::WallType::InFlow(title_indent),
+        span,
+    );
+
+    let verbatim_block = create_verbatim_element(&verbatim_token).unwrap();
+    let lines: Vec<&str> = verbatim_block
+        .content
+        .ignore_lines
+        .iter()
+        .map(|line| line.content())
+        .collect();
+
+    // Indentation beyond the wall belongs to the content and must survive
+    assert_eq!(lines, vec!["fn main() {", "    body();"]);
+    assert_eq!(verbatim_block.base_indent, wall.len());
+}
+
+/// Build an unlabeled verbatim token around the given content lines
+fn unlabeled_verbatim(lines: &[&str]) -> txxt::cst::HighLevelToken {
+    let span = SourceSpan {
+        start: Position { row: 0, column: 0 },
+        end: Position { row: 3, column: 0 },
+    };
+
+    HighLevelTokenBuilder::verbatim_block(
+        HighLevelTokenBuilder::text_span("Snippet".to_string(), span.clone()),
+        HighLevelTokenBuilder::text_span(String::new(), span.clone()),
+        lines
+            .iter()
+            .map(|line| HighLevelTokenBuilder::ignore_line(format!("    {}", line), span.clone()))
+            .collect(),
+        HighLevelTokenBuilder::text_span(String::new(), span.clone()),
+        None,
+        txxt::cst::WallType::InFlow(0),
+        span,
+    )
+}
+
+/// Test that unlabeled content gets a detected language without touching the label
+#[rstest::rstest]
+#[case::shebang(&["#!/usr/bin/env python3", "print('hi')"], Some("python"))]
+#[case::shell(&["#!/bin/bash", "echo hi"], Some("shell"))]
+#[case::rust(&["fn main() {", "    println!(\"hi\");", "}"], Some("rust"))]
+#[case::python_import(&["import os.path as osp", "print(osp.sep)"], Some("python"))]
+#[case::python_from_import(&["from collections import deque"], Some("python"))]
+#[case::js_default_import(&["import React from 'react';", "render();"], None)]
+#[case::ts_named_import(&["import { useState } from \"react\"", "useState(0)"], None)]
+#[case::js_side_effect_import(&["import './styles.css'"], None)]
+#[case::unrecognized(&["just some notes", "nothing to see"], None)]
+fn test_create_verbatim_element_detects_language(
+    #[case] lines: &[&str],
+    #[case] expected: Option<&str>,
+) {
+    let verbatim_block = create_verbatim_element(&unlabeled_verbatim(lines)).unwrap();
+
+    assert_eq!(verbatim_block.label, "");
+    assert_eq!(verbatim_block.detected_language.as_deref(), expected);
+}
+
+/// Test that an explicit label suppresses detection
+#[test]
+fn test_create_verbatim_element_label_wins_over_detection() {
+    let span = SourceSpan {
+        start: Position { row: 0, column: 0 },
+        end: Position { row: 1, column: 0 },
+    };
+
+    let verbatim_token = HighLevelTokenBuilder::verbatim_block(
+        HighLevelTokenBuilder::text_span("Snippet".to_string(), span.clone()),
+        HighLevelTokenBuilder::text_span(String::new(), span.clone()),
+        vec![HighLevelTokenBuilder::ignore_line(
+            "    #!/bin/sh".to_string(),
+            span.clone(),
+        )],
+        HighLevelTokenBuilder::label("console".to_string(), span.clone()),
+        None,
+        txxt::cst::WallType::InFlow(0),
+        span,
+    );
+
+    let verbatim_block = create_verbatim_element(&verbatim_token).unwrap();
+    assert_eq!(verbatim_block.label, "console");
+    assert_eq!(verbatim_block.detected_language, None);
+}
+
+/// Test that content lines are numbered from 1, counting interior blank lines
+#[test]
+fn test_create_verbatim_element_numbers_lines() {
+    let span = SourceSpan {
+        start: Position { row: 0, column: 0 },
+        end: Position { row: 4, column: 0 },
+    };
+
+    let verbatim_token = HighLevelTokenBuilder::verbatim_block(
+        HighLevelTokenBuilder::text_span("Snippet".to_string(), span.clone()),
+        HighLevelTokenBuilder::text_span(String::new(), span.clone()),
+        vec![
+            HighLevelTokenBuilder::ignore_line("    first".to_string(), span.clone()),
+            HighLevelTokenBuilder::blank_line(span.clone()),
+            HighLevelTokenBuilder::ignore_line("    third".to_string(), span.clone()),
+        ],
+        HighLevelTokenBuilder::label("text".to_string(), span.clone()),
+        None,
+        txxt::cst::WallType::InFlow(0),
+        span,
+    );
+
+    let verbatim_block = create_verbatim_element(&verbatim_token).unwrap();
+    assert_eq!(
+        verbatim_block.numbered_lines(),
+        vec![(1, "first"), (2, ""), (3, "third")]
+    );
+}
+
+/// Test that a nested in-flow block keeps its wall, so its content can be
+/// put back at the source indentation
+#[test]
+fn test_nested_in_flow_verbatim_reconstructs_indentation() {
+    use txxt::ast::elements::session::session_container::SessionContainerElement;
+
+    let content = [
+        "        fn main() {",
+        "            body();",
+        "",
+        "        }",
+    ];
+    let source = format!(
+        "1. Session\n\n    Intro paragraph.\n\n    Example:\n{}\n    :: rust\n",
+        content.join("\n")
+    );
+
+    let document = txxt::api::run_all_unified(&source, None).expect("Failed to parse source");
+    let session = match &document.content.content[0] {
+        SessionContainerElement::Session(session) => session,
+        other => panic!("Expected a session, got {:?}", other),
+    };
+    let verbatim_block = session
+        .content
+        .content
+        .iter()
+        .find_map(|element| match element {
+            SessionContainerElement::Verbatim(verbatim) => Some(verbatim),
+            _ => None,
+        })
+        .expect("Session should contain a verbatim block");
+
+    assert_eq!(verbatim_block.base_indent, 8);
+    assert_eq!(verbatim_block.source_lines(), content);
+}
+
+/// Build a verbatim token whose content has blank lines where `lines` has ""
+fn verbatim_with_blanks(lines: &[&str]) -> txxt::cst::HighLevelToken {
+    let span = SourceSpan {
+        start: Position { row: 0, column: 0 },
+        end: Position { row: 6, column: 0 },
+    };
+
+    HighLevelTokenBuilder::verbatim_block(
+        HighLevelTokenBuilder::text_span("Snippet".to_string(), span.clone()),
+        HighLevelTokenBuilder::text_span(String::new(), span.clone()),
+        lines
+            .iter()
+            .map(|line| match *line {
+                "" => HighLevelTokenBuilder::blank_line(span.clone()),
+                line => HighLevelTokenBuilder::ignore_line(format!("    {}", line), span.clone()),
+            })
+            .collect(),
+        HighLevelTokenBuilder::label("text".to_string(), span.clone()),
+        None,
+        txxt::cst::WallType::InFlow(0),
+        span,
+    )
+}
+
+/// Test that blank lines at the content edges are trimmed on request, and
+/// interior ones are always kept
+#[rstest::rstest]
+#[case::leading(&["", "", "first", "second"], vec![(1, "first"), (2, "second")])]
+#[case::trailing(&["first", "second", ""], vec![(1, "first"), (2, "second")])]
+#[case::interior(&["first", "", "third"], vec![(1, "first"), (2, ""), (3, "third")])]
+#[case::all(&["", "first", "", "third", "", ""], vec![(1, "first"), (2, ""), (3, "third")])]
+#[case::only_blanks(&["", ""], vec![])]
+fn test_create_verbatim_element_trims_blank_edges(
+    #[case] lines: &[&str],
+    #[case] expected: Vec<(usize, &str)>,
+) {
+    let options = VerbatimOptions {
+        trim_blank_edges: true,
+    };
+    let verbatim_block =
+        create_verbatim_element_with(&verbatim_with_blanks(lines), INDENT_SIZE, options).unwrap();
+
+    assert_eq!(verbatim_block.numbered_lines(), expected);
+}
+
+/// Test that blank lines at the content edges are kept by default
+#[test]
+fn test_create_verbatim_element_keeps_blank_edges_by_default() {
+    let verbatim_block =
+        create_verbatim_element(&verbatim_with_blanks(&["", "first", ""])).unwrap();
+
+    assert_eq!(
+        verbatim_block.numbered_lines(),
+        vec![(1, ""), (2, "first"), (3, "")]
+    );
+}
+
+/// Test that the parse options reach verbatim construction
+#[test]
+fn test_verbatim_options_in_parse_options() {
+    use txxt::api::{run_all_with_options, ParseOptions};
+    use txxt::ast::elements::session::session_container::SessionContainerElement;
+
+    let source = "Intro paragraph.\n\nExample:\n\n    let x = 1;\n\n    let y = 2;\n\n:: rust\n";
+    let options = ParseOptions {
+        verbatim: VerbatimOptions {
+            trim_blank_edges: true,
+        },
+        ..ParseOptions::default()
+    };
+
+    let document = run_all_with_options(source, None, options).unwrap();
+    let verbatim_block = document
+        .content
+        .content
+        .iter()
+        .find_map(|element| match element {
+            SessionContainerElement::Verbatim(verbatim) => Some(verbatim),
+            _ => None,
+        })
+        .expect("Document should contain a verbatim block");
+
+    assert_eq!(
+        verbatim_block.numbered_lines(),
+        vec![(1, "let x = 1;"), (2, ""), (3, "let y = 2;")]
+    );
+}
@@ -44,7 +44,8 @@
 //! ├── tools_treeviz/              # Tests for tree visualization tool
 //! ├── verbatim_scanner/           # Tests for verbatim scanner
 //! ├── integration/                # Integration and example tests
-//! └── infrastructure/             # Shared test infrastructure
+//! ├── infrastructure/             # Shared test infrastructure
+//! └── support/                    # Shared test helpers
 //! ```
 //!
 //! See `docs/dev/file-layout.txxt` for complete documentation.
@@ -54,18 +55,22 @@
 // Test directories - organized to mirror the src/ structure
 mod assembler;
 mod assertions;
+mod ast_elements;
 mod ast_query;
 mod lexer;
 mod parser;
 mod semantic;
 mod tokenizer;
 // mod tools_detokenizer; // DISABLED: Needs rewrite for Vec<ScannerToken>
+mod tools_format;
+mod tools_token_dump;
 mod tools_treeviz;
 mod verbatim_scanner;
 
 // Integration and infrastructure
 mod infrastructure;
 mod integration;
+mod support;
 
 // Individual test files that aren't included elsewhere
 mod testing_framework_check;
@@ -0,0 +1,6 @@
+//! Shared test helpers
+//!
+//! Assertions used by tests across several areas, built on the corpora
+//! loaded by `infrastructure`.
+
+pub mod round_trip;
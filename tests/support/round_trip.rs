@@ -0,0 +1,170 @@
+//! Round-trip checks for scanner tokens
+//!
+//! The detokenizer is disabled until it is rewritten for `Vec<ScannerToken>`,
+//! so these helpers check the property it relied on directly: read in order,
+//! the tokens cover the whole source, each one starting where the previous
+//! one ended, and each token's text is exactly the source under its span.
+//! Indent and Eof carry no text and must sit where the next token starts.
+//! Dedent carries no text either, and the lexer places it by the indentation
+//! it closes rather than by the text around it, so its position is not
+//! checked. A tokenizer that drops, duplicates or misplaces text fails here
+//! with the offending token.
+
+use txxt::api::Output;
+use txxt::cst::{Position, ScannerToken};
+use txxt::syntax::elements::references::footnote_ref::FootnoteType;
+
+use crate::infrastructure::corpora::{ProcessedData, ProcessingStage, TxxtCorpora};
+
+/// Load a spec corpus at the scanner token stage and assert it round-trips
+pub fn assert_corpus_round_trips(name: &str) {
+    let corpus = TxxtCorpora::load_with_processing(name, ProcessingStage::ScannerTokens)
+        .unwrap_or_else(|e| panic!("Failed to load corpus {}: {:?}", name, e));
+
+    let tokens = match &corpus.processed_data {
+        Some(ProcessedData::Output(Output::ScannerTokens(tokens))) => tokens,
+        _ => panic!("Corpus {} was not processed to scanner tokens", name),
+    };
+
+    if let Err(message) = verify_round_trip(&corpus.source_text, tokens) {
+        panic!(
+            "Corpus {} does not round-trip: {}\nSource:\n{}",
+            name, message, corpus.source_text
+        );
+    }
+}
+
+/// Check that the tokens, in order, write out exactly `source`
+pub fn verify_round_trip(source: &str, tokens: &[ScannerToken]) -> Result<(), String> {
+    let mut cursor = 0;
+
+    for token in tokens {
+        if matches!(token, ScannerToken::Dedent { .. }) {
+            continue;
+        }
+        let span = token.span();
+        let start = byte_offset(source, span.start)
+            .ok_or_else(|| format!("{:?} starts outside the source", token))?;
+        let end = byte_offset(source, span.end)
+            .ok_or_else(|| format!("{:?} ends outside the source", token))?;
+
+        if start != cursor {
+            return Err(format!(
+                "{:?} starts at byte {}, but the previous token ended at byte {}",
+                token, start, cursor
+            ));
+        }
+
+        let Some(expected) = written_form(token) else {
+            continue;
+        };
+        let actual = &source[start..end];
+        if !expected.matches(actual) {
+            return Err(format!(
+                "{:?} covers {:?} in the source, expected {:?}",
+                token, actual, expected
+            ));
+        }
+        cursor = end;
+
+        // The lexer consumes the space after a list marker without a token
+        if matches!(token, ScannerToken::SequenceMarker { .. }) && source[end..].starts_with(' ') {
+            cursor += 1;
+        }
+    }
+
+    if cursor != source.len() {
+        return Err(format!(
+            "Tokens stop at byte {}, leaving {:?} uncovered",
+            cursor,
+            &source[cursor..]
+        ));
+    }
+    Ok(())
+}
+
+/// How a token is written in the source
+#[derive(Debug)]
+enum Written {
+    /// Exactly this text
+    Exact(String),
+    /// A line ending
+    LineEnd,
+    /// A whole line, line ending included, whose words are these
+    ///
+    /// Verbatim boundary tokens keep a title or label but not the spacing
+    /// around it.
+    Line(String),
+}
+
+impl Written {
+    fn matches(&self, actual: &str) -> bool {
+        match self {
+            Written::Exact(text) => actual == text,
+            Written::LineEnd => actual == "\n" || actual == "\r\n",
+            Written::Line(words) => {
+                let line = actual
+                    .strip_suffix('\n')
+                    .map(|line| line.strip_suffix('\r').unwrap_or(line));
+                line.is_some_and(|line| line.split_whitespace().eq(words.split_whitespace()))
+            }
+        }
+    }
+}
+
+/// The written form of a token, or None for tokens that carry no text
+fn written_form(token: &ScannerToken) -> Option<Written> {
+    let exact = |text: String| Some(Written::Exact(text));
+    match token {
+        ScannerToken::Indent { .. } | ScannerToken::Eof { .. } => None,
+        ScannerToken::Newline { .. } => Some(Written::LineEnd),
+        ScannerToken::BlankLine { whitespace, .. } => {
+            Some(Written::Exact(format!("{}\n", whitespace)))
+        }
+        ScannerToken::RefMarker { content, .. } => exact(format!("[{}]", content)),
+        ScannerToken::CitationRef { content, .. } => exact(format!("[@{}]", content)),
+        ScannerToken::PageRef { content, .. } => exact(format!("[p.{}]", content)),
+        ScannerToken::SessionRef { content, .. } => exact(format!("[#{}]", content)),
+        ScannerToken::FootnoteRef { footnote_type, .. } => exact(match footnote_type {
+            FootnoteType::Naked(number) => format!("[{}]", number),
+            FootnoteType::Labeled(label) => format!("[^{}]", label),
+            FootnoteType::Auto => "[^]".to_string(),
+            FootnoteType::Inline(text) => format!("[^: {}]", text),
+        }),
+        ScannerToken::VerbatimBlockStart { title, .. } => {
+            Some(Written::Line(format!("{}:", title)))
+        }
+        ScannerToken::VerbatimContentLine {
+            content,
+            indentation,
+            ..
+        } => exact(format!("{}{}\n", indentation, content)),
+        ScannerToken::VerbatimBlockEnd {
+            truncated: true, ..
+        } => exact(String::new()),
+        ScannerToken::VerbatimBlockEnd { label_raw, .. } => {
+            Some(Written::Line(format!(":: {} ::", label_raw)))
+        }
+        _ => exact(token.content().to_string()),
+    }
+}
+
+/// Byte offset of a position, counting columns in characters
+fn byte_offset(source: &str, position: Position) -> Option<usize> {
+    let line_start = if position.row == 0 {
+        0
+    } else {
+        source
+            .match_indices('\n')
+            .nth(position.row - 1)
+            .map(|(index, _)| index + 1)?
+    };
+    let line = &source[line_start..];
+    let line = &line[..line.find('\n').map_or(line.len(), |end| end + 1)];
+    let column = match line.char_indices().nth(position.column) {
+        Some((index, _)) => index,
+        None if line.chars().count() == position.column => line.len(),
+        None => return None,
+    };
+    Some(line_start + column)
+}
@@ -1,2 +1,4 @@
 mod test_indentation_wall_consistency;
 mod test_leading_whitespace;
+mod test_round_trip;
+mod test_token_cursor;
@@ -201,6 +201,7 @@ fn verify_content_has_no_leading_spaces(
 }
 
 #[test]
+#[allow(clippy::collapsible_match)]
 fn test_wall_nested_lists_and_sessions() {
     // This specific section has complex nesting:
     // - Sessions with nested sessions
@@ -276,6 +277,7 @@ fn test_wall_nested_lists_and_sessions() {
 }
 
 #[test]
+#[allow(clippy::collapsible_match)]
 fn test_wall_top_level_has_no_indentation_chars() {
     // Top-level content should have EMPTY indentation_chars
     // (unless it's indented content like inside a definition)
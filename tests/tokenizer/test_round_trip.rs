@@ -0,0 +1,73 @@
+//! Scanner token round-trip tests over the spec corpora
+
+use crate::support::round_trip::{assert_corpus_round_trips, verify_round_trip};
+
+#[rstest::rstest]
+#[case::paragraph_simple("txxt.core.spec.paragraph.valid.simple")]
+#[case::paragraph_multiple_with_blanks("txxt.core.spec.paragraph.valid.multiple-with-blanks")]
+#[case::paragraph_with_formatting("txxt.core.spec.paragraph.valid.with-formatting")]
+#[case::paragraph_multiline("txxt.core.spec.paragraph.valid.multiline")]
+#[case::paragraph_edge_whitespace_only_line("txxt.core.spec.paragraph.edge.whitespace-only-line")]
+#[case::paragraph_consistent_indent("txxt.core.spec.paragraph.valid.consistent-indent")]
+#[case::paragraph_invalid_inconsistent_indent(
+    "txxt.core.spec.paragraph.invalid.inconsistent-indent"
+)]
+#[case::paragraph_complete_inline_elements(
+    "txxt.core.spec.paragraph.valid.complete-inline-elements"
+)]
+#[case::paragraph_invalid_broken_inline("txxt.core.spec.paragraph.invalid.broken-inline")]
+#[case::list_plain_flat("txxt.core.spec.list.valid.plain-flat")]
+#[case::list_numerical_flat("txxt.core.spec.list.valid.numerical-flat")]
+#[case::list_alphabetical_flat("txxt.core.spec.list.valid.alphabetical-flat")]
+#[case::list_roman_flat("txxt.core.spec.list.valid.roman-flat")]
+#[case::list_nested_mixed_styles("txxt.core.spec.list.valid.nested-mixed-styles")]
+#[case::list_multi_level_nesting("txxt.core.spec.list.valid.multi-level-nesting")]
+#[case::list_templated("txxt.core.spec.list.valid.templated")]
+#[case::list_edge_single_item_top_level("txxt.core.spec.list.edge.single-item-top-level")]
+#[case::list_inconsistent_numbering("txxt.core.spec.list.valid.inconsistent-numbering")]
+#[case::list_mixed_marker_styles("txxt.core.spec.list.valid.mixed-marker-styles")]
+#[case::list_nested_single_item("txxt.core.spec.list.valid.nested-single-item")]
+#[case::verbatim_inflow_basic("txxt.core.spec.verbatim.valid.inflow-basic")]
+#[case::verbatim_stretched_basic("txxt.core.spec.verbatim.valid.stretched-basic")]
+fn test_corpus_round_trips(#[case] name: &str) {
+    assert_corpus_round_trips(name);
+}
+
+#[test]
+fn test_paragraph_with_link_round_trips() {
+    use txxt::cst::ScannerToken;
+
+    let source = "Read [the guide](https://example.com/guide) before starting.\n";
+    let tokens = txxt::syntax::tokenize(source);
+
+    let contents: Vec<&str> = tokens.iter().map(|token| token.content()).collect();
+    assert_eq!(
+        contents,
+        vec![
+            "Read",
+            " ",
+            "the guide",
+            "(",
+            "https",
+            ":",
+            "//example",
+            ".",
+            "com/guide",
+            ")",
+            " ",
+            "before",
+            " ",
+            "starting",
+            ".",
+            "\n",
+            "",
+        ]
+    );
+    assert!(matches!(tokens[2], ScannerToken::RefMarker { .. }));
+    assert!(matches!(tokens[3], ScannerToken::LeftParen { .. }));
+    assert!(matches!(tokens[9], ScannerToken::RightParen { .. }));
+
+    if let Err(message) = verify_round_trip(source, &tokens) {
+        panic!("Link paragraph does not round-trip: {}", message);
+    }
+}
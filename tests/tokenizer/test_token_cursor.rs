@@ -0,0 +1,96 @@
+//! TokenCursor navigation tests
+
+use txxt::cst::{Position, ScannerToken, SourceSpan, TokenCursor};
+
+fn span(column: usize) -> SourceSpan {
+    SourceSpan {
+        start: Position { row: 0, column },
+        end: Position {
+            row: 0,
+            column: column + 1,
+        },
+    }
+}
+
+/// `[ key ]` as scanner tokens
+fn bracketed() -> Vec<ScannerToken> {
+    vec![
+        ScannerToken::LeftBracket { span: span(0) },
+        ScannerToken::Whitespace {
+            content: " ".to_string(),
+            span: span(1),
+        },
+        ScannerToken::Text {
+            content: "key".to_string(),
+            span: span(2),
+        },
+        ScannerToken::RightBracket { span: span(5) },
+    ]
+}
+
+fn is_whitespace(token: &ScannerToken) -> bool {
+    matches!(token, ScannerToken::Whitespace { .. })
+}
+
+#[test]
+fn test_peek_does_not_consume() {
+    let tokens = bracketed();
+    let cursor = TokenCursor::new(&tokens);
+
+    assert_eq!(cursor.peek(), Some(&tokens[0]));
+    assert_eq!(cursor.peek(), Some(&tokens[0]));
+    assert_eq!(cursor.peek_nth(2), Some(&tokens[2]));
+    assert_eq!(cursor.position(), 0);
+}
+
+#[test]
+fn test_next_advances_to_end() {
+    let tokens = bracketed();
+    let mut cursor = TokenCursor::new(&tokens);
+
+    for token in &tokens {
+        assert_eq!(cursor.next(), Some(token));
+    }
+    assert_eq!(cursor.next(), None);
+    assert!(cursor.is_at_end());
+    assert_eq!(cursor.source_position(), None);
+}
+
+#[test]
+fn test_expect_and_consume_while() {
+    let tokens = bracketed();
+    let mut cursor = TokenCursor::new(&tokens);
+
+    cursor
+        .expect(|t| matches!(t, ScannerToken::LeftBracket { .. }), "[")
+        .unwrap();
+    assert_eq!(cursor.consume_while(is_whitespace).len(), 1);
+    assert_eq!(
+        cursor.source_position(),
+        Some(Position { row: 0, column: 2 })
+    );
+
+    let key = cursor
+        .expect(|t| matches!(t, ScannerToken::Text { .. }), "key")
+        .unwrap();
+    assert_eq!(key.content(), "key");
+    assert_eq!(cursor.remaining().len(), 1);
+}
+
+#[test]
+fn test_expect_failure_leaves_cursor_in_place() {
+    let tokens = bracketed();
+    let mut cursor = TokenCursor::new(&tokens);
+
+    let error = cursor
+        .expect(|t| matches!(t, ScannerToken::RightBracket { .. }), "]")
+        .unwrap_err();
+    assert_eq!(error.expected, "]");
+    assert_eq!(error.found, Some(tokens[0].clone()));
+    assert_eq!(error.index, 0);
+    assert_eq!(cursor.position(), 0);
+
+    cursor.reset(tokens.len());
+    let error = cursor.expect(|_| true, "anything").unwrap_err();
+    assert_eq!(error.found, None);
+}
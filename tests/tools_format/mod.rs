@@ -0,0 +1,185 @@
+//! Tests for source formatting
+
+use txxt::tools::format::{format_source, FormatOptions};
+
+fn reflow(source: &str, width: usize) -> String {
+    let options = FormatOptions {
+        wrap_width: Some(width),
+        ..FormatOptions::default()
+    };
+    format_source(source, &options).unwrap()
+}
+
+#[test]
+fn test_long_paragraph_is_reflowed_to_width() {
+    let source = "This paragraph is written on one long line that goes well past forty columns before it ends.\n";
+
+    let formatted = reflow(source, 40);
+
+    assert_eq!(
+        formatted,
+        "This paragraph is written on one long\n\
+         line that goes well past forty columns\n\
+         before it ends.\n"
+    );
+    assert!(formatted.lines().all(|line| line.chars().count() <= 40));
+}
+
+#[test]
+fn test_short_lines_are_joined() {
+    let source = "Short\nlines get\njoined.\n";
+
+    assert_eq!(reflow(source, 40), "Short lines get joined.\n");
+}
+
+#[test]
+fn test_code_and_bold_spans_are_not_broken() {
+    let source = "Run the command `cargo test --workspace --all-targets` and read *the whole output carefully* first.\n";
+
+    let formatted = reflow(source, 40);
+
+    assert!(formatted
+        .lines()
+        .any(|line| line.contains("`cargo test --workspace --all-targets`")));
+    assert!(formatted
+        .lines()
+        .any(|line| line.contains("*the whole output carefully*")));
+    assert_eq!(
+        formatted.split_whitespace().collect::<Vec<_>>(),
+        source.split_whitespace().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_nested_paragraph_keeps_its_indentation() {
+    let source =
+        "1. Session\n\n    A paragraph inside the session that is long enough to need wrapping.\n";
+
+    assert_eq!(
+        reflow(source, 40),
+        "1. Session\n\n    A paragraph inside the session that\n    is long enough to need wrapping.\n"
+    );
+}
+
+#[test]
+fn test_two_space_hard_break_is_kept() {
+    let source = "Roses are red,  \nviolets are blue.\n";
+
+    assert_eq!(reflow(source, 40), source);
+}
+
+#[test]
+fn test_lines_between_hard_breaks_are_reflowed() {
+    let source = "First line\nends here.  \nSecond part\nis joined too.\n";
+
+    assert_eq!(
+        reflow(source, 40),
+        "First line ends here.  \nSecond part is joined too.\n"
+    );
+}
+
+#[test]
+fn test_backslash_hard_break_is_kept() {
+    let source = "One line\\\nand the next\nline.\n";
+
+    assert_eq!(reflow(source, 40), "One line\\\nand the next line.\n");
+}
+
+#[test]
+fn test_escaped_backslash_is_not_a_break() {
+    let source = "A path like C:\\\\\nwraps as text.\n";
+
+    assert_eq!(reflow(source, 40), "A path like C:\\\\ wraps as text.\n");
+}
+
+#[test]
+fn test_list_marker_never_starts_a_line() {
+    let source = "The steps, in order, go from one - to 1. then two.\n";
+
+    // `-` would start the second line at this width; it stays behind
+    // instead, one column over
+    assert_eq!(
+        reflow(source, 33),
+        "The steps, in order, go from one -\nto 1. then two.\n"
+    );
+}
+
+#[test]
+fn test_verbatim_content_is_left_alone() {
+    let source = "Code:\n    let value = some_function_with_a_long_name(first_argument, second_argument);\n:: rust ::\n";
+
+    assert_eq!(reflow(source, 40), source);
+}
+
+#[test]
+fn test_without_width_source_is_unchanged() {
+    let source = "Short\nlines.\n";
+
+    assert_eq!(
+        format_source(source, &FormatOptions::default()).unwrap(),
+        source
+    );
+}
+
+fn space_blocks(source: &str, between_blocks: usize, before_session: usize) -> String {
+    let options = FormatOptions {
+        blank_lines_between_blocks: Some(between_blocks),
+        blank_lines_before_session: Some(before_session),
+        ..FormatOptions::default()
+    };
+    format_source(source, &options).unwrap()
+}
+
+#[test]
+fn test_inconsistent_blank_lines_are_normalized() {
+    let source = "First paragraph.\n\n\n\nSecond paragraph.\n\n1. Session\n\n\n    Inside.\n\n    - one\n    - two\n";
+
+    assert_eq!(
+        space_blocks(source, 1, 2),
+        "First paragraph.\n\nSecond paragraph.\n\n\n1. Session\n\n    Inside.\n\n    - one\n    - two\n"
+    );
+}
+
+#[test]
+fn test_blank_line_counts_are_respected() {
+    let source = "First paragraph.\n\nSecond paragraph.\n\n\n\n1. Session\n\n    Inside.\n";
+
+    assert_eq!(
+        space_blocks(source, 2, 1),
+        "First paragraph.\n\n\nSecond paragraph.\n\n1. Session\n\n\n    Inside.\n"
+    );
+}
+
+#[test]
+fn test_blocks_keep_at_least_one_blank_line() {
+    let source = "First paragraph.\n\n\nSecond paragraph.\n";
+
+    assert_eq!(
+        space_blocks(source, 0, 0),
+        "First paragraph.\n\nSecond paragraph.\n"
+    );
+}
+
+#[test]
+fn test_blank_lines_in_verbatim_are_left_alone() {
+    let source = "Intro.\n\n\nCode:\n    first();\n\n\n    second();\n:: rust ::\n";
+
+    assert_eq!(
+        space_blocks(source, 1, 1),
+        "Intro.\n\nCode:\n    first();\n\n\n    second();\n:: rust ::\n"
+    );
+}
+
+#[test]
+fn test_unset_blank_line_option_keeps_runs() {
+    let source = "First paragraph.\n\n\nSecond paragraph.\n\n\n1. Session\n\n    Inside.\n";
+    let options = FormatOptions {
+        blank_lines_before_session: Some(1),
+        ..FormatOptions::default()
+    };
+
+    assert_eq!(
+        format_source(source, &options).unwrap(),
+        "First paragraph.\n\n\nSecond paragraph.\n\n1. Session\n\n    Inside.\n"
+    );
+}
@@ -0,0 +1,35 @@
+//! Tests for the token dump
+
+use txxt::tools::token_dump::dump_tokens;
+
+#[test]
+fn test_two_lines_are_grouped_by_row() {
+    let dump = dump_tokens("Hello world\nBye\n");
+
+    assert_eq!(
+        dump,
+        "row 0:\n\
+         \x20 Text                0:0-0:5     \"Hello\"\n\
+         \x20 Whitespace          0:5-0:6     \" \"\n\
+         \x20 Text                0:6-0:11    \"world\"\n\
+         \x20 Newline             0:11-1:0    \"\\n\"\n\
+         row 1:\n\
+         \x20 Text                1:0-1:3     \"Bye\"\n\
+         \x20 Newline             1:3-2:0     \"\\n\"\n\
+         row 2:\n\
+         \x20 Eof                 2:0-2:0     \"\"\n"
+    );
+}
+
+#[test]
+fn test_newline_ends_each_row() {
+    let dump = dump_tokens("a\nb\n");
+
+    let newlines: Vec<&str> = dump
+        .lines()
+        .filter(|line| line.trim_start().starts_with("Newline"))
+        .collect();
+    assert_eq!(newlines.len(), 2);
+    assert!(newlines[0].contains("0:1-1:0"));
+    assert!(newlines[1].contains("1:1-2:0"));
+}
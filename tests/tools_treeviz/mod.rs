@@ -665,6 +665,92 @@ mod tests {
         assert!(output.contains("author=test"));
     }
 
+    /// A document node holding one paragraph node with the given text
+    fn paragraph_notation(text: &str) -> NotationData {
+        let mut root = TreeNode::new(
+            "⧉".to_string(),
+            "Document".to_string(),
+            "Document".to_string(),
+        );
+        root.add_child(TreeNode::new(
+            "¶".to_string(),
+            text.to_string(),
+            "ParagraphBlock".to_string(),
+        ));
+        NotationData::new(root, IconConfig::default())
+    }
+
+    /// The content of each rendered line, after the connector and icon
+    fn rendered_contents(output: &str) -> Vec<String> {
+        output
+            .lines()
+            .filter_map(|line| line.split_once("─ "))
+            .filter_map(|(_, node)| node.split_once(' '))
+            .map(|(_icon, content)| content.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_content_max_width_truncates_long_paragraph() {
+        let text = "Long paragraph text makes treeviz lines wrap awkwardly in narrow terminals";
+        let options = RenderOptions {
+            content_max_width: Some(20),
+            ..Default::default()
+        };
+
+        let output = render_with_options(&paragraph_notation(text), &options).unwrap();
+        let contents = rendered_contents(&output);
+
+        // Short content is untouched; the paragraph is cut to exactly 20 characters
+        assert_eq!(contents[0], "Document");
+        assert_eq!(contents[1], "Long paragraph text…");
+        assert_eq!(contents[1].chars().count(), 20);
+        // The tree structure is unchanged
+        assert!(output.lines().nth(1).unwrap().starts_with("    └─ ¶ "));
+    }
+
+    #[test]
+    fn test_content_max_width_boundary() {
+        let text = "exactly twenty chars";
+        assert_eq!(text.chars().count(), 20);
+
+        let fits = RenderOptions {
+            content_max_width: Some(20),
+            ..Default::default()
+        };
+        let output = render_with_options(&paragraph_notation(text), &fits).unwrap();
+        assert_eq!(rendered_contents(&output)[1], text);
+
+        let one_short = RenderOptions {
+            content_max_width: Some(19),
+            ..Default::default()
+        };
+        let output = render_with_options(&paragraph_notation(text), &one_short).unwrap();
+        assert_eq!(rendered_contents(&output)[1], "exactly twenty cha…");
+    }
+
+    #[test]
+    fn test_content_max_width_multibyte_content() {
+        let text = "日本語のテキストと émojis 🎉🎉🎉 mixed ça va";
+        // Every width must cut on a character boundary without panicking
+        for width in 0..=text.chars().count() + 1 {
+            let options = RenderOptions {
+                content_max_width: Some(width),
+                ..Default::default()
+            };
+            let output = render_with_options(&paragraph_notation(text), &options).unwrap();
+            let content = &rendered_contents(&output)[1];
+            assert!(content.chars().count() <= width);
+        }
+
+        let options = RenderOptions {
+            content_max_width: Some(4),
+            ..Default::default()
+        };
+        let output = render_with_options(&paragraph_notation(text), &options).unwrap();
+        assert_eq!(rendered_contents(&output)[1], "日本語…");
+    }
+
     #[test]
     fn test_full_pipeline_with_synthetic_ast() {
         use txxt::ast::elements::inlines::{Text, TextSpan};